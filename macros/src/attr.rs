@@ -0,0 +1,276 @@
+//! The `#[c_enum]` attribute.
+//!
+//! This doesn't generate any code itself. Instead, it checks that the enum
+//! can be represented as a C enum, reporting errors with proper spans if not,
+//! and then forwards it to `c_enum!`.
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Fields, Ident, ItemEnum, Meta, Path, Token, Type};
+
+use crate::generic::{self, GenericEnum};
+
+/// The integer types that can be taken from a `#[repr(...)]` attribute.
+const REPR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// The arguments to the attribute: the inner type followed by any options
+/// that would otherwise be passed using `#[c_enum(...)]`.
+struct Args {
+    inner: Option<Type>,
+    options: TokenStream,
+    /// Whether the `predicates` option was passed. This and `crate = <path>`
+    /// are handled by the attribute itself instead of being passed on to
+    /// `c_enum!`.
+    predicates: bool,
+    /// The path to the `c_enum` crate used within the expansion.
+    krate: Path,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            inner: None,
+            options: TokenStream::new(),
+            predicates: false,
+            krate: syn::parse_quote!(::c_enum),
+        };
+        if input.is_empty() {
+            return Ok(args);
+        }
+
+        args.inner = Some(input.parse()?);
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            args.options = args.take_options(input.parse()?)?;
+        }
+
+        Ok(args)
+    }
+}
+
+impl Args {
+    /// Removes the options handled by the attribute itself from the options
+    /// passed to it, returning the remaining ones.
+    fn take_options(&mut self, options: TokenStream) -> syn::Result<TokenStream> {
+        let mut kept = Vec::new();
+        let mut option = Vec::new();
+
+        let mut tokens = options.into_iter();
+        loop {
+            let token = tokens.next();
+            let end = match &token {
+                Some(TokenTree::Punct(punct)) => punct.as_char() == ',',
+                Some(_) => false,
+                None => true,
+            };
+            if !end {
+                option.extend(token);
+                continue;
+            }
+
+            match option.as_slice() {
+                [TokenTree::Ident(ident)] if ident == "predicates" => self.predicates = true,
+                [TokenTree::Ident(ident), TokenTree::Punct(eq), path @ ..]
+                    if ident == "crate" && eq.as_char() == '=' =>
+                {
+                    self.krate = syn::parse2(path.iter().cloned().collect())?;
+                }
+                [] => (),
+                _ => {
+                    kept.append(&mut option);
+                    kept.extend(token.clone());
+                }
+            }
+            option.clear();
+
+            if token.is_none() {
+                break;
+            }
+        }
+
+        Ok(kept.into_iter().collect())
+    }
+}
+
+pub(crate) fn expand(args: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let args: Args = syn::parse2(args)?;
+    let mut item: ItemEnum = syn::parse2(input)?;
+
+    if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+        return expand_generic(args, item);
+    }
+
+    let mut errors: Option<syn::Error> = None;
+    for variant in &item.variants {
+        if matches!(variant.fields, Fields::Unit) {
+            continue;
+        }
+
+        let error = syn::Error::new(
+            variant.fields.span(),
+            "variants of a c_enum can't have fields",
+        );
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let inner = match args.inner {
+        Some(inner) => inner,
+        None => take_repr(&mut item.attrs).ok_or_else(|| {
+            syn::Error::new(
+                item.ident.span(),
+                "expected an inner type, either as `#[c_enum(u32)]` or as `#[repr(u32)]`",
+            )
+        })?,
+    };
+
+    let options = &args.options;
+    let options = if options.is_empty() {
+        None
+    } else {
+        Some(quote!(#[c_enum(#options)]))
+    };
+
+    let ItemEnum {
+        attrs,
+        vis,
+        ident,
+        variants,
+        ..
+    } = &item;
+    let variants = variants.iter().map(|variant| {
+        let attrs = &variant.attrs;
+        let ident = &variant.ident;
+        let value = variant
+            .discriminant
+            .as_ref()
+            .map(|(eq, value)| quote!(#eq #value));
+        let predicate = if args.predicates && !attrs.iter().any(is_predicate) {
+            let name = ident.to_string();
+            let name = name.strip_prefix("r#").unwrap_or(&name);
+            let method = format_ident!("is_{}", snake_case(name));
+            Some(quote!(#[predicate = #method]))
+        } else {
+            None
+        };
+
+        quote!(#( #attrs )* #predicate #ident #value)
+    });
+
+    let krate = &args.krate;
+    Ok(quote! {
+        #krate::c_enum! {
+            #( #attrs )*
+            #options
+            #vis enum #ident : #inner {
+                #( #variants, )*
+            }
+        }
+    })
+}
+
+/// Expands an enum with a type or lifetime parameter. These can't be passed on to
+/// `c_enum!`, so they are expanded here instead.
+fn expand_generic(args: Args, item: ItemEnum) -> syn::Result<TokenStream> {
+    let inner = args.inner.ok_or_else(|| {
+        syn::Error::new(
+            item.ident.span(),
+            "expected the inner type of the generic enum, e.g. `#[c_enum(T, types(u32))]` or \
+             `#[c_enum(&'a str)]`",
+        )
+    })?;
+
+    if args.predicates {
+        return Err(syn::Error::new(
+            item.ident.span(),
+            "`predicates` is not supported for generic enums",
+        ));
+    }
+
+    let mut attrs = item.attrs;
+    let mut options = generic::take_options(&mut attrs)?;
+    let metas = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args.options)?;
+    generic::parse_options(metas, &mut options)?;
+
+    let item = GenericEnum {
+        attrs,
+        vis: item.vis,
+        ident: item.ident,
+        generics: item.generics,
+        inner,
+        variants: item.variants,
+    };
+    generic::expand(item, options, &args.krate)
+}
+
+fn is_predicate(attr: &Attribute) -> bool {
+    attr.path().is_ident("predicate")
+}
+
+/// Converts the name of a variant to snake case, splitting words the same way
+/// as `rename_all = "snake_case"` does.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            continue;
+        }
+
+        if i > 0 && !out.ends_with('_') {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let boundary = (prev.is_ascii_lowercase() && c.is_ascii_uppercase())
+                || (prev.is_ascii_uppercase()
+                    && c.is_ascii_uppercase()
+                    && next.is_some_and(|next| next.is_ascii_lowercase()));
+            if boundary {
+                out.push('_');
+            }
+        }
+
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out.trim_end_matches('_').to_owned()
+}
+
+/// Removes a `#[repr(<int>)]` attribute and returns the integer type within
+/// it, if there is one.
+///
+/// The generated struct is marked `#[repr(transparent)]` in its place so that
+/// it still has the same layout as the integer type.
+fn take_repr(attrs: &mut [Attribute]) -> Option<Type> {
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        let ty: Ident = match attr.parse_args() {
+            Ok(ty) => ty,
+            Err(_) => continue,
+        };
+        if !REPR_TYPES.iter().any(|name| ty == name) {
+            continue;
+        }
+
+        *attr = syn::parse_quote!(#[repr(transparent)]);
+        return Some(syn::parse_quote!(#ty));
+    }
+
+    None
+}
@@ -0,0 +1,168 @@
+//! `#[derive(CEnum)]`, which implements the C enum traits for an existing
+//! newtype struct.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, Ident, Meta, Path, Token};
+
+/// The options passed using `#[c_enum(...)]` on the struct.
+#[derive(Default)]
+struct Options {
+    variants: Vec<Ident>,
+    skip_debug: bool,
+    skip_from: bool,
+    /// The path to the `c_enum` crate, if it isn't `::c_enum`.
+    krate: Option<Path>,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        for attr in &input.attrs {
+            if !attr.path().is_ident("c_enum") {
+                continue;
+            }
+
+            let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            for meta in metas {
+                match &meta {
+                    Meta::List(list) if list.path.is_ident("variants") => {
+                        let variants =
+                            list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                        options.variants.extend(variants);
+                    }
+                    Meta::Path(path) if path.is_ident("skip_debug") => options.skip_debug = true,
+                    Meta::Path(path) if path.is_ident("skip_from") => options.skip_from = true,
+                    Meta::NameValue(value) if value.path.is_ident("crate") => {
+                        options.krate = Some(syn::parse2(value.value.to_token_stream())?);
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            meta.span(),
+                            "expected `variants(...)`, `skip_debug`, `skip_from` or \
+                             `crate = <path>`",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let input: DeriveInput = syn::parse2(input)?;
+    let options = Options::parse(&input)?;
+
+    let inner = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            fields => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "`#[derive(CEnum)]` requires a struct with exactly one unnamed field",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`#[derive(CEnum)]` can only be used on newtype structs",
+            ))
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "`#[derive(CEnum)]` doesn't support generic parameters",
+        ));
+    }
+
+    let name = &input.ident;
+    let variants = &options.variants;
+    let krate = options.krate.unwrap_or_else(|| syn::parse_quote!(::c_enum));
+
+    let debug = if options.skip_debug {
+        None
+    } else {
+        Some(quote! {
+            #[automatically_derived]
+            impl ::core::fmt::Debug for #name
+            where
+                #inner: ::core::fmt::Debug + ::core::cmp::PartialEq,
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match #krate::CEnum::variant_label(self) {
+                        ::core::option::Option::Some(label) => f.write_fmt(
+                            ::core::format_args!("{}::{}", ::core::stringify!(#name), label)
+                        ),
+                        ::core::option::Option::None => f
+                            .debug_tuple(::core::stringify!(#name))
+                            .field(&self.0)
+                            .finish(),
+                    }
+                }
+            }
+        })
+    };
+
+    let from = if options.skip_from {
+        None
+    } else {
+        Some(quote! {
+            #[automatically_derived]
+            impl ::core::convert::From<#inner> for #name {
+                fn from(value: #inner) -> Self {
+                    Self(value)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<#name> for #inner {
+                fn from(value: #name) -> Self {
+                    value.0
+                }
+            }
+        })
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #krate::CEnum for #name {
+            type Inner = #inner;
+
+            fn variant_label(&self) -> ::core::option::Option<&'static str>
+            where
+                Self::Inner: ::core::cmp::PartialEq,
+            {
+                #(
+                    if self.0 == Self::#variants.0 {
+                        return ::core::option::Option::Some(
+                            #krate::__private::unraw(::core::stringify!(#variants))
+                        );
+                    }
+                )*
+
+                ::core::option::Option::None
+            }
+
+            fn from_name(name: &str) -> ::core::option::Option<Self> {
+                #(
+                    if name == #krate::__private::unraw(::core::stringify!(#variants)) {
+                        return ::core::option::Option::Some(Self::#variants);
+                    }
+                )*
+
+                ::core::option::Option::None
+            }
+        }
+
+        #debug
+        #from
+    })
+}
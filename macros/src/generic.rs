@@ -0,0 +1,800 @@
+//! Enums with a type parameter as their inner type.
+//!
+//! `c_enum!` can only declare enums with a concrete inner type, so it forwards
+//! generic enums to `c_enum_generic!`, and `#[c_enum]` calls `expand` directly.
+//! Since the values of the variants can't be converted to the type parameter
+//! in a constant, they are defined by a trait instead, which is implemented
+//! for every inner type listed in `#[c_enum(types(...))]`. The constants of the
+//! enum are then declared in a generic impl which reads them from the trait.
+
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Expr, Fields, GenericParam, Generics, Ident, Lifetime, LitStr, Meta, Path, Token,
+    Type, TypeParam, TypeParamBound, Variant, Visibility, WherePredicate,
+};
+
+/// A generic enum as passed to `c_enum!`.
+pub(crate) struct GenericEnum {
+    pub(crate) attrs: Vec<Attribute>,
+    pub(crate) vis: Visibility,
+    pub(crate) ident: Ident,
+    pub(crate) generics: Generics,
+    pub(crate) inner: Type,
+    pub(crate) variants: Punctuated<Variant, Token![,]>,
+}
+
+impl Parse for GenericEnum {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![enum]>()?;
+        let ident = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let inner = input.parse()?;
+        generics.where_clause = input.parse()?;
+
+        let content;
+        syn::braced!(content in input);
+        let variants = content.parse_terminated(Variant::parse, Token![,])?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            generics,
+            inner,
+            variants,
+        })
+    }
+}
+
+/// The options of a generic enum.
+#[derive(Default)]
+pub(crate) struct Options {
+    /// The inner types listed in `types(...)`.
+    pub(crate) types: Vec<Type>,
+    /// Whether `consts` was passed, which makes the constants listing the
+    /// declared variants public.
+    pub(crate) consts: bool,
+}
+
+/// Removes the `#[c_enum(...)]` attributes from `attrs` and returns the
+/// options within them.
+pub(crate) fn take_options(attrs: &mut Vec<Attribute>) -> syn::Result<Options> {
+    let mut options = Options::default();
+    let mut result = Ok(());
+
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("c_enum") {
+            return true;
+        }
+
+        if result.is_ok() {
+            result = attr
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .and_then(|metas| parse_options(metas, &mut options));
+        }
+        false
+    });
+
+    result.map(|()| options)
+}
+
+/// Parses the options of a generic enum, which may only list its inner types
+/// and make its constants public.
+pub(crate) fn parse_options(
+    metas: Punctuated<Meta, Token![,]>,
+    options: &mut Options,
+) -> syn::Result<()> {
+    for meta in metas {
+        match &meta {
+            Meta::List(list) if list.path.is_ident("types") => {
+                let list = list.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+                options.types.extend(list);
+            }
+            Meta::Path(path) if path.is_ident("consts") && !options.consts => {
+                options.consts = true;
+            }
+            Meta::Path(path) if path.is_ident("consts") => {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "`#[c_enum(consts)]` can only be specified once",
+                ))
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    format!(
+                        "`{}` is not supported for generic enums, which only support the \
+                         `types(...)` and `consts` options",
+                        meta.path().to_token_stream(),
+                    ),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The variants of a generic enum.
+struct Variants {
+    fields: Vec<Ident>,
+    docs: Vec<Vec<Attribute>>,
+    labels: Vec<LitStr>,
+    values: Vec<Option<Expr>>,
+}
+
+impl Variants {
+    fn parse(variants: &Punctuated<Variant, Token![,]>) -> syn::Result<Self> {
+        let mut fields = Vec::new();
+        let mut docs = Vec::new();
+        let mut labels = Vec::new();
+        let mut values = Vec::new();
+        for variant in variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new(
+                    variant.fields.span(),
+                    "variants of a c_enum can't have fields",
+                ));
+            }
+
+            let mut label = LitStr::new(&variant.ident.unraw().to_string(), variant.ident.span());
+            let mut doc = Vec::new();
+            for attr in &variant.attrs {
+                if attr.path().is_ident("doc") {
+                    doc.push(attr.clone());
+                } else if attr.path().is_ident("label") {
+                    label = match &attr.meta.require_name_value()?.value {
+                        Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }) => lit.clone(),
+                        value => return Err(syn::Error::new(value.span(), "expected a string")),
+                    };
+                } else {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "only doc comments and `#[label = \"...\"]` are supported on the \
+                         variants of generic enums",
+                    ));
+                }
+            }
+
+            fields.push(variant.ident.clone());
+            docs.push(doc);
+            labels.push(label);
+            values.push(
+                variant
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, value)| value.clone()),
+            );
+        }
+
+        Ok(Self {
+            fields,
+            docs,
+            labels,
+            values,
+        })
+    }
+}
+
+/// Expands a generic enum, referring to the `c_enum` crate using `krate`.
+pub(crate) fn expand(
+    item: GenericEnum,
+    options: Options,
+    krate: &Path,
+) -> syn::Result<TokenStream> {
+    let param = match item.generics.params.iter().collect::<Vec<_>>().as_slice() {
+        [GenericParam::Type(param)] => param.clone(),
+        [GenericParam::Lifetime(param)] if options.types.is_empty() => {
+            let lifetime = param.lifetime.clone();
+            return expand_lifetime(item, lifetime, options, krate);
+        }
+        [GenericParam::Lifetime(_)] => {
+            return Err(syn::Error::new(
+                options.types[0].span(),
+                "`types(...)` is only supported for enums with a type parameter",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new(
+                item.generics.span(),
+                "generic enums need to have exactly one type or lifetime parameter",
+            ))
+        }
+    };
+
+    expand_type(item, param, options, krate)
+}
+
+/// Expands an enum with a type parameter as its inner type.
+fn expand_type(
+    item: GenericEnum,
+    param: TypeParam,
+    options: Options,
+    krate: &Path,
+) -> syn::Result<TokenStream> {
+    let Options { types, consts } = options;
+    let GenericEnum {
+        attrs,
+        vis,
+        ident,
+        generics,
+        inner,
+        variants,
+    } = item;
+
+    let param_ident = &param.ident;
+    if !matches!(&inner, Type::Path(path) if path.qself.is_none() && path.path.is_ident(param_ident))
+    {
+        return Err(syn::Error::new(
+            inner.span(),
+            format!("the inner type of a generic enum must be its type parameter `{param_ident}`"),
+        ));
+    }
+    if types.is_empty() {
+        return Err(syn::Error::new(
+            ident.span(),
+            "generic enums need to list their inner types using `#[c_enum(types(...))]`",
+        ));
+    }
+
+    let Variants {
+        fields,
+        docs,
+        labels,
+        values,
+    } = Variants::parse(&variants)?;
+
+    // The trait has the bounds of the type parameter as its supertraits, so
+    // that `Enum<Self>` is a valid type within it.
+    let mut bounds: Vec<TypeParamBound> = param.bounds.iter().cloned().collect();
+    if let Some(where_clause) = &generics.where_clause {
+        for predicate in &where_clause.predicates {
+            match predicate {
+                WherePredicate::Type(predicate) if matches!(&predicate.bounded_ty, Type::Path(path) if path.path.is_ident(param_ident)) => {
+                    bounds.extend(predicate.bounds.iter().cloned())
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        predicate.span(),
+                        format!("only bounds on `{param_ident}` are supported here"),
+                    ))
+                }
+            }
+        }
+    }
+    let bounds = bounds
+        .iter()
+        .map(|bound| replace_ident(quote!(#bound), param_ident));
+
+    let trait_ident = format_ident!("{}Values", ident);
+    let trait_doc = format!(
+        "The values of the variants of [`{ident}`] for each of its inner types.\n\n\
+         This is implemented for each of the types listed in `#[c_enum(types(...))]`."
+    );
+    let count = fields.len();
+
+    let mut previous: Option<&Ident> = None;
+    let trait_values = fields.iter().zip(&values).map(|(field, value)| {
+        let value = match (value, previous) {
+            (Some(value), _) => quote!(#value),
+            (None, Some(previous)) => quote!(<Self as #trait_ident>::#previous + 1),
+            (None, None) => quote!(0),
+        };
+        previous = Some(field);
+        quote!(const #field: Self = #value;)
+    });
+    let trait_values: Vec<_> = trait_values.collect();
+
+    let trait_impls = types.iter().map(|ty| {
+        quote! {
+            #[automatically_derived]
+            #[allow(non_upper_case_globals)]
+            impl #trait_ident for #ty {
+                #( #trait_values )*
+
+                const __C_ENUM_VALUES: &'static [Self] =
+                    &[ #( <Self as #trait_ident>::#fields, )* ];
+                const __C_ENUM_VARIANTS: &'static [#ident<Self>] =
+                    &[ #( #ident(<Self as #trait_ident>::#fields), )* ];
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<#ident<#ty>> for #ty {
+                fn from(value: #ident<#ty>) -> Self {
+                    value.0
+                }
+            }
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let bounded = with_predicate(&generics, syn::parse_quote!(#param_ident: #trait_ident));
+    let (_, _, bounded_where) = bounded.split_for_impl();
+    let debug = with_predicate(
+        &bounded,
+        syn::parse_quote!(#param_ident: ::core::fmt::Debug + ::core::cmp::PartialEq),
+    );
+    let (_, _, debug_where) = debug.split_for_impl();
+    let c_enum = with_predicate(
+        &bounded,
+        syn::parse_quote!(#param_ident: ::core::convert::From<#ident #ty_generics> + ::core::clone::Clone),
+    );
+    let (_, _, c_enum_where) = c_enum.split_for_impl();
+    let field_names = fields.iter().map(|field| field.unraw().to_string());
+
+    let public_consts = consts.then(|| {
+        quote! {
+            impl #impl_generics #ident #ty_generics #bounded_where {
+                /// All declared variants of this enum, in declaration order.
+                pub const VARIANTS: &'static [Self] = Self::__C_ENUM_VARIANTS;
+
+                /// The number of declared variants of this enum.
+                pub const VARIANT_COUNT: usize = #count;
+
+                /// The names of all declared variants of this enum, in
+                /// declaration order.
+                pub const LABELS: &'static [&'static str] = Self::__C_ENUM_LABELS;
+
+                /// The values of all declared variants of this enum, in
+                /// declaration order.
+                pub const VALUES: &'static [#param_ident] = Self::__C_ENUM_VALUES;
+            }
+        }
+    });
+
+    Ok(quote! {
+        #( #attrs )*
+        #[repr(transparent)]
+        #vis struct #ident #generics (pub #param_ident) #where_clause;
+
+        #[doc = #trait_doc]
+        #[allow(non_upper_case_globals)]
+        #vis trait #trait_ident: ::core::marker::Sized + 'static #( + #bounds )* {
+            #(
+                #( #docs )*
+                const #fields: Self;
+            )*
+
+            #[doc(hidden)]
+            const __C_ENUM_VALUES: &'static [Self];
+
+            #[doc(hidden)]
+            const __C_ENUM_VARIANTS: &'static [#ident<Self>];
+        }
+
+        #( #trait_impls )*
+
+        #public_consts
+
+        #[allow(non_upper_case_globals)]
+        impl #impl_generics #ident #ty_generics #bounded_where {
+            #(
+                #( #docs )*
+                pub const #fields: Self = Self(<#param_ident as #trait_ident>::#fields);
+            )*
+
+            /// Creates a new value of this enum from its inner value.
+            pub const fn new(value: #param_ident) -> Self {
+                Self(value)
+            }
+
+            /// Returns the inner value of this enum.
+            pub fn value(self) -> #param_ident {
+                self.0
+            }
+
+            const __C_ENUM_VARIANTS: &'static [Self] =
+                <#param_ident as #trait_ident>::__C_ENUM_VARIANTS;
+
+            const __C_ENUM_LABELS: &'static [&'static str] = &[ #( #labels, )* ];
+
+            const __C_ENUM_VALUES: &'static [#param_ident] =
+                <#param_ident as #trait_ident>::__C_ENUM_VALUES;
+
+            /// Returns an iterator over all declared variants of this enum, in
+            /// declaration order.
+            pub fn variants() -> ::core::slice::Iter<'static, Self> {
+                Self::__C_ENUM_VARIANTS.iter()
+            }
+
+            /// Returns the declared variant with the given name, if there is
+            /// one.
+            pub fn from_name(name: &str) -> ::core::option::Option<Self>
+            where
+                #param_ident: ::core::clone::Clone,
+            {
+                let index = Self::__C_ENUM_LABELS.iter().position(|label| *label == name)?;
+                Self::from_index(index)
+            }
+
+            /// Returns the name of the declared variant corresponding to the
+            /// current value, if there is one.
+            pub fn as_str(&self) -> ::core::option::Option<&'static str>
+            where
+                #param_ident: ::core::cmp::PartialEq,
+            {
+                self.variant_index().map(|index| Self::__C_ENUM_LABELS[index])
+            }
+
+            /// Returns whether the current value corresponds to one of the
+            /// declared variants of this enum.
+            pub fn is_known(&self) -> bool
+            where
+                #param_ident: ::core::cmp::PartialEq,
+            {
+                self.variant_index().is_some()
+            }
+
+            /// Returns the index of the current value within `variants()`, or
+            /// `None` if it does not correspond to a declared variant.
+            ///
+            /// If multiple variants share the same value then the index of the
+            /// first one is returned.
+            pub fn variant_index(&self) -> ::core::option::Option<usize>
+            where
+                #param_ident: ::core::cmp::PartialEq,
+            {
+                Self::__C_ENUM_VALUES.iter().position(|value| *value == self.0)
+            }
+
+            /// Returns the declared variant at `index` within `variants()`, or
+            /// `None` if `index` is out of bounds.
+            pub fn from_index(index: usize) -> ::core::option::Option<Self>
+            where
+                #param_ident: ::core::clone::Clone,
+            {
+                Self::__C_ENUM_VALUES.get(index).cloned().map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#param_ident> for #ident #ty_generics
+        #where_clause
+        {
+            fn from(value: #param_ident) -> Self {
+                Self(value)
+            }
+        }
+
+        // Declared variants are always formatted using the name of their
+        // constant, even if their label has been changed.
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #debug_where {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                const NAMES: &[&str] = &[ #( #field_names, )* ];
+
+                match self.variant_index() {
+                    ::core::option::Option::Some(index) => f.write_fmt(::core::format_args!(
+                        "{}::{}",
+                        ::core::stringify!(#ident),
+                        NAMES[index]
+                    )),
+                    ::core::option::Option::None => f
+                        .debug_tuple(::core::stringify!(#ident))
+                        .field(&self.0)
+                        .finish(),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #krate::CEnum for #ident #ty_generics #c_enum_where {
+            type Inner = #param_ident;
+
+            fn variant_label(&self) -> ::core::option::Option<&'static str>
+            where
+                Self::Inner: ::core::cmp::PartialEq,
+            {
+                self.as_str()
+            }
+
+            fn from_name(name: &str) -> ::core::option::Option<Self> {
+                #ident::from_name(name)
+            }
+        }
+    })
+}
+
+/// Expands an enum with a lifetime parameter, which its inner type borrows
+/// from.
+///
+/// The values of the variants don't depend on the lifetime, so there's no need
+/// for a trait here. Items that don't refer to a particular value of the enum
+/// use `'static` in place of the lifetime parameter.
+fn expand_lifetime(
+    item: GenericEnum,
+    lifetime: Lifetime,
+    options: Options,
+    krate: &Path,
+) -> syn::Result<TokenStream> {
+    let GenericEnum {
+        attrs,
+        vis,
+        ident,
+        generics,
+        inner,
+        variants,
+    } = item;
+
+    let Variants {
+        fields,
+        docs,
+        labels,
+        values,
+    } = Variants::parse(&variants)?;
+
+    // Like `&'static str`, variants of string enums without an explicit value
+    // use their label as their value.
+    let is_str = matches!(
+        &inner,
+        Type::Reference(reference)
+            if reference.mutability.is_none()
+                && matches!(&*reference.elem, Type::Path(path) if path.path.is_ident("str"))
+    );
+    let values = values
+        .into_iter()
+        .zip(fields.iter().zip(&labels))
+        .map(|(value, (field, label))| match value {
+            Some(value) => Ok(quote!(#value)),
+            None if is_str => Ok(quote!(#label)),
+            None => Err(syn::Error::new(
+                field.span(),
+                "variants of enums with a lifetime parameter need an explicit value unless the \
+                 inner type is `&str`",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let static_inner = replace_lifetime(quote!(#inner), &lifetime);
+    let count = fields.len();
+    let field_names = fields.iter().map(|field| field.unraw().to_string());
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let debug = with_predicate(
+        &generics,
+        syn::parse_quote!(#inner: ::core::fmt::Debug + ::core::cmp::PartialEq),
+    );
+    let (_, _, debug_where) = debug.split_for_impl();
+    let c_enum = with_predicate(
+        &generics,
+        syn::parse_quote!(#static_inner: ::core::clone::Clone),
+    );
+    let (_, _, c_enum_where) = c_enum.split_for_impl();
+
+    let public_consts = options.consts.then(|| {
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// All declared variants of this enum, in declaration order.
+                pub const VARIANTS: &'static [#ident<'static>] = Self::__C_ENUM_VARIANTS;
+
+                /// The number of declared variants of this enum.
+                pub const VARIANT_COUNT: usize = #count;
+
+                /// The names of all declared variants of this enum, in
+                /// declaration order.
+                pub const LABELS: &'static [&'static str] = Self::__C_ENUM_LABELS;
+
+                /// The values of all declared variants of this enum, in
+                /// declaration order.
+                pub const VALUES: &'static [#static_inner] = Self::__C_ENUM_VALUES;
+            }
+        }
+    });
+
+    Ok(quote! {
+        #( #attrs )*
+        #[repr(transparent)]
+        #vis struct #ident #generics (pub #inner) #where_clause;
+
+        #public_consts
+
+        #[allow(non_upper_case_globals)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(
+                #( #docs )*
+                pub const #fields: Self = Self(#values);
+            )*
+
+            /// Creates a new value of this enum from its inner value.
+            pub const fn new(value: #inner) -> Self {
+                Self(value)
+            }
+
+            /// Returns the inner value of this enum.
+            pub fn value(self) -> #inner {
+                self.0
+            }
+
+            const __C_ENUM_VARIANTS: &'static [#ident<'static>] = &[ #( #ident::#fields, )* ];
+
+            const __C_ENUM_LABELS: &'static [&'static str] = &[ #( #labels, )* ];
+
+            const __C_ENUM_VALUES: &'static [#static_inner] = &[ #( #ident::#fields.0, )* ];
+
+            /// Returns an iterator over all declared variants of this enum, in
+            /// declaration order.
+            pub fn variants() -> ::core::slice::Iter<'static, #ident<'static>> {
+                Self::__C_ENUM_VARIANTS.iter()
+            }
+
+            /// Returns the declared variant with the given name, if there is
+            /// one.
+            pub fn from_name(name: &str) -> ::core::option::Option<Self>
+            where
+                #static_inner: ::core::clone::Clone,
+            {
+                let index = Self::__C_ENUM_LABELS.iter().position(|label| *label == name)?;
+                Self::from_index(index)
+            }
+
+            /// Returns the name of the declared variant corresponding to the
+            /// current value, if there is one.
+            pub fn as_str(&self) -> ::core::option::Option<&'static str>
+            where
+                #inner: ::core::cmp::PartialEq,
+            {
+                self.variant_index().map(|index| Self::__C_ENUM_LABELS[index])
+            }
+
+            /// Returns whether the current value corresponds to one of the
+            /// declared variants of this enum.
+            pub fn is_known(&self) -> bool
+            where
+                #inner: ::core::cmp::PartialEq,
+            {
+                self.variant_index().is_some()
+            }
+
+            /// Returns the index of the current value within `variants()`, or
+            /// `None` if it does not correspond to a declared variant.
+            ///
+            /// If multiple variants share the same value then the index of the
+            /// first one is returned.
+            pub fn variant_index(&self) -> ::core::option::Option<usize>
+            where
+                #inner: ::core::cmp::PartialEq,
+            {
+                Self::__C_ENUM_VALUES.iter().position(|value| self.0 == *value)
+            }
+
+            /// Returns the declared variant at `index` within `variants()`, or
+            /// `None` if `index` is out of bounds.
+            pub fn from_index(index: usize) -> ::core::option::Option<Self>
+            where
+                #static_inner: ::core::clone::Clone,
+            {
+                Self::__C_ENUM_VALUES.get(index).cloned().map(|value| Self(value))
+            }
+
+            /// Returns the declared variant corresponding to the current value,
+            /// if there is one.
+            ///
+            /// Unlike the current value, the returned variant doesn't borrow
+            /// from the data that the current value was created from.
+            pub fn variant(&self) -> ::core::option::Option<#ident<'static>>
+            where
+                #inner: ::core::cmp::PartialEq,
+                #static_inner: ::core::clone::Clone,
+            {
+                self.variant_index().and_then(#ident::from_index)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#inner> for #ident #ty_generics #where_clause {
+            fn from(value: #inner) -> Self {
+                Self(value)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for #inner #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                value.0
+            }
+        }
+
+        // Declared variants are always formatted using the name of their
+        // constant, even if their label has been changed.
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #debug_where {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                const NAMES: &[&str] = &[ #( #field_names, )* ];
+
+                match self.variant_index() {
+                    ::core::option::Option::Some(index) => f.write_fmt(::core::format_args!(
+                        "{}::{}",
+                        ::core::stringify!(#ident),
+                        NAMES[index]
+                    )),
+                    ::core::option::Option::None => f
+                        .debug_tuple(::core::stringify!(#ident))
+                        .field(&self.0)
+                        .finish(),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #krate::CEnum for #ident #ty_generics #c_enum_where {
+            type Inner = #inner;
+
+            fn variant_label(&self) -> ::core::option::Option<&'static str>
+            where
+                Self::Inner: ::core::cmp::PartialEq,
+            {
+                self.as_str()
+            }
+
+            fn from_name(name: &str) -> ::core::option::Option<Self> {
+                #ident::from_name(name)
+            }
+        }
+    })
+}
+
+/// Returns a copy of `generics` with `predicate` added to its where clause.
+fn with_predicate(generics: &Generics, predicate: WherePredicate) -> Generics {
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.push(predicate);
+    generics
+}
+
+/// Replaces every occurrence of `ident` within `tokens` with `Self`.
+fn replace_ident(tokens: TokenStream, ident: &Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(token) if token == *ident => {
+                TokenTree::Ident(Ident::new("Self", token.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut new = Group::new(group.delimiter(), replace_ident(group.stream(), ident));
+                new.set_span(group.span());
+                TokenTree::Group(new)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+/// Replaces every occurrence of `lifetime` within `tokens` with `'static`.
+fn replace_lifetime(tokens: TokenStream, lifetime: &Lifetime) -> TokenStream {
+    let mut output = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                output.push(TokenTree::Punct(punct));
+                if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                    if *ident == lifetime.ident {
+                        output.push(TokenTree::Ident(Ident::new("static", ident.span())));
+                        tokens.next();
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut new = Group::new(
+                    group.delimiter(),
+                    replace_lifetime(group.stream(), lifetime),
+                );
+                new.set_span(group.span());
+                output.push(TokenTree::Group(new));
+            }
+            token => output.push(token),
+        }
+    }
+
+    output.into_iter().collect()
+}
@@ -0,0 +1,55 @@
+//! Procedural macros for the `c-enum` crate.
+//!
+//! These are re-exported by `c-enum` when its `proc-macro` feature is enabled
+//! and should be used through it instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use syn::parse::{ParseStream, Parser};
+use syn::{Path, Token};
+
+mod attr;
+mod derive;
+mod generic;
+
+/// Declares a C enum using regular enum syntax.
+///
+/// See the docs of `c_enum::attr` for details.
+#[proc_macro_attribute]
+pub fn c_enum(args: TokenStream, input: TokenStream) -> TokenStream {
+    attr::expand(args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Declares a generic C enum. This is used by `c_enum!` and is not part of the
+/// public API.
+///
+/// The enum is preceded by the path to the `c_enum` crate and a semicolon.
+#[doc(hidden)]
+#[proc_macro]
+pub fn c_enum_generic(input: TokenStream) -> TokenStream {
+    let parser = |input: ParseStream| {
+        let krate = input.call(Path::parse_mod_style)?;
+        input.parse::<Token![;]>()?;
+        Ok((krate, input.parse::<generic::GenericEnum>()?))
+    };
+
+    parser
+        .parse(input)
+        .and_then(|(krate, mut item)| {
+            let options = generic::take_options(&mut item.attrs)?;
+            generic::expand(item, options, &krate)
+        })
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements the C enum traits for an existing newtype struct.
+///
+/// See the docs of `c_enum::CEnum` for details.
+#[proc_macro_derive(CEnum, attributes(c_enum))]
+pub fn derive_c_enum(input: TokenStream) -> TokenStream {
+    derive::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -6,12 +6,73 @@ USAGE: python3 scripts/enum-impl.py > src/decl_variants.rs
 """
 
 
-def emit_multi_variant(count):
+def next_default(index):
+    # Variants removed by #[cfg] still advance the default value of the
+    # following variant, unless they have an explicit value.
+    return (
+        f"$crate::__c_enum_impl!(\n"
+        f"                    impl(cfgs) [ $( #[ $( $attr{index} )* ] )* ] []\n"
+        f"                    (cfg_next $kind $opts $field{index} [ $( $value{index} )? ]))"
+    )
+
+
+def emit_variant(index, prev, prev_doc):
+    print(
+        f"""\
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr{index} )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr{index} )* ] )* ]
+                    [ $( ::core::stringify!($value{index}) )? ] {prev_doc}
+                )]
+            ]
+            [] [ pub ]
+            $field{index} = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value{index}, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr{index} )* ] )* ] $field{index})
+                        {prev}
+                    )
+                )
+            )
+        );
+"""
+    )
+
+
+def emit_fallback(index, prev, prev_doc):
+    print(
+        f"""\
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr{index} )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field{index} [ {prev} ] [ $( $value{index} )? ])
+        );
+"""
+    )
+
+
+HEADER = "$kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr"
+ARGS = "$kind, $opts, $name, $inner"
+
+MACROS = {
+    "__c_enum_decl_variants": emit_variant,
+    "__c_enum_decl_fallbacks": emit_fallback,
+}
+
+
+def emit_multi_variant(macro, count):
+    emit = MACROS[macro]
+
     print(
         f"""\
     // Match {count} fields
     (
-        impl($name:ident, $inner:ty, $default:expr)
+        impl({HEADER})
 """
     )
 
@@ -20,7 +81,7 @@ def emit_multi_variant(count):
 
         print(
             f"""\
-        $( #[$attr{index}:meta] )*
+        $( #[ $( $attr{index}:tt )* ] )*
         $field{index}:ident $( = $value{index}:expr )? ,
 """
         )
@@ -28,7 +89,7 @@ def emit_multi_variant(count):
     print(
         """\
         $(
-            $( #[$rattr:meta] )*
+            $( #[ $( $rattr:tt )* ] )*
             $frest:ident $( = $frest_val:expr )?
         ),* $(,)?
     ) => {\
@@ -40,32 +101,69 @@ def emit_multi_variant(count):
 
         if index == 1:
             prev = "$default"
+            prev_doc = "$default_doc"
         else:
-            prev = f"Self::$field{index - 1}.0 + 1"
+            prev = next_default(index - 1)
+            prev_doc = f"$crate::__c_enum_impl!(impl(next_doc $opts $field{index - 1}))"
 
-        print(
-            f"""\
-        $( #[$attr{index}] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field{index}: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value{index}, )? {prev}));
-"""
-        )
+        emit(index, prev, prev_doc)
 
     print(
         f"""\
-        $crate::__c_enum_decl_variants!(
-            impl($name, $inner, Self::$field{count}.0 + 1)
-            $( $( #[$rattr] )* $frest $( = $frest_val )?, )*
+        $crate::{macro}!(
+            impl(
+                {ARGS},
+                {next_default(count)},
+                $crate::__c_enum_impl!(impl(next_doc $opts $field{count}))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
         );
     }};
 """
     )
 
 
-print(
-    """\
-/// Helper macro for defining the fields of a c_enum!
+def emit_base_case(macro):
+    emit = MACROS[macro]
+
+    print(
+        f"""\
+    // Base case
+    (
+        impl({HEADER})
+        $( #[ $( $attr1:tt )* ] )*
+        $field1:ident $( = $value1:expr )?
+        $( ,
+            $( #[ $( $rattr:tt )* ] )*
+            $frest:ident $( = $frest_val:expr )?
+        )*
+        $(,)?
+    ) => {{\
+"""
+    )
+
+    emit(1, "$default", "$default_doc")
+
+    print(
+        f"""\
+        $crate::{macro}!(
+            impl(
+                {ARGS},
+                {next_default(1)},
+                $crate::__c_enum_impl!(impl(next_doc $opts $field1))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
+        );
+    }}
+}}\
+"""
+    )
+
+
+def emit_macro(macro, doc, terminal):
+    print(
+        f"""\
+{doc}
 ///
 /// Large enums can easily hit the recursion limit imposed by rustc on macros.
 /// This macro defines a bunch of variants which consume a bunch of fields at
@@ -76,40 +174,218 @@ print(
 /// Edit the script and regenerate it instead.
 #[doc(hidden)]
 #[macro_export]
-macro_rules! __c_enum_decl_variants {
-    (impl($name:ident, $inner:ty, $default:expr)) => {};
+macro_rules! {macro} {{
+    (impl({HEADER})) => {{{terminal}}};
 """
-)
+    )
 
-# ~10k variants ought to be enough for now. Anyone going over that can probably
-# afford to bump up rustc's recursion limit.
-emit_multi_variant(128)
-emit_multi_variant(32)
-emit_multi_variant(8)
+    # ~10k variants ought to be enough for now. Anyone going over that can
+    # probably afford to bump up rustc's recursion limit.
+    emit_multi_variant(macro, 128)
+    emit_multi_variant(macro, 32)
+    emit_multi_variant(macro, 8)
+    emit_base_case(macro)
 
-print(
-    """\
-    // Base case
+
+ATTR_FLAGS_HEADER = "$cat:tt $meta:tt $args:tt"
+
+
+def token_lines(tokens, indent):
+    lines = [tokens[i : i + 8] for i in range(0, len(tokens), 8)]
+    return "".join(" " * indent + " ".join(line) + "\n" for line in lines)
+
+
+def emit_attr_flags_guard(state, count, attr):
+    pattern, output = state
+    skipped = [f"$a{i}:tt" for i in range(count)]
+    kept = [f"$a{i}" for i in range(count)]
+
+    print(
+        f"""\
     (
-        impl($name:ident, $inner:ty, $default:expr)
-        $( #[$fattr:meta] )*
-        $field:ident $( = $fvalue:expr )?
-        $( ,
-            $( #[$rattr:meta] )*
-            $frest:ident $( = $frest_val:expr )?
-        )*
-        $(,)?
-    ) => {
-        $( #[$fattr] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $fvalue, )? $default));
-
-        $crate::__c_enum_decl_variants!(
-            impl($name, $inner, Self::$field.0 + 1)
-            $( $( #[$rattr] )* $frest $( = $frest_val )?, )*
+        impl({pattern} $args:tt)
+{token_lines(skipped + [attr, "$( $rest:tt )*"], 8)}\
+    ) => {{
+        $crate::__c_enum_attr_flags!(
+            impl({output} $args)
+{token_lines(kept + ["$( $rest )*"], 12)}\
+        );
+    }};"""
+    )
+
+
+def emit_attr_flags_macro(batch):
+    print(
+        f"""\
+/// Helper macro for checking whether any variant of a c_enum! uses
+/// `#[category = ...]` or `#[meta(...)]`.
+///
+/// The attributes are passed in as a flat list of their bracketed contents.
+/// Checking them one at a time would hit the recursion limit for large enums
+/// so this macro looks for the attributes within the next {batch} and skips all
+/// of them at once if there are none.
+///
+/// This code is generated by scripts/enum-impl.py. Don't edit it manually.
+/// Edit the script and regenerate it instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_attr_flags {{
+    (impl([ yes ] [ yes ] $args:tt) $( $attr:tt )*) => {{
+        $crate::__c_enum_impl!(impl(attr_items [ yes ] [ yes ]) $args);
+    }};"""
+    )
+
+    for count in range(batch):
+        emit_attr_flags_guard(
+            ("[] $meta:tt", "[ yes ] $meta"), count, "[category = $category:tt]"
+        )
+    for count in range(batch):
+        emit_attr_flags_guard(("$cat:tt []", "$cat [ yes ]"), count, "[meta $values:tt]")
+
+    skipped = [f"$a{i}:tt" for i in range(batch)]
+    print(
+        f"""\
+    (
+        impl({ATTR_FLAGS_HEADER})
+{token_lines(skipped + ["$( $rest:tt )*"], 8)}\
+    ) => {{
+        $crate::__c_enum_attr_flags!(impl($cat $meta $args) $( $rest )*);
+    }};
+    (impl({ATTR_FLAGS_HEADER}) $( $attr:tt )*) => {{
+        $crate::__c_enum_impl!(impl(attr_items $cat $meta) $args);
+    }};
+}}\
+"""
+    )
+
+
+VALUES_HEADER = "$head:tt [ $( $done:tt )* ] $body:tt $impl:tt"
+
+
+def simple_pattern(i):
+    return f"""\
+        $( #[ $( $sa{i}:tt )* ] )* $sf{i}:ident $(
+            = $sv{i}:tt $( :: $sp{i}:ident )*
+            $( ..= $si{i}:tt $( :: $sip{i}:ident )* )?
+            $( .. $sh{i}:tt $( :: $shp{i}:ident )* )?
+        )?,
+"""
+
+
+def simple_output(i):
+    return f"""\
+                    $( #[ $( $sa{i} )* ] )* $sf{i} $(
+                        = $sv{i} $( :: $sp{i} )*
+                        $( ..= $si{i} $( :: $sip{i} )* )?
+                        $( .. $sh{i} $( :: $shp{i} )* )?
+                    )?,
+"""
+
+
+def expr_pattern(i):
+    return f"        $( #[ $( $ea{i}:tt )* ] )* $ef{i}:ident $( = $ev{i}:expr )?,\n"
+
+
+def expr_output(i):
+    return f"                    $( #[ $( $ea{i} )* ] )* $ef{i} $( = $ev{i} )?,\n"
+
+
+def emit_values_arm(comment, exprs, simples):
+    patterns = [expr_pattern(i) for i in range(exprs)]
+    patterns += [simple_pattern(exprs + i) for i in range(simples)]
+    outputs = [expr_output(i) for i in range(exprs)]
+    outputs += [simple_output(exprs + i) for i in range(simples)]
+
+    print(
+        f"""\
+    // {comment}
+    (
+        impl({VALUES_HEADER})
+{"".join(patterns)}\
+        $( $rest:tt )*
+    ) => {{
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+{"".join(outputs)}\
+                ]
+                $body $impl
+            )
+            $( $rest )*
         );
-    }
-}\
+    }};
+"""
+    )
+
+
+def emit_values_macro(batch):
+    print(
+        f"""\
+/// Helper macro for parsing the values of a c_enum! where only some of them
+/// are a single token tree.
+///
+/// Values which aren't a single token tree are parsed as expressions, which
+/// are passed on as a single token tree. Since ranges are valid expressions
+/// and values selected by `#[cfg]` can't be parsed as one, a value is only
+/// parsed as an expression once none of the variants following it up to the
+/// next value which is a single token tree are left to look at.
+///
+/// This code is generated by scripts/enum-impl.py. Don't edit it manually.
+/// Edit the script and regenerate it instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_values {{
+    (impl({VALUES_HEADER}) $(,)?) => {{
+        $crate::__c_enum_impl!(impl(values_done) $head [ $( $done )* ] $impl);
+    }};
+"""
+    )
+
+    def plural(count, noun):
+        return f"{count} {noun}" if count == 1 else f"{count} {noun}s"
+
+    for count in (batch, 8, 1):
+        emit_values_arm(f"Match {plural(count, 'single token tree value')}", 0, count)
+    for count in range(1, batch):
+        emit_values_arm(
+            f"Match {plural(count, 'expression')} followed by a single token tree value",
+            count,
+            1,
+        )
+    for count in (batch, 8, 1):
+        emit_values_arm(f"Match {plural(count, 'expression')}", count, 0)
+
+    print(
+        f"""\
+    // Anything else is left for the rules of c_enum! that parse every value as
+    // an expression.
+    (impl({VALUES_HEADER}) $( $rest:tt )*) => {{
+        $crate::__c_enum_impl!(impl(values_bail) $head $body $impl);
+    }};
+}}\
 """
+    )
+
+
+emit_macro(
+    "__c_enum_decl_variants",
+    "/// Helper macro for defining the fields of a c_enum!",
+    # Once all the fields have been declared the default value is the one the
+    # next variant would get. This is used by #[c_enum(continue_from = ...)].
+    "\n        $crate::__c_enum_impl!(impl(next_const $kind $inner) $default);\n    ",
+)
+print()
+emit_macro(
+    "__c_enum_decl_fallbacks",
+    "/// Helper macro for defining the constants that stand in for fields of a\n"
+    "/// c_enum! which have been removed by `#[cfg]`.",
+    "",
 )
+print()
+emit_attr_flags_macro(64)
+print()
+# Enums with ~3k variants whose values are expressions (or ~2k if each of them
+# has a doc comment) still stay within the default recursion limit.
+emit_values_macro(64)
@@ -0,0 +1,45 @@
+//! Helper macros for `#[c_enum(arbitrary)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `arbitrary` feature is enabled.
+
+/// Implements `arbitrary::Arbitrary` for a C enum.
+///
+/// Most of the time this picks one of the declared variants but it will
+/// occasionally produce an arbitrary inner value instead so that code
+/// handling undeclared values gets exercised too.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_arbitrary {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name
+        where
+            $inner: $crate::__private::arbitrary::Arbitrary<'a> + ::core::clone::Clone,
+        {
+            fn arbitrary(
+                u: &mut $crate::__private::arbitrary::Unstructured<'a>,
+            ) -> $crate::__private::arbitrary::Result<Self> {
+                if !Self::__C_ENUM_VALUES.is_empty() && u.ratio(7u8, 8u8)? {
+                    return u
+                        .choose(Self::__C_ENUM_VALUES)
+                        .map(|value| Self(value.clone()));
+                }
+
+                <$inner as $crate::__private::arbitrary::Arbitrary<'a>>::arbitrary(u).map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_arbitrary {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(arbitrary)]` requires the `arbitrary` feature of c-enum to be enabled"
+        );
+    };
+}
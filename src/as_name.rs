@@ -0,0 +1,107 @@
+//! Serialize a C enum field using the label of its variant.
+//!
+//! This is meant to be used with `#[serde(with = "c_enum::as_name")]` and
+//! behaves the same as `#[c_enum(serde = "name")]`, except that it can be
+//! chosen separately for every field. Declared variants are serialized as
+//! their label and undeclared values are serialized using the inner value.
+//! When deserializing, either a name or an inner value is accepted. This uses
+//! `deserialize_any` so it only works with self-describing formats.
+//!
+//! The enum itself doesn't need to implement `Serialize` or `Deserialize`.
+//!
+//! # Example
+//! ```
+//! # use c_enum::c_enum;
+//! # use serde::{Deserialize, Serialize};
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Event: u32 {
+//!         CPU_CYCLES,
+//!         INSTRUCTIONS,
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Counter {
+//!     #[serde(with = "c_enum::as_name")]
+//!     event: Event,
+//!     count: u64,
+//! }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, IntoDeserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::CEnum;
+
+/// Serialize a C enum as its label, falling back to the inner value for
+/// undeclared values.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CEnum + Clone,
+    T::Inner: PartialEq + Serialize,
+    S: Serializer,
+{
+    match value.variant_label() {
+        Some(label) => serializer.serialize_str(label),
+        None => value.clone().into().serialize(serializer),
+    }
+}
+
+/// Deserialize a C enum from either a label or an inner value.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: CEnum,
+    T::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(Visitor(PhantomData))
+}
+
+struct Visitor<T>(PhantomData<T>);
+
+macro_rules! visit_value {
+    ($( $method:ident($ty:ty), )*) => {
+        $(
+            fn $method<E>(self, value: $ty) -> Result<T, E>
+            where
+                E: de::Error,
+            {
+                T::Inner::deserialize(value.into_deserializer()).map(T::from)
+            }
+        )*
+    };
+}
+
+impl<'de, T> de::Visitor<'de> for Visitor<T>
+where
+    T: CEnum,
+    T::Inner: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a variant name or value")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<T, E>
+    where
+        E: de::Error,
+    {
+        T::from_name(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &"a variant name"))
+    }
+
+    visit_value!(
+        visit_bool(bool),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f64(f64),
+        visit_char(char),
+    );
+}
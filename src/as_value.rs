@@ -0,0 +1,52 @@
+//! Serialize a C enum field using its inner value.
+//!
+//! This is meant to be used with `#[serde(with = "c_enum::as_value")]` and
+//! behaves the same as `#[c_enum(serde = "value")]`, except that it can be
+//! chosen separately for every field. This is useful when the enum itself
+//! serializes as a name but some formats need the raw value.
+//!
+//! The enum itself doesn't need to implement `Serialize` or `Deserialize`.
+//!
+//! # Example
+//! ```
+//! # use c_enum::c_enum;
+//! # use serde::{Deserialize, Serialize};
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Event: u32 {
+//!         CPU_CYCLES,
+//!         INSTRUCTIONS,
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Counter {
+//!     #[serde(with = "c_enum::as_value")]
+//!     event: Event,
+//!     count: u64,
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::CEnum;
+
+/// Serialize a C enum using its inner value.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CEnum + Clone,
+    T::Inner: Serialize,
+    S: Serializer,
+{
+    value.clone().into().serialize(serializer)
+}
+
+/// Deserialize a C enum from its inner value.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: CEnum,
+    T::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::Inner::deserialize(deserializer).map(T::from)
+}
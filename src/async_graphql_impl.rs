@@ -0,0 +1,255 @@
+//! Helpers for `#[c_enum(async_graphql)]`.
+//!
+//! Like the serde helpers, the macros here are defined differently depending
+//! on whether the `async-graphql` feature is enabled.
+
+#[cfg(feature = "async-graphql")]
+use alloc::string::ToString;
+#[cfg(feature = "async-graphql")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "async-graphql")]
+use async_graphql::registry::{Deprecation, MetaEnumValue, MetaType};
+#[cfg(feature = "async-graphql")]
+use async_graphql::{InputType, InputValueError, InputValueResult, Value};
+
+/// Builds the GraphQL enum type for `T` with one value for each label.
+#[cfg(feature = "async-graphql")]
+pub fn async_graphql_enum_type<T: ?Sized>(name: &str, labels: &[&str]) -> MetaType {
+    let enum_values = labels
+        .iter()
+        .map(|label| {
+            let value = MetaEnumValue {
+                name: label.to_string(),
+                description: None,
+                deprecation: Deprecation::NoDeprecated,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                directive_invocations: Vec::new(),
+            };
+
+            (label.to_string(), value)
+        })
+        .collect();
+
+    MetaType::Enum {
+        name: name.to_string(),
+        description: None,
+        enum_values,
+        visible: None,
+        inaccessible: false,
+        tags: Vec::new(),
+        rust_typename: Some(core::any::type_name::<T>()),
+        directive_invocations: Vec::new(),
+        requires_scopes: Vec::new(),
+    }
+}
+
+/// Parses a GraphQL enum value using `from_name`.
+#[cfg(feature = "async-graphql")]
+pub fn async_graphql_parse_enum<T: InputType>(
+    value: Option<Value>,
+    from_name: fn(&str) -> Option<T>,
+) -> InputValueResult<T> {
+    let value = value.unwrap_or_default();
+    let label = match &value {
+        Value::Enum(name) => name.as_str(),
+        Value::String(label) => label.as_str(),
+        _ => return Err(InputValueError::expected_type(value)),
+    };
+
+    from_name(label).ok_or_else(|| {
+        InputValueError::custom(format_args!(
+            r#"Enumeration type does not contain value "{}"."#,
+            label
+        ))
+    })
+}
+
+/// Implements async-graphql's `InputType` and `OutputType` for a C enum.
+///
+/// In `enum` mode the declared variants become the values of a GraphQL enum
+/// and resolving an undeclared value is an error. In `int` mode everything
+/// forwards to the inner type so that undeclared values round-trip.
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_async_graphql {
+    (enum $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::async_graphql::InputType for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq
+                + ::core::marker::Send
+                + ::core::marker::Sync
+                + $crate::__private::async_graphql::InputType,
+        {
+            type RawValueType = Self;
+
+            fn type_name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($name))
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> $crate::__private::String {
+                registry.create_input_type::<Self, _>(
+                    $crate::__private::async_graphql::registry::MetaTypeId::Enum,
+                    |_| {
+                        $crate::__private::async_graphql_enum_type::<Self>(
+                            ::core::stringify!($name),
+                            Self::__C_ENUM_LABELS,
+                        )
+                    },
+                )
+            }
+
+            fn parse(
+                value: ::core::option::Option<$crate::__private::async_graphql::Value>,
+            ) -> $crate::__private::async_graphql::InputValueResult<Self> {
+                $crate::__private::async_graphql_parse_enum(value, Self::from_name)
+            }
+
+            fn to_value(&self) -> $crate::__private::async_graphql::Value {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => {
+                        $crate::__private::async_graphql::Value::Enum(
+                            $crate::__private::async_graphql::Name::new(label),
+                        )
+                    }
+                    ::core::option::Option::None => {
+                        $crate::__private::async_graphql::InputType::to_value(&self.0)
+                    }
+                }
+            }
+
+            fn as_raw_value(&self) -> ::core::option::Option<&Self::RawValueType> {
+                ::core::option::Option::Some(self)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::async_graphql::OutputType for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + ::core::marker::Send + ::core::marker::Sync,
+        {
+            fn type_name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($name))
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> $crate::__private::String {
+                registry.create_output_type::<Self, _>(
+                    $crate::__private::async_graphql::registry::MetaTypeId::Enum,
+                    |_| {
+                        $crate::__private::async_graphql_enum_type::<Self>(
+                            ::core::stringify!($name),
+                            Self::__C_ENUM_LABELS,
+                        )
+                    },
+                )
+            }
+
+            async fn resolve(
+                &self,
+                _: &$crate::__private::async_graphql::ContextSelectionSet<'_>,
+                field: &$crate::__private::async_graphql::Positioned<
+                    $crate::__private::async_graphql::parser::types::Field,
+                >,
+            ) -> $crate::__private::async_graphql::ServerResult<
+                $crate::__private::async_graphql::Value,
+            > {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => {
+                        ::core::result::Result::Ok($crate::__private::async_graphql::Value::Enum(
+                            $crate::__private::async_graphql::Name::new(label),
+                        ))
+                    }
+                    ::core::option::Option::None => ::core::result::Result::Err(
+                        $crate::__private::async_graphql::ServerError::new(
+                            "value does not correspond to any declared variant",
+                            ::core::option::Option::Some(field.pos),
+                        ),
+                    ),
+                }
+            }
+        }
+    };
+    (int $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::async_graphql::InputType for $name
+        where
+            for<'a> $inner: $crate::__private::async_graphql::InputType,
+        {
+            type RawValueType =
+                <$inner as $crate::__private::async_graphql::InputType>::RawValueType;
+
+            fn type_name() -> $crate::__private::Cow<'static, str> {
+                <$inner as $crate::__private::async_graphql::InputType>::type_name()
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> $crate::__private::String {
+                <$inner as $crate::__private::async_graphql::InputType>::create_type_info(registry)
+            }
+
+            fn parse(
+                value: ::core::option::Option<$crate::__private::async_graphql::Value>,
+            ) -> $crate::__private::async_graphql::InputValueResult<Self> {
+                <$inner as $crate::__private::async_graphql::InputType>::parse(value)
+                    .map(Self)
+                    .map_err($crate::__private::async_graphql::InputValueError::propagate)
+            }
+
+            fn to_value(&self) -> $crate::__private::async_graphql::Value {
+                $crate::__private::async_graphql::InputType::to_value(&self.0)
+            }
+
+            fn as_raw_value(&self) -> ::core::option::Option<&Self::RawValueType> {
+                $crate::__private::async_graphql::InputType::as_raw_value(&self.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::async_graphql::OutputType for $name
+        where
+            for<'a> $inner: $crate::__private::async_graphql::OutputType,
+        {
+            fn type_name() -> $crate::__private::Cow<'static, str> {
+                <$inner as $crate::__private::async_graphql::OutputType>::type_name()
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> $crate::__private::String {
+                <$inner as $crate::__private::async_graphql::OutputType>::create_type_info(registry)
+            }
+
+            async fn resolve(
+                &self,
+                ctx: &$crate::__private::async_graphql::ContextSelectionSet<'_>,
+                field: &$crate::__private::async_graphql::Positioned<
+                    $crate::__private::async_graphql::parser::types::Field,
+                >,
+            ) -> $crate::__private::async_graphql::ServerResult<
+                $crate::__private::async_graphql::Value,
+            > {
+                $crate::__private::async_graphql::OutputType::resolve(&self.0, ctx, field).await
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "async-graphql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_async_graphql {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(async_graphql)]` requires the `async-graphql` feature of c-enum to be enabled"
+        );
+    };
+}
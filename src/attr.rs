@@ -0,0 +1,179 @@
+//! Declaring C enums using an attribute instead of [`c_enum!`].
+//!
+//! With the `proc-macro` feature enabled, `#[c_enum]` can be applied to a
+//! regular enum declaration. The inner type is passed as the first argument
+//! of the attribute, or taken from a `#[repr(...)]` attribute on the enum. Any
+//! following arguments are treated as options, as if they had been passed
+//! using `#[c_enum(...)]`.
+//!
+//! ```
+//! use c_enum::attr::c_enum;
+//!
+//! #[c_enum(u32, ord)]
+//! #[derive(Copy, Clone, PartialEq, Eq)]
+//! pub enum Level {
+//!     Debug,
+//!     Info,
+//!     Error = 5,
+//! }
+//!
+//! #[c_enum]
+//! #[derive(Copy, Clone, PartialEq, Eq)]
+//! #[repr(u8)]
+//! pub enum Color {
+//!     Red,
+//!     Green,
+//! }
+//!
+//! assert_eq!(Level::Error.value(), 5);
+//! assert!(Level::Info < Level::Error);
+//! assert_eq!(core::mem::size_of::<Color>(), 1);
+//! ```
+//!
+//! The enum is expanded using [`c_enum!`], so everything described in the
+//! [crate level docs](crate) applies here as well. The attribute reports
+//! variants with fields with an error pointing at them, instead of the "no
+//! rules expected the token" errors that `c_enum!` gives for these.
+//!
+//! The expansion refers to this crate as `::c_enum`. If it has been renamed in
+//! `Cargo.toml`, or is only available through a re-export, its path can be
+//! passed using the `crate = <path>` option instead.
+//!
+//! ```
+//! mod reexport {
+//!     pub(crate) use c_enum as renamed;
+//! }
+//!
+//! #[c_enum::attr::c_enum(u8, crate = reexport::renamed)]
+//! pub enum Mode {
+//!     Read,
+//!     Write,
+//! }
+//!
+//! assert_eq!(Mode::Write.value(), 1);
+//! ```
+//!
+//! # Predicates
+//! The attribute additionally accepts a `predicates` option, which generates a
+//! [predicate](crate#predicates) named `is_<variant>` for every variant, with
+//! the name of the variant converted to snake case. Variants that have their
+//! own `#[predicate = ...]` attribute keep it instead.
+//!
+//! ```
+//! use c_enum::attr::c_enum;
+//!
+//! #[c_enum(u32, predicates)]
+//! pub enum Event {
+//!     CpuCycles,
+//!     #[predicate = is_insns]
+//!     Instructions,
+//!     CACHE_MISSES,
+//! }
+//!
+//! assert!(Event::CpuCycles.is_cpu_cycles());
+//! assert!(Event::Instructions.is_insns());
+//! assert!(!Event::new(7).is_cache_misses());
+//! ```
+//!
+//! # Generic Enums
+//! Enums can have a single type parameter as their inner type. Since the
+//! values of the variants can't be converted to the type parameter in a
+//! constant, they are instead defined by a trait named after the enum (e.g.
+//! `RegisterValues` for `Register`) which is implemented for each of the inner
+//! types listed in `types(...)`. The constants of the enum are declared for
+//! all inner types implementing that trait, so code that is generic over the
+//! inner type can use them as well.
+//!
+//! Generic enums can be declared using either the attribute, where the type
+//! parameter is passed as the inner type, or `c_enum!`.
+//!
+//! ```
+//! use c_enum::c_enum;
+//!
+//! c_enum! {
+//!     #[c_enum(types(u32, u64))]
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Register<T: Copy>: T {
+//!         CTRL,
+//!         STATUS = 0x04,
+//!         DATA,
+//!     }
+//! }
+//!
+//! #[c_enum::attr::c_enum(T, types(u8))]
+//! #[derive(Copy, Clone, PartialEq, Eq)]
+//! pub enum Command<T: Copy> {
+//!     Reset = 0x10,
+//!     Start,
+//! }
+//!
+//! fn is_status<T: RegisterValues + PartialEq>(register: Register<T>) -> bool {
+//!     register == Register::STATUS
+//! }
+//!
+//! assert_eq!(Register::<u32>::DATA.value(), 5u32);
+//! assert_eq!(Register::<u64>::DATA.value(), 5u64);
+//! assert!(is_status(Register::new(4u64)));
+//! assert_eq!(Register::new(5u32).as_str(), Some("DATA"));
+//! assert_eq!(format!("{:?}", Command::new(0x11u8)), "Command::Start");
+//! ```
+//!
+//! Only a subset of the items described in the [crate level docs](crate) is
+//! generated for generic enums: the constants for each variant, `new()`,
+//! `value()`, `variants()`, `from_name()`, `as_str()`, `is_known()`,
+//! `variant_index()`, `from_index()`, the `From` impls, `Debug` and `CEnum`,
+//! along with `VARIANTS`, `VARIANT_COUNT`, `LABELS` and `VALUES` if `consts`
+//! is passed. Options other than `types(...)` and `consts`, and `crate = <path>`
+//! for the attribute, result in a compile error, and the variants may only have
+//! doc comments and `#[label = "..."]` as attributes.
+//!
+//! ## Lifetime Parameters
+//! Enums can instead have a single lifetime parameter, which their inner type
+//! borrows from. The values of the variants don't borrow anything, so they are
+//! declared as usual and the constants of the enum are valid for any lifetime.
+//! Borrowed data can be converted into the enum using `From` or `new()` and
+//! then looked up using `as_str()`, `variant_index()` and `variant()`.
+//!
+//! The lifetime parameter is replaced with `'static` in these items:
+//! - `variants()` and `VARIANTS` hold the variants with a `'static` lifetime,
+//! - `VALUES` holds inner values with a `'static` lifetime,
+//! - `variant()` returns a variant with a `'static` lifetime, so it no longer
+//!   borrows from the value it was called on,
+//! - `from_name()` and `from_index()` require the `'static` inner type to be
+//!   `Clone` and the `CEnum` implementation requires the same.
+//!
+//! The declared variants themselves are valid for any lifetime, so this only
+//! matters when naming the types of these items.
+//!
+//! Variants without a value use their label as their value if the inner type
+//! is `&'a str`. For any other inner type each variant needs an explicit
+//! value.
+//!
+//! ```
+//! use c_enum::c_enum;
+//!
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Method<'a>: &'a str {
+//!         GET,
+//!         POST,
+//!     }
+//! }
+//!
+//! fn parse(line: &str) -> Method<'_> {
+//!     Method::from(line.split(' ').next().unwrap_or(line))
+//! }
+//!
+//! let request = String::from("POST /index.html HTTP/1.1");
+//! let method: Option<Method<'static>> = parse(&request).variant();
+//! assert_eq!(method, Some(Method::POST));
+//! assert_eq!(parse(&request).as_str(), Some("POST"));
+//! assert!(!parse("PUT /").is_known());
+//! ```
+//!
+//! The same subset of items is generated as for enums with a type parameter,
+//! along with `variant()`.
+//!
+//! [`c_enum!`]: crate::c_enum!
+
+pub use c_enum_macros::c_enum;
@@ -0,0 +1,73 @@
+//! Helper macros for `#[c_enum(bincode)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `bincode` feature is enabled.
+
+/// Implements bincode's `Encode`, `Decode` and `BorrowDecode` for a C enum by
+/// forwarding to the inner value.
+#[cfg(feature = "bincode")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_bincode {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::bincode::Encode for $name
+        where
+            for<'a> $inner: $crate::__private::bincode::Encode,
+        {
+            fn encode<E>(
+                &self,
+                encoder: &mut E,
+            ) -> ::core::result::Result<(), $crate::__private::bincode::error::EncodeError>
+            where
+                E: $crate::__private::bincode::enc::Encoder,
+            {
+                $crate::__private::bincode::Encode::encode(&self.0, encoder)
+            }
+        }
+
+        #[automatically_derived]
+        impl<Context> $crate::__private::bincode::Decode<Context> for $name
+        where
+            $inner: $crate::__private::bincode::Decode<Context>,
+        {
+            fn decode<D>(
+                decoder: &mut D,
+            ) -> ::core::result::Result<Self, $crate::__private::bincode::error::DecodeError>
+            where
+                D: $crate::__private::bincode::de::Decoder<Context = Context>,
+            {
+                <$inner as $crate::__private::bincode::Decode<Context>>::decode(decoder).map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de, Context> $crate::__private::bincode::BorrowDecode<'de, Context> for $name
+        where
+            $inner: $crate::__private::bincode::BorrowDecode<'de, Context>,
+        {
+            fn borrow_decode<D>(
+                decoder: &mut D,
+            ) -> ::core::result::Result<Self, $crate::__private::bincode::error::DecodeError>
+            where
+                D: $crate::__private::bincode::de::BorrowDecoder<'de, Context = Context>,
+            {
+                <$inner as $crate::__private::bincode::BorrowDecode<'de, Context>>::borrow_decode(
+                    decoder,
+                )
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "bincode"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_bincode {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(bincode)]` requires the `bincode` feature of c-enum to be enabled"
+        );
+    };
+}
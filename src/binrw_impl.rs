@@ -0,0 +1,64 @@
+//! Helper macros for `#[c_enum(binrw)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `binrw` feature is enabled.
+
+/// Implements `BinRead` and `BinWrite` for a C enum by forwarding to the inner
+/// type, passing along both the endianness and any arguments.
+#[cfg(feature = "binrw")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_binrw {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::binrw::BinRead for $name
+        where
+            for<'a> $inner: $crate::__private::binrw::BinRead,
+        {
+            type Args<'a> = <$inner as $crate::__private::binrw::BinRead>::Args<'a>;
+
+            fn read_options<R>(
+                reader: &mut R,
+                endian: $crate::__private::binrw::Endian,
+                args: Self::Args<'_>,
+            ) -> $crate::__private::binrw::BinResult<Self>
+            where
+                R: $crate::__private::binrw::io::Read + $crate::__private::binrw::io::Seek,
+            {
+                <$inner as $crate::__private::binrw::BinRead>::read_options(reader, endian, args)
+                    .map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::binrw::BinWrite for $name
+        where
+            for<'a> $inner: $crate::__private::binrw::BinWrite,
+        {
+            type Args<'a> = <$inner as $crate::__private::binrw::BinWrite>::Args<'a>;
+
+            fn write_options<W>(
+                &self,
+                writer: &mut W,
+                endian: $crate::__private::binrw::Endian,
+                args: Self::Args<'_>,
+            ) -> $crate::__private::binrw::BinResult<()>
+            where
+                W: $crate::__private::binrw::io::Write + $crate::__private::binrw::io::Seek,
+            {
+                $crate::__private::binrw::BinWrite::write_options(&self.0, writer, endian, args)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "binrw"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_binrw {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(binrw)]` requires the `binrw` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,18 @@
+//! Formatting for enums with a `&'static [u8]` inner type.
+
+use core::fmt::{self, Write};
+
+/// Formats a byte string as an escaped `b"..."` literal.
+pub struct ByteStr<'a>(pub &'a [u8]);
+
+impl fmt::Debug for ByteStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("b\"")?;
+        for &byte in self.0 {
+            for c in core::ascii::escape_default(byte) {
+                f.write_char(c as char)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
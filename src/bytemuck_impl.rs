@@ -0,0 +1,49 @@
+//! Helper macros for `#[c_enum(bytemuck)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `bytemuck` feature is enabled.
+
+/// Implements `bytemuck::Zeroable` and `bytemuck::Pod` for a C enum.
+///
+/// This is only sound if the struct is `#[repr(transparent)]` so we look for
+/// that within its attributes and emit an error if it isn't there.
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_bytemuck {
+    ([ #[repr(transparent)] $( $rest:tt )* ] $name:ident [ $inner:ty ]) => {
+        const _: () = {
+            fn assert_pod<T: $crate::__private::bytemuck::Pod>() {}
+            let _ = assert_pod::<$inner>;
+        };
+
+        // SAFETY: The struct is #[repr(transparent)] over an inner type that
+        //         implements Pod, so it is also valid for any bit pattern,
+        //         including all zeros.
+        #[automatically_derived]
+        unsafe impl $crate::__private::bytemuck::Zeroable for $name {}
+
+        // SAFETY: See above.
+        #[automatically_derived]
+        unsafe impl $crate::__private::bytemuck::Pod for $name {}
+    };
+    ([ #[ $( $attr:tt )* ] $( $rest:tt )* ] $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_bytemuck!([ $( $rest )* ] $name [ $inner ]);
+    };
+    ([] $name:ident [ $inner:ty ]) => {
+        ::core::compile_error!(
+            "`#[c_enum(bytemuck)]` requires the enum to be `#[repr(transparent)]`"
+        );
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_bytemuck {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(bytemuck)]` requires the `bytemuck` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,184 @@
+//! Const-evaluable label transformations used to implement
+//! `#[c_enum(rename_all = "...")]` and `#[c_enum(strip_prefix = "...")]`.
+//!
+//! Everything in here needs to work as a `const fn` since the renamed labels
+//! are computed at compile time within the code generated by `c_enum!`.
+
+/// The casing conventions supported by `rename_all`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+const fn is_separator(c: u8) -> bool {
+    c == b'_' || c == b'-'
+}
+
+/// Whether a new word starts at `bytes[i]`, given that `bytes[i - 1]` is part
+/// of the same run of non-separator characters.
+const fn is_word_boundary(bytes: &[u8], i: usize) -> bool {
+    let prev = bytes[i - 1];
+    let curr = bytes[i];
+
+    if prev.is_ascii_lowercase() && curr.is_ascii_uppercase() {
+        return true;
+    }
+
+    // The end of an acronym, e.g. the `S` in `HTTPServer`.
+    prev.is_ascii_uppercase()
+        && curr.is_ascii_uppercase()
+        && i + 1 < bytes.len()
+        && bytes[i + 1].is_ascii_lowercase()
+}
+
+/// Returns the length of `prefix` if `name` starts with it and is longer
+/// than it, or 0 otherwise.
+const fn prefix_len(name: &[u8], prefix: &[u8]) -> usize {
+    if prefix.len() >= name.len() {
+        return 0;
+    }
+
+    let mut i = 0;
+    while i < prefix.len() {
+        if name[i] != prefix[i] {
+            return 0;
+        }
+        i += 1;
+    }
+
+    prefix.len()
+}
+
+/// Strips `prefix` from `name` and then converts it to the requested case,
+/// returning a buffer containing the result along with the length of the
+/// result within that buffer.
+///
+/// `N` must be at least twice the length of `name`.
+pub const fn rename<const N: usize>(
+    name: &str,
+    prefix: &str,
+    case: Option<Case>,
+) -> ([u8; N], usize) {
+    let name = name.as_bytes();
+    let start = prefix_len(name, prefix.as_bytes());
+    let mut out = [0u8; N];
+    let mut len = 0;
+
+    let case = match case {
+        Some(case) => case,
+        None => {
+            while start + len < name.len() {
+                out[len] = name[start + len];
+                len += 1;
+            }
+
+            return (out, len);
+        }
+    };
+
+    // lowercase and UPPERCASE only change the case of each character.
+    if let Case::Lower | Case::Upper = case {
+        while start + len < name.len() {
+            let c = name[start + len];
+            out[len] = match case {
+                Case::Lower => c.to_ascii_lowercase(),
+                _ => c.to_ascii_uppercase(),
+            };
+            len += 1;
+        }
+
+        return (out, len);
+    }
+
+    let (separator, upper) = match case {
+        Case::Snake => (Some(b'_'), false),
+        Case::ScreamingSnake => (Some(b'_'), true),
+        Case::Kebab => (Some(b'-'), false),
+        Case::ScreamingKebab => (Some(b'-'), true),
+        _ => (None, false),
+    };
+
+    let bytes = name;
+    let mut words = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        if is_separator(bytes[i]) {
+            i += 1;
+            continue;
+        }
+
+        // Emit a single word starting at bytes[i].
+        let mut j = i;
+        while j < bytes.len() && !is_separator(bytes[j]) {
+            if j > i && is_word_boundary(bytes, j) {
+                break;
+            }
+
+            let c = bytes[j];
+            let first = j == i;
+
+            if first && words > 0 {
+                if let Some(separator) = separator {
+                    out[len] = separator;
+                    len += 1;
+                }
+            }
+
+            out[len] = match case {
+                Case::Pascal if first => c.to_ascii_uppercase(),
+                Case::Camel if first && words > 0 => c.to_ascii_uppercase(),
+                _ if upper => c.to_ascii_uppercase(),
+                _ => c.to_ascii_lowercase(),
+            };
+            len += 1;
+            j += 1;
+        }
+
+        words += 1;
+        i = j;
+    }
+
+    (out, len)
+}
+
+/// Copies the first `M` bytes of `bytes` into a new array.
+pub const fn truncate<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    let mut out = [0u8; M];
+    let mut i = 0;
+    while i < M {
+        out[i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+/// Converts `bytes` to a `&str` if it consists entirely of ASCII characters.
+pub const fn ascii_str(bytes: &[u8]) -> Option<&str> {
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii() {
+            return None;
+        }
+        i += 1;
+    }
+
+    // SAFETY: ASCII is always valid UTF-8.
+    Some(unsafe { core::str::from_utf8_unchecked(bytes) })
+}
+
+/// Removes the `r#` prefix from the stringified name of a raw identifier.
+pub const fn unraw(name: &str) -> &str {
+    match name.as_bytes() {
+        // SAFETY: Removing an ASCII prefix from a valid `str` leaves valid
+        //         UTF-8 behind.
+        [b'r', b'#', rest @ ..] => unsafe { core::str::from_utf8_unchecked(rest) },
+        _ => name,
+    }
+}
@@ -0,0 +1,42 @@
+//! Helper macros for `#[c_enum(clap)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `clap` feature is enabled.
+
+/// Implements `clap::ValueEnum` for a C enum using its declared variants and
+/// their labels.
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_clap {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::clap::ValueEnum for $name
+        where
+            for<'a> $name: ::core::clone::Clone,
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            fn value_variants<'a>() -> &'a [Self] {
+                Self::__C_ENUM_VARIANTS
+            }
+
+            fn to_possible_value(
+                &self,
+            ) -> ::core::option::Option<$crate::__private::clap::builder::PossibleValue> {
+                <Self as $crate::CEnum>::variant_label(self)
+                    .map($crate::__private::clap::builder::PossibleValue::new)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "clap"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_clap {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(clap)]` requires the `clap` feature of c-enum to be enabled"
+        );
+    };
+}
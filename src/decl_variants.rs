@@ -10,1416 +10,15430 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __c_enum_decl_variants {
-    (impl($name:ident, $inner:ty, $default:expr)) => {};
+    (impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)) => {
+        $crate::__c_enum_impl!(impl(next_const $kind $inner) $default);
+    };
 
     // Match 128 fields
     (
-        impl($name:ident, $inner:ty, $default:expr)
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
 
-        $( #[$attr1:meta] )*
+        $( #[ $( $attr1:tt )* ] )*
         $field1:ident $( = $value1:expr )? ,
 
-        $( #[$attr2:meta] )*
+        $( #[ $( $attr2:tt )* ] )*
         $field2:ident $( = $value2:expr )? ,
 
-        $( #[$attr3:meta] )*
+        $( #[ $( $attr3:tt )* ] )*
         $field3:ident $( = $value3:expr )? ,
 
-        $( #[$attr4:meta] )*
+        $( #[ $( $attr4:tt )* ] )*
         $field4:ident $( = $value4:expr )? ,
 
-        $( #[$attr5:meta] )*
+        $( #[ $( $attr5:tt )* ] )*
         $field5:ident $( = $value5:expr )? ,
 
-        $( #[$attr6:meta] )*
+        $( #[ $( $attr6:tt )* ] )*
         $field6:ident $( = $value6:expr )? ,
 
-        $( #[$attr7:meta] )*
+        $( #[ $( $attr7:tt )* ] )*
         $field7:ident $( = $value7:expr )? ,
 
-        $( #[$attr8:meta] )*
+        $( #[ $( $attr8:tt )* ] )*
         $field8:ident $( = $value8:expr )? ,
 
-        $( #[$attr9:meta] )*
+        $( #[ $( $attr9:tt )* ] )*
         $field9:ident $( = $value9:expr )? ,
 
-        $( #[$attr10:meta] )*
+        $( #[ $( $attr10:tt )* ] )*
         $field10:ident $( = $value10:expr )? ,
 
-        $( #[$attr11:meta] )*
+        $( #[ $( $attr11:tt )* ] )*
         $field11:ident $( = $value11:expr )? ,
 
-        $( #[$attr12:meta] )*
+        $( #[ $( $attr12:tt )* ] )*
         $field12:ident $( = $value12:expr )? ,
 
-        $( #[$attr13:meta] )*
+        $( #[ $( $attr13:tt )* ] )*
         $field13:ident $( = $value13:expr )? ,
 
-        $( #[$attr14:meta] )*
+        $( #[ $( $attr14:tt )* ] )*
         $field14:ident $( = $value14:expr )? ,
 
-        $( #[$attr15:meta] )*
+        $( #[ $( $attr15:tt )* ] )*
         $field15:ident $( = $value15:expr )? ,
 
-        $( #[$attr16:meta] )*
+        $( #[ $( $attr16:tt )* ] )*
         $field16:ident $( = $value16:expr )? ,
 
-        $( #[$attr17:meta] )*
+        $( #[ $( $attr17:tt )* ] )*
         $field17:ident $( = $value17:expr )? ,
 
-        $( #[$attr18:meta] )*
+        $( #[ $( $attr18:tt )* ] )*
         $field18:ident $( = $value18:expr )? ,
 
-        $( #[$attr19:meta] )*
+        $( #[ $( $attr19:tt )* ] )*
         $field19:ident $( = $value19:expr )? ,
 
-        $( #[$attr20:meta] )*
+        $( #[ $( $attr20:tt )* ] )*
         $field20:ident $( = $value20:expr )? ,
 
-        $( #[$attr21:meta] )*
+        $( #[ $( $attr21:tt )* ] )*
         $field21:ident $( = $value21:expr )? ,
 
-        $( #[$attr22:meta] )*
+        $( #[ $( $attr22:tt )* ] )*
         $field22:ident $( = $value22:expr )? ,
 
-        $( #[$attr23:meta] )*
+        $( #[ $( $attr23:tt )* ] )*
         $field23:ident $( = $value23:expr )? ,
 
-        $( #[$attr24:meta] )*
+        $( #[ $( $attr24:tt )* ] )*
         $field24:ident $( = $value24:expr )? ,
 
-        $( #[$attr25:meta] )*
+        $( #[ $( $attr25:tt )* ] )*
         $field25:ident $( = $value25:expr )? ,
 
-        $( #[$attr26:meta] )*
+        $( #[ $( $attr26:tt )* ] )*
         $field26:ident $( = $value26:expr )? ,
 
-        $( #[$attr27:meta] )*
+        $( #[ $( $attr27:tt )* ] )*
         $field27:ident $( = $value27:expr )? ,
 
-        $( #[$attr28:meta] )*
+        $( #[ $( $attr28:tt )* ] )*
         $field28:ident $( = $value28:expr )? ,
 
-        $( #[$attr29:meta] )*
+        $( #[ $( $attr29:tt )* ] )*
         $field29:ident $( = $value29:expr )? ,
 
-        $( #[$attr30:meta] )*
+        $( #[ $( $attr30:tt )* ] )*
         $field30:ident $( = $value30:expr )? ,
 
-        $( #[$attr31:meta] )*
+        $( #[ $( $attr31:tt )* ] )*
         $field31:ident $( = $value31:expr )? ,
 
-        $( #[$attr32:meta] )*
+        $( #[ $( $attr32:tt )* ] )*
         $field32:ident $( = $value32:expr )? ,
 
-        $( #[$attr33:meta] )*
+        $( #[ $( $attr33:tt )* ] )*
         $field33:ident $( = $value33:expr )? ,
 
-        $( #[$attr34:meta] )*
+        $( #[ $( $attr34:tt )* ] )*
         $field34:ident $( = $value34:expr )? ,
 
-        $( #[$attr35:meta] )*
+        $( #[ $( $attr35:tt )* ] )*
         $field35:ident $( = $value35:expr )? ,
 
-        $( #[$attr36:meta] )*
+        $( #[ $( $attr36:tt )* ] )*
         $field36:ident $( = $value36:expr )? ,
 
-        $( #[$attr37:meta] )*
+        $( #[ $( $attr37:tt )* ] )*
         $field37:ident $( = $value37:expr )? ,
 
-        $( #[$attr38:meta] )*
+        $( #[ $( $attr38:tt )* ] )*
         $field38:ident $( = $value38:expr )? ,
 
-        $( #[$attr39:meta] )*
+        $( #[ $( $attr39:tt )* ] )*
         $field39:ident $( = $value39:expr )? ,
 
-        $( #[$attr40:meta] )*
+        $( #[ $( $attr40:tt )* ] )*
         $field40:ident $( = $value40:expr )? ,
 
-        $( #[$attr41:meta] )*
+        $( #[ $( $attr41:tt )* ] )*
         $field41:ident $( = $value41:expr )? ,
 
-        $( #[$attr42:meta] )*
+        $( #[ $( $attr42:tt )* ] )*
         $field42:ident $( = $value42:expr )? ,
 
-        $( #[$attr43:meta] )*
+        $( #[ $( $attr43:tt )* ] )*
         $field43:ident $( = $value43:expr )? ,
 
-        $( #[$attr44:meta] )*
+        $( #[ $( $attr44:tt )* ] )*
         $field44:ident $( = $value44:expr )? ,
 
-        $( #[$attr45:meta] )*
+        $( #[ $( $attr45:tt )* ] )*
         $field45:ident $( = $value45:expr )? ,
 
-        $( #[$attr46:meta] )*
+        $( #[ $( $attr46:tt )* ] )*
         $field46:ident $( = $value46:expr )? ,
 
-        $( #[$attr47:meta] )*
+        $( #[ $( $attr47:tt )* ] )*
         $field47:ident $( = $value47:expr )? ,
 
-        $( #[$attr48:meta] )*
+        $( #[ $( $attr48:tt )* ] )*
         $field48:ident $( = $value48:expr )? ,
 
-        $( #[$attr49:meta] )*
+        $( #[ $( $attr49:tt )* ] )*
         $field49:ident $( = $value49:expr )? ,
 
-        $( #[$attr50:meta] )*
+        $( #[ $( $attr50:tt )* ] )*
         $field50:ident $( = $value50:expr )? ,
 
-        $( #[$attr51:meta] )*
+        $( #[ $( $attr51:tt )* ] )*
         $field51:ident $( = $value51:expr )? ,
 
-        $( #[$attr52:meta] )*
+        $( #[ $( $attr52:tt )* ] )*
         $field52:ident $( = $value52:expr )? ,
 
-        $( #[$attr53:meta] )*
+        $( #[ $( $attr53:tt )* ] )*
         $field53:ident $( = $value53:expr )? ,
 
-        $( #[$attr54:meta] )*
+        $( #[ $( $attr54:tt )* ] )*
         $field54:ident $( = $value54:expr )? ,
 
-        $( #[$attr55:meta] )*
+        $( #[ $( $attr55:tt )* ] )*
         $field55:ident $( = $value55:expr )? ,
 
-        $( #[$attr56:meta] )*
+        $( #[ $( $attr56:tt )* ] )*
         $field56:ident $( = $value56:expr )? ,
 
-        $( #[$attr57:meta] )*
+        $( #[ $( $attr57:tt )* ] )*
         $field57:ident $( = $value57:expr )? ,
 
-        $( #[$attr58:meta] )*
+        $( #[ $( $attr58:tt )* ] )*
         $field58:ident $( = $value58:expr )? ,
 
-        $( #[$attr59:meta] )*
+        $( #[ $( $attr59:tt )* ] )*
         $field59:ident $( = $value59:expr )? ,
 
-        $( #[$attr60:meta] )*
+        $( #[ $( $attr60:tt )* ] )*
         $field60:ident $( = $value60:expr )? ,
 
-        $( #[$attr61:meta] )*
+        $( #[ $( $attr61:tt )* ] )*
         $field61:ident $( = $value61:expr )? ,
 
-        $( #[$attr62:meta] )*
+        $( #[ $( $attr62:tt )* ] )*
         $field62:ident $( = $value62:expr )? ,
 
-        $( #[$attr63:meta] )*
+        $( #[ $( $attr63:tt )* ] )*
         $field63:ident $( = $value63:expr )? ,
 
-        $( #[$attr64:meta] )*
+        $( #[ $( $attr64:tt )* ] )*
         $field64:ident $( = $value64:expr )? ,
 
-        $( #[$attr65:meta] )*
+        $( #[ $( $attr65:tt )* ] )*
         $field65:ident $( = $value65:expr )? ,
 
-        $( #[$attr66:meta] )*
+        $( #[ $( $attr66:tt )* ] )*
         $field66:ident $( = $value66:expr )? ,
 
-        $( #[$attr67:meta] )*
+        $( #[ $( $attr67:tt )* ] )*
         $field67:ident $( = $value67:expr )? ,
 
-        $( #[$attr68:meta] )*
+        $( #[ $( $attr68:tt )* ] )*
         $field68:ident $( = $value68:expr )? ,
 
-        $( #[$attr69:meta] )*
+        $( #[ $( $attr69:tt )* ] )*
         $field69:ident $( = $value69:expr )? ,
 
-        $( #[$attr70:meta] )*
+        $( #[ $( $attr70:tt )* ] )*
         $field70:ident $( = $value70:expr )? ,
 
-        $( #[$attr71:meta] )*
+        $( #[ $( $attr71:tt )* ] )*
         $field71:ident $( = $value71:expr )? ,
 
-        $( #[$attr72:meta] )*
+        $( #[ $( $attr72:tt )* ] )*
         $field72:ident $( = $value72:expr )? ,
 
-        $( #[$attr73:meta] )*
+        $( #[ $( $attr73:tt )* ] )*
         $field73:ident $( = $value73:expr )? ,
 
-        $( #[$attr74:meta] )*
+        $( #[ $( $attr74:tt )* ] )*
         $field74:ident $( = $value74:expr )? ,
 
-        $( #[$attr75:meta] )*
+        $( #[ $( $attr75:tt )* ] )*
         $field75:ident $( = $value75:expr )? ,
 
-        $( #[$attr76:meta] )*
+        $( #[ $( $attr76:tt )* ] )*
         $field76:ident $( = $value76:expr )? ,
 
-        $( #[$attr77:meta] )*
+        $( #[ $( $attr77:tt )* ] )*
         $field77:ident $( = $value77:expr )? ,
 
-        $( #[$attr78:meta] )*
+        $( #[ $( $attr78:tt )* ] )*
         $field78:ident $( = $value78:expr )? ,
 
-        $( #[$attr79:meta] )*
+        $( #[ $( $attr79:tt )* ] )*
         $field79:ident $( = $value79:expr )? ,
 
-        $( #[$attr80:meta] )*
+        $( #[ $( $attr80:tt )* ] )*
         $field80:ident $( = $value80:expr )? ,
 
-        $( #[$attr81:meta] )*
+        $( #[ $( $attr81:tt )* ] )*
         $field81:ident $( = $value81:expr )? ,
 
-        $( #[$attr82:meta] )*
+        $( #[ $( $attr82:tt )* ] )*
         $field82:ident $( = $value82:expr )? ,
 
-        $( #[$attr83:meta] )*
+        $( #[ $( $attr83:tt )* ] )*
         $field83:ident $( = $value83:expr )? ,
 
-        $( #[$attr84:meta] )*
+        $( #[ $( $attr84:tt )* ] )*
         $field84:ident $( = $value84:expr )? ,
 
-        $( #[$attr85:meta] )*
+        $( #[ $( $attr85:tt )* ] )*
         $field85:ident $( = $value85:expr )? ,
 
-        $( #[$attr86:meta] )*
+        $( #[ $( $attr86:tt )* ] )*
         $field86:ident $( = $value86:expr )? ,
 
-        $( #[$attr87:meta] )*
+        $( #[ $( $attr87:tt )* ] )*
         $field87:ident $( = $value87:expr )? ,
 
-        $( #[$attr88:meta] )*
+        $( #[ $( $attr88:tt )* ] )*
         $field88:ident $( = $value88:expr )? ,
 
-        $( #[$attr89:meta] )*
+        $( #[ $( $attr89:tt )* ] )*
         $field89:ident $( = $value89:expr )? ,
 
-        $( #[$attr90:meta] )*
+        $( #[ $( $attr90:tt )* ] )*
         $field90:ident $( = $value90:expr )? ,
 
-        $( #[$attr91:meta] )*
+        $( #[ $( $attr91:tt )* ] )*
         $field91:ident $( = $value91:expr )? ,
 
-        $( #[$attr92:meta] )*
+        $( #[ $( $attr92:tt )* ] )*
         $field92:ident $( = $value92:expr )? ,
 
-        $( #[$attr93:meta] )*
+        $( #[ $( $attr93:tt )* ] )*
         $field93:ident $( = $value93:expr )? ,
 
-        $( #[$attr94:meta] )*
+        $( #[ $( $attr94:tt )* ] )*
         $field94:ident $( = $value94:expr )? ,
 
-        $( #[$attr95:meta] )*
+        $( #[ $( $attr95:tt )* ] )*
         $field95:ident $( = $value95:expr )? ,
 
-        $( #[$attr96:meta] )*
+        $( #[ $( $attr96:tt )* ] )*
         $field96:ident $( = $value96:expr )? ,
 
-        $( #[$attr97:meta] )*
+        $( #[ $( $attr97:tt )* ] )*
         $field97:ident $( = $value97:expr )? ,
 
-        $( #[$attr98:meta] )*
+        $( #[ $( $attr98:tt )* ] )*
         $field98:ident $( = $value98:expr )? ,
 
-        $( #[$attr99:meta] )*
+        $( #[ $( $attr99:tt )* ] )*
         $field99:ident $( = $value99:expr )? ,
 
-        $( #[$attr100:meta] )*
+        $( #[ $( $attr100:tt )* ] )*
         $field100:ident $( = $value100:expr )? ,
 
-        $( #[$attr101:meta] )*
+        $( #[ $( $attr101:tt )* ] )*
         $field101:ident $( = $value101:expr )? ,
 
-        $( #[$attr102:meta] )*
+        $( #[ $( $attr102:tt )* ] )*
         $field102:ident $( = $value102:expr )? ,
 
-        $( #[$attr103:meta] )*
+        $( #[ $( $attr103:tt )* ] )*
         $field103:ident $( = $value103:expr )? ,
 
-        $( #[$attr104:meta] )*
+        $( #[ $( $attr104:tt )* ] )*
         $field104:ident $( = $value104:expr )? ,
 
-        $( #[$attr105:meta] )*
+        $( #[ $( $attr105:tt )* ] )*
         $field105:ident $( = $value105:expr )? ,
 
-        $( #[$attr106:meta] )*
+        $( #[ $( $attr106:tt )* ] )*
         $field106:ident $( = $value106:expr )? ,
 
-        $( #[$attr107:meta] )*
+        $( #[ $( $attr107:tt )* ] )*
         $field107:ident $( = $value107:expr )? ,
 
-        $( #[$attr108:meta] )*
+        $( #[ $( $attr108:tt )* ] )*
         $field108:ident $( = $value108:expr )? ,
 
-        $( #[$attr109:meta] )*
+        $( #[ $( $attr109:tt )* ] )*
         $field109:ident $( = $value109:expr )? ,
 
-        $( #[$attr110:meta] )*
+        $( #[ $( $attr110:tt )* ] )*
         $field110:ident $( = $value110:expr )? ,
 
-        $( #[$attr111:meta] )*
+        $( #[ $( $attr111:tt )* ] )*
         $field111:ident $( = $value111:expr )? ,
 
-        $( #[$attr112:meta] )*
+        $( #[ $( $attr112:tt )* ] )*
         $field112:ident $( = $value112:expr )? ,
 
-        $( #[$attr113:meta] )*
+        $( #[ $( $attr113:tt )* ] )*
         $field113:ident $( = $value113:expr )? ,
 
-        $( #[$attr114:meta] )*
+        $( #[ $( $attr114:tt )* ] )*
         $field114:ident $( = $value114:expr )? ,
 
-        $( #[$attr115:meta] )*
+        $( #[ $( $attr115:tt )* ] )*
         $field115:ident $( = $value115:expr )? ,
 
-        $( #[$attr116:meta] )*
+        $( #[ $( $attr116:tt )* ] )*
         $field116:ident $( = $value116:expr )? ,
 
-        $( #[$attr117:meta] )*
+        $( #[ $( $attr117:tt )* ] )*
         $field117:ident $( = $value117:expr )? ,
 
-        $( #[$attr118:meta] )*
+        $( #[ $( $attr118:tt )* ] )*
         $field118:ident $( = $value118:expr )? ,
 
-        $( #[$attr119:meta] )*
+        $( #[ $( $attr119:tt )* ] )*
         $field119:ident $( = $value119:expr )? ,
 
-        $( #[$attr120:meta] )*
+        $( #[ $( $attr120:tt )* ] )*
         $field120:ident $( = $value120:expr )? ,
 
-        $( #[$attr121:meta] )*
+        $( #[ $( $attr121:tt )* ] )*
         $field121:ident $( = $value121:expr )? ,
 
-        $( #[$attr122:meta] )*
+        $( #[ $( $attr122:tt )* ] )*
         $field122:ident $( = $value122:expr )? ,
 
-        $( #[$attr123:meta] )*
+        $( #[ $( $attr123:tt )* ] )*
         $field123:ident $( = $value123:expr )? ,
 
-        $( #[$attr124:meta] )*
+        $( #[ $( $attr124:tt )* ] )*
         $field124:ident $( = $value124:expr )? ,
 
-        $( #[$attr125:meta] )*
+        $( #[ $( $attr125:tt )* ] )*
         $field125:ident $( = $value125:expr )? ,
 
-        $( #[$attr126:meta] )*
+        $( #[ $( $attr126:tt )* ] )*
         $field126:ident $( = $value126:expr )? ,
 
-        $( #[$attr127:meta] )*
+        $( #[ $( $attr127:tt )* ] )*
         $field127:ident $( = $value127:expr )? ,
 
-        $( #[$attr128:meta] )*
+        $( #[ $( $attr128:tt )* ] )*
         $field128:ident $( = $value128:expr )? ,
 
         $(
-            $( #[$rattr:meta] )*
+            $( #[ $( $rattr:tt )* ] )*
             $frest:ident $( = $frest_val:expr )?
         ),* $(,)?
     ) => {
-        $( #[$attr1] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field1: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value1, )? $default));
-
-        $( #[$attr2] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field2: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value2, )? Self::$field1.0 + 1));
-
-        $( #[$attr3] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field3: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value3, )? Self::$field2.0 + 1));
-
-        $( #[$attr4] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field4: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value4, )? Self::$field3.0 + 1));
-
-        $( #[$attr5] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field5: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value5, )? Self::$field4.0 + 1));
-
-        $( #[$attr6] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field6: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value6, )? Self::$field5.0 + 1));
-
-        $( #[$attr7] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field7: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value7, )? Self::$field6.0 + 1));
-
-        $( #[$attr8] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field8: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value8, )? Self::$field7.0 + 1));
-
-        $( #[$attr9] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field9: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value9, )? Self::$field8.0 + 1));
-
-        $( #[$attr10] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field10: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value10, )? Self::$field9.0 + 1));
-
-        $( #[$attr11] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field11: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value11, )? Self::$field10.0 + 1));
-
-        $( #[$attr12] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field12: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value12, )? Self::$field11.0 + 1));
-
-        $( #[$attr13] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field13: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value13, )? Self::$field12.0 + 1));
-
-        $( #[$attr14] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field14: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value14, )? Self::$field13.0 + 1));
-
-        $( #[$attr15] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field15: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value15, )? Self::$field14.0 + 1));
-
-        $( #[$attr16] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field16: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value16, )? Self::$field15.0 + 1));
-
-        $( #[$attr17] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field17: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value17, )? Self::$field16.0 + 1));
-
-        $( #[$attr18] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field18: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value18, )? Self::$field17.0 + 1));
-
-        $( #[$attr19] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field19: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value19, )? Self::$field18.0 + 1));
-
-        $( #[$attr20] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field20: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value20, )? Self::$field19.0 + 1));
-
-        $( #[$attr21] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field21: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value21, )? Self::$field20.0 + 1));
-
-        $( #[$attr22] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field22: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value22, )? Self::$field21.0 + 1));
-
-        $( #[$attr23] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field23: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value23, )? Self::$field22.0 + 1));
-
-        $( #[$attr24] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field24: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value24, )? Self::$field23.0 + 1));
-
-        $( #[$attr25] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field25: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value25, )? Self::$field24.0 + 1));
-
-        $( #[$attr26] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field26: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value26, )? Self::$field25.0 + 1));
-
-        $( #[$attr27] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field27: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value27, )? Self::$field26.0 + 1));
-
-        $( #[$attr28] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field28: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value28, )? Self::$field27.0 + 1));
-
-        $( #[$attr29] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field29: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value29, )? Self::$field28.0 + 1));
-
-        $( #[$attr30] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field30: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value30, )? Self::$field29.0 + 1));
-
-        $( #[$attr31] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field31: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value31, )? Self::$field30.0 + 1));
-
-        $( #[$attr32] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field32: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value32, )? Self::$field31.0 + 1));
-
-        $( #[$attr33] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field33: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value33, )? Self::$field32.0 + 1));
-
-        $( #[$attr34] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field34: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value34, )? Self::$field33.0 + 1));
-
-        $( #[$attr35] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field35: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value35, )? Self::$field34.0 + 1));
-
-        $( #[$attr36] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field36: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value36, )? Self::$field35.0 + 1));
-
-        $( #[$attr37] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field37: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value37, )? Self::$field36.0 + 1));
-
-        $( #[$attr38] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field38: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value38, )? Self::$field37.0 + 1));
-
-        $( #[$attr39] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field39: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value39, )? Self::$field38.0 + 1));
-
-        $( #[$attr40] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field40: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value40, )? Self::$field39.0 + 1));
-
-        $( #[$attr41] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field41: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value41, )? Self::$field40.0 + 1));
-
-        $( #[$attr42] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field42: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value42, )? Self::$field41.0 + 1));
-
-        $( #[$attr43] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field43: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value43, )? Self::$field42.0 + 1));
-
-        $( #[$attr44] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field44: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value44, )? Self::$field43.0 + 1));
-
-        $( #[$attr45] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field45: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value45, )? Self::$field44.0 + 1));
-
-        $( #[$attr46] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field46: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value46, )? Self::$field45.0 + 1));
-
-        $( #[$attr47] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field47: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value47, )? Self::$field46.0 + 1));
-
-        $( #[$attr48] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field48: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value48, )? Self::$field47.0 + 1));
-
-        $( #[$attr49] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field49: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value49, )? Self::$field48.0 + 1));
-
-        $( #[$attr50] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field50: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value50, )? Self::$field49.0 + 1));
-
-        $( #[$attr51] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field51: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value51, )? Self::$field50.0 + 1));
-
-        $( #[$attr52] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field52: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value52, )? Self::$field51.0 + 1));
-
-        $( #[$attr53] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field53: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value53, )? Self::$field52.0 + 1));
-
-        $( #[$attr54] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field54: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value54, )? Self::$field53.0 + 1));
-
-        $( #[$attr55] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field55: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value55, )? Self::$field54.0 + 1));
-
-        $( #[$attr56] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field56: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value56, )? Self::$field55.0 + 1));
-
-        $( #[$attr57] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field57: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value57, )? Self::$field56.0 + 1));
-
-        $( #[$attr58] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field58: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value58, )? Self::$field57.0 + 1));
-
-        $( #[$attr59] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field59: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value59, )? Self::$field58.0 + 1));
-
-        $( #[$attr60] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field60: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value60, )? Self::$field59.0 + 1));
-
-        $( #[$attr61] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field61: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value61, )? Self::$field60.0 + 1));
-
-        $( #[$attr62] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field62: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value62, )? Self::$field61.0 + 1));
-
-        $( #[$attr63] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field63: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value63, )? Self::$field62.0 + 1));
-
-        $( #[$attr64] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field64: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value64, )? Self::$field63.0 + 1));
-
-        $( #[$attr65] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field65: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value65, )? Self::$field64.0 + 1));
-
-        $( #[$attr66] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field66: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value66, )? Self::$field65.0 + 1));
-
-        $( #[$attr67] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field67: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value67, )? Self::$field66.0 + 1));
-
-        $( #[$attr68] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field68: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value68, )? Self::$field67.0 + 1));
-
-        $( #[$attr69] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field69: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value69, )? Self::$field68.0 + 1));
-
-        $( #[$attr70] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field70: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value70, )? Self::$field69.0 + 1));
-
-        $( #[$attr71] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field71: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value71, )? Self::$field70.0 + 1));
-
-        $( #[$attr72] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field72: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value72, )? Self::$field71.0 + 1));
-
-        $( #[$attr73] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field73: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value73, )? Self::$field72.0 + 1));
-
-        $( #[$attr74] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field74: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value74, )? Self::$field73.0 + 1));
-
-        $( #[$attr75] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field75: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value75, )? Self::$field74.0 + 1));
-
-        $( #[$attr76] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field76: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value76, )? Self::$field75.0 + 1));
-
-        $( #[$attr77] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field77: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value77, )? Self::$field76.0 + 1));
-
-        $( #[$attr78] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field78: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value78, )? Self::$field77.0 + 1));
-
-        $( #[$attr79] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field79: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value79, )? Self::$field78.0 + 1));
-
-        $( #[$attr80] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field80: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value80, )? Self::$field79.0 + 1));
-
-        $( #[$attr81] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field81: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value81, )? Self::$field80.0 + 1));
-
-        $( #[$attr82] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field82: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value82, )? Self::$field81.0 + 1));
-
-        $( #[$attr83] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field83: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value83, )? Self::$field82.0 + 1));
-
-        $( #[$attr84] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field84: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value84, )? Self::$field83.0 + 1));
-
-        $( #[$attr85] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field85: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value85, )? Self::$field84.0 + 1));
-
-        $( #[$attr86] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field86: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value86, )? Self::$field85.0 + 1));
-
-        $( #[$attr87] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field87: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value87, )? Self::$field86.0 + 1));
-
-        $( #[$attr88] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field88: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value88, )? Self::$field87.0 + 1));
-
-        $( #[$attr89] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field89: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value89, )? Self::$field88.0 + 1));
-
-        $( #[$attr90] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field90: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value90, )? Self::$field89.0 + 1));
-
-        $( #[$attr91] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field91: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value91, )? Self::$field90.0 + 1));
-
-        $( #[$attr92] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field92: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value92, )? Self::$field91.0 + 1));
-
-        $( #[$attr93] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field93: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value93, )? Self::$field92.0 + 1));
-
-        $( #[$attr94] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field94: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value94, )? Self::$field93.0 + 1));
-
-        $( #[$attr95] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field95: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value95, )? Self::$field94.0 + 1));
-
-        $( #[$attr96] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field96: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value96, )? Self::$field95.0 + 1));
-
-        $( #[$attr97] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field97: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value97, )? Self::$field96.0 + 1));
-
-        $( #[$attr98] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field98: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value98, )? Self::$field97.0 + 1));
-
-        $( #[$attr99] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field99: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value99, )? Self::$field98.0 + 1));
-
-        $( #[$attr100] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field100: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value100, )? Self::$field99.0 + 1));
-
-        $( #[$attr101] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field101: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value101, )? Self::$field100.0 + 1));
-
-        $( #[$attr102] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field102: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value102, )? Self::$field101.0 + 1));
-
-        $( #[$attr103] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field103: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value103, )? Self::$field102.0 + 1));
-
-        $( #[$attr104] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field104: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value104, )? Self::$field103.0 + 1));
-
-        $( #[$attr105] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field105: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value105, )? Self::$field104.0 + 1));
-
-        $( #[$attr106] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field106: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value106, )? Self::$field105.0 + 1));
-
-        $( #[$attr107] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field107: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value107, )? Self::$field106.0 + 1));
-
-        $( #[$attr108] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field108: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value108, )? Self::$field107.0 + 1));
-
-        $( #[$attr109] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field109: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value109, )? Self::$field108.0 + 1));
-
-        $( #[$attr110] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field110: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value110, )? Self::$field109.0 + 1));
-
-        $( #[$attr111] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field111: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value111, )? Self::$field110.0 + 1));
-
-        $( #[$attr112] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field112: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value112, )? Self::$field111.0 + 1));
-
-        $( #[$attr113] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field113: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value113, )? Self::$field112.0 + 1));
-
-        $( #[$attr114] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field114: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value114, )? Self::$field113.0 + 1));
-
-        $( #[$attr115] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field115: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value115, )? Self::$field114.0 + 1));
-
-        $( #[$attr116] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field116: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value116, )? Self::$field115.0 + 1));
-
-        $( #[$attr117] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field117: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value117, )? Self::$field116.0 + 1));
-
-        $( #[$attr118] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field118: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value118, )? Self::$field117.0 + 1));
-
-        $( #[$attr119] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field119: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value119, )? Self::$field118.0 + 1));
-
-        $( #[$attr120] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field120: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value120, )? Self::$field119.0 + 1));
-
-        $( #[$attr121] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field121: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value121, )? Self::$field120.0 + 1));
-
-        $( #[$attr122] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field122: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value122, )? Self::$field121.0 + 1));
-
-        $( #[$attr123] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field123: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value123, )? Self::$field122.0 + 1));
-
-        $( #[$attr124] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field124: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value124, )? Self::$field123.0 + 1));
-
-        $( #[$attr125] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field125: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value125, )? Self::$field124.0 + 1));
-
-        $( #[$attr126] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field126: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value126, )? Self::$field125.0 + 1));
-
-        $( #[$attr127] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field127: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value127, )? Self::$field126.0 + 1));
-
-        $( #[$attr128] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field128: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value128, )? Self::$field127.0 + 1));
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr1 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr1 )* ] )* ]
+                    [ $( ::core::stringify!($value1) )? ] $default_doc
+                )]
+            ]
+            [] [ pub ]
+            $field1 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value1, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr1 )* ] )* ] $field1)
+                        $default
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr2 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr2 )* ] )* ]
+                    [ $( ::core::stringify!($value2) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field1))
+                )]
+            ]
+            [] [ pub ]
+            $field2 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value2, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr2 )* ] )* ] $field2)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr3 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr3 )* ] )* ]
+                    [ $( ::core::stringify!($value3) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field2))
+                )]
+            ]
+            [] [ pub ]
+            $field3 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value3, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr3 )* ] )* ] $field3)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+                    (cfg_next $kind $opts $field2 [ $( $value2 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr4 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr4 )* ] )* ]
+                    [ $( ::core::stringify!($value4) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field3))
+                )]
+            ]
+            [] [ pub ]
+            $field4 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value4, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr4 )* ] )* ] $field4)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+                    (cfg_next $kind $opts $field3 [ $( $value3 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr5 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr5 )* ] )* ]
+                    [ $( ::core::stringify!($value5) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field4))
+                )]
+            ]
+            [] [ pub ]
+            $field5 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value5, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr5 )* ] )* ] $field5)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+                    (cfg_next $kind $opts $field4 [ $( $value4 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr6 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr6 )* ] )* ]
+                    [ $( ::core::stringify!($value6) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field5))
+                )]
+            ]
+            [] [ pub ]
+            $field6 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value6, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr6 )* ] )* ] $field6)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+                    (cfg_next $kind $opts $field5 [ $( $value5 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr7 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr7 )* ] )* ]
+                    [ $( ::core::stringify!($value7) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field6))
+                )]
+            ]
+            [] [ pub ]
+            $field7 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value7, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr7 )* ] )* ] $field7)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+                    (cfg_next $kind $opts $field6 [ $( $value6 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr8 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr8 )* ] )* ]
+                    [ $( ::core::stringify!($value8) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field7))
+                )]
+            ]
+            [] [ pub ]
+            $field8 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value8, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr8 )* ] )* ] $field8)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+                    (cfg_next $kind $opts $field7 [ $( $value7 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr9 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr9 )* ] )* ]
+                    [ $( ::core::stringify!($value9) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field8))
+                )]
+            ]
+            [] [ pub ]
+            $field9 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value9, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr9 )* ] )* ] $field9)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+                    (cfg_next $kind $opts $field8 [ $( $value8 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr10 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr10 )* ] )* ]
+                    [ $( ::core::stringify!($value10) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field9))
+                )]
+            ]
+            [] [ pub ]
+            $field10 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value10, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr10 )* ] )* ] $field10)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr9 )* ] )* ] []
+                    (cfg_next $kind $opts $field9 [ $( $value9 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr11 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr11 )* ] )* ]
+                    [ $( ::core::stringify!($value11) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field10))
+                )]
+            ]
+            [] [ pub ]
+            $field11 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value11, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr11 )* ] )* ] $field11)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr10 )* ] )* ] []
+                    (cfg_next $kind $opts $field10 [ $( $value10 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr12 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr12 )* ] )* ]
+                    [ $( ::core::stringify!($value12) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field11))
+                )]
+            ]
+            [] [ pub ]
+            $field12 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value12, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr12 )* ] )* ] $field12)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr11 )* ] )* ] []
+                    (cfg_next $kind $opts $field11 [ $( $value11 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr13 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr13 )* ] )* ]
+                    [ $( ::core::stringify!($value13) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field12))
+                )]
+            ]
+            [] [ pub ]
+            $field13 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value13, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr13 )* ] )* ] $field13)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr12 )* ] )* ] []
+                    (cfg_next $kind $opts $field12 [ $( $value12 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr14 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr14 )* ] )* ]
+                    [ $( ::core::stringify!($value14) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field13))
+                )]
+            ]
+            [] [ pub ]
+            $field14 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value14, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr14 )* ] )* ] $field14)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr13 )* ] )* ] []
+                    (cfg_next $kind $opts $field13 [ $( $value13 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr15 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr15 )* ] )* ]
+                    [ $( ::core::stringify!($value15) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field14))
+                )]
+            ]
+            [] [ pub ]
+            $field15 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value15, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr15 )* ] )* ] $field15)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr14 )* ] )* ] []
+                    (cfg_next $kind $opts $field14 [ $( $value14 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr16 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr16 )* ] )* ]
+                    [ $( ::core::stringify!($value16) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field15))
+                )]
+            ]
+            [] [ pub ]
+            $field16 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value16, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr16 )* ] )* ] $field16)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr15 )* ] )* ] []
+                    (cfg_next $kind $opts $field15 [ $( $value15 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr17 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr17 )* ] )* ]
+                    [ $( ::core::stringify!($value17) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field16))
+                )]
+            ]
+            [] [ pub ]
+            $field17 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value17, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr17 )* ] )* ] $field17)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr16 )* ] )* ] []
+                    (cfg_next $kind $opts $field16 [ $( $value16 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr18 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr18 )* ] )* ]
+                    [ $( ::core::stringify!($value18) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field17))
+                )]
+            ]
+            [] [ pub ]
+            $field18 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value18, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr18 )* ] )* ] $field18)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr17 )* ] )* ] []
+                    (cfg_next $kind $opts $field17 [ $( $value17 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr19 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr19 )* ] )* ]
+                    [ $( ::core::stringify!($value19) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field18))
+                )]
+            ]
+            [] [ pub ]
+            $field19 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value19, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr19 )* ] )* ] $field19)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr18 )* ] )* ] []
+                    (cfg_next $kind $opts $field18 [ $( $value18 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr20 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr20 )* ] )* ]
+                    [ $( ::core::stringify!($value20) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field19))
+                )]
+            ]
+            [] [ pub ]
+            $field20 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value20, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr20 )* ] )* ] $field20)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr19 )* ] )* ] []
+                    (cfg_next $kind $opts $field19 [ $( $value19 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr21 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr21 )* ] )* ]
+                    [ $( ::core::stringify!($value21) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field20))
+                )]
+            ]
+            [] [ pub ]
+            $field21 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value21, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr21 )* ] )* ] $field21)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr20 )* ] )* ] []
+                    (cfg_next $kind $opts $field20 [ $( $value20 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr22 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr22 )* ] )* ]
+                    [ $( ::core::stringify!($value22) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field21))
+                )]
+            ]
+            [] [ pub ]
+            $field22 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value22, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr22 )* ] )* ] $field22)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr21 )* ] )* ] []
+                    (cfg_next $kind $opts $field21 [ $( $value21 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr23 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr23 )* ] )* ]
+                    [ $( ::core::stringify!($value23) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field22))
+                )]
+            ]
+            [] [ pub ]
+            $field23 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value23, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr23 )* ] )* ] $field23)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr22 )* ] )* ] []
+                    (cfg_next $kind $opts $field22 [ $( $value22 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr24 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr24 )* ] )* ]
+                    [ $( ::core::stringify!($value24) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field23))
+                )]
+            ]
+            [] [ pub ]
+            $field24 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value24, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr24 )* ] )* ] $field24)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr23 )* ] )* ] []
+                    (cfg_next $kind $opts $field23 [ $( $value23 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr25 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr25 )* ] )* ]
+                    [ $( ::core::stringify!($value25) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field24))
+                )]
+            ]
+            [] [ pub ]
+            $field25 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value25, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr25 )* ] )* ] $field25)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr24 )* ] )* ] []
+                    (cfg_next $kind $opts $field24 [ $( $value24 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr26 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr26 )* ] )* ]
+                    [ $( ::core::stringify!($value26) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field25))
+                )]
+            ]
+            [] [ pub ]
+            $field26 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value26, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr26 )* ] )* ] $field26)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr25 )* ] )* ] []
+                    (cfg_next $kind $opts $field25 [ $( $value25 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr27 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr27 )* ] )* ]
+                    [ $( ::core::stringify!($value27) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field26))
+                )]
+            ]
+            [] [ pub ]
+            $field27 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value27, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr27 )* ] )* ] $field27)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr26 )* ] )* ] []
+                    (cfg_next $kind $opts $field26 [ $( $value26 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr28 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr28 )* ] )* ]
+                    [ $( ::core::stringify!($value28) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field27))
+                )]
+            ]
+            [] [ pub ]
+            $field28 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value28, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr28 )* ] )* ] $field28)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr27 )* ] )* ] []
+                    (cfg_next $kind $opts $field27 [ $( $value27 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr29 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr29 )* ] )* ]
+                    [ $( ::core::stringify!($value29) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field28))
+                )]
+            ]
+            [] [ pub ]
+            $field29 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value29, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr29 )* ] )* ] $field29)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr28 )* ] )* ] []
+                    (cfg_next $kind $opts $field28 [ $( $value28 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr30 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr30 )* ] )* ]
+                    [ $( ::core::stringify!($value30) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field29))
+                )]
+            ]
+            [] [ pub ]
+            $field30 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value30, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr30 )* ] )* ] $field30)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr29 )* ] )* ] []
+                    (cfg_next $kind $opts $field29 [ $( $value29 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr31 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr31 )* ] )* ]
+                    [ $( ::core::stringify!($value31) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field30))
+                )]
+            ]
+            [] [ pub ]
+            $field31 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value31, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr31 )* ] )* ] $field31)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr30 )* ] )* ] []
+                    (cfg_next $kind $opts $field30 [ $( $value30 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr32 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr32 )* ] )* ]
+                    [ $( ::core::stringify!($value32) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field31))
+                )]
+            ]
+            [] [ pub ]
+            $field32 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value32, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr32 )* ] )* ] $field32)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr31 )* ] )* ] []
+                    (cfg_next $kind $opts $field31 [ $( $value31 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr33 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr33 )* ] )* ]
+                    [ $( ::core::stringify!($value33) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field32))
+                )]
+            ]
+            [] [ pub ]
+            $field33 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value33, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr33 )* ] )* ] $field33)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr32 )* ] )* ] []
+                    (cfg_next $kind $opts $field32 [ $( $value32 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr34 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr34 )* ] )* ]
+                    [ $( ::core::stringify!($value34) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field33))
+                )]
+            ]
+            [] [ pub ]
+            $field34 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value34, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr34 )* ] )* ] $field34)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr33 )* ] )* ] []
+                    (cfg_next $kind $opts $field33 [ $( $value33 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr35 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr35 )* ] )* ]
+                    [ $( ::core::stringify!($value35) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field34))
+                )]
+            ]
+            [] [ pub ]
+            $field35 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value35, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr35 )* ] )* ] $field35)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr34 )* ] )* ] []
+                    (cfg_next $kind $opts $field34 [ $( $value34 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr36 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr36 )* ] )* ]
+                    [ $( ::core::stringify!($value36) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field35))
+                )]
+            ]
+            [] [ pub ]
+            $field36 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value36, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr36 )* ] )* ] $field36)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr35 )* ] )* ] []
+                    (cfg_next $kind $opts $field35 [ $( $value35 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr37 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr37 )* ] )* ]
+                    [ $( ::core::stringify!($value37) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field36))
+                )]
+            ]
+            [] [ pub ]
+            $field37 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value37, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr37 )* ] )* ] $field37)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr36 )* ] )* ] []
+                    (cfg_next $kind $opts $field36 [ $( $value36 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr38 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr38 )* ] )* ]
+                    [ $( ::core::stringify!($value38) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field37))
+                )]
+            ]
+            [] [ pub ]
+            $field38 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value38, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr38 )* ] )* ] $field38)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr37 )* ] )* ] []
+                    (cfg_next $kind $opts $field37 [ $( $value37 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr39 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr39 )* ] )* ]
+                    [ $( ::core::stringify!($value39) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field38))
+                )]
+            ]
+            [] [ pub ]
+            $field39 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value39, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr39 )* ] )* ] $field39)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr38 )* ] )* ] []
+                    (cfg_next $kind $opts $field38 [ $( $value38 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr40 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr40 )* ] )* ]
+                    [ $( ::core::stringify!($value40) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field39))
+                )]
+            ]
+            [] [ pub ]
+            $field40 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value40, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr40 )* ] )* ] $field40)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr39 )* ] )* ] []
+                    (cfg_next $kind $opts $field39 [ $( $value39 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr41 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr41 )* ] )* ]
+                    [ $( ::core::stringify!($value41) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field40))
+                )]
+            ]
+            [] [ pub ]
+            $field41 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value41, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr41 )* ] )* ] $field41)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr40 )* ] )* ] []
+                    (cfg_next $kind $opts $field40 [ $( $value40 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr42 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr42 )* ] )* ]
+                    [ $( ::core::stringify!($value42) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field41))
+                )]
+            ]
+            [] [ pub ]
+            $field42 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value42, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr42 )* ] )* ] $field42)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr41 )* ] )* ] []
+                    (cfg_next $kind $opts $field41 [ $( $value41 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr43 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr43 )* ] )* ]
+                    [ $( ::core::stringify!($value43) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field42))
+                )]
+            ]
+            [] [ pub ]
+            $field43 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value43, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr43 )* ] )* ] $field43)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr42 )* ] )* ] []
+                    (cfg_next $kind $opts $field42 [ $( $value42 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr44 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr44 )* ] )* ]
+                    [ $( ::core::stringify!($value44) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field43))
+                )]
+            ]
+            [] [ pub ]
+            $field44 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value44, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr44 )* ] )* ] $field44)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr43 )* ] )* ] []
+                    (cfg_next $kind $opts $field43 [ $( $value43 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr45 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr45 )* ] )* ]
+                    [ $( ::core::stringify!($value45) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field44))
+                )]
+            ]
+            [] [ pub ]
+            $field45 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value45, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr45 )* ] )* ] $field45)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr44 )* ] )* ] []
+                    (cfg_next $kind $opts $field44 [ $( $value44 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr46 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr46 )* ] )* ]
+                    [ $( ::core::stringify!($value46) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field45))
+                )]
+            ]
+            [] [ pub ]
+            $field46 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value46, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr46 )* ] )* ] $field46)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr45 )* ] )* ] []
+                    (cfg_next $kind $opts $field45 [ $( $value45 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr47 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr47 )* ] )* ]
+                    [ $( ::core::stringify!($value47) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field46))
+                )]
+            ]
+            [] [ pub ]
+            $field47 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value47, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr47 )* ] )* ] $field47)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr46 )* ] )* ] []
+                    (cfg_next $kind $opts $field46 [ $( $value46 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr48 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr48 )* ] )* ]
+                    [ $( ::core::stringify!($value48) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field47))
+                )]
+            ]
+            [] [ pub ]
+            $field48 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value48, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr48 )* ] )* ] $field48)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr47 )* ] )* ] []
+                    (cfg_next $kind $opts $field47 [ $( $value47 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr49 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr49 )* ] )* ]
+                    [ $( ::core::stringify!($value49) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field48))
+                )]
+            ]
+            [] [ pub ]
+            $field49 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value49, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr49 )* ] )* ] $field49)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr48 )* ] )* ] []
+                    (cfg_next $kind $opts $field48 [ $( $value48 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr50 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr50 )* ] )* ]
+                    [ $( ::core::stringify!($value50) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field49))
+                )]
+            ]
+            [] [ pub ]
+            $field50 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value50, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr50 )* ] )* ] $field50)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr49 )* ] )* ] []
+                    (cfg_next $kind $opts $field49 [ $( $value49 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr51 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr51 )* ] )* ]
+                    [ $( ::core::stringify!($value51) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field50))
+                )]
+            ]
+            [] [ pub ]
+            $field51 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value51, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr51 )* ] )* ] $field51)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr50 )* ] )* ] []
+                    (cfg_next $kind $opts $field50 [ $( $value50 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr52 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr52 )* ] )* ]
+                    [ $( ::core::stringify!($value52) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field51))
+                )]
+            ]
+            [] [ pub ]
+            $field52 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value52, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr52 )* ] )* ] $field52)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr51 )* ] )* ] []
+                    (cfg_next $kind $opts $field51 [ $( $value51 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr53 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr53 )* ] )* ]
+                    [ $( ::core::stringify!($value53) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field52))
+                )]
+            ]
+            [] [ pub ]
+            $field53 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value53, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr53 )* ] )* ] $field53)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr52 )* ] )* ] []
+                    (cfg_next $kind $opts $field52 [ $( $value52 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr54 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr54 )* ] )* ]
+                    [ $( ::core::stringify!($value54) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field53))
+                )]
+            ]
+            [] [ pub ]
+            $field54 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value54, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr54 )* ] )* ] $field54)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr53 )* ] )* ] []
+                    (cfg_next $kind $opts $field53 [ $( $value53 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr55 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr55 )* ] )* ]
+                    [ $( ::core::stringify!($value55) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field54))
+                )]
+            ]
+            [] [ pub ]
+            $field55 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value55, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr55 )* ] )* ] $field55)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr54 )* ] )* ] []
+                    (cfg_next $kind $opts $field54 [ $( $value54 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr56 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr56 )* ] )* ]
+                    [ $( ::core::stringify!($value56) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field55))
+                )]
+            ]
+            [] [ pub ]
+            $field56 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value56, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr56 )* ] )* ] $field56)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr55 )* ] )* ] []
+                    (cfg_next $kind $opts $field55 [ $( $value55 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr57 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr57 )* ] )* ]
+                    [ $( ::core::stringify!($value57) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field56))
+                )]
+            ]
+            [] [ pub ]
+            $field57 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value57, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr57 )* ] )* ] $field57)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr56 )* ] )* ] []
+                    (cfg_next $kind $opts $field56 [ $( $value56 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr58 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr58 )* ] )* ]
+                    [ $( ::core::stringify!($value58) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field57))
+                )]
+            ]
+            [] [ pub ]
+            $field58 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value58, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr58 )* ] )* ] $field58)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr57 )* ] )* ] []
+                    (cfg_next $kind $opts $field57 [ $( $value57 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr59 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr59 )* ] )* ]
+                    [ $( ::core::stringify!($value59) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field58))
+                )]
+            ]
+            [] [ pub ]
+            $field59 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value59, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr59 )* ] )* ] $field59)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr58 )* ] )* ] []
+                    (cfg_next $kind $opts $field58 [ $( $value58 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr60 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr60 )* ] )* ]
+                    [ $( ::core::stringify!($value60) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field59))
+                )]
+            ]
+            [] [ pub ]
+            $field60 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value60, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr60 )* ] )* ] $field60)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr59 )* ] )* ] []
+                    (cfg_next $kind $opts $field59 [ $( $value59 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr61 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr61 )* ] )* ]
+                    [ $( ::core::stringify!($value61) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field60))
+                )]
+            ]
+            [] [ pub ]
+            $field61 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value61, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr61 )* ] )* ] $field61)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr60 )* ] )* ] []
+                    (cfg_next $kind $opts $field60 [ $( $value60 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr62 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr62 )* ] )* ]
+                    [ $( ::core::stringify!($value62) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field61))
+                )]
+            ]
+            [] [ pub ]
+            $field62 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value62, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr62 )* ] )* ] $field62)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr61 )* ] )* ] []
+                    (cfg_next $kind $opts $field61 [ $( $value61 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr63 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr63 )* ] )* ]
+                    [ $( ::core::stringify!($value63) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field62))
+                )]
+            ]
+            [] [ pub ]
+            $field63 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value63, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr63 )* ] )* ] $field63)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr62 )* ] )* ] []
+                    (cfg_next $kind $opts $field62 [ $( $value62 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr64 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr64 )* ] )* ]
+                    [ $( ::core::stringify!($value64) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field63))
+                )]
+            ]
+            [] [ pub ]
+            $field64 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value64, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr64 )* ] )* ] $field64)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr63 )* ] )* ] []
+                    (cfg_next $kind $opts $field63 [ $( $value63 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr65 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr65 )* ] )* ]
+                    [ $( ::core::stringify!($value65) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field64))
+                )]
+            ]
+            [] [ pub ]
+            $field65 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value65, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr65 )* ] )* ] $field65)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr64 )* ] )* ] []
+                    (cfg_next $kind $opts $field64 [ $( $value64 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr66 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr66 )* ] )* ]
+                    [ $( ::core::stringify!($value66) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field65))
+                )]
+            ]
+            [] [ pub ]
+            $field66 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value66, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr66 )* ] )* ] $field66)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr65 )* ] )* ] []
+                    (cfg_next $kind $opts $field65 [ $( $value65 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr67 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr67 )* ] )* ]
+                    [ $( ::core::stringify!($value67) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field66))
+                )]
+            ]
+            [] [ pub ]
+            $field67 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value67, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr67 )* ] )* ] $field67)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr66 )* ] )* ] []
+                    (cfg_next $kind $opts $field66 [ $( $value66 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr68 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr68 )* ] )* ]
+                    [ $( ::core::stringify!($value68) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field67))
+                )]
+            ]
+            [] [ pub ]
+            $field68 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value68, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr68 )* ] )* ] $field68)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr67 )* ] )* ] []
+                    (cfg_next $kind $opts $field67 [ $( $value67 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr69 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr69 )* ] )* ]
+                    [ $( ::core::stringify!($value69) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field68))
+                )]
+            ]
+            [] [ pub ]
+            $field69 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value69, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr69 )* ] )* ] $field69)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr68 )* ] )* ] []
+                    (cfg_next $kind $opts $field68 [ $( $value68 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr70 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr70 )* ] )* ]
+                    [ $( ::core::stringify!($value70) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field69))
+                )]
+            ]
+            [] [ pub ]
+            $field70 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value70, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr70 )* ] )* ] $field70)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr69 )* ] )* ] []
+                    (cfg_next $kind $opts $field69 [ $( $value69 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr71 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr71 )* ] )* ]
+                    [ $( ::core::stringify!($value71) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field70))
+                )]
+            ]
+            [] [ pub ]
+            $field71 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value71, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr71 )* ] )* ] $field71)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr70 )* ] )* ] []
+                    (cfg_next $kind $opts $field70 [ $( $value70 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr72 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr72 )* ] )* ]
+                    [ $( ::core::stringify!($value72) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field71))
+                )]
+            ]
+            [] [ pub ]
+            $field72 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value72, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr72 )* ] )* ] $field72)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr71 )* ] )* ] []
+                    (cfg_next $kind $opts $field71 [ $( $value71 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr73 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr73 )* ] )* ]
+                    [ $( ::core::stringify!($value73) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field72))
+                )]
+            ]
+            [] [ pub ]
+            $field73 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value73, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr73 )* ] )* ] $field73)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr72 )* ] )* ] []
+                    (cfg_next $kind $opts $field72 [ $( $value72 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr74 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr74 )* ] )* ]
+                    [ $( ::core::stringify!($value74) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field73))
+                )]
+            ]
+            [] [ pub ]
+            $field74 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value74, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr74 )* ] )* ] $field74)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr73 )* ] )* ] []
+                    (cfg_next $kind $opts $field73 [ $( $value73 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr75 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr75 )* ] )* ]
+                    [ $( ::core::stringify!($value75) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field74))
+                )]
+            ]
+            [] [ pub ]
+            $field75 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value75, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr75 )* ] )* ] $field75)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr74 )* ] )* ] []
+                    (cfg_next $kind $opts $field74 [ $( $value74 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr76 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr76 )* ] )* ]
+                    [ $( ::core::stringify!($value76) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field75))
+                )]
+            ]
+            [] [ pub ]
+            $field76 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value76, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr76 )* ] )* ] $field76)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr75 )* ] )* ] []
+                    (cfg_next $kind $opts $field75 [ $( $value75 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr77 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr77 )* ] )* ]
+                    [ $( ::core::stringify!($value77) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field76))
+                )]
+            ]
+            [] [ pub ]
+            $field77 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value77, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr77 )* ] )* ] $field77)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr76 )* ] )* ] []
+                    (cfg_next $kind $opts $field76 [ $( $value76 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr78 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr78 )* ] )* ]
+                    [ $( ::core::stringify!($value78) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field77))
+                )]
+            ]
+            [] [ pub ]
+            $field78 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value78, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr78 )* ] )* ] $field78)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr77 )* ] )* ] []
+                    (cfg_next $kind $opts $field77 [ $( $value77 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr79 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr79 )* ] )* ]
+                    [ $( ::core::stringify!($value79) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field78))
+                )]
+            ]
+            [] [ pub ]
+            $field79 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value79, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr79 )* ] )* ] $field79)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr78 )* ] )* ] []
+                    (cfg_next $kind $opts $field78 [ $( $value78 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr80 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr80 )* ] )* ]
+                    [ $( ::core::stringify!($value80) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field79))
+                )]
+            ]
+            [] [ pub ]
+            $field80 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value80, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr80 )* ] )* ] $field80)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr79 )* ] )* ] []
+                    (cfg_next $kind $opts $field79 [ $( $value79 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr81 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr81 )* ] )* ]
+                    [ $( ::core::stringify!($value81) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field80))
+                )]
+            ]
+            [] [ pub ]
+            $field81 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value81, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr81 )* ] )* ] $field81)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr80 )* ] )* ] []
+                    (cfg_next $kind $opts $field80 [ $( $value80 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr82 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr82 )* ] )* ]
+                    [ $( ::core::stringify!($value82) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field81))
+                )]
+            ]
+            [] [ pub ]
+            $field82 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value82, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr82 )* ] )* ] $field82)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr81 )* ] )* ] []
+                    (cfg_next $kind $opts $field81 [ $( $value81 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr83 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr83 )* ] )* ]
+                    [ $( ::core::stringify!($value83) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field82))
+                )]
+            ]
+            [] [ pub ]
+            $field83 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value83, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr83 )* ] )* ] $field83)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr82 )* ] )* ] []
+                    (cfg_next $kind $opts $field82 [ $( $value82 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr84 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr84 )* ] )* ]
+                    [ $( ::core::stringify!($value84) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field83))
+                )]
+            ]
+            [] [ pub ]
+            $field84 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value84, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr84 )* ] )* ] $field84)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr83 )* ] )* ] []
+                    (cfg_next $kind $opts $field83 [ $( $value83 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr85 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr85 )* ] )* ]
+                    [ $( ::core::stringify!($value85) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field84))
+                )]
+            ]
+            [] [ pub ]
+            $field85 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value85, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr85 )* ] )* ] $field85)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr84 )* ] )* ] []
+                    (cfg_next $kind $opts $field84 [ $( $value84 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr86 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr86 )* ] )* ]
+                    [ $( ::core::stringify!($value86) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field85))
+                )]
+            ]
+            [] [ pub ]
+            $field86 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value86, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr86 )* ] )* ] $field86)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr85 )* ] )* ] []
+                    (cfg_next $kind $opts $field85 [ $( $value85 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr87 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr87 )* ] )* ]
+                    [ $( ::core::stringify!($value87) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field86))
+                )]
+            ]
+            [] [ pub ]
+            $field87 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value87, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr87 )* ] )* ] $field87)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr86 )* ] )* ] []
+                    (cfg_next $kind $opts $field86 [ $( $value86 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr88 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr88 )* ] )* ]
+                    [ $( ::core::stringify!($value88) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field87))
+                )]
+            ]
+            [] [ pub ]
+            $field88 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value88, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr88 )* ] )* ] $field88)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr87 )* ] )* ] []
+                    (cfg_next $kind $opts $field87 [ $( $value87 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr89 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr89 )* ] )* ]
+                    [ $( ::core::stringify!($value89) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field88))
+                )]
+            ]
+            [] [ pub ]
+            $field89 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value89, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr89 )* ] )* ] $field89)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr88 )* ] )* ] []
+                    (cfg_next $kind $opts $field88 [ $( $value88 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr90 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr90 )* ] )* ]
+                    [ $( ::core::stringify!($value90) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field89))
+                )]
+            ]
+            [] [ pub ]
+            $field90 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value90, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr90 )* ] )* ] $field90)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr89 )* ] )* ] []
+                    (cfg_next $kind $opts $field89 [ $( $value89 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr91 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr91 )* ] )* ]
+                    [ $( ::core::stringify!($value91) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field90))
+                )]
+            ]
+            [] [ pub ]
+            $field91 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value91, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr91 )* ] )* ] $field91)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr90 )* ] )* ] []
+                    (cfg_next $kind $opts $field90 [ $( $value90 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr92 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr92 )* ] )* ]
+                    [ $( ::core::stringify!($value92) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field91))
+                )]
+            ]
+            [] [ pub ]
+            $field92 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value92, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr92 )* ] )* ] $field92)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr91 )* ] )* ] []
+                    (cfg_next $kind $opts $field91 [ $( $value91 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr93 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr93 )* ] )* ]
+                    [ $( ::core::stringify!($value93) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field92))
+                )]
+            ]
+            [] [ pub ]
+            $field93 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value93, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr93 )* ] )* ] $field93)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr92 )* ] )* ] []
+                    (cfg_next $kind $opts $field92 [ $( $value92 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr94 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr94 )* ] )* ]
+                    [ $( ::core::stringify!($value94) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field93))
+                )]
+            ]
+            [] [ pub ]
+            $field94 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value94, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr94 )* ] )* ] $field94)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr93 )* ] )* ] []
+                    (cfg_next $kind $opts $field93 [ $( $value93 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr95 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr95 )* ] )* ]
+                    [ $( ::core::stringify!($value95) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field94))
+                )]
+            ]
+            [] [ pub ]
+            $field95 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value95, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr95 )* ] )* ] $field95)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr94 )* ] )* ] []
+                    (cfg_next $kind $opts $field94 [ $( $value94 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr96 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr96 )* ] )* ]
+                    [ $( ::core::stringify!($value96) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field95))
+                )]
+            ]
+            [] [ pub ]
+            $field96 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value96, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr96 )* ] )* ] $field96)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr95 )* ] )* ] []
+                    (cfg_next $kind $opts $field95 [ $( $value95 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr97 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr97 )* ] )* ]
+                    [ $( ::core::stringify!($value97) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field96))
+                )]
+            ]
+            [] [ pub ]
+            $field97 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value97, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr97 )* ] )* ] $field97)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr96 )* ] )* ] []
+                    (cfg_next $kind $opts $field96 [ $( $value96 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr98 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr98 )* ] )* ]
+                    [ $( ::core::stringify!($value98) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field97))
+                )]
+            ]
+            [] [ pub ]
+            $field98 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value98, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr98 )* ] )* ] $field98)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr97 )* ] )* ] []
+                    (cfg_next $kind $opts $field97 [ $( $value97 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr99 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr99 )* ] )* ]
+                    [ $( ::core::stringify!($value99) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field98))
+                )]
+            ]
+            [] [ pub ]
+            $field99 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value99, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr99 )* ] )* ] $field99)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr98 )* ] )* ] []
+                    (cfg_next $kind $opts $field98 [ $( $value98 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr100 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr100 )* ] )* ]
+                    [ $( ::core::stringify!($value100) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field99))
+                )]
+            ]
+            [] [ pub ]
+            $field100 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value100, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr100 )* ] )* ] $field100)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr99 )* ] )* ] []
+                    (cfg_next $kind $opts $field99 [ $( $value99 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr101 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr101 )* ] )* ]
+                    [ $( ::core::stringify!($value101) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field100))
+                )]
+            ]
+            [] [ pub ]
+            $field101 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value101, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr101 )* ] )* ] $field101)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr100 )* ] )* ] []
+                    (cfg_next $kind $opts $field100 [ $( $value100 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr102 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr102 )* ] )* ]
+                    [ $( ::core::stringify!($value102) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field101))
+                )]
+            ]
+            [] [ pub ]
+            $field102 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value102, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr102 )* ] )* ] $field102)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr101 )* ] )* ] []
+                    (cfg_next $kind $opts $field101 [ $( $value101 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr103 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr103 )* ] )* ]
+                    [ $( ::core::stringify!($value103) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field102))
+                )]
+            ]
+            [] [ pub ]
+            $field103 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value103, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr103 )* ] )* ] $field103)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr102 )* ] )* ] []
+                    (cfg_next $kind $opts $field102 [ $( $value102 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr104 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr104 )* ] )* ]
+                    [ $( ::core::stringify!($value104) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field103))
+                )]
+            ]
+            [] [ pub ]
+            $field104 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value104, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr104 )* ] )* ] $field104)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr103 )* ] )* ] []
+                    (cfg_next $kind $opts $field103 [ $( $value103 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr105 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr105 )* ] )* ]
+                    [ $( ::core::stringify!($value105) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field104))
+                )]
+            ]
+            [] [ pub ]
+            $field105 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value105, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr105 )* ] )* ] $field105)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr104 )* ] )* ] []
+                    (cfg_next $kind $opts $field104 [ $( $value104 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr106 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr106 )* ] )* ]
+                    [ $( ::core::stringify!($value106) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field105))
+                )]
+            ]
+            [] [ pub ]
+            $field106 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value106, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr106 )* ] )* ] $field106)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr105 )* ] )* ] []
+                    (cfg_next $kind $opts $field105 [ $( $value105 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr107 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr107 )* ] )* ]
+                    [ $( ::core::stringify!($value107) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field106))
+                )]
+            ]
+            [] [ pub ]
+            $field107 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value107, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr107 )* ] )* ] $field107)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr106 )* ] )* ] []
+                    (cfg_next $kind $opts $field106 [ $( $value106 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr108 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr108 )* ] )* ]
+                    [ $( ::core::stringify!($value108) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field107))
+                )]
+            ]
+            [] [ pub ]
+            $field108 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value108, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr108 )* ] )* ] $field108)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr107 )* ] )* ] []
+                    (cfg_next $kind $opts $field107 [ $( $value107 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr109 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr109 )* ] )* ]
+                    [ $( ::core::stringify!($value109) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field108))
+                )]
+            ]
+            [] [ pub ]
+            $field109 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value109, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr109 )* ] )* ] $field109)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr108 )* ] )* ] []
+                    (cfg_next $kind $opts $field108 [ $( $value108 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr110 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr110 )* ] )* ]
+                    [ $( ::core::stringify!($value110) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field109))
+                )]
+            ]
+            [] [ pub ]
+            $field110 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value110, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr110 )* ] )* ] $field110)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr109 )* ] )* ] []
+                    (cfg_next $kind $opts $field109 [ $( $value109 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr111 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr111 )* ] )* ]
+                    [ $( ::core::stringify!($value111) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field110))
+                )]
+            ]
+            [] [ pub ]
+            $field111 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value111, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr111 )* ] )* ] $field111)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr110 )* ] )* ] []
+                    (cfg_next $kind $opts $field110 [ $( $value110 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr112 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr112 )* ] )* ]
+                    [ $( ::core::stringify!($value112) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field111))
+                )]
+            ]
+            [] [ pub ]
+            $field112 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value112, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr112 )* ] )* ] $field112)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr111 )* ] )* ] []
+                    (cfg_next $kind $opts $field111 [ $( $value111 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr113 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr113 )* ] )* ]
+                    [ $( ::core::stringify!($value113) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field112))
+                )]
+            ]
+            [] [ pub ]
+            $field113 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value113, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr113 )* ] )* ] $field113)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr112 )* ] )* ] []
+                    (cfg_next $kind $opts $field112 [ $( $value112 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr114 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr114 )* ] )* ]
+                    [ $( ::core::stringify!($value114) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field113))
+                )]
+            ]
+            [] [ pub ]
+            $field114 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value114, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr114 )* ] )* ] $field114)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr113 )* ] )* ] []
+                    (cfg_next $kind $opts $field113 [ $( $value113 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr115 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr115 )* ] )* ]
+                    [ $( ::core::stringify!($value115) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field114))
+                )]
+            ]
+            [] [ pub ]
+            $field115 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value115, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr115 )* ] )* ] $field115)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr114 )* ] )* ] []
+                    (cfg_next $kind $opts $field114 [ $( $value114 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr116 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr116 )* ] )* ]
+                    [ $( ::core::stringify!($value116) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field115))
+                )]
+            ]
+            [] [ pub ]
+            $field116 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value116, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr116 )* ] )* ] $field116)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr115 )* ] )* ] []
+                    (cfg_next $kind $opts $field115 [ $( $value115 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr117 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr117 )* ] )* ]
+                    [ $( ::core::stringify!($value117) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field116))
+                )]
+            ]
+            [] [ pub ]
+            $field117 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value117, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr117 )* ] )* ] $field117)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr116 )* ] )* ] []
+                    (cfg_next $kind $opts $field116 [ $( $value116 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr118 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr118 )* ] )* ]
+                    [ $( ::core::stringify!($value118) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field117))
+                )]
+            ]
+            [] [ pub ]
+            $field118 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value118, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr118 )* ] )* ] $field118)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr117 )* ] )* ] []
+                    (cfg_next $kind $opts $field117 [ $( $value117 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr119 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr119 )* ] )* ]
+                    [ $( ::core::stringify!($value119) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field118))
+                )]
+            ]
+            [] [ pub ]
+            $field119 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value119, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr119 )* ] )* ] $field119)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr118 )* ] )* ] []
+                    (cfg_next $kind $opts $field118 [ $( $value118 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr120 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr120 )* ] )* ]
+                    [ $( ::core::stringify!($value120) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field119))
+                )]
+            ]
+            [] [ pub ]
+            $field120 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value120, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr120 )* ] )* ] $field120)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr119 )* ] )* ] []
+                    (cfg_next $kind $opts $field119 [ $( $value119 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr121 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr121 )* ] )* ]
+                    [ $( ::core::stringify!($value121) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field120))
+                )]
+            ]
+            [] [ pub ]
+            $field121 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value121, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr121 )* ] )* ] $field121)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr120 )* ] )* ] []
+                    (cfg_next $kind $opts $field120 [ $( $value120 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr122 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr122 )* ] )* ]
+                    [ $( ::core::stringify!($value122) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field121))
+                )]
+            ]
+            [] [ pub ]
+            $field122 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value122, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr122 )* ] )* ] $field122)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr121 )* ] )* ] []
+                    (cfg_next $kind $opts $field121 [ $( $value121 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr123 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr123 )* ] )* ]
+                    [ $( ::core::stringify!($value123) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field122))
+                )]
+            ]
+            [] [ pub ]
+            $field123 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value123, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr123 )* ] )* ] $field123)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr122 )* ] )* ] []
+                    (cfg_next $kind $opts $field122 [ $( $value122 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr124 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr124 )* ] )* ]
+                    [ $( ::core::stringify!($value124) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field123))
+                )]
+            ]
+            [] [ pub ]
+            $field124 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value124, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr124 )* ] )* ] $field124)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr123 )* ] )* ] []
+                    (cfg_next $kind $opts $field123 [ $( $value123 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr125 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr125 )* ] )* ]
+                    [ $( ::core::stringify!($value125) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field124))
+                )]
+            ]
+            [] [ pub ]
+            $field125 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value125, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr125 )* ] )* ] $field125)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr124 )* ] )* ] []
+                    (cfg_next $kind $opts $field124 [ $( $value124 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr126 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr126 )* ] )* ]
+                    [ $( ::core::stringify!($value126) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field125))
+                )]
+            ]
+            [] [ pub ]
+            $field126 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value126, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr126 )* ] )* ] $field126)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr125 )* ] )* ] []
+                    (cfg_next $kind $opts $field125 [ $( $value125 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr127 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr127 )* ] )* ]
+                    [ $( ::core::stringify!($value127) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field126))
+                )]
+            ]
+            [] [ pub ]
+            $field127 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value127, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr127 )* ] )* ] $field127)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr126 )* ] )* ] []
+                    (cfg_next $kind $opts $field126 [ $( $value126 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr128 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr128 )* ] )* ]
+                    [ $( ::core::stringify!($value128) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field127))
+                )]
+            ]
+            [] [ pub ]
+            $field128 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value128, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr128 )* ] )* ] $field128)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr127 )* ] )* ] []
+                    (cfg_next $kind $opts $field127 [ $( $value127 )? ]))
+                    )
+                )
+            )
+        );
 
         $crate::__c_enum_decl_variants!(
-            impl($name, $inner, Self::$field128.0 + 1)
-            $( $( #[$rattr] )* $frest $( = $frest_val )?, )*
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr128 )* ] )* ] []
+                    (cfg_next $kind $opts $field128 [ $( $value128 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field128))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
         );
     };
 
     // Match 32 fields
     (
-        impl($name:ident, $inner:ty, $default:expr)
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
 
-        $( #[$attr1:meta] )*
+        $( #[ $( $attr1:tt )* ] )*
         $field1:ident $( = $value1:expr )? ,
 
-        $( #[$attr2:meta] )*
+        $( #[ $( $attr2:tt )* ] )*
         $field2:ident $( = $value2:expr )? ,
 
-        $( #[$attr3:meta] )*
+        $( #[ $( $attr3:tt )* ] )*
         $field3:ident $( = $value3:expr )? ,
 
-        $( #[$attr4:meta] )*
+        $( #[ $( $attr4:tt )* ] )*
         $field4:ident $( = $value4:expr )? ,
 
-        $( #[$attr5:meta] )*
+        $( #[ $( $attr5:tt )* ] )*
         $field5:ident $( = $value5:expr )? ,
 
-        $( #[$attr6:meta] )*
+        $( #[ $( $attr6:tt )* ] )*
         $field6:ident $( = $value6:expr )? ,
 
-        $( #[$attr7:meta] )*
+        $( #[ $( $attr7:tt )* ] )*
         $field7:ident $( = $value7:expr )? ,
 
-        $( #[$attr8:meta] )*
+        $( #[ $( $attr8:tt )* ] )*
         $field8:ident $( = $value8:expr )? ,
 
-        $( #[$attr9:meta] )*
+        $( #[ $( $attr9:tt )* ] )*
         $field9:ident $( = $value9:expr )? ,
 
-        $( #[$attr10:meta] )*
+        $( #[ $( $attr10:tt )* ] )*
         $field10:ident $( = $value10:expr )? ,
 
-        $( #[$attr11:meta] )*
+        $( #[ $( $attr11:tt )* ] )*
         $field11:ident $( = $value11:expr )? ,
 
-        $( #[$attr12:meta] )*
+        $( #[ $( $attr12:tt )* ] )*
         $field12:ident $( = $value12:expr )? ,
 
-        $( #[$attr13:meta] )*
+        $( #[ $( $attr13:tt )* ] )*
         $field13:ident $( = $value13:expr )? ,
 
-        $( #[$attr14:meta] )*
+        $( #[ $( $attr14:tt )* ] )*
         $field14:ident $( = $value14:expr )? ,
 
-        $( #[$attr15:meta] )*
+        $( #[ $( $attr15:tt )* ] )*
         $field15:ident $( = $value15:expr )? ,
 
-        $( #[$attr16:meta] )*
+        $( #[ $( $attr16:tt )* ] )*
         $field16:ident $( = $value16:expr )? ,
 
-        $( #[$attr17:meta] )*
+        $( #[ $( $attr17:tt )* ] )*
         $field17:ident $( = $value17:expr )? ,
 
-        $( #[$attr18:meta] )*
+        $( #[ $( $attr18:tt )* ] )*
         $field18:ident $( = $value18:expr )? ,
 
-        $( #[$attr19:meta] )*
+        $( #[ $( $attr19:tt )* ] )*
         $field19:ident $( = $value19:expr )? ,
 
-        $( #[$attr20:meta] )*
+        $( #[ $( $attr20:tt )* ] )*
         $field20:ident $( = $value20:expr )? ,
 
-        $( #[$attr21:meta] )*
+        $( #[ $( $attr21:tt )* ] )*
         $field21:ident $( = $value21:expr )? ,
 
-        $( #[$attr22:meta] )*
+        $( #[ $( $attr22:tt )* ] )*
         $field22:ident $( = $value22:expr )? ,
 
-        $( #[$attr23:meta] )*
+        $( #[ $( $attr23:tt )* ] )*
         $field23:ident $( = $value23:expr )? ,
 
-        $( #[$attr24:meta] )*
+        $( #[ $( $attr24:tt )* ] )*
         $field24:ident $( = $value24:expr )? ,
 
-        $( #[$attr25:meta] )*
+        $( #[ $( $attr25:tt )* ] )*
         $field25:ident $( = $value25:expr )? ,
 
-        $( #[$attr26:meta] )*
+        $( #[ $( $attr26:tt )* ] )*
         $field26:ident $( = $value26:expr )? ,
 
-        $( #[$attr27:meta] )*
+        $( #[ $( $attr27:tt )* ] )*
         $field27:ident $( = $value27:expr )? ,
 
-        $( #[$attr28:meta] )*
+        $( #[ $( $attr28:tt )* ] )*
         $field28:ident $( = $value28:expr )? ,
 
-        $( #[$attr29:meta] )*
+        $( #[ $( $attr29:tt )* ] )*
         $field29:ident $( = $value29:expr )? ,
 
-        $( #[$attr30:meta] )*
+        $( #[ $( $attr30:tt )* ] )*
         $field30:ident $( = $value30:expr )? ,
 
-        $( #[$attr31:meta] )*
+        $( #[ $( $attr31:tt )* ] )*
         $field31:ident $( = $value31:expr )? ,
 
-        $( #[$attr32:meta] )*
+        $( #[ $( $attr32:tt )* ] )*
         $field32:ident $( = $value32:expr )? ,
 
         $(
-            $( #[$rattr:meta] )*
+            $( #[ $( $rattr:tt )* ] )*
             $frest:ident $( = $frest_val:expr )?
         ),* $(,)?
     ) => {
-        $( #[$attr1] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field1: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value1, )? $default));
-
-        $( #[$attr2] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field2: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value2, )? Self::$field1.0 + 1));
-
-        $( #[$attr3] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field3: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value3, )? Self::$field2.0 + 1));
-
-        $( #[$attr4] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field4: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value4, )? Self::$field3.0 + 1));
-
-        $( #[$attr5] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field5: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value5, )? Self::$field4.0 + 1));
-
-        $( #[$attr6] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field6: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value6, )? Self::$field5.0 + 1));
-
-        $( #[$attr7] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field7: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value7, )? Self::$field6.0 + 1));
-
-        $( #[$attr8] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field8: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value8, )? Self::$field7.0 + 1));
-
-        $( #[$attr9] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field9: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value9, )? Self::$field8.0 + 1));
-
-        $( #[$attr10] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field10: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value10, )? Self::$field9.0 + 1));
-
-        $( #[$attr11] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field11: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value11, )? Self::$field10.0 + 1));
-
-        $( #[$attr12] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field12: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value12, )? Self::$field11.0 + 1));
-
-        $( #[$attr13] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field13: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value13, )? Self::$field12.0 + 1));
-
-        $( #[$attr14] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field14: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value14, )? Self::$field13.0 + 1));
-
-        $( #[$attr15] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field15: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value15, )? Self::$field14.0 + 1));
-
-        $( #[$attr16] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field16: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value16, )? Self::$field15.0 + 1));
-
-        $( #[$attr17] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field17: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value17, )? Self::$field16.0 + 1));
-
-        $( #[$attr18] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field18: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value18, )? Self::$field17.0 + 1));
-
-        $( #[$attr19] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field19: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value19, )? Self::$field18.0 + 1));
-
-        $( #[$attr20] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field20: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value20, )? Self::$field19.0 + 1));
-
-        $( #[$attr21] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field21: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value21, )? Self::$field20.0 + 1));
-
-        $( #[$attr22] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field22: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value22, )? Self::$field21.0 + 1));
-
-        $( #[$attr23] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field23: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value23, )? Self::$field22.0 + 1));
-
-        $( #[$attr24] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field24: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value24, )? Self::$field23.0 + 1));
-
-        $( #[$attr25] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field25: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value25, )? Self::$field24.0 + 1));
-
-        $( #[$attr26] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field26: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value26, )? Self::$field25.0 + 1));
-
-        $( #[$attr27] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field27: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value27, )? Self::$field26.0 + 1));
-
-        $( #[$attr28] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field28: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value28, )? Self::$field27.0 + 1));
-
-        $( #[$attr29] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field29: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value29, )? Self::$field28.0 + 1));
-
-        $( #[$attr30] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field30: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value30, )? Self::$field29.0 + 1));
-
-        $( #[$attr31] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field31: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value31, )? Self::$field30.0 + 1));
-
-        $( #[$attr32] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field32: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value32, )? Self::$field31.0 + 1));
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr1 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr1 )* ] )* ]
+                    [ $( ::core::stringify!($value1) )? ] $default_doc
+                )]
+            ]
+            [] [ pub ]
+            $field1 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value1, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr1 )* ] )* ] $field1)
+                        $default
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr2 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr2 )* ] )* ]
+                    [ $( ::core::stringify!($value2) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field1))
+                )]
+            ]
+            [] [ pub ]
+            $field2 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value2, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr2 )* ] )* ] $field2)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr3 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr3 )* ] )* ]
+                    [ $( ::core::stringify!($value3) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field2))
+                )]
+            ]
+            [] [ pub ]
+            $field3 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value3, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr3 )* ] )* ] $field3)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+                    (cfg_next $kind $opts $field2 [ $( $value2 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr4 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr4 )* ] )* ]
+                    [ $( ::core::stringify!($value4) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field3))
+                )]
+            ]
+            [] [ pub ]
+            $field4 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value4, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr4 )* ] )* ] $field4)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+                    (cfg_next $kind $opts $field3 [ $( $value3 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr5 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr5 )* ] )* ]
+                    [ $( ::core::stringify!($value5) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field4))
+                )]
+            ]
+            [] [ pub ]
+            $field5 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value5, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr5 )* ] )* ] $field5)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+                    (cfg_next $kind $opts $field4 [ $( $value4 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr6 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr6 )* ] )* ]
+                    [ $( ::core::stringify!($value6) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field5))
+                )]
+            ]
+            [] [ pub ]
+            $field6 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value6, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr6 )* ] )* ] $field6)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+                    (cfg_next $kind $opts $field5 [ $( $value5 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr7 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr7 )* ] )* ]
+                    [ $( ::core::stringify!($value7) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field6))
+                )]
+            ]
+            [] [ pub ]
+            $field7 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value7, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr7 )* ] )* ] $field7)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+                    (cfg_next $kind $opts $field6 [ $( $value6 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr8 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr8 )* ] )* ]
+                    [ $( ::core::stringify!($value8) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field7))
+                )]
+            ]
+            [] [ pub ]
+            $field8 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value8, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr8 )* ] )* ] $field8)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+                    (cfg_next $kind $opts $field7 [ $( $value7 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr9 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr9 )* ] )* ]
+                    [ $( ::core::stringify!($value9) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field8))
+                )]
+            ]
+            [] [ pub ]
+            $field9 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value9, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr9 )* ] )* ] $field9)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+                    (cfg_next $kind $opts $field8 [ $( $value8 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr10 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr10 )* ] )* ]
+                    [ $( ::core::stringify!($value10) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field9))
+                )]
+            ]
+            [] [ pub ]
+            $field10 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value10, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr10 )* ] )* ] $field10)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr9 )* ] )* ] []
+                    (cfg_next $kind $opts $field9 [ $( $value9 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr11 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr11 )* ] )* ]
+                    [ $( ::core::stringify!($value11) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field10))
+                )]
+            ]
+            [] [ pub ]
+            $field11 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value11, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr11 )* ] )* ] $field11)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr10 )* ] )* ] []
+                    (cfg_next $kind $opts $field10 [ $( $value10 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr12 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr12 )* ] )* ]
+                    [ $( ::core::stringify!($value12) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field11))
+                )]
+            ]
+            [] [ pub ]
+            $field12 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value12, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr12 )* ] )* ] $field12)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr11 )* ] )* ] []
+                    (cfg_next $kind $opts $field11 [ $( $value11 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr13 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr13 )* ] )* ]
+                    [ $( ::core::stringify!($value13) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field12))
+                )]
+            ]
+            [] [ pub ]
+            $field13 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value13, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr13 )* ] )* ] $field13)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr12 )* ] )* ] []
+                    (cfg_next $kind $opts $field12 [ $( $value12 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr14 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr14 )* ] )* ]
+                    [ $( ::core::stringify!($value14) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field13))
+                )]
+            ]
+            [] [ pub ]
+            $field14 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value14, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr14 )* ] )* ] $field14)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr13 )* ] )* ] []
+                    (cfg_next $kind $opts $field13 [ $( $value13 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr15 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr15 )* ] )* ]
+                    [ $( ::core::stringify!($value15) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field14))
+                )]
+            ]
+            [] [ pub ]
+            $field15 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value15, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr15 )* ] )* ] $field15)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr14 )* ] )* ] []
+                    (cfg_next $kind $opts $field14 [ $( $value14 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr16 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr16 )* ] )* ]
+                    [ $( ::core::stringify!($value16) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field15))
+                )]
+            ]
+            [] [ pub ]
+            $field16 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value16, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr16 )* ] )* ] $field16)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr15 )* ] )* ] []
+                    (cfg_next $kind $opts $field15 [ $( $value15 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr17 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr17 )* ] )* ]
+                    [ $( ::core::stringify!($value17) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field16))
+                )]
+            ]
+            [] [ pub ]
+            $field17 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value17, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr17 )* ] )* ] $field17)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr16 )* ] )* ] []
+                    (cfg_next $kind $opts $field16 [ $( $value16 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr18 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr18 )* ] )* ]
+                    [ $( ::core::stringify!($value18) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field17))
+                )]
+            ]
+            [] [ pub ]
+            $field18 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value18, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr18 )* ] )* ] $field18)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr17 )* ] )* ] []
+                    (cfg_next $kind $opts $field17 [ $( $value17 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr19 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr19 )* ] )* ]
+                    [ $( ::core::stringify!($value19) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field18))
+                )]
+            ]
+            [] [ pub ]
+            $field19 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value19, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr19 )* ] )* ] $field19)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr18 )* ] )* ] []
+                    (cfg_next $kind $opts $field18 [ $( $value18 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr20 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr20 )* ] )* ]
+                    [ $( ::core::stringify!($value20) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field19))
+                )]
+            ]
+            [] [ pub ]
+            $field20 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value20, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr20 )* ] )* ] $field20)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr19 )* ] )* ] []
+                    (cfg_next $kind $opts $field19 [ $( $value19 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr21 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr21 )* ] )* ]
+                    [ $( ::core::stringify!($value21) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field20))
+                )]
+            ]
+            [] [ pub ]
+            $field21 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value21, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr21 )* ] )* ] $field21)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr20 )* ] )* ] []
+                    (cfg_next $kind $opts $field20 [ $( $value20 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr22 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr22 )* ] )* ]
+                    [ $( ::core::stringify!($value22) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field21))
+                )]
+            ]
+            [] [ pub ]
+            $field22 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value22, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr22 )* ] )* ] $field22)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr21 )* ] )* ] []
+                    (cfg_next $kind $opts $field21 [ $( $value21 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr23 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr23 )* ] )* ]
+                    [ $( ::core::stringify!($value23) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field22))
+                )]
+            ]
+            [] [ pub ]
+            $field23 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value23, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr23 )* ] )* ] $field23)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr22 )* ] )* ] []
+                    (cfg_next $kind $opts $field22 [ $( $value22 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr24 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr24 )* ] )* ]
+                    [ $( ::core::stringify!($value24) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field23))
+                )]
+            ]
+            [] [ pub ]
+            $field24 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value24, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr24 )* ] )* ] $field24)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr23 )* ] )* ] []
+                    (cfg_next $kind $opts $field23 [ $( $value23 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr25 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr25 )* ] )* ]
+                    [ $( ::core::stringify!($value25) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field24))
+                )]
+            ]
+            [] [ pub ]
+            $field25 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value25, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr25 )* ] )* ] $field25)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr24 )* ] )* ] []
+                    (cfg_next $kind $opts $field24 [ $( $value24 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr26 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr26 )* ] )* ]
+                    [ $( ::core::stringify!($value26) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field25))
+                )]
+            ]
+            [] [ pub ]
+            $field26 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value26, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr26 )* ] )* ] $field26)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr25 )* ] )* ] []
+                    (cfg_next $kind $opts $field25 [ $( $value25 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr27 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr27 )* ] )* ]
+                    [ $( ::core::stringify!($value27) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field26))
+                )]
+            ]
+            [] [ pub ]
+            $field27 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value27, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr27 )* ] )* ] $field27)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr26 )* ] )* ] []
+                    (cfg_next $kind $opts $field26 [ $( $value26 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr28 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr28 )* ] )* ]
+                    [ $( ::core::stringify!($value28) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field27))
+                )]
+            ]
+            [] [ pub ]
+            $field28 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value28, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr28 )* ] )* ] $field28)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr27 )* ] )* ] []
+                    (cfg_next $kind $opts $field27 [ $( $value27 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr29 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr29 )* ] )* ]
+                    [ $( ::core::stringify!($value29) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field28))
+                )]
+            ]
+            [] [ pub ]
+            $field29 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value29, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr29 )* ] )* ] $field29)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr28 )* ] )* ] []
+                    (cfg_next $kind $opts $field28 [ $( $value28 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr30 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr30 )* ] )* ]
+                    [ $( ::core::stringify!($value30) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field29))
+                )]
+            ]
+            [] [ pub ]
+            $field30 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value30, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr30 )* ] )* ] $field30)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr29 )* ] )* ] []
+                    (cfg_next $kind $opts $field29 [ $( $value29 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr31 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr31 )* ] )* ]
+                    [ $( ::core::stringify!($value31) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field30))
+                )]
+            ]
+            [] [ pub ]
+            $field31 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value31, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr31 )* ] )* ] $field31)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr30 )* ] )* ] []
+                    (cfg_next $kind $opts $field30 [ $( $value30 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr32 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr32 )* ] )* ]
+                    [ $( ::core::stringify!($value32) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field31))
+                )]
+            ]
+            [] [ pub ]
+            $field32 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value32, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr32 )* ] )* ] $field32)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr31 )* ] )* ] []
+                    (cfg_next $kind $opts $field31 [ $( $value31 )? ]))
+                    )
+                )
+            )
+        );
 
         $crate::__c_enum_decl_variants!(
-            impl($name, $inner, Self::$field32.0 + 1)
-            $( $( #[$rattr] )* $frest $( = $frest_val )?, )*
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr32 )* ] )* ] []
+                    (cfg_next $kind $opts $field32 [ $( $value32 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field32))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
         );
     };
 
     // Match 8 fields
     (
-        impl($name:ident, $inner:ty, $default:expr)
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
 
-        $( #[$attr1:meta] )*
+        $( #[ $( $attr1:tt )* ] )*
         $field1:ident $( = $value1:expr )? ,
 
-        $( #[$attr2:meta] )*
+        $( #[ $( $attr2:tt )* ] )*
         $field2:ident $( = $value2:expr )? ,
 
-        $( #[$attr3:meta] )*
+        $( #[ $( $attr3:tt )* ] )*
         $field3:ident $( = $value3:expr )? ,
 
-        $( #[$attr4:meta] )*
+        $( #[ $( $attr4:tt )* ] )*
         $field4:ident $( = $value4:expr )? ,
 
-        $( #[$attr5:meta] )*
+        $( #[ $( $attr5:tt )* ] )*
         $field5:ident $( = $value5:expr )? ,
 
-        $( #[$attr6:meta] )*
+        $( #[ $( $attr6:tt )* ] )*
         $field6:ident $( = $value6:expr )? ,
 
-        $( #[$attr7:meta] )*
+        $( #[ $( $attr7:tt )* ] )*
         $field7:ident $( = $value7:expr )? ,
 
-        $( #[$attr8:meta] )*
+        $( #[ $( $attr8:tt )* ] )*
         $field8:ident $( = $value8:expr )? ,
 
         $(
-            $( #[$rattr:meta] )*
+            $( #[ $( $rattr:tt )* ] )*
             $frest:ident $( = $frest_val:expr )?
         ),* $(,)?
     ) => {
-        $( #[$attr1] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field1: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value1, )? $default));
-
-        $( #[$attr2] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field2: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value2, )? Self::$field1.0 + 1));
-
-        $( #[$attr3] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field3: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value3, )? Self::$field2.0 + 1));
-
-        $( #[$attr4] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field4: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value4, )? Self::$field3.0 + 1));
-
-        $( #[$attr5] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field5: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value5, )? Self::$field4.0 + 1));
-
-        $( #[$attr6] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field6: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value6, )? Self::$field5.0 + 1));
-
-        $( #[$attr7] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field7: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value7, )? Self::$field6.0 + 1));
-
-        $( #[$attr8] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field8: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $value8, )? Self::$field7.0 + 1));
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr1 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr1 )* ] )* ]
+                    [ $( ::core::stringify!($value1) )? ] $default_doc
+                )]
+            ]
+            [] [ pub ]
+            $field1 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value1, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr1 )* ] )* ] $field1)
+                        $default
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr2 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr2 )* ] )* ]
+                    [ $( ::core::stringify!($value2) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field1))
+                )]
+            ]
+            [] [ pub ]
+            $field2 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value2, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr2 )* ] )* ] $field2)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr3 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr3 )* ] )* ]
+                    [ $( ::core::stringify!($value3) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field2))
+                )]
+            ]
+            [] [ pub ]
+            $field3 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value3, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr3 )* ] )* ] $field3)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+                    (cfg_next $kind $opts $field2 [ $( $value2 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr4 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr4 )* ] )* ]
+                    [ $( ::core::stringify!($value4) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field3))
+                )]
+            ]
+            [] [ pub ]
+            $field4 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value4, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr4 )* ] )* ] $field4)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+                    (cfg_next $kind $opts $field3 [ $( $value3 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr5 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr5 )* ] )* ]
+                    [ $( ::core::stringify!($value5) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field4))
+                )]
+            ]
+            [] [ pub ]
+            $field5 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value5, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr5 )* ] )* ] $field5)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+                    (cfg_next $kind $opts $field4 [ $( $value4 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr6 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr6 )* ] )* ]
+                    [ $( ::core::stringify!($value6) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field5))
+                )]
+            ]
+            [] [ pub ]
+            $field6 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value6, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr6 )* ] )* ] $field6)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+                    (cfg_next $kind $opts $field5 [ $( $value5 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr7 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr7 )* ] )* ]
+                    [ $( ::core::stringify!($value7) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field6))
+                )]
+            ]
+            [] [ pub ]
+            $field7 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value7, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr7 )* ] )* ] $field7)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+                    (cfg_next $kind $opts $field6 [ $( $value6 )? ]))
+                    )
+                )
+            )
+        );
+
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr8 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr8 )* ] )* ]
+                    [ $( ::core::stringify!($value8) )? ] $crate::__c_enum_impl!(impl(next_doc $opts $field7))
+                )]
+            ]
+            [] [ pub ]
+            $field8 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value8, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr8 )* ] )* ] $field8)
+                        $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+                    (cfg_next $kind $opts $field7 [ $( $value7 )? ]))
+                    )
+                )
+            )
+        );
 
         $crate::__c_enum_decl_variants!(
-            impl($name, $inner, Self::$field8.0 + 1)
-            $( $( #[$rattr] )* $frest $( = $frest_val )?, )*
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+                    (cfg_next $kind $opts $field8 [ $( $value8 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field8))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
         );
     };
 
     // Base case
     (
-        impl($name:ident, $inner:ty, $default:expr)
-        $( #[$fattr:meta] )*
-        $field:ident $( = $fvalue:expr )?
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
+        $( #[ $( $attr1:tt )* ] )*
+        $field1:ident $( = $value1:expr )?
         $( ,
-            $( #[$rattr:meta] )*
+            $( #[ $( $rattr:tt )* ] )*
             $frest:ident $( = $frest_val:expr )?
         )*
         $(,)?
     ) => {
-        $( #[$fattr] )*
-        #[allow(non_upper_case_globals)]
-        pub const $field: Self = Self($crate::__c_enum_impl!(
-            impl(first_expr) $( $fvalue, )? $default));
+        $crate::__c_enum_impl!(
+            impl(variant_const)
+            [
+                $( #[ $( $attr1 )* ] )*
+                #[doc = ""]
+                #[doc = $crate::__c_enum_impl!(
+                    impl(value_doc $kind) [ $( #[ $( $attr1 )* ] )* ]
+                    [ $( ::core::stringify!($value1) )? ] $default_doc
+                )]
+            ]
+            [] [ pub ]
+            $field1 = $crate::__c_enum_impl!(
+                impl(to_inner $kind [ $inner ])
+                $crate::__c_enum_impl!(
+                    impl(first_expr) $( $value1, )?
+                    $crate::__c_enum_impl!(
+                        impl(default_value $kind $opts [ $( #[ $( $attr1 )* ] )* ] $field1)
+                        $default
+                    )
+                )
+            )
+        );
 
         $crate::__c_enum_decl_variants!(
-            impl($name, $inner, Self::$field.0 + 1)
-            $( $( #[$rattr] )* $frest $( = $frest_val )?, )*
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field1))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
         );
     }
 }
+
+/// Helper macro for defining the constants that stand in for fields of a
+/// c_enum! which have been removed by `#[cfg]`.
+///
+/// Large enums can easily hit the recursion limit imposed by rustc on macros.
+/// This macro defines a bunch of variants which consume a bunch of fields at
+/// once with the goal being to reduce the recursion limit to something
+/// managable even for large enum definitions.
+///
+/// This code is generated by scripts/enum-impl.py. Don't edit it manually.
+/// Edit the script and regenerate it instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_decl_fallbacks {
+    (impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)) => {};
+
+    // Match 128 fields
+    (
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
+
+        $( #[ $( $attr1:tt )* ] )*
+        $field1:ident $( = $value1:expr )? ,
+
+        $( #[ $( $attr2:tt )* ] )*
+        $field2:ident $( = $value2:expr )? ,
+
+        $( #[ $( $attr3:tt )* ] )*
+        $field3:ident $( = $value3:expr )? ,
+
+        $( #[ $( $attr4:tt )* ] )*
+        $field4:ident $( = $value4:expr )? ,
+
+        $( #[ $( $attr5:tt )* ] )*
+        $field5:ident $( = $value5:expr )? ,
+
+        $( #[ $( $attr6:tt )* ] )*
+        $field6:ident $( = $value6:expr )? ,
+
+        $( #[ $( $attr7:tt )* ] )*
+        $field7:ident $( = $value7:expr )? ,
+
+        $( #[ $( $attr8:tt )* ] )*
+        $field8:ident $( = $value8:expr )? ,
+
+        $( #[ $( $attr9:tt )* ] )*
+        $field9:ident $( = $value9:expr )? ,
+
+        $( #[ $( $attr10:tt )* ] )*
+        $field10:ident $( = $value10:expr )? ,
+
+        $( #[ $( $attr11:tt )* ] )*
+        $field11:ident $( = $value11:expr )? ,
+
+        $( #[ $( $attr12:tt )* ] )*
+        $field12:ident $( = $value12:expr )? ,
+
+        $( #[ $( $attr13:tt )* ] )*
+        $field13:ident $( = $value13:expr )? ,
+
+        $( #[ $( $attr14:tt )* ] )*
+        $field14:ident $( = $value14:expr )? ,
+
+        $( #[ $( $attr15:tt )* ] )*
+        $field15:ident $( = $value15:expr )? ,
+
+        $( #[ $( $attr16:tt )* ] )*
+        $field16:ident $( = $value16:expr )? ,
+
+        $( #[ $( $attr17:tt )* ] )*
+        $field17:ident $( = $value17:expr )? ,
+
+        $( #[ $( $attr18:tt )* ] )*
+        $field18:ident $( = $value18:expr )? ,
+
+        $( #[ $( $attr19:tt )* ] )*
+        $field19:ident $( = $value19:expr )? ,
+
+        $( #[ $( $attr20:tt )* ] )*
+        $field20:ident $( = $value20:expr )? ,
+
+        $( #[ $( $attr21:tt )* ] )*
+        $field21:ident $( = $value21:expr )? ,
+
+        $( #[ $( $attr22:tt )* ] )*
+        $field22:ident $( = $value22:expr )? ,
+
+        $( #[ $( $attr23:tt )* ] )*
+        $field23:ident $( = $value23:expr )? ,
+
+        $( #[ $( $attr24:tt )* ] )*
+        $field24:ident $( = $value24:expr )? ,
+
+        $( #[ $( $attr25:tt )* ] )*
+        $field25:ident $( = $value25:expr )? ,
+
+        $( #[ $( $attr26:tt )* ] )*
+        $field26:ident $( = $value26:expr )? ,
+
+        $( #[ $( $attr27:tt )* ] )*
+        $field27:ident $( = $value27:expr )? ,
+
+        $( #[ $( $attr28:tt )* ] )*
+        $field28:ident $( = $value28:expr )? ,
+
+        $( #[ $( $attr29:tt )* ] )*
+        $field29:ident $( = $value29:expr )? ,
+
+        $( #[ $( $attr30:tt )* ] )*
+        $field30:ident $( = $value30:expr )? ,
+
+        $( #[ $( $attr31:tt )* ] )*
+        $field31:ident $( = $value31:expr )? ,
+
+        $( #[ $( $attr32:tt )* ] )*
+        $field32:ident $( = $value32:expr )? ,
+
+        $( #[ $( $attr33:tt )* ] )*
+        $field33:ident $( = $value33:expr )? ,
+
+        $( #[ $( $attr34:tt )* ] )*
+        $field34:ident $( = $value34:expr )? ,
+
+        $( #[ $( $attr35:tt )* ] )*
+        $field35:ident $( = $value35:expr )? ,
+
+        $( #[ $( $attr36:tt )* ] )*
+        $field36:ident $( = $value36:expr )? ,
+
+        $( #[ $( $attr37:tt )* ] )*
+        $field37:ident $( = $value37:expr )? ,
+
+        $( #[ $( $attr38:tt )* ] )*
+        $field38:ident $( = $value38:expr )? ,
+
+        $( #[ $( $attr39:tt )* ] )*
+        $field39:ident $( = $value39:expr )? ,
+
+        $( #[ $( $attr40:tt )* ] )*
+        $field40:ident $( = $value40:expr )? ,
+
+        $( #[ $( $attr41:tt )* ] )*
+        $field41:ident $( = $value41:expr )? ,
+
+        $( #[ $( $attr42:tt )* ] )*
+        $field42:ident $( = $value42:expr )? ,
+
+        $( #[ $( $attr43:tt )* ] )*
+        $field43:ident $( = $value43:expr )? ,
+
+        $( #[ $( $attr44:tt )* ] )*
+        $field44:ident $( = $value44:expr )? ,
+
+        $( #[ $( $attr45:tt )* ] )*
+        $field45:ident $( = $value45:expr )? ,
+
+        $( #[ $( $attr46:tt )* ] )*
+        $field46:ident $( = $value46:expr )? ,
+
+        $( #[ $( $attr47:tt )* ] )*
+        $field47:ident $( = $value47:expr )? ,
+
+        $( #[ $( $attr48:tt )* ] )*
+        $field48:ident $( = $value48:expr )? ,
+
+        $( #[ $( $attr49:tt )* ] )*
+        $field49:ident $( = $value49:expr )? ,
+
+        $( #[ $( $attr50:tt )* ] )*
+        $field50:ident $( = $value50:expr )? ,
+
+        $( #[ $( $attr51:tt )* ] )*
+        $field51:ident $( = $value51:expr )? ,
+
+        $( #[ $( $attr52:tt )* ] )*
+        $field52:ident $( = $value52:expr )? ,
+
+        $( #[ $( $attr53:tt )* ] )*
+        $field53:ident $( = $value53:expr )? ,
+
+        $( #[ $( $attr54:tt )* ] )*
+        $field54:ident $( = $value54:expr )? ,
+
+        $( #[ $( $attr55:tt )* ] )*
+        $field55:ident $( = $value55:expr )? ,
+
+        $( #[ $( $attr56:tt )* ] )*
+        $field56:ident $( = $value56:expr )? ,
+
+        $( #[ $( $attr57:tt )* ] )*
+        $field57:ident $( = $value57:expr )? ,
+
+        $( #[ $( $attr58:tt )* ] )*
+        $field58:ident $( = $value58:expr )? ,
+
+        $( #[ $( $attr59:tt )* ] )*
+        $field59:ident $( = $value59:expr )? ,
+
+        $( #[ $( $attr60:tt )* ] )*
+        $field60:ident $( = $value60:expr )? ,
+
+        $( #[ $( $attr61:tt )* ] )*
+        $field61:ident $( = $value61:expr )? ,
+
+        $( #[ $( $attr62:tt )* ] )*
+        $field62:ident $( = $value62:expr )? ,
+
+        $( #[ $( $attr63:tt )* ] )*
+        $field63:ident $( = $value63:expr )? ,
+
+        $( #[ $( $attr64:tt )* ] )*
+        $field64:ident $( = $value64:expr )? ,
+
+        $( #[ $( $attr65:tt )* ] )*
+        $field65:ident $( = $value65:expr )? ,
+
+        $( #[ $( $attr66:tt )* ] )*
+        $field66:ident $( = $value66:expr )? ,
+
+        $( #[ $( $attr67:tt )* ] )*
+        $field67:ident $( = $value67:expr )? ,
+
+        $( #[ $( $attr68:tt )* ] )*
+        $field68:ident $( = $value68:expr )? ,
+
+        $( #[ $( $attr69:tt )* ] )*
+        $field69:ident $( = $value69:expr )? ,
+
+        $( #[ $( $attr70:tt )* ] )*
+        $field70:ident $( = $value70:expr )? ,
+
+        $( #[ $( $attr71:tt )* ] )*
+        $field71:ident $( = $value71:expr )? ,
+
+        $( #[ $( $attr72:tt )* ] )*
+        $field72:ident $( = $value72:expr )? ,
+
+        $( #[ $( $attr73:tt )* ] )*
+        $field73:ident $( = $value73:expr )? ,
+
+        $( #[ $( $attr74:tt )* ] )*
+        $field74:ident $( = $value74:expr )? ,
+
+        $( #[ $( $attr75:tt )* ] )*
+        $field75:ident $( = $value75:expr )? ,
+
+        $( #[ $( $attr76:tt )* ] )*
+        $field76:ident $( = $value76:expr )? ,
+
+        $( #[ $( $attr77:tt )* ] )*
+        $field77:ident $( = $value77:expr )? ,
+
+        $( #[ $( $attr78:tt )* ] )*
+        $field78:ident $( = $value78:expr )? ,
+
+        $( #[ $( $attr79:tt )* ] )*
+        $field79:ident $( = $value79:expr )? ,
+
+        $( #[ $( $attr80:tt )* ] )*
+        $field80:ident $( = $value80:expr )? ,
+
+        $( #[ $( $attr81:tt )* ] )*
+        $field81:ident $( = $value81:expr )? ,
+
+        $( #[ $( $attr82:tt )* ] )*
+        $field82:ident $( = $value82:expr )? ,
+
+        $( #[ $( $attr83:tt )* ] )*
+        $field83:ident $( = $value83:expr )? ,
+
+        $( #[ $( $attr84:tt )* ] )*
+        $field84:ident $( = $value84:expr )? ,
+
+        $( #[ $( $attr85:tt )* ] )*
+        $field85:ident $( = $value85:expr )? ,
+
+        $( #[ $( $attr86:tt )* ] )*
+        $field86:ident $( = $value86:expr )? ,
+
+        $( #[ $( $attr87:tt )* ] )*
+        $field87:ident $( = $value87:expr )? ,
+
+        $( #[ $( $attr88:tt )* ] )*
+        $field88:ident $( = $value88:expr )? ,
+
+        $( #[ $( $attr89:tt )* ] )*
+        $field89:ident $( = $value89:expr )? ,
+
+        $( #[ $( $attr90:tt )* ] )*
+        $field90:ident $( = $value90:expr )? ,
+
+        $( #[ $( $attr91:tt )* ] )*
+        $field91:ident $( = $value91:expr )? ,
+
+        $( #[ $( $attr92:tt )* ] )*
+        $field92:ident $( = $value92:expr )? ,
+
+        $( #[ $( $attr93:tt )* ] )*
+        $field93:ident $( = $value93:expr )? ,
+
+        $( #[ $( $attr94:tt )* ] )*
+        $field94:ident $( = $value94:expr )? ,
+
+        $( #[ $( $attr95:tt )* ] )*
+        $field95:ident $( = $value95:expr )? ,
+
+        $( #[ $( $attr96:tt )* ] )*
+        $field96:ident $( = $value96:expr )? ,
+
+        $( #[ $( $attr97:tt )* ] )*
+        $field97:ident $( = $value97:expr )? ,
+
+        $( #[ $( $attr98:tt )* ] )*
+        $field98:ident $( = $value98:expr )? ,
+
+        $( #[ $( $attr99:tt )* ] )*
+        $field99:ident $( = $value99:expr )? ,
+
+        $( #[ $( $attr100:tt )* ] )*
+        $field100:ident $( = $value100:expr )? ,
+
+        $( #[ $( $attr101:tt )* ] )*
+        $field101:ident $( = $value101:expr )? ,
+
+        $( #[ $( $attr102:tt )* ] )*
+        $field102:ident $( = $value102:expr )? ,
+
+        $( #[ $( $attr103:tt )* ] )*
+        $field103:ident $( = $value103:expr )? ,
+
+        $( #[ $( $attr104:tt )* ] )*
+        $field104:ident $( = $value104:expr )? ,
+
+        $( #[ $( $attr105:tt )* ] )*
+        $field105:ident $( = $value105:expr )? ,
+
+        $( #[ $( $attr106:tt )* ] )*
+        $field106:ident $( = $value106:expr )? ,
+
+        $( #[ $( $attr107:tt )* ] )*
+        $field107:ident $( = $value107:expr )? ,
+
+        $( #[ $( $attr108:tt )* ] )*
+        $field108:ident $( = $value108:expr )? ,
+
+        $( #[ $( $attr109:tt )* ] )*
+        $field109:ident $( = $value109:expr )? ,
+
+        $( #[ $( $attr110:tt )* ] )*
+        $field110:ident $( = $value110:expr )? ,
+
+        $( #[ $( $attr111:tt )* ] )*
+        $field111:ident $( = $value111:expr )? ,
+
+        $( #[ $( $attr112:tt )* ] )*
+        $field112:ident $( = $value112:expr )? ,
+
+        $( #[ $( $attr113:tt )* ] )*
+        $field113:ident $( = $value113:expr )? ,
+
+        $( #[ $( $attr114:tt )* ] )*
+        $field114:ident $( = $value114:expr )? ,
+
+        $( #[ $( $attr115:tt )* ] )*
+        $field115:ident $( = $value115:expr )? ,
+
+        $( #[ $( $attr116:tt )* ] )*
+        $field116:ident $( = $value116:expr )? ,
+
+        $( #[ $( $attr117:tt )* ] )*
+        $field117:ident $( = $value117:expr )? ,
+
+        $( #[ $( $attr118:tt )* ] )*
+        $field118:ident $( = $value118:expr )? ,
+
+        $( #[ $( $attr119:tt )* ] )*
+        $field119:ident $( = $value119:expr )? ,
+
+        $( #[ $( $attr120:tt )* ] )*
+        $field120:ident $( = $value120:expr )? ,
+
+        $( #[ $( $attr121:tt )* ] )*
+        $field121:ident $( = $value121:expr )? ,
+
+        $( #[ $( $attr122:tt )* ] )*
+        $field122:ident $( = $value122:expr )? ,
+
+        $( #[ $( $attr123:tt )* ] )*
+        $field123:ident $( = $value123:expr )? ,
+
+        $( #[ $( $attr124:tt )* ] )*
+        $field124:ident $( = $value124:expr )? ,
+
+        $( #[ $( $attr125:tt )* ] )*
+        $field125:ident $( = $value125:expr )? ,
+
+        $( #[ $( $attr126:tt )* ] )*
+        $field126:ident $( = $value126:expr )? ,
+
+        $( #[ $( $attr127:tt )* ] )*
+        $field127:ident $( = $value127:expr )? ,
+
+        $( #[ $( $attr128:tt )* ] )*
+        $field128:ident $( = $value128:expr )? ,
+
+        $(
+            $( #[ $( $rattr:tt )* ] )*
+            $frest:ident $( = $frest_val:expr )?
+        ),* $(,)?
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field1 [ $default ] [ $( $value1 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field2 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ])) ] [ $( $value2 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field3 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+                    (cfg_next $kind $opts $field2 [ $( $value2 )? ])) ] [ $( $value3 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field4 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+                    (cfg_next $kind $opts $field3 [ $( $value3 )? ])) ] [ $( $value4 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field5 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+                    (cfg_next $kind $opts $field4 [ $( $value4 )? ])) ] [ $( $value5 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field6 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+                    (cfg_next $kind $opts $field5 [ $( $value5 )? ])) ] [ $( $value6 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field7 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+                    (cfg_next $kind $opts $field6 [ $( $value6 )? ])) ] [ $( $value7 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field8 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+                    (cfg_next $kind $opts $field7 [ $( $value7 )? ])) ] [ $( $value8 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr9 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field9 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+                    (cfg_next $kind $opts $field8 [ $( $value8 )? ])) ] [ $( $value9 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr10 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field10 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr9 )* ] )* ] []
+                    (cfg_next $kind $opts $field9 [ $( $value9 )? ])) ] [ $( $value10 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr11 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field11 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr10 )* ] )* ] []
+                    (cfg_next $kind $opts $field10 [ $( $value10 )? ])) ] [ $( $value11 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr12 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field12 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr11 )* ] )* ] []
+                    (cfg_next $kind $opts $field11 [ $( $value11 )? ])) ] [ $( $value12 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr13 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field13 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr12 )* ] )* ] []
+                    (cfg_next $kind $opts $field12 [ $( $value12 )? ])) ] [ $( $value13 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr14 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field14 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr13 )* ] )* ] []
+                    (cfg_next $kind $opts $field13 [ $( $value13 )? ])) ] [ $( $value14 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr15 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field15 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr14 )* ] )* ] []
+                    (cfg_next $kind $opts $field14 [ $( $value14 )? ])) ] [ $( $value15 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr16 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field16 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr15 )* ] )* ] []
+                    (cfg_next $kind $opts $field15 [ $( $value15 )? ])) ] [ $( $value16 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr17 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field17 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr16 )* ] )* ] []
+                    (cfg_next $kind $opts $field16 [ $( $value16 )? ])) ] [ $( $value17 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr18 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field18 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr17 )* ] )* ] []
+                    (cfg_next $kind $opts $field17 [ $( $value17 )? ])) ] [ $( $value18 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr19 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field19 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr18 )* ] )* ] []
+                    (cfg_next $kind $opts $field18 [ $( $value18 )? ])) ] [ $( $value19 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr20 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field20 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr19 )* ] )* ] []
+                    (cfg_next $kind $opts $field19 [ $( $value19 )? ])) ] [ $( $value20 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr21 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field21 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr20 )* ] )* ] []
+                    (cfg_next $kind $opts $field20 [ $( $value20 )? ])) ] [ $( $value21 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr22 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field22 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr21 )* ] )* ] []
+                    (cfg_next $kind $opts $field21 [ $( $value21 )? ])) ] [ $( $value22 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr23 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field23 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr22 )* ] )* ] []
+                    (cfg_next $kind $opts $field22 [ $( $value22 )? ])) ] [ $( $value23 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr24 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field24 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr23 )* ] )* ] []
+                    (cfg_next $kind $opts $field23 [ $( $value23 )? ])) ] [ $( $value24 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr25 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field25 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr24 )* ] )* ] []
+                    (cfg_next $kind $opts $field24 [ $( $value24 )? ])) ] [ $( $value25 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr26 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field26 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr25 )* ] )* ] []
+                    (cfg_next $kind $opts $field25 [ $( $value25 )? ])) ] [ $( $value26 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr27 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field27 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr26 )* ] )* ] []
+                    (cfg_next $kind $opts $field26 [ $( $value26 )? ])) ] [ $( $value27 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr28 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field28 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr27 )* ] )* ] []
+                    (cfg_next $kind $opts $field27 [ $( $value27 )? ])) ] [ $( $value28 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr29 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field29 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr28 )* ] )* ] []
+                    (cfg_next $kind $opts $field28 [ $( $value28 )? ])) ] [ $( $value29 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr30 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field30 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr29 )* ] )* ] []
+                    (cfg_next $kind $opts $field29 [ $( $value29 )? ])) ] [ $( $value30 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr31 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field31 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr30 )* ] )* ] []
+                    (cfg_next $kind $opts $field30 [ $( $value30 )? ])) ] [ $( $value31 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr32 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field32 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr31 )* ] )* ] []
+                    (cfg_next $kind $opts $field31 [ $( $value31 )? ])) ] [ $( $value32 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr33 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field33 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr32 )* ] )* ] []
+                    (cfg_next $kind $opts $field32 [ $( $value32 )? ])) ] [ $( $value33 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr34 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field34 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr33 )* ] )* ] []
+                    (cfg_next $kind $opts $field33 [ $( $value33 )? ])) ] [ $( $value34 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr35 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field35 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr34 )* ] )* ] []
+                    (cfg_next $kind $opts $field34 [ $( $value34 )? ])) ] [ $( $value35 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr36 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field36 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr35 )* ] )* ] []
+                    (cfg_next $kind $opts $field35 [ $( $value35 )? ])) ] [ $( $value36 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr37 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field37 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr36 )* ] )* ] []
+                    (cfg_next $kind $opts $field36 [ $( $value36 )? ])) ] [ $( $value37 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr38 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field38 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr37 )* ] )* ] []
+                    (cfg_next $kind $opts $field37 [ $( $value37 )? ])) ] [ $( $value38 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr39 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field39 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr38 )* ] )* ] []
+                    (cfg_next $kind $opts $field38 [ $( $value38 )? ])) ] [ $( $value39 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr40 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field40 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr39 )* ] )* ] []
+                    (cfg_next $kind $opts $field39 [ $( $value39 )? ])) ] [ $( $value40 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr41 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field41 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr40 )* ] )* ] []
+                    (cfg_next $kind $opts $field40 [ $( $value40 )? ])) ] [ $( $value41 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr42 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field42 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr41 )* ] )* ] []
+                    (cfg_next $kind $opts $field41 [ $( $value41 )? ])) ] [ $( $value42 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr43 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field43 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr42 )* ] )* ] []
+                    (cfg_next $kind $opts $field42 [ $( $value42 )? ])) ] [ $( $value43 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr44 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field44 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr43 )* ] )* ] []
+                    (cfg_next $kind $opts $field43 [ $( $value43 )? ])) ] [ $( $value44 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr45 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field45 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr44 )* ] )* ] []
+                    (cfg_next $kind $opts $field44 [ $( $value44 )? ])) ] [ $( $value45 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr46 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field46 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr45 )* ] )* ] []
+                    (cfg_next $kind $opts $field45 [ $( $value45 )? ])) ] [ $( $value46 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr47 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field47 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr46 )* ] )* ] []
+                    (cfg_next $kind $opts $field46 [ $( $value46 )? ])) ] [ $( $value47 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr48 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field48 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr47 )* ] )* ] []
+                    (cfg_next $kind $opts $field47 [ $( $value47 )? ])) ] [ $( $value48 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr49 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field49 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr48 )* ] )* ] []
+                    (cfg_next $kind $opts $field48 [ $( $value48 )? ])) ] [ $( $value49 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr50 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field50 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr49 )* ] )* ] []
+                    (cfg_next $kind $opts $field49 [ $( $value49 )? ])) ] [ $( $value50 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr51 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field51 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr50 )* ] )* ] []
+                    (cfg_next $kind $opts $field50 [ $( $value50 )? ])) ] [ $( $value51 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr52 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field52 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr51 )* ] )* ] []
+                    (cfg_next $kind $opts $field51 [ $( $value51 )? ])) ] [ $( $value52 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr53 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field53 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr52 )* ] )* ] []
+                    (cfg_next $kind $opts $field52 [ $( $value52 )? ])) ] [ $( $value53 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr54 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field54 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr53 )* ] )* ] []
+                    (cfg_next $kind $opts $field53 [ $( $value53 )? ])) ] [ $( $value54 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr55 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field55 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr54 )* ] )* ] []
+                    (cfg_next $kind $opts $field54 [ $( $value54 )? ])) ] [ $( $value55 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr56 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field56 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr55 )* ] )* ] []
+                    (cfg_next $kind $opts $field55 [ $( $value55 )? ])) ] [ $( $value56 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr57 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field57 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr56 )* ] )* ] []
+                    (cfg_next $kind $opts $field56 [ $( $value56 )? ])) ] [ $( $value57 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr58 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field58 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr57 )* ] )* ] []
+                    (cfg_next $kind $opts $field57 [ $( $value57 )? ])) ] [ $( $value58 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr59 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field59 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr58 )* ] )* ] []
+                    (cfg_next $kind $opts $field58 [ $( $value58 )? ])) ] [ $( $value59 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr60 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field60 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr59 )* ] )* ] []
+                    (cfg_next $kind $opts $field59 [ $( $value59 )? ])) ] [ $( $value60 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr61 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field61 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr60 )* ] )* ] []
+                    (cfg_next $kind $opts $field60 [ $( $value60 )? ])) ] [ $( $value61 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr62 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field62 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr61 )* ] )* ] []
+                    (cfg_next $kind $opts $field61 [ $( $value61 )? ])) ] [ $( $value62 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr63 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field63 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr62 )* ] )* ] []
+                    (cfg_next $kind $opts $field62 [ $( $value62 )? ])) ] [ $( $value63 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr64 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field64 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr63 )* ] )* ] []
+                    (cfg_next $kind $opts $field63 [ $( $value63 )? ])) ] [ $( $value64 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr65 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field65 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr64 )* ] )* ] []
+                    (cfg_next $kind $opts $field64 [ $( $value64 )? ])) ] [ $( $value65 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr66 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field66 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr65 )* ] )* ] []
+                    (cfg_next $kind $opts $field65 [ $( $value65 )? ])) ] [ $( $value66 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr67 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field67 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr66 )* ] )* ] []
+                    (cfg_next $kind $opts $field66 [ $( $value66 )? ])) ] [ $( $value67 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr68 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field68 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr67 )* ] )* ] []
+                    (cfg_next $kind $opts $field67 [ $( $value67 )? ])) ] [ $( $value68 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr69 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field69 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr68 )* ] )* ] []
+                    (cfg_next $kind $opts $field68 [ $( $value68 )? ])) ] [ $( $value69 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr70 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field70 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr69 )* ] )* ] []
+                    (cfg_next $kind $opts $field69 [ $( $value69 )? ])) ] [ $( $value70 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr71 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field71 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr70 )* ] )* ] []
+                    (cfg_next $kind $opts $field70 [ $( $value70 )? ])) ] [ $( $value71 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr72 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field72 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr71 )* ] )* ] []
+                    (cfg_next $kind $opts $field71 [ $( $value71 )? ])) ] [ $( $value72 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr73 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field73 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr72 )* ] )* ] []
+                    (cfg_next $kind $opts $field72 [ $( $value72 )? ])) ] [ $( $value73 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr74 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field74 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr73 )* ] )* ] []
+                    (cfg_next $kind $opts $field73 [ $( $value73 )? ])) ] [ $( $value74 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr75 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field75 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr74 )* ] )* ] []
+                    (cfg_next $kind $opts $field74 [ $( $value74 )? ])) ] [ $( $value75 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr76 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field76 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr75 )* ] )* ] []
+                    (cfg_next $kind $opts $field75 [ $( $value75 )? ])) ] [ $( $value76 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr77 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field77 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr76 )* ] )* ] []
+                    (cfg_next $kind $opts $field76 [ $( $value76 )? ])) ] [ $( $value77 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr78 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field78 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr77 )* ] )* ] []
+                    (cfg_next $kind $opts $field77 [ $( $value77 )? ])) ] [ $( $value78 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr79 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field79 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr78 )* ] )* ] []
+                    (cfg_next $kind $opts $field78 [ $( $value78 )? ])) ] [ $( $value79 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr80 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field80 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr79 )* ] )* ] []
+                    (cfg_next $kind $opts $field79 [ $( $value79 )? ])) ] [ $( $value80 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr81 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field81 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr80 )* ] )* ] []
+                    (cfg_next $kind $opts $field80 [ $( $value80 )? ])) ] [ $( $value81 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr82 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field82 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr81 )* ] )* ] []
+                    (cfg_next $kind $opts $field81 [ $( $value81 )? ])) ] [ $( $value82 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr83 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field83 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr82 )* ] )* ] []
+                    (cfg_next $kind $opts $field82 [ $( $value82 )? ])) ] [ $( $value83 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr84 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field84 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr83 )* ] )* ] []
+                    (cfg_next $kind $opts $field83 [ $( $value83 )? ])) ] [ $( $value84 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr85 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field85 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr84 )* ] )* ] []
+                    (cfg_next $kind $opts $field84 [ $( $value84 )? ])) ] [ $( $value85 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr86 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field86 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr85 )* ] )* ] []
+                    (cfg_next $kind $opts $field85 [ $( $value85 )? ])) ] [ $( $value86 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr87 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field87 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr86 )* ] )* ] []
+                    (cfg_next $kind $opts $field86 [ $( $value86 )? ])) ] [ $( $value87 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr88 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field88 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr87 )* ] )* ] []
+                    (cfg_next $kind $opts $field87 [ $( $value87 )? ])) ] [ $( $value88 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr89 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field89 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr88 )* ] )* ] []
+                    (cfg_next $kind $opts $field88 [ $( $value88 )? ])) ] [ $( $value89 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr90 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field90 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr89 )* ] )* ] []
+                    (cfg_next $kind $opts $field89 [ $( $value89 )? ])) ] [ $( $value90 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr91 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field91 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr90 )* ] )* ] []
+                    (cfg_next $kind $opts $field90 [ $( $value90 )? ])) ] [ $( $value91 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr92 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field92 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr91 )* ] )* ] []
+                    (cfg_next $kind $opts $field91 [ $( $value91 )? ])) ] [ $( $value92 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr93 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field93 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr92 )* ] )* ] []
+                    (cfg_next $kind $opts $field92 [ $( $value92 )? ])) ] [ $( $value93 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr94 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field94 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr93 )* ] )* ] []
+                    (cfg_next $kind $opts $field93 [ $( $value93 )? ])) ] [ $( $value94 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr95 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field95 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr94 )* ] )* ] []
+                    (cfg_next $kind $opts $field94 [ $( $value94 )? ])) ] [ $( $value95 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr96 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field96 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr95 )* ] )* ] []
+                    (cfg_next $kind $opts $field95 [ $( $value95 )? ])) ] [ $( $value96 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr97 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field97 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr96 )* ] )* ] []
+                    (cfg_next $kind $opts $field96 [ $( $value96 )? ])) ] [ $( $value97 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr98 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field98 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr97 )* ] )* ] []
+                    (cfg_next $kind $opts $field97 [ $( $value97 )? ])) ] [ $( $value98 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr99 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field99 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr98 )* ] )* ] []
+                    (cfg_next $kind $opts $field98 [ $( $value98 )? ])) ] [ $( $value99 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr100 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field100 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr99 )* ] )* ] []
+                    (cfg_next $kind $opts $field99 [ $( $value99 )? ])) ] [ $( $value100 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr101 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field101 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr100 )* ] )* ] []
+                    (cfg_next $kind $opts $field100 [ $( $value100 )? ])) ] [ $( $value101 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr102 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field102 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr101 )* ] )* ] []
+                    (cfg_next $kind $opts $field101 [ $( $value101 )? ])) ] [ $( $value102 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr103 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field103 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr102 )* ] )* ] []
+                    (cfg_next $kind $opts $field102 [ $( $value102 )? ])) ] [ $( $value103 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr104 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field104 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr103 )* ] )* ] []
+                    (cfg_next $kind $opts $field103 [ $( $value103 )? ])) ] [ $( $value104 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr105 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field105 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr104 )* ] )* ] []
+                    (cfg_next $kind $opts $field104 [ $( $value104 )? ])) ] [ $( $value105 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr106 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field106 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr105 )* ] )* ] []
+                    (cfg_next $kind $opts $field105 [ $( $value105 )? ])) ] [ $( $value106 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr107 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field107 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr106 )* ] )* ] []
+                    (cfg_next $kind $opts $field106 [ $( $value106 )? ])) ] [ $( $value107 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr108 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field108 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr107 )* ] )* ] []
+                    (cfg_next $kind $opts $field107 [ $( $value107 )? ])) ] [ $( $value108 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr109 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field109 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr108 )* ] )* ] []
+                    (cfg_next $kind $opts $field108 [ $( $value108 )? ])) ] [ $( $value109 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr110 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field110 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr109 )* ] )* ] []
+                    (cfg_next $kind $opts $field109 [ $( $value109 )? ])) ] [ $( $value110 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr111 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field111 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr110 )* ] )* ] []
+                    (cfg_next $kind $opts $field110 [ $( $value110 )? ])) ] [ $( $value111 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr112 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field112 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr111 )* ] )* ] []
+                    (cfg_next $kind $opts $field111 [ $( $value111 )? ])) ] [ $( $value112 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr113 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field113 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr112 )* ] )* ] []
+                    (cfg_next $kind $opts $field112 [ $( $value112 )? ])) ] [ $( $value113 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr114 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field114 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr113 )* ] )* ] []
+                    (cfg_next $kind $opts $field113 [ $( $value113 )? ])) ] [ $( $value114 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr115 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field115 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr114 )* ] )* ] []
+                    (cfg_next $kind $opts $field114 [ $( $value114 )? ])) ] [ $( $value115 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr116 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field116 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr115 )* ] )* ] []
+                    (cfg_next $kind $opts $field115 [ $( $value115 )? ])) ] [ $( $value116 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr117 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field117 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr116 )* ] )* ] []
+                    (cfg_next $kind $opts $field116 [ $( $value116 )? ])) ] [ $( $value117 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr118 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field118 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr117 )* ] )* ] []
+                    (cfg_next $kind $opts $field117 [ $( $value117 )? ])) ] [ $( $value118 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr119 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field119 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr118 )* ] )* ] []
+                    (cfg_next $kind $opts $field118 [ $( $value118 )? ])) ] [ $( $value119 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr120 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field120 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr119 )* ] )* ] []
+                    (cfg_next $kind $opts $field119 [ $( $value119 )? ])) ] [ $( $value120 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr121 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field121 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr120 )* ] )* ] []
+                    (cfg_next $kind $opts $field120 [ $( $value120 )? ])) ] [ $( $value121 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr122 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field122 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr121 )* ] )* ] []
+                    (cfg_next $kind $opts $field121 [ $( $value121 )? ])) ] [ $( $value122 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr123 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field123 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr122 )* ] )* ] []
+                    (cfg_next $kind $opts $field122 [ $( $value122 )? ])) ] [ $( $value123 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr124 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field124 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr123 )* ] )* ] []
+                    (cfg_next $kind $opts $field123 [ $( $value123 )? ])) ] [ $( $value124 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr125 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field125 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr124 )* ] )* ] []
+                    (cfg_next $kind $opts $field124 [ $( $value124 )? ])) ] [ $( $value125 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr126 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field126 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr125 )* ] )* ] []
+                    (cfg_next $kind $opts $field125 [ $( $value125 )? ])) ] [ $( $value126 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr127 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field127 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr126 )* ] )* ] []
+                    (cfg_next $kind $opts $field126 [ $( $value126 )? ])) ] [ $( $value127 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr128 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field128 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr127 )* ] )* ] []
+                    (cfg_next $kind $opts $field127 [ $( $value127 )? ])) ] [ $( $value128 )? ])
+        );
+
+        $crate::__c_enum_decl_fallbacks!(
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr128 )* ] )* ] []
+                    (cfg_next $kind $opts $field128 [ $( $value128 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field128))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
+        );
+    };
+
+    // Match 32 fields
+    (
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
+
+        $( #[ $( $attr1:tt )* ] )*
+        $field1:ident $( = $value1:expr )? ,
+
+        $( #[ $( $attr2:tt )* ] )*
+        $field2:ident $( = $value2:expr )? ,
+
+        $( #[ $( $attr3:tt )* ] )*
+        $field3:ident $( = $value3:expr )? ,
+
+        $( #[ $( $attr4:tt )* ] )*
+        $field4:ident $( = $value4:expr )? ,
+
+        $( #[ $( $attr5:tt )* ] )*
+        $field5:ident $( = $value5:expr )? ,
+
+        $( #[ $( $attr6:tt )* ] )*
+        $field6:ident $( = $value6:expr )? ,
+
+        $( #[ $( $attr7:tt )* ] )*
+        $field7:ident $( = $value7:expr )? ,
+
+        $( #[ $( $attr8:tt )* ] )*
+        $field8:ident $( = $value8:expr )? ,
+
+        $( #[ $( $attr9:tt )* ] )*
+        $field9:ident $( = $value9:expr )? ,
+
+        $( #[ $( $attr10:tt )* ] )*
+        $field10:ident $( = $value10:expr )? ,
+
+        $( #[ $( $attr11:tt )* ] )*
+        $field11:ident $( = $value11:expr )? ,
+
+        $( #[ $( $attr12:tt )* ] )*
+        $field12:ident $( = $value12:expr )? ,
+
+        $( #[ $( $attr13:tt )* ] )*
+        $field13:ident $( = $value13:expr )? ,
+
+        $( #[ $( $attr14:tt )* ] )*
+        $field14:ident $( = $value14:expr )? ,
+
+        $( #[ $( $attr15:tt )* ] )*
+        $field15:ident $( = $value15:expr )? ,
+
+        $( #[ $( $attr16:tt )* ] )*
+        $field16:ident $( = $value16:expr )? ,
+
+        $( #[ $( $attr17:tt )* ] )*
+        $field17:ident $( = $value17:expr )? ,
+
+        $( #[ $( $attr18:tt )* ] )*
+        $field18:ident $( = $value18:expr )? ,
+
+        $( #[ $( $attr19:tt )* ] )*
+        $field19:ident $( = $value19:expr )? ,
+
+        $( #[ $( $attr20:tt )* ] )*
+        $field20:ident $( = $value20:expr )? ,
+
+        $( #[ $( $attr21:tt )* ] )*
+        $field21:ident $( = $value21:expr )? ,
+
+        $( #[ $( $attr22:tt )* ] )*
+        $field22:ident $( = $value22:expr )? ,
+
+        $( #[ $( $attr23:tt )* ] )*
+        $field23:ident $( = $value23:expr )? ,
+
+        $( #[ $( $attr24:tt )* ] )*
+        $field24:ident $( = $value24:expr )? ,
+
+        $( #[ $( $attr25:tt )* ] )*
+        $field25:ident $( = $value25:expr )? ,
+
+        $( #[ $( $attr26:tt )* ] )*
+        $field26:ident $( = $value26:expr )? ,
+
+        $( #[ $( $attr27:tt )* ] )*
+        $field27:ident $( = $value27:expr )? ,
+
+        $( #[ $( $attr28:tt )* ] )*
+        $field28:ident $( = $value28:expr )? ,
+
+        $( #[ $( $attr29:tt )* ] )*
+        $field29:ident $( = $value29:expr )? ,
+
+        $( #[ $( $attr30:tt )* ] )*
+        $field30:ident $( = $value30:expr )? ,
+
+        $( #[ $( $attr31:tt )* ] )*
+        $field31:ident $( = $value31:expr )? ,
+
+        $( #[ $( $attr32:tt )* ] )*
+        $field32:ident $( = $value32:expr )? ,
+
+        $(
+            $( #[ $( $rattr:tt )* ] )*
+            $frest:ident $( = $frest_val:expr )?
+        ),* $(,)?
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field1 [ $default ] [ $( $value1 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field2 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ])) ] [ $( $value2 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field3 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+                    (cfg_next $kind $opts $field2 [ $( $value2 )? ])) ] [ $( $value3 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field4 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+                    (cfg_next $kind $opts $field3 [ $( $value3 )? ])) ] [ $( $value4 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field5 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+                    (cfg_next $kind $opts $field4 [ $( $value4 )? ])) ] [ $( $value5 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field6 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+                    (cfg_next $kind $opts $field5 [ $( $value5 )? ])) ] [ $( $value6 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field7 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+                    (cfg_next $kind $opts $field6 [ $( $value6 )? ])) ] [ $( $value7 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field8 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+                    (cfg_next $kind $opts $field7 [ $( $value7 )? ])) ] [ $( $value8 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr9 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field9 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+                    (cfg_next $kind $opts $field8 [ $( $value8 )? ])) ] [ $( $value9 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr10 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field10 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr9 )* ] )* ] []
+                    (cfg_next $kind $opts $field9 [ $( $value9 )? ])) ] [ $( $value10 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr11 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field11 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr10 )* ] )* ] []
+                    (cfg_next $kind $opts $field10 [ $( $value10 )? ])) ] [ $( $value11 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr12 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field12 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr11 )* ] )* ] []
+                    (cfg_next $kind $opts $field11 [ $( $value11 )? ])) ] [ $( $value12 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr13 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field13 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr12 )* ] )* ] []
+                    (cfg_next $kind $opts $field12 [ $( $value12 )? ])) ] [ $( $value13 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr14 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field14 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr13 )* ] )* ] []
+                    (cfg_next $kind $opts $field13 [ $( $value13 )? ])) ] [ $( $value14 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr15 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field15 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr14 )* ] )* ] []
+                    (cfg_next $kind $opts $field14 [ $( $value14 )? ])) ] [ $( $value15 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr16 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field16 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr15 )* ] )* ] []
+                    (cfg_next $kind $opts $field15 [ $( $value15 )? ])) ] [ $( $value16 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr17 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field17 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr16 )* ] )* ] []
+                    (cfg_next $kind $opts $field16 [ $( $value16 )? ])) ] [ $( $value17 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr18 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field18 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr17 )* ] )* ] []
+                    (cfg_next $kind $opts $field17 [ $( $value17 )? ])) ] [ $( $value18 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr19 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field19 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr18 )* ] )* ] []
+                    (cfg_next $kind $opts $field18 [ $( $value18 )? ])) ] [ $( $value19 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr20 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field20 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr19 )* ] )* ] []
+                    (cfg_next $kind $opts $field19 [ $( $value19 )? ])) ] [ $( $value20 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr21 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field21 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr20 )* ] )* ] []
+                    (cfg_next $kind $opts $field20 [ $( $value20 )? ])) ] [ $( $value21 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr22 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field22 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr21 )* ] )* ] []
+                    (cfg_next $kind $opts $field21 [ $( $value21 )? ])) ] [ $( $value22 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr23 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field23 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr22 )* ] )* ] []
+                    (cfg_next $kind $opts $field22 [ $( $value22 )? ])) ] [ $( $value23 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr24 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field24 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr23 )* ] )* ] []
+                    (cfg_next $kind $opts $field23 [ $( $value23 )? ])) ] [ $( $value24 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr25 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field25 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr24 )* ] )* ] []
+                    (cfg_next $kind $opts $field24 [ $( $value24 )? ])) ] [ $( $value25 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr26 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field26 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr25 )* ] )* ] []
+                    (cfg_next $kind $opts $field25 [ $( $value25 )? ])) ] [ $( $value26 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr27 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field27 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr26 )* ] )* ] []
+                    (cfg_next $kind $opts $field26 [ $( $value26 )? ])) ] [ $( $value27 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr28 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field28 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr27 )* ] )* ] []
+                    (cfg_next $kind $opts $field27 [ $( $value27 )? ])) ] [ $( $value28 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr29 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field29 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr28 )* ] )* ] []
+                    (cfg_next $kind $opts $field28 [ $( $value28 )? ])) ] [ $( $value29 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr30 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field30 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr29 )* ] )* ] []
+                    (cfg_next $kind $opts $field29 [ $( $value29 )? ])) ] [ $( $value30 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr31 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field31 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr30 )* ] )* ] []
+                    (cfg_next $kind $opts $field30 [ $( $value30 )? ])) ] [ $( $value31 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr32 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field32 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr31 )* ] )* ] []
+                    (cfg_next $kind $opts $field31 [ $( $value31 )? ])) ] [ $( $value32 )? ])
+        );
+
+        $crate::__c_enum_decl_fallbacks!(
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr32 )* ] )* ] []
+                    (cfg_next $kind $opts $field32 [ $( $value32 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field32))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
+        );
+    };
+
+    // Match 8 fields
+    (
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
+
+        $( #[ $( $attr1:tt )* ] )*
+        $field1:ident $( = $value1:expr )? ,
+
+        $( #[ $( $attr2:tt )* ] )*
+        $field2:ident $( = $value2:expr )? ,
+
+        $( #[ $( $attr3:tt )* ] )*
+        $field3:ident $( = $value3:expr )? ,
+
+        $( #[ $( $attr4:tt )* ] )*
+        $field4:ident $( = $value4:expr )? ,
+
+        $( #[ $( $attr5:tt )* ] )*
+        $field5:ident $( = $value5:expr )? ,
+
+        $( #[ $( $attr6:tt )* ] )*
+        $field6:ident $( = $value6:expr )? ,
+
+        $( #[ $( $attr7:tt )* ] )*
+        $field7:ident $( = $value7:expr )? ,
+
+        $( #[ $( $attr8:tt )* ] )*
+        $field8:ident $( = $value8:expr )? ,
+
+        $(
+            $( #[ $( $rattr:tt )* ] )*
+            $frest:ident $( = $frest_val:expr )?
+        ),* $(,)?
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field1 [ $default ] [ $( $value1 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field2 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ])) ] [ $( $value2 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field3 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr2 )* ] )* ] []
+                    (cfg_next $kind $opts $field2 [ $( $value2 )? ])) ] [ $( $value3 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field4 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr3 )* ] )* ] []
+                    (cfg_next $kind $opts $field3 [ $( $value3 )? ])) ] [ $( $value4 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field5 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr4 )* ] )* ] []
+                    (cfg_next $kind $opts $field4 [ $( $value4 )? ])) ] [ $( $value5 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field6 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr5 )* ] )* ] []
+                    (cfg_next $kind $opts $field5 [ $( $value5 )? ])) ] [ $( $value6 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field7 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr6 )* ] )* ] []
+                    (cfg_next $kind $opts $field6 [ $( $value6 )? ])) ] [ $( $value7 )? ])
+        );
+
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field8 [ $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr7 )* ] )* ] []
+                    (cfg_next $kind $opts $field7 [ $( $value7 )? ])) ] [ $( $value8 )? ])
+        );
+
+        $crate::__c_enum_decl_fallbacks!(
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr8 )* ] )* ] []
+                    (cfg_next $kind $opts $field8 [ $( $value8 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field8))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
+        );
+    };
+
+    // Base case
+    (
+        impl($kind:ident, $opts:tt, $name:ident, $inner:ty, $default:expr, $default_doc:expr)
+        $( #[ $( $attr1:tt )* ] )*
+        $field1:ident $( = $value1:expr )?
+        $( ,
+            $( #[ $( $rattr:tt )* ] )*
+            $frest:ident $( = $frest_val:expr )?
+        )*
+        $(,)?
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+            (cfg_fallback $kind $name [ $inner ] $field1 [ $default ] [ $( $value1 )? ])
+        );
+
+        $crate::__c_enum_decl_fallbacks!(
+            impl(
+                $kind, $opts, $name, $inner,
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $attr1 )* ] )* ] []
+                    (cfg_next $kind $opts $field1 [ $( $value1 )? ])),
+                $crate::__c_enum_impl!(impl(next_doc $opts $field1))
+            )
+            $( $( #[ $( $rattr )* ] )* $frest $( = $frest_val )?, )*
+        );
+    }
+}
+
+/// Helper macro for checking whether any variant of a c_enum! uses
+/// `#[category = ...]` or `#[meta(...)]`.
+///
+/// The attributes are passed in as a flat list of their bracketed contents.
+/// Checking them one at a time would hit the recursion limit for large enums
+/// so this macro looks for the attributes within the next 64 and skips all
+/// of them at once if there are none.
+///
+/// This code is generated by scripts/enum-impl.py. Don't edit it manually.
+/// Edit the script and regenerate it instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_attr_flags {
+    (impl([ yes ] [ yes ] $args:tt) $( $attr:tt )*) => {
+        $crate::__c_enum_impl!(impl(attr_items [ yes ] [ yes ]) $args);
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $a60 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt $a61:tt [category = $category:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $a60 $a61 $( $rest )*
+        );
+    };
+    (
+        impl([] $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt $a61:tt $a62:tt [category = $category:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl([ yes ] $meta $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $a60 $a61 $a62 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $a60 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt $a61:tt [meta $values:tt] $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $a60 $a61 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt [] $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt $a61:tt $a62:tt [meta $values:tt]
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(
+            impl($cat [ yes ] $args)
+            $a0 $a1 $a2 $a3 $a4 $a5 $a6 $a7
+            $a8 $a9 $a10 $a11 $a12 $a13 $a14 $a15
+            $a16 $a17 $a18 $a19 $a20 $a21 $a22 $a23
+            $a24 $a25 $a26 $a27 $a28 $a29 $a30 $a31
+            $a32 $a33 $a34 $a35 $a36 $a37 $a38 $a39
+            $a40 $a41 $a42 $a43 $a44 $a45 $a46 $a47
+            $a48 $a49 $a50 $a51 $a52 $a53 $a54 $a55
+            $a56 $a57 $a58 $a59 $a60 $a61 $a62 $( $rest )*
+        );
+    };
+    (
+        impl($cat:tt $meta:tt $args:tt)
+        $a0:tt $a1:tt $a2:tt $a3:tt $a4:tt $a5:tt $a6:tt $a7:tt
+        $a8:tt $a9:tt $a10:tt $a11:tt $a12:tt $a13:tt $a14:tt $a15:tt
+        $a16:tt $a17:tt $a18:tt $a19:tt $a20:tt $a21:tt $a22:tt $a23:tt
+        $a24:tt $a25:tt $a26:tt $a27:tt $a28:tt $a29:tt $a30:tt $a31:tt
+        $a32:tt $a33:tt $a34:tt $a35:tt $a36:tt $a37:tt $a38:tt $a39:tt
+        $a40:tt $a41:tt $a42:tt $a43:tt $a44:tt $a45:tt $a46:tt $a47:tt
+        $a48:tt $a49:tt $a50:tt $a51:tt $a52:tt $a53:tt $a54:tt $a55:tt
+        $a56:tt $a57:tt $a58:tt $a59:tt $a60:tt $a61:tt $a62:tt $a63:tt
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_attr_flags!(impl($cat $meta $args) $( $rest )*);
+    };
+    (impl($cat:tt $meta:tt $args:tt) $( $attr:tt )*) => {
+        $crate::__c_enum_impl!(impl(attr_items $cat $meta) $args);
+    };
+}
+
+/// Helper macro for parsing the values of a c_enum! where only some of them
+/// are a single token tree.
+///
+/// Values which aren't a single token tree are parsed as expressions, which
+/// are passed on as a single token tree. Since ranges are valid expressions
+/// and values selected by `#[cfg]` can't be parsed as one, a value is only
+/// parsed as an expression once none of the variants following it up to the
+/// next value which is a single token tree are left to look at.
+///
+/// This code is generated by scripts/enum-impl.py. Don't edit it manually.
+/// Edit the script and regenerate it instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_values {
+    (impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt) $(,)?) => {
+        $crate::__c_enum_impl!(impl(values_done) $head [ $( $done )* ] $impl);
+    };
+
+    // Match 64 single token tree values
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $sa0:tt )* ] )* $sf0:ident $(
+            = $sv0:tt $( :: $sp0:ident )*
+            $( ..= $si0:tt $( :: $sip0:ident )* )?
+            $( .. $sh0:tt $( :: $shp0:ident )* )?
+        )?,
+        $( #[ $( $sa1:tt )* ] )* $sf1:ident $(
+            = $sv1:tt $( :: $sp1:ident )*
+            $( ..= $si1:tt $( :: $sip1:ident )* )?
+            $( .. $sh1:tt $( :: $shp1:ident )* )?
+        )?,
+        $( #[ $( $sa2:tt )* ] )* $sf2:ident $(
+            = $sv2:tt $( :: $sp2:ident )*
+            $( ..= $si2:tt $( :: $sip2:ident )* )?
+            $( .. $sh2:tt $( :: $shp2:ident )* )?
+        )?,
+        $( #[ $( $sa3:tt )* ] )* $sf3:ident $(
+            = $sv3:tt $( :: $sp3:ident )*
+            $( ..= $si3:tt $( :: $sip3:ident )* )?
+            $( .. $sh3:tt $( :: $shp3:ident )* )?
+        )?,
+        $( #[ $( $sa4:tt )* ] )* $sf4:ident $(
+            = $sv4:tt $( :: $sp4:ident )*
+            $( ..= $si4:tt $( :: $sip4:ident )* )?
+            $( .. $sh4:tt $( :: $shp4:ident )* )?
+        )?,
+        $( #[ $( $sa5:tt )* ] )* $sf5:ident $(
+            = $sv5:tt $( :: $sp5:ident )*
+            $( ..= $si5:tt $( :: $sip5:ident )* )?
+            $( .. $sh5:tt $( :: $shp5:ident )* )?
+        )?,
+        $( #[ $( $sa6:tt )* ] )* $sf6:ident $(
+            = $sv6:tt $( :: $sp6:ident )*
+            $( ..= $si6:tt $( :: $sip6:ident )* )?
+            $( .. $sh6:tt $( :: $shp6:ident )* )?
+        )?,
+        $( #[ $( $sa7:tt )* ] )* $sf7:ident $(
+            = $sv7:tt $( :: $sp7:ident )*
+            $( ..= $si7:tt $( :: $sip7:ident )* )?
+            $( .. $sh7:tt $( :: $shp7:ident )* )?
+        )?,
+        $( #[ $( $sa8:tt )* ] )* $sf8:ident $(
+            = $sv8:tt $( :: $sp8:ident )*
+            $( ..= $si8:tt $( :: $sip8:ident )* )?
+            $( .. $sh8:tt $( :: $shp8:ident )* )?
+        )?,
+        $( #[ $( $sa9:tt )* ] )* $sf9:ident $(
+            = $sv9:tt $( :: $sp9:ident )*
+            $( ..= $si9:tt $( :: $sip9:ident )* )?
+            $( .. $sh9:tt $( :: $shp9:ident )* )?
+        )?,
+        $( #[ $( $sa10:tt )* ] )* $sf10:ident $(
+            = $sv10:tt $( :: $sp10:ident )*
+            $( ..= $si10:tt $( :: $sip10:ident )* )?
+            $( .. $sh10:tt $( :: $shp10:ident )* )?
+        )?,
+        $( #[ $( $sa11:tt )* ] )* $sf11:ident $(
+            = $sv11:tt $( :: $sp11:ident )*
+            $( ..= $si11:tt $( :: $sip11:ident )* )?
+            $( .. $sh11:tt $( :: $shp11:ident )* )?
+        )?,
+        $( #[ $( $sa12:tt )* ] )* $sf12:ident $(
+            = $sv12:tt $( :: $sp12:ident )*
+            $( ..= $si12:tt $( :: $sip12:ident )* )?
+            $( .. $sh12:tt $( :: $shp12:ident )* )?
+        )?,
+        $( #[ $( $sa13:tt )* ] )* $sf13:ident $(
+            = $sv13:tt $( :: $sp13:ident )*
+            $( ..= $si13:tt $( :: $sip13:ident )* )?
+            $( .. $sh13:tt $( :: $shp13:ident )* )?
+        )?,
+        $( #[ $( $sa14:tt )* ] )* $sf14:ident $(
+            = $sv14:tt $( :: $sp14:ident )*
+            $( ..= $si14:tt $( :: $sip14:ident )* )?
+            $( .. $sh14:tt $( :: $shp14:ident )* )?
+        )?,
+        $( #[ $( $sa15:tt )* ] )* $sf15:ident $(
+            = $sv15:tt $( :: $sp15:ident )*
+            $( ..= $si15:tt $( :: $sip15:ident )* )?
+            $( .. $sh15:tt $( :: $shp15:ident )* )?
+        )?,
+        $( #[ $( $sa16:tt )* ] )* $sf16:ident $(
+            = $sv16:tt $( :: $sp16:ident )*
+            $( ..= $si16:tt $( :: $sip16:ident )* )?
+            $( .. $sh16:tt $( :: $shp16:ident )* )?
+        )?,
+        $( #[ $( $sa17:tt )* ] )* $sf17:ident $(
+            = $sv17:tt $( :: $sp17:ident )*
+            $( ..= $si17:tt $( :: $sip17:ident )* )?
+            $( .. $sh17:tt $( :: $shp17:ident )* )?
+        )?,
+        $( #[ $( $sa18:tt )* ] )* $sf18:ident $(
+            = $sv18:tt $( :: $sp18:ident )*
+            $( ..= $si18:tt $( :: $sip18:ident )* )?
+            $( .. $sh18:tt $( :: $shp18:ident )* )?
+        )?,
+        $( #[ $( $sa19:tt )* ] )* $sf19:ident $(
+            = $sv19:tt $( :: $sp19:ident )*
+            $( ..= $si19:tt $( :: $sip19:ident )* )?
+            $( .. $sh19:tt $( :: $shp19:ident )* )?
+        )?,
+        $( #[ $( $sa20:tt )* ] )* $sf20:ident $(
+            = $sv20:tt $( :: $sp20:ident )*
+            $( ..= $si20:tt $( :: $sip20:ident )* )?
+            $( .. $sh20:tt $( :: $shp20:ident )* )?
+        )?,
+        $( #[ $( $sa21:tt )* ] )* $sf21:ident $(
+            = $sv21:tt $( :: $sp21:ident )*
+            $( ..= $si21:tt $( :: $sip21:ident )* )?
+            $( .. $sh21:tt $( :: $shp21:ident )* )?
+        )?,
+        $( #[ $( $sa22:tt )* ] )* $sf22:ident $(
+            = $sv22:tt $( :: $sp22:ident )*
+            $( ..= $si22:tt $( :: $sip22:ident )* )?
+            $( .. $sh22:tt $( :: $shp22:ident )* )?
+        )?,
+        $( #[ $( $sa23:tt )* ] )* $sf23:ident $(
+            = $sv23:tt $( :: $sp23:ident )*
+            $( ..= $si23:tt $( :: $sip23:ident )* )?
+            $( .. $sh23:tt $( :: $shp23:ident )* )?
+        )?,
+        $( #[ $( $sa24:tt )* ] )* $sf24:ident $(
+            = $sv24:tt $( :: $sp24:ident )*
+            $( ..= $si24:tt $( :: $sip24:ident )* )?
+            $( .. $sh24:tt $( :: $shp24:ident )* )?
+        )?,
+        $( #[ $( $sa25:tt )* ] )* $sf25:ident $(
+            = $sv25:tt $( :: $sp25:ident )*
+            $( ..= $si25:tt $( :: $sip25:ident )* )?
+            $( .. $sh25:tt $( :: $shp25:ident )* )?
+        )?,
+        $( #[ $( $sa26:tt )* ] )* $sf26:ident $(
+            = $sv26:tt $( :: $sp26:ident )*
+            $( ..= $si26:tt $( :: $sip26:ident )* )?
+            $( .. $sh26:tt $( :: $shp26:ident )* )?
+        )?,
+        $( #[ $( $sa27:tt )* ] )* $sf27:ident $(
+            = $sv27:tt $( :: $sp27:ident )*
+            $( ..= $si27:tt $( :: $sip27:ident )* )?
+            $( .. $sh27:tt $( :: $shp27:ident )* )?
+        )?,
+        $( #[ $( $sa28:tt )* ] )* $sf28:ident $(
+            = $sv28:tt $( :: $sp28:ident )*
+            $( ..= $si28:tt $( :: $sip28:ident )* )?
+            $( .. $sh28:tt $( :: $shp28:ident )* )?
+        )?,
+        $( #[ $( $sa29:tt )* ] )* $sf29:ident $(
+            = $sv29:tt $( :: $sp29:ident )*
+            $( ..= $si29:tt $( :: $sip29:ident )* )?
+            $( .. $sh29:tt $( :: $shp29:ident )* )?
+        )?,
+        $( #[ $( $sa30:tt )* ] )* $sf30:ident $(
+            = $sv30:tt $( :: $sp30:ident )*
+            $( ..= $si30:tt $( :: $sip30:ident )* )?
+            $( .. $sh30:tt $( :: $shp30:ident )* )?
+        )?,
+        $( #[ $( $sa31:tt )* ] )* $sf31:ident $(
+            = $sv31:tt $( :: $sp31:ident )*
+            $( ..= $si31:tt $( :: $sip31:ident )* )?
+            $( .. $sh31:tt $( :: $shp31:ident )* )?
+        )?,
+        $( #[ $( $sa32:tt )* ] )* $sf32:ident $(
+            = $sv32:tt $( :: $sp32:ident )*
+            $( ..= $si32:tt $( :: $sip32:ident )* )?
+            $( .. $sh32:tt $( :: $shp32:ident )* )?
+        )?,
+        $( #[ $( $sa33:tt )* ] )* $sf33:ident $(
+            = $sv33:tt $( :: $sp33:ident )*
+            $( ..= $si33:tt $( :: $sip33:ident )* )?
+            $( .. $sh33:tt $( :: $shp33:ident )* )?
+        )?,
+        $( #[ $( $sa34:tt )* ] )* $sf34:ident $(
+            = $sv34:tt $( :: $sp34:ident )*
+            $( ..= $si34:tt $( :: $sip34:ident )* )?
+            $( .. $sh34:tt $( :: $shp34:ident )* )?
+        )?,
+        $( #[ $( $sa35:tt )* ] )* $sf35:ident $(
+            = $sv35:tt $( :: $sp35:ident )*
+            $( ..= $si35:tt $( :: $sip35:ident )* )?
+            $( .. $sh35:tt $( :: $shp35:ident )* )?
+        )?,
+        $( #[ $( $sa36:tt )* ] )* $sf36:ident $(
+            = $sv36:tt $( :: $sp36:ident )*
+            $( ..= $si36:tt $( :: $sip36:ident )* )?
+            $( .. $sh36:tt $( :: $shp36:ident )* )?
+        )?,
+        $( #[ $( $sa37:tt )* ] )* $sf37:ident $(
+            = $sv37:tt $( :: $sp37:ident )*
+            $( ..= $si37:tt $( :: $sip37:ident )* )?
+            $( .. $sh37:tt $( :: $shp37:ident )* )?
+        )?,
+        $( #[ $( $sa38:tt )* ] )* $sf38:ident $(
+            = $sv38:tt $( :: $sp38:ident )*
+            $( ..= $si38:tt $( :: $sip38:ident )* )?
+            $( .. $sh38:tt $( :: $shp38:ident )* )?
+        )?,
+        $( #[ $( $sa39:tt )* ] )* $sf39:ident $(
+            = $sv39:tt $( :: $sp39:ident )*
+            $( ..= $si39:tt $( :: $sip39:ident )* )?
+            $( .. $sh39:tt $( :: $shp39:ident )* )?
+        )?,
+        $( #[ $( $sa40:tt )* ] )* $sf40:ident $(
+            = $sv40:tt $( :: $sp40:ident )*
+            $( ..= $si40:tt $( :: $sip40:ident )* )?
+            $( .. $sh40:tt $( :: $shp40:ident )* )?
+        )?,
+        $( #[ $( $sa41:tt )* ] )* $sf41:ident $(
+            = $sv41:tt $( :: $sp41:ident )*
+            $( ..= $si41:tt $( :: $sip41:ident )* )?
+            $( .. $sh41:tt $( :: $shp41:ident )* )?
+        )?,
+        $( #[ $( $sa42:tt )* ] )* $sf42:ident $(
+            = $sv42:tt $( :: $sp42:ident )*
+            $( ..= $si42:tt $( :: $sip42:ident )* )?
+            $( .. $sh42:tt $( :: $shp42:ident )* )?
+        )?,
+        $( #[ $( $sa43:tt )* ] )* $sf43:ident $(
+            = $sv43:tt $( :: $sp43:ident )*
+            $( ..= $si43:tt $( :: $sip43:ident )* )?
+            $( .. $sh43:tt $( :: $shp43:ident )* )?
+        )?,
+        $( #[ $( $sa44:tt )* ] )* $sf44:ident $(
+            = $sv44:tt $( :: $sp44:ident )*
+            $( ..= $si44:tt $( :: $sip44:ident )* )?
+            $( .. $sh44:tt $( :: $shp44:ident )* )?
+        )?,
+        $( #[ $( $sa45:tt )* ] )* $sf45:ident $(
+            = $sv45:tt $( :: $sp45:ident )*
+            $( ..= $si45:tt $( :: $sip45:ident )* )?
+            $( .. $sh45:tt $( :: $shp45:ident )* )?
+        )?,
+        $( #[ $( $sa46:tt )* ] )* $sf46:ident $(
+            = $sv46:tt $( :: $sp46:ident )*
+            $( ..= $si46:tt $( :: $sip46:ident )* )?
+            $( .. $sh46:tt $( :: $shp46:ident )* )?
+        )?,
+        $( #[ $( $sa47:tt )* ] )* $sf47:ident $(
+            = $sv47:tt $( :: $sp47:ident )*
+            $( ..= $si47:tt $( :: $sip47:ident )* )?
+            $( .. $sh47:tt $( :: $shp47:ident )* )?
+        )?,
+        $( #[ $( $sa48:tt )* ] )* $sf48:ident $(
+            = $sv48:tt $( :: $sp48:ident )*
+            $( ..= $si48:tt $( :: $sip48:ident )* )?
+            $( .. $sh48:tt $( :: $shp48:ident )* )?
+        )?,
+        $( #[ $( $sa49:tt )* ] )* $sf49:ident $(
+            = $sv49:tt $( :: $sp49:ident )*
+            $( ..= $si49:tt $( :: $sip49:ident )* )?
+            $( .. $sh49:tt $( :: $shp49:ident )* )?
+        )?,
+        $( #[ $( $sa50:tt )* ] )* $sf50:ident $(
+            = $sv50:tt $( :: $sp50:ident )*
+            $( ..= $si50:tt $( :: $sip50:ident )* )?
+            $( .. $sh50:tt $( :: $shp50:ident )* )?
+        )?,
+        $( #[ $( $sa51:tt )* ] )* $sf51:ident $(
+            = $sv51:tt $( :: $sp51:ident )*
+            $( ..= $si51:tt $( :: $sip51:ident )* )?
+            $( .. $sh51:tt $( :: $shp51:ident )* )?
+        )?,
+        $( #[ $( $sa52:tt )* ] )* $sf52:ident $(
+            = $sv52:tt $( :: $sp52:ident )*
+            $( ..= $si52:tt $( :: $sip52:ident )* )?
+            $( .. $sh52:tt $( :: $shp52:ident )* )?
+        )?,
+        $( #[ $( $sa53:tt )* ] )* $sf53:ident $(
+            = $sv53:tt $( :: $sp53:ident )*
+            $( ..= $si53:tt $( :: $sip53:ident )* )?
+            $( .. $sh53:tt $( :: $shp53:ident )* )?
+        )?,
+        $( #[ $( $sa54:tt )* ] )* $sf54:ident $(
+            = $sv54:tt $( :: $sp54:ident )*
+            $( ..= $si54:tt $( :: $sip54:ident )* )?
+            $( .. $sh54:tt $( :: $shp54:ident )* )?
+        )?,
+        $( #[ $( $sa55:tt )* ] )* $sf55:ident $(
+            = $sv55:tt $( :: $sp55:ident )*
+            $( ..= $si55:tt $( :: $sip55:ident )* )?
+            $( .. $sh55:tt $( :: $shp55:ident )* )?
+        )?,
+        $( #[ $( $sa56:tt )* ] )* $sf56:ident $(
+            = $sv56:tt $( :: $sp56:ident )*
+            $( ..= $si56:tt $( :: $sip56:ident )* )?
+            $( .. $sh56:tt $( :: $shp56:ident )* )?
+        )?,
+        $( #[ $( $sa57:tt )* ] )* $sf57:ident $(
+            = $sv57:tt $( :: $sp57:ident )*
+            $( ..= $si57:tt $( :: $sip57:ident )* )?
+            $( .. $sh57:tt $( :: $shp57:ident )* )?
+        )?,
+        $( #[ $( $sa58:tt )* ] )* $sf58:ident $(
+            = $sv58:tt $( :: $sp58:ident )*
+            $( ..= $si58:tt $( :: $sip58:ident )* )?
+            $( .. $sh58:tt $( :: $shp58:ident )* )?
+        )?,
+        $( #[ $( $sa59:tt )* ] )* $sf59:ident $(
+            = $sv59:tt $( :: $sp59:ident )*
+            $( ..= $si59:tt $( :: $sip59:ident )* )?
+            $( .. $sh59:tt $( :: $shp59:ident )* )?
+        )?,
+        $( #[ $( $sa60:tt )* ] )* $sf60:ident $(
+            = $sv60:tt $( :: $sp60:ident )*
+            $( ..= $si60:tt $( :: $sip60:ident )* )?
+            $( .. $sh60:tt $( :: $shp60:ident )* )?
+        )?,
+        $( #[ $( $sa61:tt )* ] )* $sf61:ident $(
+            = $sv61:tt $( :: $sp61:ident )*
+            $( ..= $si61:tt $( :: $sip61:ident )* )?
+            $( .. $sh61:tt $( :: $shp61:ident )* )?
+        )?,
+        $( #[ $( $sa62:tt )* ] )* $sf62:ident $(
+            = $sv62:tt $( :: $sp62:ident )*
+            $( ..= $si62:tt $( :: $sip62:ident )* )?
+            $( .. $sh62:tt $( :: $shp62:ident )* )?
+        )?,
+        $( #[ $( $sa63:tt )* ] )* $sf63:ident $(
+            = $sv63:tt $( :: $sp63:ident )*
+            $( ..= $si63:tt $( :: $sip63:ident )* )?
+            $( .. $sh63:tt $( :: $shp63:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $sa0 )* ] )* $sf0 $(
+                        = $sv0 $( :: $sp0 )*
+                        $( ..= $si0 $( :: $sip0 )* )?
+                        $( .. $sh0 $( :: $shp0 )* )?
+                    )?,
+                    $( #[ $( $sa1 )* ] )* $sf1 $(
+                        = $sv1 $( :: $sp1 )*
+                        $( ..= $si1 $( :: $sip1 )* )?
+                        $( .. $sh1 $( :: $shp1 )* )?
+                    )?,
+                    $( #[ $( $sa2 )* ] )* $sf2 $(
+                        = $sv2 $( :: $sp2 )*
+                        $( ..= $si2 $( :: $sip2 )* )?
+                        $( .. $sh2 $( :: $shp2 )* )?
+                    )?,
+                    $( #[ $( $sa3 )* ] )* $sf3 $(
+                        = $sv3 $( :: $sp3 )*
+                        $( ..= $si3 $( :: $sip3 )* )?
+                        $( .. $sh3 $( :: $shp3 )* )?
+                    )?,
+                    $( #[ $( $sa4 )* ] )* $sf4 $(
+                        = $sv4 $( :: $sp4 )*
+                        $( ..= $si4 $( :: $sip4 )* )?
+                        $( .. $sh4 $( :: $shp4 )* )?
+                    )?,
+                    $( #[ $( $sa5 )* ] )* $sf5 $(
+                        = $sv5 $( :: $sp5 )*
+                        $( ..= $si5 $( :: $sip5 )* )?
+                        $( .. $sh5 $( :: $shp5 )* )?
+                    )?,
+                    $( #[ $( $sa6 )* ] )* $sf6 $(
+                        = $sv6 $( :: $sp6 )*
+                        $( ..= $si6 $( :: $sip6 )* )?
+                        $( .. $sh6 $( :: $shp6 )* )?
+                    )?,
+                    $( #[ $( $sa7 )* ] )* $sf7 $(
+                        = $sv7 $( :: $sp7 )*
+                        $( ..= $si7 $( :: $sip7 )* )?
+                        $( .. $sh7 $( :: $shp7 )* )?
+                    )?,
+                    $( #[ $( $sa8 )* ] )* $sf8 $(
+                        = $sv8 $( :: $sp8 )*
+                        $( ..= $si8 $( :: $sip8 )* )?
+                        $( .. $sh8 $( :: $shp8 )* )?
+                    )?,
+                    $( #[ $( $sa9 )* ] )* $sf9 $(
+                        = $sv9 $( :: $sp9 )*
+                        $( ..= $si9 $( :: $sip9 )* )?
+                        $( .. $sh9 $( :: $shp9 )* )?
+                    )?,
+                    $( #[ $( $sa10 )* ] )* $sf10 $(
+                        = $sv10 $( :: $sp10 )*
+                        $( ..= $si10 $( :: $sip10 )* )?
+                        $( .. $sh10 $( :: $shp10 )* )?
+                    )?,
+                    $( #[ $( $sa11 )* ] )* $sf11 $(
+                        = $sv11 $( :: $sp11 )*
+                        $( ..= $si11 $( :: $sip11 )* )?
+                        $( .. $sh11 $( :: $shp11 )* )?
+                    )?,
+                    $( #[ $( $sa12 )* ] )* $sf12 $(
+                        = $sv12 $( :: $sp12 )*
+                        $( ..= $si12 $( :: $sip12 )* )?
+                        $( .. $sh12 $( :: $shp12 )* )?
+                    )?,
+                    $( #[ $( $sa13 )* ] )* $sf13 $(
+                        = $sv13 $( :: $sp13 )*
+                        $( ..= $si13 $( :: $sip13 )* )?
+                        $( .. $sh13 $( :: $shp13 )* )?
+                    )?,
+                    $( #[ $( $sa14 )* ] )* $sf14 $(
+                        = $sv14 $( :: $sp14 )*
+                        $( ..= $si14 $( :: $sip14 )* )?
+                        $( .. $sh14 $( :: $shp14 )* )?
+                    )?,
+                    $( #[ $( $sa15 )* ] )* $sf15 $(
+                        = $sv15 $( :: $sp15 )*
+                        $( ..= $si15 $( :: $sip15 )* )?
+                        $( .. $sh15 $( :: $shp15 )* )?
+                    )?,
+                    $( #[ $( $sa16 )* ] )* $sf16 $(
+                        = $sv16 $( :: $sp16 )*
+                        $( ..= $si16 $( :: $sip16 )* )?
+                        $( .. $sh16 $( :: $shp16 )* )?
+                    )?,
+                    $( #[ $( $sa17 )* ] )* $sf17 $(
+                        = $sv17 $( :: $sp17 )*
+                        $( ..= $si17 $( :: $sip17 )* )?
+                        $( .. $sh17 $( :: $shp17 )* )?
+                    )?,
+                    $( #[ $( $sa18 )* ] )* $sf18 $(
+                        = $sv18 $( :: $sp18 )*
+                        $( ..= $si18 $( :: $sip18 )* )?
+                        $( .. $sh18 $( :: $shp18 )* )?
+                    )?,
+                    $( #[ $( $sa19 )* ] )* $sf19 $(
+                        = $sv19 $( :: $sp19 )*
+                        $( ..= $si19 $( :: $sip19 )* )?
+                        $( .. $sh19 $( :: $shp19 )* )?
+                    )?,
+                    $( #[ $( $sa20 )* ] )* $sf20 $(
+                        = $sv20 $( :: $sp20 )*
+                        $( ..= $si20 $( :: $sip20 )* )?
+                        $( .. $sh20 $( :: $shp20 )* )?
+                    )?,
+                    $( #[ $( $sa21 )* ] )* $sf21 $(
+                        = $sv21 $( :: $sp21 )*
+                        $( ..= $si21 $( :: $sip21 )* )?
+                        $( .. $sh21 $( :: $shp21 )* )?
+                    )?,
+                    $( #[ $( $sa22 )* ] )* $sf22 $(
+                        = $sv22 $( :: $sp22 )*
+                        $( ..= $si22 $( :: $sip22 )* )?
+                        $( .. $sh22 $( :: $shp22 )* )?
+                    )?,
+                    $( #[ $( $sa23 )* ] )* $sf23 $(
+                        = $sv23 $( :: $sp23 )*
+                        $( ..= $si23 $( :: $sip23 )* )?
+                        $( .. $sh23 $( :: $shp23 )* )?
+                    )?,
+                    $( #[ $( $sa24 )* ] )* $sf24 $(
+                        = $sv24 $( :: $sp24 )*
+                        $( ..= $si24 $( :: $sip24 )* )?
+                        $( .. $sh24 $( :: $shp24 )* )?
+                    )?,
+                    $( #[ $( $sa25 )* ] )* $sf25 $(
+                        = $sv25 $( :: $sp25 )*
+                        $( ..= $si25 $( :: $sip25 )* )?
+                        $( .. $sh25 $( :: $shp25 )* )?
+                    )?,
+                    $( #[ $( $sa26 )* ] )* $sf26 $(
+                        = $sv26 $( :: $sp26 )*
+                        $( ..= $si26 $( :: $sip26 )* )?
+                        $( .. $sh26 $( :: $shp26 )* )?
+                    )?,
+                    $( #[ $( $sa27 )* ] )* $sf27 $(
+                        = $sv27 $( :: $sp27 )*
+                        $( ..= $si27 $( :: $sip27 )* )?
+                        $( .. $sh27 $( :: $shp27 )* )?
+                    )?,
+                    $( #[ $( $sa28 )* ] )* $sf28 $(
+                        = $sv28 $( :: $sp28 )*
+                        $( ..= $si28 $( :: $sip28 )* )?
+                        $( .. $sh28 $( :: $shp28 )* )?
+                    )?,
+                    $( #[ $( $sa29 )* ] )* $sf29 $(
+                        = $sv29 $( :: $sp29 )*
+                        $( ..= $si29 $( :: $sip29 )* )?
+                        $( .. $sh29 $( :: $shp29 )* )?
+                    )?,
+                    $( #[ $( $sa30 )* ] )* $sf30 $(
+                        = $sv30 $( :: $sp30 )*
+                        $( ..= $si30 $( :: $sip30 )* )?
+                        $( .. $sh30 $( :: $shp30 )* )?
+                    )?,
+                    $( #[ $( $sa31 )* ] )* $sf31 $(
+                        = $sv31 $( :: $sp31 )*
+                        $( ..= $si31 $( :: $sip31 )* )?
+                        $( .. $sh31 $( :: $shp31 )* )?
+                    )?,
+                    $( #[ $( $sa32 )* ] )* $sf32 $(
+                        = $sv32 $( :: $sp32 )*
+                        $( ..= $si32 $( :: $sip32 )* )?
+                        $( .. $sh32 $( :: $shp32 )* )?
+                    )?,
+                    $( #[ $( $sa33 )* ] )* $sf33 $(
+                        = $sv33 $( :: $sp33 )*
+                        $( ..= $si33 $( :: $sip33 )* )?
+                        $( .. $sh33 $( :: $shp33 )* )?
+                    )?,
+                    $( #[ $( $sa34 )* ] )* $sf34 $(
+                        = $sv34 $( :: $sp34 )*
+                        $( ..= $si34 $( :: $sip34 )* )?
+                        $( .. $sh34 $( :: $shp34 )* )?
+                    )?,
+                    $( #[ $( $sa35 )* ] )* $sf35 $(
+                        = $sv35 $( :: $sp35 )*
+                        $( ..= $si35 $( :: $sip35 )* )?
+                        $( .. $sh35 $( :: $shp35 )* )?
+                    )?,
+                    $( #[ $( $sa36 )* ] )* $sf36 $(
+                        = $sv36 $( :: $sp36 )*
+                        $( ..= $si36 $( :: $sip36 )* )?
+                        $( .. $sh36 $( :: $shp36 )* )?
+                    )?,
+                    $( #[ $( $sa37 )* ] )* $sf37 $(
+                        = $sv37 $( :: $sp37 )*
+                        $( ..= $si37 $( :: $sip37 )* )?
+                        $( .. $sh37 $( :: $shp37 )* )?
+                    )?,
+                    $( #[ $( $sa38 )* ] )* $sf38 $(
+                        = $sv38 $( :: $sp38 )*
+                        $( ..= $si38 $( :: $sip38 )* )?
+                        $( .. $sh38 $( :: $shp38 )* )?
+                    )?,
+                    $( #[ $( $sa39 )* ] )* $sf39 $(
+                        = $sv39 $( :: $sp39 )*
+                        $( ..= $si39 $( :: $sip39 )* )?
+                        $( .. $sh39 $( :: $shp39 )* )?
+                    )?,
+                    $( #[ $( $sa40 )* ] )* $sf40 $(
+                        = $sv40 $( :: $sp40 )*
+                        $( ..= $si40 $( :: $sip40 )* )?
+                        $( .. $sh40 $( :: $shp40 )* )?
+                    )?,
+                    $( #[ $( $sa41 )* ] )* $sf41 $(
+                        = $sv41 $( :: $sp41 )*
+                        $( ..= $si41 $( :: $sip41 )* )?
+                        $( .. $sh41 $( :: $shp41 )* )?
+                    )?,
+                    $( #[ $( $sa42 )* ] )* $sf42 $(
+                        = $sv42 $( :: $sp42 )*
+                        $( ..= $si42 $( :: $sip42 )* )?
+                        $( .. $sh42 $( :: $shp42 )* )?
+                    )?,
+                    $( #[ $( $sa43 )* ] )* $sf43 $(
+                        = $sv43 $( :: $sp43 )*
+                        $( ..= $si43 $( :: $sip43 )* )?
+                        $( .. $sh43 $( :: $shp43 )* )?
+                    )?,
+                    $( #[ $( $sa44 )* ] )* $sf44 $(
+                        = $sv44 $( :: $sp44 )*
+                        $( ..= $si44 $( :: $sip44 )* )?
+                        $( .. $sh44 $( :: $shp44 )* )?
+                    )?,
+                    $( #[ $( $sa45 )* ] )* $sf45 $(
+                        = $sv45 $( :: $sp45 )*
+                        $( ..= $si45 $( :: $sip45 )* )?
+                        $( .. $sh45 $( :: $shp45 )* )?
+                    )?,
+                    $( #[ $( $sa46 )* ] )* $sf46 $(
+                        = $sv46 $( :: $sp46 )*
+                        $( ..= $si46 $( :: $sip46 )* )?
+                        $( .. $sh46 $( :: $shp46 )* )?
+                    )?,
+                    $( #[ $( $sa47 )* ] )* $sf47 $(
+                        = $sv47 $( :: $sp47 )*
+                        $( ..= $si47 $( :: $sip47 )* )?
+                        $( .. $sh47 $( :: $shp47 )* )?
+                    )?,
+                    $( #[ $( $sa48 )* ] )* $sf48 $(
+                        = $sv48 $( :: $sp48 )*
+                        $( ..= $si48 $( :: $sip48 )* )?
+                        $( .. $sh48 $( :: $shp48 )* )?
+                    )?,
+                    $( #[ $( $sa49 )* ] )* $sf49 $(
+                        = $sv49 $( :: $sp49 )*
+                        $( ..= $si49 $( :: $sip49 )* )?
+                        $( .. $sh49 $( :: $shp49 )* )?
+                    )?,
+                    $( #[ $( $sa50 )* ] )* $sf50 $(
+                        = $sv50 $( :: $sp50 )*
+                        $( ..= $si50 $( :: $sip50 )* )?
+                        $( .. $sh50 $( :: $shp50 )* )?
+                    )?,
+                    $( #[ $( $sa51 )* ] )* $sf51 $(
+                        = $sv51 $( :: $sp51 )*
+                        $( ..= $si51 $( :: $sip51 )* )?
+                        $( .. $sh51 $( :: $shp51 )* )?
+                    )?,
+                    $( #[ $( $sa52 )* ] )* $sf52 $(
+                        = $sv52 $( :: $sp52 )*
+                        $( ..= $si52 $( :: $sip52 )* )?
+                        $( .. $sh52 $( :: $shp52 )* )?
+                    )?,
+                    $( #[ $( $sa53 )* ] )* $sf53 $(
+                        = $sv53 $( :: $sp53 )*
+                        $( ..= $si53 $( :: $sip53 )* )?
+                        $( .. $sh53 $( :: $shp53 )* )?
+                    )?,
+                    $( #[ $( $sa54 )* ] )* $sf54 $(
+                        = $sv54 $( :: $sp54 )*
+                        $( ..= $si54 $( :: $sip54 )* )?
+                        $( .. $sh54 $( :: $shp54 )* )?
+                    )?,
+                    $( #[ $( $sa55 )* ] )* $sf55 $(
+                        = $sv55 $( :: $sp55 )*
+                        $( ..= $si55 $( :: $sip55 )* )?
+                        $( .. $sh55 $( :: $shp55 )* )?
+                    )?,
+                    $( #[ $( $sa56 )* ] )* $sf56 $(
+                        = $sv56 $( :: $sp56 )*
+                        $( ..= $si56 $( :: $sip56 )* )?
+                        $( .. $sh56 $( :: $shp56 )* )?
+                    )?,
+                    $( #[ $( $sa57 )* ] )* $sf57 $(
+                        = $sv57 $( :: $sp57 )*
+                        $( ..= $si57 $( :: $sip57 )* )?
+                        $( .. $sh57 $( :: $shp57 )* )?
+                    )?,
+                    $( #[ $( $sa58 )* ] )* $sf58 $(
+                        = $sv58 $( :: $sp58 )*
+                        $( ..= $si58 $( :: $sip58 )* )?
+                        $( .. $sh58 $( :: $shp58 )* )?
+                    )?,
+                    $( #[ $( $sa59 )* ] )* $sf59 $(
+                        = $sv59 $( :: $sp59 )*
+                        $( ..= $si59 $( :: $sip59 )* )?
+                        $( .. $sh59 $( :: $shp59 )* )?
+                    )?,
+                    $( #[ $( $sa60 )* ] )* $sf60 $(
+                        = $sv60 $( :: $sp60 )*
+                        $( ..= $si60 $( :: $sip60 )* )?
+                        $( .. $sh60 $( :: $shp60 )* )?
+                    )?,
+                    $( #[ $( $sa61 )* ] )* $sf61 $(
+                        = $sv61 $( :: $sp61 )*
+                        $( ..= $si61 $( :: $sip61 )* )?
+                        $( .. $sh61 $( :: $shp61 )* )?
+                    )?,
+                    $( #[ $( $sa62 )* ] )* $sf62 $(
+                        = $sv62 $( :: $sp62 )*
+                        $( ..= $si62 $( :: $sip62 )* )?
+                        $( .. $sh62 $( :: $shp62 )* )?
+                    )?,
+                    $( #[ $( $sa63 )* ] )* $sf63 $(
+                        = $sv63 $( :: $sp63 )*
+                        $( ..= $si63 $( :: $sip63 )* )?
+                        $( .. $sh63 $( :: $shp63 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 8 single token tree values
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $sa0:tt )* ] )* $sf0:ident $(
+            = $sv0:tt $( :: $sp0:ident )*
+            $( ..= $si0:tt $( :: $sip0:ident )* )?
+            $( .. $sh0:tt $( :: $shp0:ident )* )?
+        )?,
+        $( #[ $( $sa1:tt )* ] )* $sf1:ident $(
+            = $sv1:tt $( :: $sp1:ident )*
+            $( ..= $si1:tt $( :: $sip1:ident )* )?
+            $( .. $sh1:tt $( :: $shp1:ident )* )?
+        )?,
+        $( #[ $( $sa2:tt )* ] )* $sf2:ident $(
+            = $sv2:tt $( :: $sp2:ident )*
+            $( ..= $si2:tt $( :: $sip2:ident )* )?
+            $( .. $sh2:tt $( :: $shp2:ident )* )?
+        )?,
+        $( #[ $( $sa3:tt )* ] )* $sf3:ident $(
+            = $sv3:tt $( :: $sp3:ident )*
+            $( ..= $si3:tt $( :: $sip3:ident )* )?
+            $( .. $sh3:tt $( :: $shp3:ident )* )?
+        )?,
+        $( #[ $( $sa4:tt )* ] )* $sf4:ident $(
+            = $sv4:tt $( :: $sp4:ident )*
+            $( ..= $si4:tt $( :: $sip4:ident )* )?
+            $( .. $sh4:tt $( :: $shp4:ident )* )?
+        )?,
+        $( #[ $( $sa5:tt )* ] )* $sf5:ident $(
+            = $sv5:tt $( :: $sp5:ident )*
+            $( ..= $si5:tt $( :: $sip5:ident )* )?
+            $( .. $sh5:tt $( :: $shp5:ident )* )?
+        )?,
+        $( #[ $( $sa6:tt )* ] )* $sf6:ident $(
+            = $sv6:tt $( :: $sp6:ident )*
+            $( ..= $si6:tt $( :: $sip6:ident )* )?
+            $( .. $sh6:tt $( :: $shp6:ident )* )?
+        )?,
+        $( #[ $( $sa7:tt )* ] )* $sf7:ident $(
+            = $sv7:tt $( :: $sp7:ident )*
+            $( ..= $si7:tt $( :: $sip7:ident )* )?
+            $( .. $sh7:tt $( :: $shp7:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $sa0 )* ] )* $sf0 $(
+                        = $sv0 $( :: $sp0 )*
+                        $( ..= $si0 $( :: $sip0 )* )?
+                        $( .. $sh0 $( :: $shp0 )* )?
+                    )?,
+                    $( #[ $( $sa1 )* ] )* $sf1 $(
+                        = $sv1 $( :: $sp1 )*
+                        $( ..= $si1 $( :: $sip1 )* )?
+                        $( .. $sh1 $( :: $shp1 )* )?
+                    )?,
+                    $( #[ $( $sa2 )* ] )* $sf2 $(
+                        = $sv2 $( :: $sp2 )*
+                        $( ..= $si2 $( :: $sip2 )* )?
+                        $( .. $sh2 $( :: $shp2 )* )?
+                    )?,
+                    $( #[ $( $sa3 )* ] )* $sf3 $(
+                        = $sv3 $( :: $sp3 )*
+                        $( ..= $si3 $( :: $sip3 )* )?
+                        $( .. $sh3 $( :: $shp3 )* )?
+                    )?,
+                    $( #[ $( $sa4 )* ] )* $sf4 $(
+                        = $sv4 $( :: $sp4 )*
+                        $( ..= $si4 $( :: $sip4 )* )?
+                        $( .. $sh4 $( :: $shp4 )* )?
+                    )?,
+                    $( #[ $( $sa5 )* ] )* $sf5 $(
+                        = $sv5 $( :: $sp5 )*
+                        $( ..= $si5 $( :: $sip5 )* )?
+                        $( .. $sh5 $( :: $shp5 )* )?
+                    )?,
+                    $( #[ $( $sa6 )* ] )* $sf6 $(
+                        = $sv6 $( :: $sp6 )*
+                        $( ..= $si6 $( :: $sip6 )* )?
+                        $( .. $sh6 $( :: $shp6 )* )?
+                    )?,
+                    $( #[ $( $sa7 )* ] )* $sf7 $(
+                        = $sv7 $( :: $sp7 )*
+                        $( ..= $si7 $( :: $sip7 )* )?
+                        $( .. $sh7 $( :: $shp7 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 1 single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $sa0:tt )* ] )* $sf0:ident $(
+            = $sv0:tt $( :: $sp0:ident )*
+            $( ..= $si0:tt $( :: $sip0:ident )* )?
+            $( .. $sh0:tt $( :: $shp0:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $sa0 )* ] )* $sf0 $(
+                        = $sv0 $( :: $sp0 )*
+                        $( ..= $si0 $( :: $sip0 )* )?
+                        $( .. $sh0 $( :: $shp0 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 1 expression followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $sa1:tt )* ] )* $sf1:ident $(
+            = $sv1:tt $( :: $sp1:ident )*
+            $( ..= $si1:tt $( :: $sip1:ident )* )?
+            $( .. $sh1:tt $( :: $shp1:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $sa1 )* ] )* $sf1 $(
+                        = $sv1 $( :: $sp1 )*
+                        $( ..= $si1 $( :: $sip1 )* )?
+                        $( .. $sh1 $( :: $shp1 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 2 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $sa2:tt )* ] )* $sf2:ident $(
+            = $sv2:tt $( :: $sp2:ident )*
+            $( ..= $si2:tt $( :: $sip2:ident )* )?
+            $( .. $sh2:tt $( :: $shp2:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $sa2 )* ] )* $sf2 $(
+                        = $sv2 $( :: $sp2 )*
+                        $( ..= $si2 $( :: $sip2 )* )?
+                        $( .. $sh2 $( :: $shp2 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 3 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $sa3:tt )* ] )* $sf3:ident $(
+            = $sv3:tt $( :: $sp3:ident )*
+            $( ..= $si3:tt $( :: $sip3:ident )* )?
+            $( .. $sh3:tt $( :: $shp3:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $sa3 )* ] )* $sf3 $(
+                        = $sv3 $( :: $sp3 )*
+                        $( ..= $si3 $( :: $sip3 )* )?
+                        $( .. $sh3 $( :: $shp3 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 4 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $sa4:tt )* ] )* $sf4:ident $(
+            = $sv4:tt $( :: $sp4:ident )*
+            $( ..= $si4:tt $( :: $sip4:ident )* )?
+            $( .. $sh4:tt $( :: $shp4:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $sa4 )* ] )* $sf4 $(
+                        = $sv4 $( :: $sp4 )*
+                        $( ..= $si4 $( :: $sip4 )* )?
+                        $( .. $sh4 $( :: $shp4 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 5 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $sa5:tt )* ] )* $sf5:ident $(
+            = $sv5:tt $( :: $sp5:ident )*
+            $( ..= $si5:tt $( :: $sip5:ident )* )?
+            $( .. $sh5:tt $( :: $shp5:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $sa5 )* ] )* $sf5 $(
+                        = $sv5 $( :: $sp5 )*
+                        $( ..= $si5 $( :: $sip5 )* )?
+                        $( .. $sh5 $( :: $shp5 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 6 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $sa6:tt )* ] )* $sf6:ident $(
+            = $sv6:tt $( :: $sp6:ident )*
+            $( ..= $si6:tt $( :: $sip6:ident )* )?
+            $( .. $sh6:tt $( :: $shp6:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $sa6 )* ] )* $sf6 $(
+                        = $sv6 $( :: $sp6 )*
+                        $( ..= $si6 $( :: $sip6 )* )?
+                        $( .. $sh6 $( :: $shp6 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 7 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $sa7:tt )* ] )* $sf7:ident $(
+            = $sv7:tt $( :: $sp7:ident )*
+            $( ..= $si7:tt $( :: $sip7:ident )* )?
+            $( .. $sh7:tt $( :: $shp7:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $sa7 )* ] )* $sf7 $(
+                        = $sv7 $( :: $sp7 )*
+                        $( ..= $si7 $( :: $sip7 )* )?
+                        $( .. $sh7 $( :: $shp7 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 8 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $sa8:tt )* ] )* $sf8:ident $(
+            = $sv8:tt $( :: $sp8:ident )*
+            $( ..= $si8:tt $( :: $sip8:ident )* )?
+            $( .. $sh8:tt $( :: $shp8:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $sa8 )* ] )* $sf8 $(
+                        = $sv8 $( :: $sp8 )*
+                        $( ..= $si8 $( :: $sip8 )* )?
+                        $( .. $sh8 $( :: $shp8 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 9 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $sa9:tt )* ] )* $sf9:ident $(
+            = $sv9:tt $( :: $sp9:ident )*
+            $( ..= $si9:tt $( :: $sip9:ident )* )?
+            $( .. $sh9:tt $( :: $shp9:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $sa9 )* ] )* $sf9 $(
+                        = $sv9 $( :: $sp9 )*
+                        $( ..= $si9 $( :: $sip9 )* )?
+                        $( .. $sh9 $( :: $shp9 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 10 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $sa10:tt )* ] )* $sf10:ident $(
+            = $sv10:tt $( :: $sp10:ident )*
+            $( ..= $si10:tt $( :: $sip10:ident )* )?
+            $( .. $sh10:tt $( :: $shp10:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $sa10 )* ] )* $sf10 $(
+                        = $sv10 $( :: $sp10 )*
+                        $( ..= $si10 $( :: $sip10 )* )?
+                        $( .. $sh10 $( :: $shp10 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 11 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $sa11:tt )* ] )* $sf11:ident $(
+            = $sv11:tt $( :: $sp11:ident )*
+            $( ..= $si11:tt $( :: $sip11:ident )* )?
+            $( .. $sh11:tt $( :: $shp11:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $sa11 )* ] )* $sf11 $(
+                        = $sv11 $( :: $sp11 )*
+                        $( ..= $si11 $( :: $sip11 )* )?
+                        $( .. $sh11 $( :: $shp11 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 12 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $sa12:tt )* ] )* $sf12:ident $(
+            = $sv12:tt $( :: $sp12:ident )*
+            $( ..= $si12:tt $( :: $sip12:ident )* )?
+            $( .. $sh12:tt $( :: $shp12:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $sa12 )* ] )* $sf12 $(
+                        = $sv12 $( :: $sp12 )*
+                        $( ..= $si12 $( :: $sip12 )* )?
+                        $( .. $sh12 $( :: $shp12 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 13 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $sa13:tt )* ] )* $sf13:ident $(
+            = $sv13:tt $( :: $sp13:ident )*
+            $( ..= $si13:tt $( :: $sip13:ident )* )?
+            $( .. $sh13:tt $( :: $shp13:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $sa13 )* ] )* $sf13 $(
+                        = $sv13 $( :: $sp13 )*
+                        $( ..= $si13 $( :: $sip13 )* )?
+                        $( .. $sh13 $( :: $shp13 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 14 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $sa14:tt )* ] )* $sf14:ident $(
+            = $sv14:tt $( :: $sp14:ident )*
+            $( ..= $si14:tt $( :: $sip14:ident )* )?
+            $( .. $sh14:tt $( :: $shp14:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $sa14 )* ] )* $sf14 $(
+                        = $sv14 $( :: $sp14 )*
+                        $( ..= $si14 $( :: $sip14 )* )?
+                        $( .. $sh14 $( :: $shp14 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 15 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $sa15:tt )* ] )* $sf15:ident $(
+            = $sv15:tt $( :: $sp15:ident )*
+            $( ..= $si15:tt $( :: $sip15:ident )* )?
+            $( .. $sh15:tt $( :: $shp15:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $sa15 )* ] )* $sf15 $(
+                        = $sv15 $( :: $sp15 )*
+                        $( ..= $si15 $( :: $sip15 )* )?
+                        $( .. $sh15 $( :: $shp15 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 16 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $sa16:tt )* ] )* $sf16:ident $(
+            = $sv16:tt $( :: $sp16:ident )*
+            $( ..= $si16:tt $( :: $sip16:ident )* )?
+            $( .. $sh16:tt $( :: $shp16:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $sa16 )* ] )* $sf16 $(
+                        = $sv16 $( :: $sp16 )*
+                        $( ..= $si16 $( :: $sip16 )* )?
+                        $( .. $sh16 $( :: $shp16 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 17 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $sa17:tt )* ] )* $sf17:ident $(
+            = $sv17:tt $( :: $sp17:ident )*
+            $( ..= $si17:tt $( :: $sip17:ident )* )?
+            $( .. $sh17:tt $( :: $shp17:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $sa17 )* ] )* $sf17 $(
+                        = $sv17 $( :: $sp17 )*
+                        $( ..= $si17 $( :: $sip17 )* )?
+                        $( .. $sh17 $( :: $shp17 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 18 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $sa18:tt )* ] )* $sf18:ident $(
+            = $sv18:tt $( :: $sp18:ident )*
+            $( ..= $si18:tt $( :: $sip18:ident )* )?
+            $( .. $sh18:tt $( :: $shp18:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $sa18 )* ] )* $sf18 $(
+                        = $sv18 $( :: $sp18 )*
+                        $( ..= $si18 $( :: $sip18 )* )?
+                        $( .. $sh18 $( :: $shp18 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 19 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $sa19:tt )* ] )* $sf19:ident $(
+            = $sv19:tt $( :: $sp19:ident )*
+            $( ..= $si19:tt $( :: $sip19:ident )* )?
+            $( .. $sh19:tt $( :: $shp19:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $sa19 )* ] )* $sf19 $(
+                        = $sv19 $( :: $sp19 )*
+                        $( ..= $si19 $( :: $sip19 )* )?
+                        $( .. $sh19 $( :: $shp19 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 20 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $sa20:tt )* ] )* $sf20:ident $(
+            = $sv20:tt $( :: $sp20:ident )*
+            $( ..= $si20:tt $( :: $sip20:ident )* )?
+            $( .. $sh20:tt $( :: $shp20:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $sa20 )* ] )* $sf20 $(
+                        = $sv20 $( :: $sp20 )*
+                        $( ..= $si20 $( :: $sip20 )* )?
+                        $( .. $sh20 $( :: $shp20 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 21 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $sa21:tt )* ] )* $sf21:ident $(
+            = $sv21:tt $( :: $sp21:ident )*
+            $( ..= $si21:tt $( :: $sip21:ident )* )?
+            $( .. $sh21:tt $( :: $shp21:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $sa21 )* ] )* $sf21 $(
+                        = $sv21 $( :: $sp21 )*
+                        $( ..= $si21 $( :: $sip21 )* )?
+                        $( .. $sh21 $( :: $shp21 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 22 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $sa22:tt )* ] )* $sf22:ident $(
+            = $sv22:tt $( :: $sp22:ident )*
+            $( ..= $si22:tt $( :: $sip22:ident )* )?
+            $( .. $sh22:tt $( :: $shp22:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $sa22 )* ] )* $sf22 $(
+                        = $sv22 $( :: $sp22 )*
+                        $( ..= $si22 $( :: $sip22 )* )?
+                        $( .. $sh22 $( :: $shp22 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 23 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $sa23:tt )* ] )* $sf23:ident $(
+            = $sv23:tt $( :: $sp23:ident )*
+            $( ..= $si23:tt $( :: $sip23:ident )* )?
+            $( .. $sh23:tt $( :: $shp23:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $sa23 )* ] )* $sf23 $(
+                        = $sv23 $( :: $sp23 )*
+                        $( ..= $si23 $( :: $sip23 )* )?
+                        $( .. $sh23 $( :: $shp23 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 24 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $sa24:tt )* ] )* $sf24:ident $(
+            = $sv24:tt $( :: $sp24:ident )*
+            $( ..= $si24:tt $( :: $sip24:ident )* )?
+            $( .. $sh24:tt $( :: $shp24:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $sa24 )* ] )* $sf24 $(
+                        = $sv24 $( :: $sp24 )*
+                        $( ..= $si24 $( :: $sip24 )* )?
+                        $( .. $sh24 $( :: $shp24 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 25 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $sa25:tt )* ] )* $sf25:ident $(
+            = $sv25:tt $( :: $sp25:ident )*
+            $( ..= $si25:tt $( :: $sip25:ident )* )?
+            $( .. $sh25:tt $( :: $shp25:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $sa25 )* ] )* $sf25 $(
+                        = $sv25 $( :: $sp25 )*
+                        $( ..= $si25 $( :: $sip25 )* )?
+                        $( .. $sh25 $( :: $shp25 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 26 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $sa26:tt )* ] )* $sf26:ident $(
+            = $sv26:tt $( :: $sp26:ident )*
+            $( ..= $si26:tt $( :: $sip26:ident )* )?
+            $( .. $sh26:tt $( :: $shp26:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $sa26 )* ] )* $sf26 $(
+                        = $sv26 $( :: $sp26 )*
+                        $( ..= $si26 $( :: $sip26 )* )?
+                        $( .. $sh26 $( :: $shp26 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 27 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $sa27:tt )* ] )* $sf27:ident $(
+            = $sv27:tt $( :: $sp27:ident )*
+            $( ..= $si27:tt $( :: $sip27:ident )* )?
+            $( .. $sh27:tt $( :: $shp27:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $sa27 )* ] )* $sf27 $(
+                        = $sv27 $( :: $sp27 )*
+                        $( ..= $si27 $( :: $sip27 )* )?
+                        $( .. $sh27 $( :: $shp27 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 28 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $sa28:tt )* ] )* $sf28:ident $(
+            = $sv28:tt $( :: $sp28:ident )*
+            $( ..= $si28:tt $( :: $sip28:ident )* )?
+            $( .. $sh28:tt $( :: $shp28:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $sa28 )* ] )* $sf28 $(
+                        = $sv28 $( :: $sp28 )*
+                        $( ..= $si28 $( :: $sip28 )* )?
+                        $( .. $sh28 $( :: $shp28 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 29 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $sa29:tt )* ] )* $sf29:ident $(
+            = $sv29:tt $( :: $sp29:ident )*
+            $( ..= $si29:tt $( :: $sip29:ident )* )?
+            $( .. $sh29:tt $( :: $shp29:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $sa29 )* ] )* $sf29 $(
+                        = $sv29 $( :: $sp29 )*
+                        $( ..= $si29 $( :: $sip29 )* )?
+                        $( .. $sh29 $( :: $shp29 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 30 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $sa30:tt )* ] )* $sf30:ident $(
+            = $sv30:tt $( :: $sp30:ident )*
+            $( ..= $si30:tt $( :: $sip30:ident )* )?
+            $( .. $sh30:tt $( :: $shp30:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $sa30 )* ] )* $sf30 $(
+                        = $sv30 $( :: $sp30 )*
+                        $( ..= $si30 $( :: $sip30 )* )?
+                        $( .. $sh30 $( :: $shp30 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 31 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $sa31:tt )* ] )* $sf31:ident $(
+            = $sv31:tt $( :: $sp31:ident )*
+            $( ..= $si31:tt $( :: $sip31:ident )* )?
+            $( .. $sh31:tt $( :: $shp31:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $sa31 )* ] )* $sf31 $(
+                        = $sv31 $( :: $sp31 )*
+                        $( ..= $si31 $( :: $sip31 )* )?
+                        $( .. $sh31 $( :: $shp31 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 32 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $sa32:tt )* ] )* $sf32:ident $(
+            = $sv32:tt $( :: $sp32:ident )*
+            $( ..= $si32:tt $( :: $sip32:ident )* )?
+            $( .. $sh32:tt $( :: $shp32:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $sa32 )* ] )* $sf32 $(
+                        = $sv32 $( :: $sp32 )*
+                        $( ..= $si32 $( :: $sip32 )* )?
+                        $( .. $sh32 $( :: $shp32 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 33 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $sa33:tt )* ] )* $sf33:ident $(
+            = $sv33:tt $( :: $sp33:ident )*
+            $( ..= $si33:tt $( :: $sip33:ident )* )?
+            $( .. $sh33:tt $( :: $shp33:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $sa33 )* ] )* $sf33 $(
+                        = $sv33 $( :: $sp33 )*
+                        $( ..= $si33 $( :: $sip33 )* )?
+                        $( .. $sh33 $( :: $shp33 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 34 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $sa34:tt )* ] )* $sf34:ident $(
+            = $sv34:tt $( :: $sp34:ident )*
+            $( ..= $si34:tt $( :: $sip34:ident )* )?
+            $( .. $sh34:tt $( :: $shp34:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $sa34 )* ] )* $sf34 $(
+                        = $sv34 $( :: $sp34 )*
+                        $( ..= $si34 $( :: $sip34 )* )?
+                        $( .. $sh34 $( :: $shp34 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 35 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $sa35:tt )* ] )* $sf35:ident $(
+            = $sv35:tt $( :: $sp35:ident )*
+            $( ..= $si35:tt $( :: $sip35:ident )* )?
+            $( .. $sh35:tt $( :: $shp35:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $sa35 )* ] )* $sf35 $(
+                        = $sv35 $( :: $sp35 )*
+                        $( ..= $si35 $( :: $sip35 )* )?
+                        $( .. $sh35 $( :: $shp35 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 36 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $sa36:tt )* ] )* $sf36:ident $(
+            = $sv36:tt $( :: $sp36:ident )*
+            $( ..= $si36:tt $( :: $sip36:ident )* )?
+            $( .. $sh36:tt $( :: $shp36:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $sa36 )* ] )* $sf36 $(
+                        = $sv36 $( :: $sp36 )*
+                        $( ..= $si36 $( :: $sip36 )* )?
+                        $( .. $sh36 $( :: $shp36 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 37 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $sa37:tt )* ] )* $sf37:ident $(
+            = $sv37:tt $( :: $sp37:ident )*
+            $( ..= $si37:tt $( :: $sip37:ident )* )?
+            $( .. $sh37:tt $( :: $shp37:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $sa37 )* ] )* $sf37 $(
+                        = $sv37 $( :: $sp37 )*
+                        $( ..= $si37 $( :: $sip37 )* )?
+                        $( .. $sh37 $( :: $shp37 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 38 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $sa38:tt )* ] )* $sf38:ident $(
+            = $sv38:tt $( :: $sp38:ident )*
+            $( ..= $si38:tt $( :: $sip38:ident )* )?
+            $( .. $sh38:tt $( :: $shp38:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $sa38 )* ] )* $sf38 $(
+                        = $sv38 $( :: $sp38 )*
+                        $( ..= $si38 $( :: $sip38 )* )?
+                        $( .. $sh38 $( :: $shp38 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 39 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $sa39:tt )* ] )* $sf39:ident $(
+            = $sv39:tt $( :: $sp39:ident )*
+            $( ..= $si39:tt $( :: $sip39:ident )* )?
+            $( .. $sh39:tt $( :: $shp39:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $sa39 )* ] )* $sf39 $(
+                        = $sv39 $( :: $sp39 )*
+                        $( ..= $si39 $( :: $sip39 )* )?
+                        $( .. $sh39 $( :: $shp39 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 40 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $sa40:tt )* ] )* $sf40:ident $(
+            = $sv40:tt $( :: $sp40:ident )*
+            $( ..= $si40:tt $( :: $sip40:ident )* )?
+            $( .. $sh40:tt $( :: $shp40:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $sa40 )* ] )* $sf40 $(
+                        = $sv40 $( :: $sp40 )*
+                        $( ..= $si40 $( :: $sip40 )* )?
+                        $( .. $sh40 $( :: $shp40 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 41 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $sa41:tt )* ] )* $sf41:ident $(
+            = $sv41:tt $( :: $sp41:ident )*
+            $( ..= $si41:tt $( :: $sip41:ident )* )?
+            $( .. $sh41:tt $( :: $shp41:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $sa41 )* ] )* $sf41 $(
+                        = $sv41 $( :: $sp41 )*
+                        $( ..= $si41 $( :: $sip41 )* )?
+                        $( .. $sh41 $( :: $shp41 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 42 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $sa42:tt )* ] )* $sf42:ident $(
+            = $sv42:tt $( :: $sp42:ident )*
+            $( ..= $si42:tt $( :: $sip42:ident )* )?
+            $( .. $sh42:tt $( :: $shp42:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $sa42 )* ] )* $sf42 $(
+                        = $sv42 $( :: $sp42 )*
+                        $( ..= $si42 $( :: $sip42 )* )?
+                        $( .. $sh42 $( :: $shp42 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 43 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $sa43:tt )* ] )* $sf43:ident $(
+            = $sv43:tt $( :: $sp43:ident )*
+            $( ..= $si43:tt $( :: $sip43:ident )* )?
+            $( .. $sh43:tt $( :: $shp43:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $sa43 )* ] )* $sf43 $(
+                        = $sv43 $( :: $sp43 )*
+                        $( ..= $si43 $( :: $sip43 )* )?
+                        $( .. $sh43 $( :: $shp43 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 44 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $sa44:tt )* ] )* $sf44:ident $(
+            = $sv44:tt $( :: $sp44:ident )*
+            $( ..= $si44:tt $( :: $sip44:ident )* )?
+            $( .. $sh44:tt $( :: $shp44:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $sa44 )* ] )* $sf44 $(
+                        = $sv44 $( :: $sp44 )*
+                        $( ..= $si44 $( :: $sip44 )* )?
+                        $( .. $sh44 $( :: $shp44 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 45 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $sa45:tt )* ] )* $sf45:ident $(
+            = $sv45:tt $( :: $sp45:ident )*
+            $( ..= $si45:tt $( :: $sip45:ident )* )?
+            $( .. $sh45:tt $( :: $shp45:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $sa45 )* ] )* $sf45 $(
+                        = $sv45 $( :: $sp45 )*
+                        $( ..= $si45 $( :: $sip45 )* )?
+                        $( .. $sh45 $( :: $shp45 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 46 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $sa46:tt )* ] )* $sf46:ident $(
+            = $sv46:tt $( :: $sp46:ident )*
+            $( ..= $si46:tt $( :: $sip46:ident )* )?
+            $( .. $sh46:tt $( :: $shp46:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $sa46 )* ] )* $sf46 $(
+                        = $sv46 $( :: $sp46 )*
+                        $( ..= $si46 $( :: $sip46 )* )?
+                        $( .. $sh46 $( :: $shp46 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 47 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $sa47:tt )* ] )* $sf47:ident $(
+            = $sv47:tt $( :: $sp47:ident )*
+            $( ..= $si47:tt $( :: $sip47:ident )* )?
+            $( .. $sh47:tt $( :: $shp47:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $sa47 )* ] )* $sf47 $(
+                        = $sv47 $( :: $sp47 )*
+                        $( ..= $si47 $( :: $sip47 )* )?
+                        $( .. $sh47 $( :: $shp47 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 48 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $sa48:tt )* ] )* $sf48:ident $(
+            = $sv48:tt $( :: $sp48:ident )*
+            $( ..= $si48:tt $( :: $sip48:ident )* )?
+            $( .. $sh48:tt $( :: $shp48:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $sa48 )* ] )* $sf48 $(
+                        = $sv48 $( :: $sp48 )*
+                        $( ..= $si48 $( :: $sip48 )* )?
+                        $( .. $sh48 $( :: $shp48 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 49 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $sa49:tt )* ] )* $sf49:ident $(
+            = $sv49:tt $( :: $sp49:ident )*
+            $( ..= $si49:tt $( :: $sip49:ident )* )?
+            $( .. $sh49:tt $( :: $shp49:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $sa49 )* ] )* $sf49 $(
+                        = $sv49 $( :: $sp49 )*
+                        $( ..= $si49 $( :: $sip49 )* )?
+                        $( .. $sh49 $( :: $shp49 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 50 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $sa50:tt )* ] )* $sf50:ident $(
+            = $sv50:tt $( :: $sp50:ident )*
+            $( ..= $si50:tt $( :: $sip50:ident )* )?
+            $( .. $sh50:tt $( :: $shp50:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $sa50 )* ] )* $sf50 $(
+                        = $sv50 $( :: $sp50 )*
+                        $( ..= $si50 $( :: $sip50 )* )?
+                        $( .. $sh50 $( :: $shp50 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 51 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $sa51:tt )* ] )* $sf51:ident $(
+            = $sv51:tt $( :: $sp51:ident )*
+            $( ..= $si51:tt $( :: $sip51:ident )* )?
+            $( .. $sh51:tt $( :: $shp51:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $sa51 )* ] )* $sf51 $(
+                        = $sv51 $( :: $sp51 )*
+                        $( ..= $si51 $( :: $sip51 )* )?
+                        $( .. $sh51 $( :: $shp51 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 52 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $sa52:tt )* ] )* $sf52:ident $(
+            = $sv52:tt $( :: $sp52:ident )*
+            $( ..= $si52:tt $( :: $sip52:ident )* )?
+            $( .. $sh52:tt $( :: $shp52:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $sa52 )* ] )* $sf52 $(
+                        = $sv52 $( :: $sp52 )*
+                        $( ..= $si52 $( :: $sip52 )* )?
+                        $( .. $sh52 $( :: $shp52 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 53 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $sa53:tt )* ] )* $sf53:ident $(
+            = $sv53:tt $( :: $sp53:ident )*
+            $( ..= $si53:tt $( :: $sip53:ident )* )?
+            $( .. $sh53:tt $( :: $shp53:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $sa53 )* ] )* $sf53 $(
+                        = $sv53 $( :: $sp53 )*
+                        $( ..= $si53 $( :: $sip53 )* )?
+                        $( .. $sh53 $( :: $shp53 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 54 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $sa54:tt )* ] )* $sf54:ident $(
+            = $sv54:tt $( :: $sp54:ident )*
+            $( ..= $si54:tt $( :: $sip54:ident )* )?
+            $( .. $sh54:tt $( :: $shp54:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $sa54 )* ] )* $sf54 $(
+                        = $sv54 $( :: $sp54 )*
+                        $( ..= $si54 $( :: $sip54 )* )?
+                        $( .. $sh54 $( :: $shp54 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 55 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $sa55:tt )* ] )* $sf55:ident $(
+            = $sv55:tt $( :: $sp55:ident )*
+            $( ..= $si55:tt $( :: $sip55:ident )* )?
+            $( .. $sh55:tt $( :: $shp55:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $sa55 )* ] )* $sf55 $(
+                        = $sv55 $( :: $sp55 )*
+                        $( ..= $si55 $( :: $sip55 )* )?
+                        $( .. $sh55 $( :: $shp55 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 56 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $sa56:tt )* ] )* $sf56:ident $(
+            = $sv56:tt $( :: $sp56:ident )*
+            $( ..= $si56:tt $( :: $sip56:ident )* )?
+            $( .. $sh56:tt $( :: $shp56:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $sa56 )* ] )* $sf56 $(
+                        = $sv56 $( :: $sp56 )*
+                        $( ..= $si56 $( :: $sip56 )* )?
+                        $( .. $sh56 $( :: $shp56 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 57 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $sa57:tt )* ] )* $sf57:ident $(
+            = $sv57:tt $( :: $sp57:ident )*
+            $( ..= $si57:tt $( :: $sip57:ident )* )?
+            $( .. $sh57:tt $( :: $shp57:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $sa57 )* ] )* $sf57 $(
+                        = $sv57 $( :: $sp57 )*
+                        $( ..= $si57 $( :: $sip57 )* )?
+                        $( .. $sh57 $( :: $shp57 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 58 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $sa58:tt )* ] )* $sf58:ident $(
+            = $sv58:tt $( :: $sp58:ident )*
+            $( ..= $si58:tt $( :: $sip58:ident )* )?
+            $( .. $sh58:tt $( :: $shp58:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $sa58 )* ] )* $sf58 $(
+                        = $sv58 $( :: $sp58 )*
+                        $( ..= $si58 $( :: $sip58 )* )?
+                        $( .. $sh58 $( :: $shp58 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 59 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $ea58:tt )* ] )* $ef58:ident $( = $ev58:expr )?,
+        $( #[ $( $sa59:tt )* ] )* $sf59:ident $(
+            = $sv59:tt $( :: $sp59:ident )*
+            $( ..= $si59:tt $( :: $sip59:ident )* )?
+            $( .. $sh59:tt $( :: $shp59:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $ea58 )* ] )* $ef58 $( = $ev58 )?,
+                    $( #[ $( $sa59 )* ] )* $sf59 $(
+                        = $sv59 $( :: $sp59 )*
+                        $( ..= $si59 $( :: $sip59 )* )?
+                        $( .. $sh59 $( :: $shp59 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 60 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $ea58:tt )* ] )* $ef58:ident $( = $ev58:expr )?,
+        $( #[ $( $ea59:tt )* ] )* $ef59:ident $( = $ev59:expr )?,
+        $( #[ $( $sa60:tt )* ] )* $sf60:ident $(
+            = $sv60:tt $( :: $sp60:ident )*
+            $( ..= $si60:tt $( :: $sip60:ident )* )?
+            $( .. $sh60:tt $( :: $shp60:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $ea58 )* ] )* $ef58 $( = $ev58 )?,
+                    $( #[ $( $ea59 )* ] )* $ef59 $( = $ev59 )?,
+                    $( #[ $( $sa60 )* ] )* $sf60 $(
+                        = $sv60 $( :: $sp60 )*
+                        $( ..= $si60 $( :: $sip60 )* )?
+                        $( .. $sh60 $( :: $shp60 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 61 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $ea58:tt )* ] )* $ef58:ident $( = $ev58:expr )?,
+        $( #[ $( $ea59:tt )* ] )* $ef59:ident $( = $ev59:expr )?,
+        $( #[ $( $ea60:tt )* ] )* $ef60:ident $( = $ev60:expr )?,
+        $( #[ $( $sa61:tt )* ] )* $sf61:ident $(
+            = $sv61:tt $( :: $sp61:ident )*
+            $( ..= $si61:tt $( :: $sip61:ident )* )?
+            $( .. $sh61:tt $( :: $shp61:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $ea58 )* ] )* $ef58 $( = $ev58 )?,
+                    $( #[ $( $ea59 )* ] )* $ef59 $( = $ev59 )?,
+                    $( #[ $( $ea60 )* ] )* $ef60 $( = $ev60 )?,
+                    $( #[ $( $sa61 )* ] )* $sf61 $(
+                        = $sv61 $( :: $sp61 )*
+                        $( ..= $si61 $( :: $sip61 )* )?
+                        $( .. $sh61 $( :: $shp61 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 62 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $ea58:tt )* ] )* $ef58:ident $( = $ev58:expr )?,
+        $( #[ $( $ea59:tt )* ] )* $ef59:ident $( = $ev59:expr )?,
+        $( #[ $( $ea60:tt )* ] )* $ef60:ident $( = $ev60:expr )?,
+        $( #[ $( $ea61:tt )* ] )* $ef61:ident $( = $ev61:expr )?,
+        $( #[ $( $sa62:tt )* ] )* $sf62:ident $(
+            = $sv62:tt $( :: $sp62:ident )*
+            $( ..= $si62:tt $( :: $sip62:ident )* )?
+            $( .. $sh62:tt $( :: $shp62:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $ea58 )* ] )* $ef58 $( = $ev58 )?,
+                    $( #[ $( $ea59 )* ] )* $ef59 $( = $ev59 )?,
+                    $( #[ $( $ea60 )* ] )* $ef60 $( = $ev60 )?,
+                    $( #[ $( $ea61 )* ] )* $ef61 $( = $ev61 )?,
+                    $( #[ $( $sa62 )* ] )* $sf62 $(
+                        = $sv62 $( :: $sp62 )*
+                        $( ..= $si62 $( :: $sip62 )* )?
+                        $( .. $sh62 $( :: $shp62 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 63 expressions followed by a single token tree value
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $ea58:tt )* ] )* $ef58:ident $( = $ev58:expr )?,
+        $( #[ $( $ea59:tt )* ] )* $ef59:ident $( = $ev59:expr )?,
+        $( #[ $( $ea60:tt )* ] )* $ef60:ident $( = $ev60:expr )?,
+        $( #[ $( $ea61:tt )* ] )* $ef61:ident $( = $ev61:expr )?,
+        $( #[ $( $ea62:tt )* ] )* $ef62:ident $( = $ev62:expr )?,
+        $( #[ $( $sa63:tt )* ] )* $sf63:ident $(
+            = $sv63:tt $( :: $sp63:ident )*
+            $( ..= $si63:tt $( :: $sip63:ident )* )?
+            $( .. $sh63:tt $( :: $shp63:ident )* )?
+        )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $ea58 )* ] )* $ef58 $( = $ev58 )?,
+                    $( #[ $( $ea59 )* ] )* $ef59 $( = $ev59 )?,
+                    $( #[ $( $ea60 )* ] )* $ef60 $( = $ev60 )?,
+                    $( #[ $( $ea61 )* ] )* $ef61 $( = $ev61 )?,
+                    $( #[ $( $ea62 )* ] )* $ef62 $( = $ev62 )?,
+                    $( #[ $( $sa63 )* ] )* $sf63 $(
+                        = $sv63 $( :: $sp63 )*
+                        $( ..= $si63 $( :: $sip63 )* )?
+                        $( .. $sh63 $( :: $shp63 )* )?
+                    )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 64 expressions
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( #[ $( $ea8:tt )* ] )* $ef8:ident $( = $ev8:expr )?,
+        $( #[ $( $ea9:tt )* ] )* $ef9:ident $( = $ev9:expr )?,
+        $( #[ $( $ea10:tt )* ] )* $ef10:ident $( = $ev10:expr )?,
+        $( #[ $( $ea11:tt )* ] )* $ef11:ident $( = $ev11:expr )?,
+        $( #[ $( $ea12:tt )* ] )* $ef12:ident $( = $ev12:expr )?,
+        $( #[ $( $ea13:tt )* ] )* $ef13:ident $( = $ev13:expr )?,
+        $( #[ $( $ea14:tt )* ] )* $ef14:ident $( = $ev14:expr )?,
+        $( #[ $( $ea15:tt )* ] )* $ef15:ident $( = $ev15:expr )?,
+        $( #[ $( $ea16:tt )* ] )* $ef16:ident $( = $ev16:expr )?,
+        $( #[ $( $ea17:tt )* ] )* $ef17:ident $( = $ev17:expr )?,
+        $( #[ $( $ea18:tt )* ] )* $ef18:ident $( = $ev18:expr )?,
+        $( #[ $( $ea19:tt )* ] )* $ef19:ident $( = $ev19:expr )?,
+        $( #[ $( $ea20:tt )* ] )* $ef20:ident $( = $ev20:expr )?,
+        $( #[ $( $ea21:tt )* ] )* $ef21:ident $( = $ev21:expr )?,
+        $( #[ $( $ea22:tt )* ] )* $ef22:ident $( = $ev22:expr )?,
+        $( #[ $( $ea23:tt )* ] )* $ef23:ident $( = $ev23:expr )?,
+        $( #[ $( $ea24:tt )* ] )* $ef24:ident $( = $ev24:expr )?,
+        $( #[ $( $ea25:tt )* ] )* $ef25:ident $( = $ev25:expr )?,
+        $( #[ $( $ea26:tt )* ] )* $ef26:ident $( = $ev26:expr )?,
+        $( #[ $( $ea27:tt )* ] )* $ef27:ident $( = $ev27:expr )?,
+        $( #[ $( $ea28:tt )* ] )* $ef28:ident $( = $ev28:expr )?,
+        $( #[ $( $ea29:tt )* ] )* $ef29:ident $( = $ev29:expr )?,
+        $( #[ $( $ea30:tt )* ] )* $ef30:ident $( = $ev30:expr )?,
+        $( #[ $( $ea31:tt )* ] )* $ef31:ident $( = $ev31:expr )?,
+        $( #[ $( $ea32:tt )* ] )* $ef32:ident $( = $ev32:expr )?,
+        $( #[ $( $ea33:tt )* ] )* $ef33:ident $( = $ev33:expr )?,
+        $( #[ $( $ea34:tt )* ] )* $ef34:ident $( = $ev34:expr )?,
+        $( #[ $( $ea35:tt )* ] )* $ef35:ident $( = $ev35:expr )?,
+        $( #[ $( $ea36:tt )* ] )* $ef36:ident $( = $ev36:expr )?,
+        $( #[ $( $ea37:tt )* ] )* $ef37:ident $( = $ev37:expr )?,
+        $( #[ $( $ea38:tt )* ] )* $ef38:ident $( = $ev38:expr )?,
+        $( #[ $( $ea39:tt )* ] )* $ef39:ident $( = $ev39:expr )?,
+        $( #[ $( $ea40:tt )* ] )* $ef40:ident $( = $ev40:expr )?,
+        $( #[ $( $ea41:tt )* ] )* $ef41:ident $( = $ev41:expr )?,
+        $( #[ $( $ea42:tt )* ] )* $ef42:ident $( = $ev42:expr )?,
+        $( #[ $( $ea43:tt )* ] )* $ef43:ident $( = $ev43:expr )?,
+        $( #[ $( $ea44:tt )* ] )* $ef44:ident $( = $ev44:expr )?,
+        $( #[ $( $ea45:tt )* ] )* $ef45:ident $( = $ev45:expr )?,
+        $( #[ $( $ea46:tt )* ] )* $ef46:ident $( = $ev46:expr )?,
+        $( #[ $( $ea47:tt )* ] )* $ef47:ident $( = $ev47:expr )?,
+        $( #[ $( $ea48:tt )* ] )* $ef48:ident $( = $ev48:expr )?,
+        $( #[ $( $ea49:tt )* ] )* $ef49:ident $( = $ev49:expr )?,
+        $( #[ $( $ea50:tt )* ] )* $ef50:ident $( = $ev50:expr )?,
+        $( #[ $( $ea51:tt )* ] )* $ef51:ident $( = $ev51:expr )?,
+        $( #[ $( $ea52:tt )* ] )* $ef52:ident $( = $ev52:expr )?,
+        $( #[ $( $ea53:tt )* ] )* $ef53:ident $( = $ev53:expr )?,
+        $( #[ $( $ea54:tt )* ] )* $ef54:ident $( = $ev54:expr )?,
+        $( #[ $( $ea55:tt )* ] )* $ef55:ident $( = $ev55:expr )?,
+        $( #[ $( $ea56:tt )* ] )* $ef56:ident $( = $ev56:expr )?,
+        $( #[ $( $ea57:tt )* ] )* $ef57:ident $( = $ev57:expr )?,
+        $( #[ $( $ea58:tt )* ] )* $ef58:ident $( = $ev58:expr )?,
+        $( #[ $( $ea59:tt )* ] )* $ef59:ident $( = $ev59:expr )?,
+        $( #[ $( $ea60:tt )* ] )* $ef60:ident $( = $ev60:expr )?,
+        $( #[ $( $ea61:tt )* ] )* $ef61:ident $( = $ev61:expr )?,
+        $( #[ $( $ea62:tt )* ] )* $ef62:ident $( = $ev62:expr )?,
+        $( #[ $( $ea63:tt )* ] )* $ef63:ident $( = $ev63:expr )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                    $( #[ $( $ea8 )* ] )* $ef8 $( = $ev8 )?,
+                    $( #[ $( $ea9 )* ] )* $ef9 $( = $ev9 )?,
+                    $( #[ $( $ea10 )* ] )* $ef10 $( = $ev10 )?,
+                    $( #[ $( $ea11 )* ] )* $ef11 $( = $ev11 )?,
+                    $( #[ $( $ea12 )* ] )* $ef12 $( = $ev12 )?,
+                    $( #[ $( $ea13 )* ] )* $ef13 $( = $ev13 )?,
+                    $( #[ $( $ea14 )* ] )* $ef14 $( = $ev14 )?,
+                    $( #[ $( $ea15 )* ] )* $ef15 $( = $ev15 )?,
+                    $( #[ $( $ea16 )* ] )* $ef16 $( = $ev16 )?,
+                    $( #[ $( $ea17 )* ] )* $ef17 $( = $ev17 )?,
+                    $( #[ $( $ea18 )* ] )* $ef18 $( = $ev18 )?,
+                    $( #[ $( $ea19 )* ] )* $ef19 $( = $ev19 )?,
+                    $( #[ $( $ea20 )* ] )* $ef20 $( = $ev20 )?,
+                    $( #[ $( $ea21 )* ] )* $ef21 $( = $ev21 )?,
+                    $( #[ $( $ea22 )* ] )* $ef22 $( = $ev22 )?,
+                    $( #[ $( $ea23 )* ] )* $ef23 $( = $ev23 )?,
+                    $( #[ $( $ea24 )* ] )* $ef24 $( = $ev24 )?,
+                    $( #[ $( $ea25 )* ] )* $ef25 $( = $ev25 )?,
+                    $( #[ $( $ea26 )* ] )* $ef26 $( = $ev26 )?,
+                    $( #[ $( $ea27 )* ] )* $ef27 $( = $ev27 )?,
+                    $( #[ $( $ea28 )* ] )* $ef28 $( = $ev28 )?,
+                    $( #[ $( $ea29 )* ] )* $ef29 $( = $ev29 )?,
+                    $( #[ $( $ea30 )* ] )* $ef30 $( = $ev30 )?,
+                    $( #[ $( $ea31 )* ] )* $ef31 $( = $ev31 )?,
+                    $( #[ $( $ea32 )* ] )* $ef32 $( = $ev32 )?,
+                    $( #[ $( $ea33 )* ] )* $ef33 $( = $ev33 )?,
+                    $( #[ $( $ea34 )* ] )* $ef34 $( = $ev34 )?,
+                    $( #[ $( $ea35 )* ] )* $ef35 $( = $ev35 )?,
+                    $( #[ $( $ea36 )* ] )* $ef36 $( = $ev36 )?,
+                    $( #[ $( $ea37 )* ] )* $ef37 $( = $ev37 )?,
+                    $( #[ $( $ea38 )* ] )* $ef38 $( = $ev38 )?,
+                    $( #[ $( $ea39 )* ] )* $ef39 $( = $ev39 )?,
+                    $( #[ $( $ea40 )* ] )* $ef40 $( = $ev40 )?,
+                    $( #[ $( $ea41 )* ] )* $ef41 $( = $ev41 )?,
+                    $( #[ $( $ea42 )* ] )* $ef42 $( = $ev42 )?,
+                    $( #[ $( $ea43 )* ] )* $ef43 $( = $ev43 )?,
+                    $( #[ $( $ea44 )* ] )* $ef44 $( = $ev44 )?,
+                    $( #[ $( $ea45 )* ] )* $ef45 $( = $ev45 )?,
+                    $( #[ $( $ea46 )* ] )* $ef46 $( = $ev46 )?,
+                    $( #[ $( $ea47 )* ] )* $ef47 $( = $ev47 )?,
+                    $( #[ $( $ea48 )* ] )* $ef48 $( = $ev48 )?,
+                    $( #[ $( $ea49 )* ] )* $ef49 $( = $ev49 )?,
+                    $( #[ $( $ea50 )* ] )* $ef50 $( = $ev50 )?,
+                    $( #[ $( $ea51 )* ] )* $ef51 $( = $ev51 )?,
+                    $( #[ $( $ea52 )* ] )* $ef52 $( = $ev52 )?,
+                    $( #[ $( $ea53 )* ] )* $ef53 $( = $ev53 )?,
+                    $( #[ $( $ea54 )* ] )* $ef54 $( = $ev54 )?,
+                    $( #[ $( $ea55 )* ] )* $ef55 $( = $ev55 )?,
+                    $( #[ $( $ea56 )* ] )* $ef56 $( = $ev56 )?,
+                    $( #[ $( $ea57 )* ] )* $ef57 $( = $ev57 )?,
+                    $( #[ $( $ea58 )* ] )* $ef58 $( = $ev58 )?,
+                    $( #[ $( $ea59 )* ] )* $ef59 $( = $ev59 )?,
+                    $( #[ $( $ea60 )* ] )* $ef60 $( = $ev60 )?,
+                    $( #[ $( $ea61 )* ] )* $ef61 $( = $ev61 )?,
+                    $( #[ $( $ea62 )* ] )* $ef62 $( = $ev62 )?,
+                    $( #[ $( $ea63 )* ] )* $ef63 $( = $ev63 )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 8 expressions
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( #[ $( $ea1:tt )* ] )* $ef1:ident $( = $ev1:expr )?,
+        $( #[ $( $ea2:tt )* ] )* $ef2:ident $( = $ev2:expr )?,
+        $( #[ $( $ea3:tt )* ] )* $ef3:ident $( = $ev3:expr )?,
+        $( #[ $( $ea4:tt )* ] )* $ef4:ident $( = $ev4:expr )?,
+        $( #[ $( $ea5:tt )* ] )* $ef5:ident $( = $ev5:expr )?,
+        $( #[ $( $ea6:tt )* ] )* $ef6:ident $( = $ev6:expr )?,
+        $( #[ $( $ea7:tt )* ] )* $ef7:ident $( = $ev7:expr )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                    $( #[ $( $ea1 )* ] )* $ef1 $( = $ev1 )?,
+                    $( #[ $( $ea2 )* ] )* $ef2 $( = $ev2 )?,
+                    $( #[ $( $ea3 )* ] )* $ef3 $( = $ev3 )?,
+                    $( #[ $( $ea4 )* ] )* $ef4 $( = $ev4 )?,
+                    $( #[ $( $ea5 )* ] )* $ef5 $( = $ev5 )?,
+                    $( #[ $( $ea6 )* ] )* $ef6 $( = $ev6 )?,
+                    $( #[ $( $ea7 )* ] )* $ef7 $( = $ev7 )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Match 1 expression
+    (
+        impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt)
+        $( #[ $( $ea0:tt )* ] )* $ef0:ident $( = $ev0:expr )?,
+        $( $rest:tt )*
+    ) => {
+        $crate::__c_enum_values!(
+            impl(
+                $head
+                [
+                    $( $done )*
+                    $( #[ $( $ea0 )* ] )* $ef0 $( = $ev0 )?,
+                ]
+                $body $impl
+            )
+            $( $rest )*
+        );
+    };
+
+    // Anything else is left for the rules of c_enum! that parse every value as
+    // an expression.
+    (impl($head:tt [ $( $done:tt )* ] $body:tt $impl:tt) $( $rest:tt )*) => {
+        $crate::__c_enum_impl!(impl(values_bail) $head $body $impl);
+    };
+}
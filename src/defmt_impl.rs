@@ -0,0 +1,43 @@
+//! Helper macros for `#[c_enum(defmt)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `defmt` feature is enabled.
+
+/// Implements `defmt::Format` for a C enum.
+///
+/// Declared variants are logged using their label while undeclared values are
+/// logged using the inner value.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_defmt {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::defmt::Format for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + $crate::__private::defmt::Format,
+        {
+            fn format(&self, fmt: $crate::__private::defmt::Formatter) {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => {
+                        $crate::__private::defmt::Format::format(label, fmt)
+                    }
+                    ::core::option::Option::None => {
+                        $crate::__private::defmt::Format::format(&self.0, fmt)
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_defmt {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(defmt)]` requires the `defmt` feature of c-enum to be enabled"
+        );
+    };
+}
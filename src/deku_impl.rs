@@ -0,0 +1,63 @@
+//! Helper macros for `#[c_enum(deku)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `deku` feature is enabled.
+
+/// Implements `DekuReader` and `DekuWriter` for a C enum by forwarding to the
+/// inner type with the same context, so attributes like `endian` work the same
+/// as they would on the inner type.
+#[cfg(feature = "deku")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_deku {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<'a, Ctx> $crate::__private::deku::DekuReader<'a, Ctx> for $name
+        where
+            $inner: $crate::__private::deku::DekuReader<'a, Ctx>,
+        {
+            fn from_reader_with_ctx<R>(
+                reader: &mut $crate::__private::deku::reader::Reader<R>,
+                ctx: Ctx,
+            ) -> ::core::result::Result<Self, $crate::__private::deku::DekuError>
+            where
+                R: $crate::__private::deku::no_std_io::Read
+                    + $crate::__private::deku::no_std_io::Seek,
+            {
+                <$inner as $crate::__private::deku::DekuReader<'a, Ctx>>::from_reader_with_ctx(
+                    reader, ctx,
+                )
+                .map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl<Ctx> $crate::__private::deku::DekuWriter<Ctx> for $name
+        where
+            $inner: $crate::__private::deku::DekuWriter<Ctx>,
+        {
+            fn to_writer<W>(
+                &self,
+                writer: &mut $crate::__private::deku::writer::Writer<W>,
+                ctx: Ctx,
+            ) -> ::core::result::Result<(), $crate::__private::deku::DekuError>
+            where
+                W: $crate::__private::deku::no_std_io::Write
+                    + $crate::__private::deku::no_std_io::Seek,
+            {
+                $crate::__private::deku::DekuWriter::to_writer(&self.0, writer, ctx)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "deku"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_deku {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(deku)]` requires the `deku` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,149 @@
+//! Integers stored in a fixed byte order.
+//!
+//! These are meant to be used as the inner type of a C enum whose values are
+//! stored in wire order, e.g. within a network packet header that is parsed
+//! in place. `c_enum!` recognizes them by name so the variants are still
+//! declared using native integers and can be compared against native integers
+//! directly.
+//!
+//! # Example
+//! ```
+//! # use c_enum::c_enum;
+//! use c_enum::endian::U16Be;
+//!
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum EtherType: U16Be {
+//!         Ipv4 = 0x0800,
+//!         Arp = 0x0806,
+//!     }
+//! }
+//!
+//! assert_eq!(EtherType::Arp.value().to_bytes(), [0x08, 0x06]);
+//! assert_eq!(EtherType::Arp, 0x0806u16);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+macro_rules! endian_int {
+    ($( $name:ident($int:ty, $order:literal, $to:ident, $from:ident); )*) => {$(
+        #[doc = concat!("A `", stringify!($int), "` stored in ", $order, " byte order.")]
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default, PartialEq, Eq)]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(
+                zerocopy::FromBytes,
+                zerocopy::IntoBytes,
+                zerocopy::KnownLayout,
+                zerocopy::Immutable,
+                zerocopy::Unaligned
+            )
+        )]
+        pub struct $name([u8; core::mem::size_of::<$int>()]);
+
+        impl $name {
+            /// Creates a new value from a native integer.
+            pub const fn new(value: $int) -> Self {
+                Self(value.$to())
+            }
+
+            /// Returns the value as a native integer.
+            pub const fn get(self) -> $int {
+                <$int>::$from(self.0)
+            }
+
+            /// Creates a new value from its bytes, which are in the byte
+            /// order of this type.
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$int>()]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the bytes of this value, in the byte order of this
+            /// type.
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$int>()] {
+                self.0
+            }
+        }
+
+        impl From<$int> for $name {
+            fn from(value: $int) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $int {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl PartialEq<$int> for $name {
+            fn eq(&self, other: &$int) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl PartialEq<$name> for $int {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.get()
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        // Hash the native value so that this is consistent with the native
+        // integer type.
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+
+        endian_int!(impl(fmt) $name, Debug, Display, LowerHex, UpperHex, Octal, Binary);
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Zeroable for $name {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Pod for $name {}
+    )*};
+    (impl(fmt) $name:ident, $( $trait:ident ),*) => {$(
+        impl fmt::$trait for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$trait::fmt(&self.get(), f)
+            }
+        }
+    )*};
+}
+
+endian_int! {
+    U16Be(u16, "big-endian", to_be_bytes, from_be_bytes);
+    U16Le(u16, "little-endian", to_le_bytes, from_le_bytes);
+    U32Be(u32, "big-endian", to_be_bytes, from_be_bytes);
+    U32Le(u32, "little-endian", to_le_bytes, from_le_bytes);
+    U64Be(u64, "big-endian", to_be_bytes, from_be_bytes);
+    U64Le(u64, "little-endian", to_le_bytes, from_le_bytes);
+    U128Be(u128, "big-endian", to_be_bytes, from_be_bytes);
+    U128Le(u128, "little-endian", to_le_bytes, from_le_bytes);
+    I16Be(i16, "big-endian", to_be_bytes, from_be_bytes);
+    I16Le(i16, "little-endian", to_le_bytes, from_le_bytes);
+    I32Be(i32, "big-endian", to_be_bytes, from_be_bytes);
+    I32Le(i32, "little-endian", to_le_bytes, from_le_bytes);
+    I64Be(i64, "big-endian", to_be_bytes, from_be_bytes);
+    I64Le(i64, "little-endian", to_le_bytes, from_le_bytes);
+    I128Be(i128, "big-endian", to_be_bytes, from_be_bytes);
+    I128Le(i128, "little-endian", to_le_bytes, from_le_bytes);
+}
@@ -0,0 +1,56 @@
+use core::fmt;
+
+/// The error returned when a value does not correspond to any of the declared
+/// variants of a C enum.
+///
+/// The rejected value is available as the inner field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnknownValueError<T>(pub T);
+
+impl<T> UnknownValueError<T> {
+    /// Returns the value that was rejected.
+    pub fn into_value(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for UnknownValueError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} does not correspond to any declared variant",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: fmt::Debug> std::error::Error for UnknownValueError<T> {}
+
+/// Implements `std::error::Error` for a C enum using `#[c_enum(error)]`.
+///
+/// This is defined differently depending on whether the `std` feature is
+/// enabled, since the code generated by `c_enum!` can't check the features of
+/// this crate itself. Without `std` there is no `Error` trait to implement.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_std_error {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::error::Error for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + ::core::fmt::Display,
+            for<'a> $name: ::core::fmt::Debug,
+        {
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_std_error {
+    ($( $args:tt )*) => {};
+}
@@ -0,0 +1,203 @@
+//! Support for adding variants to a C enum with `c_enum_extend!`.
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::endian::*;
+
+/// The type that variants of enums with this inner type are declared with.
+pub trait Primitive {
+    type Primitive;
+}
+
+macro_rules! primitive {
+    ($( $ty:ty => $prim:ty, )*) => {$(
+        impl Primitive for $ty {
+            type Primitive = $prim;
+        }
+    )*};
+}
+
+primitive! {
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize,
+    U16Be => u16,
+    U16Le => u16,
+    U32Be => u32,
+    U32Le => u32,
+    U64Be => u64,
+    U64Le => u64,
+    U128Be => u128,
+    U128Le => u128,
+    I16Be => i16,
+    I16Le => i16,
+    I32Be => i32,
+    I32Le => i32,
+    I64Be => i64,
+    I64Le => i64,
+    I128Be => i128,
+    I128Le => i128,
+}
+
+/// Adds more variants to a C enum declared elsewhere in the same crate.
+///
+/// This declares a unit struct named after the extension along with a
+/// constant on the extended enum for each variant. Listing the extension in
+/// `#[c_enum(extensions = (...))]` on the extended enum makes its variants
+/// known to `variant_label`, `from_name`, `is_known` and the `Debug` impl.
+///
+/// The first variant of an extension needs an explicit value. Following
+/// variants are numbered the same way as the variants of the extended enum,
+/// as long as its inner type is an integer.
+///
+/// # Example
+/// ```
+/// # use c_enum::{c_enum, c_enum_extend, CEnum};
+/// c_enum! {
+///     #[derive(Copy, Clone, PartialEq, Eq)]
+///     #[c_enum(extensions = (vendor::VendorEvent))]
+///     pub enum Event: u32 {
+///         Start,
+///         Stop,
+///     }
+/// }
+///
+/// mod vendor {
+///     use super::Event;
+///
+///     c_enum::c_enum_extend! {
+///         pub enum VendorEvent extends Event {
+///             VendorStart = 0x8000,
+///             VendorStop,
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Event::VendorStop.value(), 0x8001);
+/// assert_eq!(Event::VendorStop.variant_label(), Some("VendorStop"));
+/// assert_eq!(Event::from_name("VendorStart"), Some(Event::VendorStart));
+/// assert_eq!(vendor::VendorEvent::ENTRIES.len(), 2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! c_enum_extend {
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $ext:ident extends $name:ident {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+    } => {
+        $( #[ $( $attr )* ] )*
+        $vis struct $ext;
+
+        // Everything else is declared within an anonymous const so that the
+        // trait below is only in scope for the generated code.
+        const _: () = {
+            #[allow(dead_code, non_upper_case_globals)]
+            trait __CEnumCfgFallback {
+                $(
+                    $crate::__c_enum_impl!(
+                        impl(cfgs) [ $( #[ $( $field_attr )* ] )* ] []
+                        (cfg_fallback_decl $name $field)
+                    );
+                )*
+            }
+
+            #[allow(deprecated, non_upper_case_globals)]
+            impl __CEnumCfgFallback for $name {
+                $crate::__c_enum_decl_fallbacks!(
+                    impl(
+                        ext, [], $name, $name,
+                        $crate::__c_enum_impl!(impl(ext_first)),
+                        ""
+                    )
+                    $(
+                        $( #[ $( $field_attr )* ] )*
+                        $field $( = $value )?,
+                    )*
+                );
+            }
+
+            #[allow(non_upper_case_globals)]
+            impl $name {
+                $crate::__c_enum_decl_variants!(
+                    impl(
+                        ext, [], $name, $name,
+                        $crate::__c_enum_impl!(impl(ext_first)),
+                        ""
+                    )
+                    $(
+                        $( #[ $( $field_attr )* ] )*
+                        $field $( = $value )?,
+                    )*
+                );
+            }
+
+            // Evaluate the value of every variant so that values which
+            // overflow the inner type are reported even if the variant is
+            // never used.
+            $(
+                #[allow(deprecated)]
+                const _: $name = $name::$field;
+            )*
+
+            impl $ext {
+                /// The names and values of all variants declared by this
+                /// extension, in declaration order.
+                #[allow(deprecated)]
+                pub const ENTRIES: &'static [(&'static str, $name)] = $crate::__c_enum_impl!(
+                    impl(filter_slice) listed [], (&'static str, $name),
+                    [
+                        $(
+                            [ $( #[ $( $field_attr )* ] )* ]
+                            (
+                                $crate::__c_enum_impl!(
+                                    impl(label) [] [ $( #[ $( $field_attr )* ] )* ] $field
+                                ),
+                                $name::$field
+                            ),
+                        )*
+                    ]
+                );
+
+                #[doc(hidden)]
+                #[allow(deprecated)]
+                pub fn __c_enum_from_name(
+                    name: &str,
+                    matches: impl Fn(&str, &str) -> bool,
+                ) -> ::core::option::Option<$name> {
+                    $(
+                        if !$crate::__c_enum_impl!(impl(hidden) [] [ $( #[ $( $field_attr )* ] )* ])
+                            && matches(
+                                name,
+                                $crate::__c_enum_impl!(
+                                    impl(label) [] [ $( #[ $( $field_attr )* ] )* ] $field
+                                )
+                            )
+                        {
+                            return ::core::option::Option::Some($name::$field);
+                        }
+                    )*
+
+                    ::core::option::Option::None
+                }
+            }
+        };
+    };
+}
@@ -57,6 +57,30 @@
 //! # }
 //! ```
 //!
+//! The constants generated for each variant are `pub` by default. A variant can
+//! be given its own visibility, which only applies to its constant. It is still
+//! a declared variant for `as_str()`, `from_name()` and the `Debug` output.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! mod example {
+//! #   use c_enum::c_enum;
+//!     c_enum! {
+//!         #[derive(Copy, Clone, PartialEq, Eq)]
+//!         pub enum Status: u8 {
+//!             Ok,
+//!             Failed,
+//!             pub(crate) Internal = 0x80,
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! assert_eq!(example::Status::Internal.value(), 0x80);
+//! assert_eq!(example::Status::new(0x80).as_str(), Some("Internal"));
+//! # }
+//! ```
+//!
 //! # Attributes
 //! Attributes can be added to the generated type or variants as normal. Note
 //! that the variants are converted to constants so macros expecting an enum
@@ -88,11 +112,609 @@
 //! }
 //! ```
 //!
+//! ## Adding Items to the Generated `impl` Block
+//! Any items within the extra `impl` block are added to the generated `impl`
+//! block. This allows keeping helper methods next to the variants they use.
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Level : u8 {
+//!         Debug,
+//!         Info,
+//!         Warn,
+//!         Error,
+//!     }
+//!
+//!     impl {
+//!         /// Returns whether this level should be reported to the user.
+//!         pub fn is_important(self) -> bool {
+//!             self.0 >= Self::Warn.0
+//!         }
+//!     }
+//! }
+//!
+//! assert!(Level::Error.is_important());
+//! assert!(!Level::Info.is_important());
+//! ```
+//!
+//! # Options
+//! Some of the code generated by `c_enum!` can be configured by placing a
+//! `#[c_enum(...)]` attribute on the enum. Multiple options can be separated by
+//! commas or split across multiple `#[c_enum]` attributes. Each option other
+//! than `bitfield` can only be specified once.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(ord)]
+//!     pub enum Enum: u32 {
+//!         A = 2,
+//!         B = 1,
+//!     }
+//! }
+//!
+//! assert!(Enum::B < Enum::A);
+//! ```
+//!
+//! The supported options are:
+//! - `ord` implements [`PartialOrd`] and [`Ord`] by comparing the inner values.
+//!   Note that this requires [`PartialEq`] and [`Eq`] to be implemented (e.g.
+//!   via `#[derive]`) for the corresponding impl to be usable, while
+//!   [`PartialOrd`] and [`Ord`] themselves can't also be derived.
+//! - `debug = "hex"` changes the [`Debug`] impl to print undeclared values in
+//!   hexadecimal (e.g. `Enum(0x1f4)`). This requires that the inner type
+//!   implements [`LowerHex`] instead of [`Debug`]. The default is
+//!   `debug = "decimal"`.
+//! - `skip_debug` doesn't generate a [`Debug`] impl so that you can write your
+//!   own.
+//! - `skip_from` doesn't generate the [`From`] impls converting to and from the
+//!   inner type so that you can write your own. They are still required by the
+//!   [`CEnum`] impl.
+//! - `consts` generates the `VARIANTS`, `VARIANT_COUNT`, `LABELS`, `VALUES`
+//!   and `ENTRIES` constants listing the declared variants. See
+//!   [Associated Items](#associated-items). For integer inner types it also
+//!   generates `VARIANTS_BY_VALUE`, `MIN_VARIANT` and `MAX_VARIANT`.
+//! - `rename_all = "..."` changes the labels of all variants to use a
+//!   different case convention. This affects `variant_label()`, `LABELS`,
+//!   `ENTRIES` and the names accepted by `from_name()` but not the names of
+//!   the generated constants or the [`Debug`] output. The supported values are
+//!   `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`,
+//!   `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` and
+//!   `"SCREAMING-KEBAB-CASE"`.
+//! - `strip_prefix = "..."` removes a common prefix (e.g. `"PERF_COUNT_SW_"`)
+//!   from the labels of all variants that start with it. This happens before
+//!   `rename_all` is applied. The names of the generated constants are not
+//!   changed.
+//! - `parse = "case_insensitive"` makes `from_name()` ignore ASCII case and any
+//!   leading or trailing whitespace when matching names. The default is
+//!   `parse = "exact"`.
+//! - `hide_deprecated` treats variants marked with `#[deprecated]` like
+//!   aliases and also makes `from_name()` reject their labels. See
+//!   [Deprecated Variants](#deprecated-variants).
+//! - `private` makes the field of the generated struct private, so code outside
+//!   of the module declaring the enum has to use `new()` and `value()` (or the
+//!   [`From`] impls) to convert to and from the inner type.
+//! - `bitfield = (get = <name>, set = <name>, ty = <type>, shift = <shift>,
+//!   mask = <mask>)` declares a bitfield within the inner value. See
+//!   [Bitfields](#bitfields).
+//! - `bitops` implements [`BitOr`], [`BitAnd`], [`BitXor`] and their assigning
+//!   versions by applying the operator to the inner values. This is useful for
+//!   C enums that are actually sets of flags. It also generates the
+//!   `contains`, `intersects`, `insert`, `remove` and `toggle` methods, which
+//!   work on the bits of the inner value.
+//!
+//!   For integer inner types, it also generates an `ALL` constant containing
+//!   the union of all declared variants as well as a `complement` method and a
+//!   [`Not`] impl which only flip the bits within `ALL`.
+//!
+//! - `bits` assigns each variant without an explicit value the next bit above
+//!   the value of the previous variant instead of adding 1 to it. See
+//!   [Flags](#flags).
+//! - `step = <value>` adds `<value>` instead of 1 to the value of the previous
+//!   variant for variants without an explicit value. The value must be a
+//!   single token, such as a literal or a parenthesized expression. This can't
+//!   be combined with `bits`.
+//! - `continue_from = <enum>` starts numbering after the last variant of
+//!   another enum instead of at 0. See
+//!   [Continuing Another Enum](#continuing-another-enum).
+//! - `deny_duplicates` fails compilation if two of the declared variants have
+//!   the same value. Aliases and skipped variants aren't checked, so they can
+//!   still share a value with another variant. This requires an integer inner
+//!   type.
+//! - `extensions = (<extension>, ...)` makes the variants added by the listed
+//!   [`c_enum_extend!`] invocations known to `variant_label`, `from_name`,
+//!   `is_known` and the [`Debug`] impl. See [Extensions](#extensions).
+//! - `known = KnownEnum` generates a companion Rust enum named `KnownEnum`
+//!   containing only the declared variants, along with a [`From`] impl to
+//!   convert it back into the `c_enum!` type and a [`TryFrom`] impl going the
+//!   other way. This allows for exhaustive matching over the declared variants.
+//!   The companion enum is `#[non_exhaustive]` so that variants can be added
+//!   without breaking downstream crates. This can't be used if any of the
+//!   variants have a `#[cfg]` attribute.
+//!
+//!   It also generates a `classify` method which returns either the companion
+//!   enum variant or the raw inner value if the value is not declared.
+//!
+//! - `mod = consts` also declares each variant as a constant within a module
+//!   named `consts` next to the enum, so that they can be glob imported with
+//!   `use path::to::consts::*`. The constants keep the visibility of their
+//!   variant and variants removed by `#[cfg]` are left out.
+//! - `error` is meant for enums of error or status codes. It generates a
+//!   `message()` method along with a [`Display`] impl which prints the message
+//!   of the current variant, or `unknown error <value>` for undeclared values.
+//!   When the `std` feature of this crate is enabled, it also implements
+//!   `std::error::Error`. See [Error Codes](#error-codes).
+//! - `display = "doc"` implements [`Display`] using the first line of the doc
+//!   comment of each declared variant, falling back to the label for variants
+//!   without one. Undeclared values are printed using the inner value. The
+//!   text is also available via the generated `description()` method. This
+//!   can't be combined with `error` since both implement [`Display`].
+//! - `serde` (or `serde = "value"`) implements `Serialize` and `Deserialize`
+//!   by (de)serializing the inner value directly, so undeclared values
+//!   round-trip unchanged. This requires the `serde` feature of this crate.
+//! - `serde = "name"` instead serializes declared variants as their label and
+//!   deserializes them using `from_name()`. What happens to undeclared values
+//!   is controlled by `serde_unknown`:
+//!   - `serde_unknown = "value"` (the default) serializes them using the inner
+//!     value and also accepts inner values when deserializing. This uses
+//!     `deserialize_any` so it only works with self-describing formats.
+//!   - `serde_unknown = "error"` fails to serialize them and only accepts
+//!     names when deserializing.
+//! - `serde = "lenient"` serializes the inner value like `serde = "value"` but
+//!   accepts either a name or an inner value when deserializing. Like
+//!   `serde_unknown = "value"`, this only works with self-describing formats.
+//!
+//!   The representation can also be picked for individual fields by using
+//!   `#[serde(with = "c_enum::as_name")]` or
+//!   `#[serde(with = "c_enum::as_value")]`. These work even if the enum itself
+//!   doesn't implement `Serialize` or `Deserialize`.
+//!
+//! - `schemars` implements `schemars::JsonSchema` using a schema that matches
+//!   the representation chosen by the `serde` options. When the inner value is
+//!   used, the declared variants are listed as constants in the schema. This
+//!   requires the `schemars` feature of this crate.
+//! - `rkyv` derives `rkyv::Archive`, `rkyv::Serialize` and `rkyv::Deserialize`
+//!   for the enum so that it can be stored within archived types. The archived
+//!   type is `ArchivedEnum`, which wraps the archived inner value. This
+//!   requires the `rkyv` feature of this crate.
+//! - `bincode` implements bincode 2's `Encode`, `Decode` and `BorrowDecode` by
+//!   encoding the inner value, so the encoded size is the same as that of the
+//!   inner type under the chosen bincode configuration. This requires the
+//!   `bincode` feature of this crate.
+//! - `minicbor` implements minicbor's `Encode`, `Decode` and `CborLen` by
+//!   encoding the inner value, so integer enums are encoded as CBOR integers.
+//!   This requires the `minicbor` feature of this crate and works without
+//!   `std`.
+//! - `defmt` implements `defmt::Format`. Declared variants are logged using
+//!   their label and undeclared values are logged using the inner value. This
+//!   requires the `defmt` feature of this crate.
+//! - `ufmt` implements `ufmt::uDisplay` and `ufmt::uDebug`. Declared variants
+//!   are written using their label and undeclared values using the inner
+//!   value (as `Enum(value)` for `uDebug`). This requires the `ufmt` feature of
+//!   this crate.
+//! - `arbitrary` implements `arbitrary::Arbitrary`. This picks one of the
+//!   declared variants most of the time but occasionally produces an arbitrary
+//!   inner value so that the handling of undeclared values is also exercised.
+//!   This requires the `arbitrary` feature of this crate.
+//! - `proptest` generates `any()` and `any_declared()` functions which return
+//!   proptest strategies. `any()` generates any inner value while
+//!   `any_declared()` only generates declared variants. This requires the
+//!   `proptest` feature of this crate.
+//! - `bytemuck` implements `bytemuck::Zeroable` and `bytemuck::Pod`. The enum
+//!   must be `#[repr(transparent)]`, its inner type must implement `Pod` and it
+//!   must implement [`Copy`]. This requires the `bytemuck` feature of this
+//!   crate.
+//! - `zerocopy` derives zerocopy's `FromBytes`, `IntoBytes`, `KnownLayout` and
+//!   `Immutable` for the enum. The enum must be `#[repr(transparent)]` and this
+//!   crate must be available as `c_enum` (i.e. not renamed in `Cargo.toml`).
+//!   This requires the `zerocopy` feature of this crate.
+//! - `clap` implements `clap::ValueEnum` using the declared variants and their
+//!   labels, so `rename_all = "kebab-case"` gives the usual CLI spelling. The
+//!   enum must implement [`Clone`]. This requires the `clap` feature of this
+//!   crate.
+//! - `strum` implements strum's `EnumCount`, `VariantNames`, `VariantArray` and
+//!   `IntoEnumIterator` traits using the declared variants. This requires the
+//!   `strum` feature of this crate.
+//! - `num_enum` implements num_enum's `FromPrimitive`, which accepts any value,
+//!   and `TryFromPrimitive`, which only accepts declared variants. This makes
+//!   it easier to migrate from enums using num_enum's derives. This requires
+//!   the `num_enum` feature of this crate.
+//! - `num_traits` implements num-traits' `FromPrimitive` and `ToPrimitive` by
+//!   converting the inner value. This requires the `num-traits` feature of
+//!   this crate.
+//! - `sea_orm` implements the traits needed to use the enum as a field of a
+//!   SeaORM entity. It is stored in a column of the same type as the inner
+//!   value, so an enum with an integer inner type is stored as an integer.
+//!   This requires the `sea-orm` feature of this crate.
+//! - `pyo3` implements PyO3's `IntoPyObject` and `FromPyObject`. Values are
+//!   converted to and from Python using the inner value, so an enum with an
+//!   integer inner type becomes a Python `int`. Extracting from a Python `str`
+//!   looks it up by label instead. This requires the `pyo3` feature of this
+//!   crate.
+//! - `wasm_bindgen` allows the enum to be used in the signatures of
+//!   `#[wasm_bindgen]` functions, where it is passed to and from JS as its
+//!   inner value. It also exports a JS function with the same name as the
+//!   enum, which returns a frozen object mapping the label of each declared
+//!   variant to its value. This requires the `wasm-bindgen` feature of this
+//!   crate.
+//! - `napi` implements napi-rs' `ToNapiValue` and `FromNapiValue` so that the
+//!   enum can be passed to and from Node.js as its inner value. This requires
+//!   the `napi` feature of this crate.
+//! - `rand` implements `Distribution<Self>` for rand's `StandardUniform`
+//!   distribution, so that `rng.random()` picks uniformly among the declared
+//!   variants. Sampling panics if the enum has no declared variants. This
+//!   requires the `rand` feature of this crate.
+//! - `prost = ProtoEnum` generates a [`From`] impl converting from
+//!   `ProtoEnum`, an enum generated by prost, and a [`TryFrom`] impl going the
+//!   other way which fails with prost's `UnknownEnumValue` if the value is not
+//!   declared by `ProtoEnum`. Both conversions go through `i32`, which is what
+//!   prost uses to store enum fields, so the inner type should usually be
+//!   `i32`. The existing [`From`] impls for the inner type can then be used to
+//!   read and write the fields of prost messages directly. `ProtoEnum` must be
+//!   in scope. This requires the `prost` feature of this crate.
+//! - `scale` implements parity-scale-codec's `Encode`, `Decode` and
+//!   `MaxEncodedLen` by encoding the inner value, so the enum is encoded as a
+//!   fixed-width integer. This requires the `scale` feature of this crate.
+//! - `valuable` implements `Valuable`, which is used by `tracing` to record
+//!   structured values. Declared variants are recorded as their label and any
+//!   other values as the inner value. This requires the `valuable` feature of
+//!   this crate.
+//! - `slog` implements `slog::Value`, emitting the label of declared variants
+//!   and the inner value otherwise. This requires the `slog` feature of this
+//!   crate.
+//! - `async_graphql` (or `async_graphql = "enum"`) implements async-graphql's
+//!   `InputType` and `OutputType` as a GraphQL enum whose values are the
+//!   labels of the declared variants. Returning an undeclared value from a
+//!   resolver is an error.
+//! - `async_graphql = "int"` instead exposes the enum as its inner type, which
+//!   is usually `Int`, so that undeclared values round-trip.
+//!
+//!   Both require the `async-graphql` feature of this crate. They don't
+//!   support async-graphql's `boxed-trait` feature.
+//!
+//! - `utoipa` implements utoipa's `ToSchema`. The schema is the same as the
+//!   one for the inner type, with a description listing the label and value of
+//!   each declared variant. Undeclared values are still valid according to the
+//!   schema. This requires the `utoipa` feature of this crate.
+//! - `zvariant` implements zvariant's `Type` using the signature of the inner
+//!   type, along with conversions to and from `Value` and `OwnedValue`. Since
+//!   zvariant uses serde for encoding, this is usually combined with the
+//!   `serde` option so that the enum can be sent over D-Bus. This requires the
+//!   `zvariant` feature of this crate.
+//! - `deku` implements deku's `DekuReader` and `DekuWriter` by reading and
+//!   writing the inner value. Any context is passed through to the inner type,
+//!   so attributes like `#[deku(endian = "big")]` work on fields using the
+//!   enum. This requires the `deku` feature of this crate.
+//! - `binrw` implements binrw's `BinRead` and `BinWrite` by reading and writing
+//!   the inner value. The endianness and arguments are passed through to the
+//!   inner type. This requires the `binrw` feature of this crate.
+//!
+//! [`BitOr`]: core::ops::BitOr
+//! [`BitAnd`]: core::ops::BitAnd
+//! [`BitXor`]: core::ops::BitXor
+//! [`Not`]: core::ops::Not
+//! [`Display`]: core::fmt::Display
+//!
+//! ## Bitfields
+//! Some C APIs pack multiple values into a single integer. For enums with
+//! integer inner types, each of these can be declared as a bitfield using
+//! `#[c_enum(bitfield = (...))]`. `get` names the getter method, `set`
+//! optionally names a setter and `ty` optionally changes the type that is
+//! returned and accepted by them (via [`From`]). The value of the bitfield
+//! is `(value >> shift) & mask`.
+//!
+//! When at least one bitfield is declared, a `from_bitfields` `const fn` is
+//! also generated which takes the value of each bitfield in declaration order.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum CacheOp: u64 {}
+//! }
+//!
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(
+//!         bitfield = (get = id, set = set_id, shift = 0, mask = 0xff),
+//!         bitfield = (get = op, set = set_op, ty = CacheOp, shift = 8, mask = 0xff),
+//!     )]
+//!     pub enum CacheConfig: u64 {}
+//! }
+//!
+//! let mut config = CacheConfig::from_bitfields(3, 1);
+//! assert_eq!(config.id(), 3);
+//! assert_eq!(config.op(), CacheOp(1));
+//!
+//! config.set_op(CacheOp(2));
+//! assert_eq!(config, CacheConfig(0x0203));
+//! ```
+//!
+//! ## Default Variant
+//! One variant can be marked with `#[default]`, just like with
+//! `#[derive(Default)]` on a regular enum. `c_enum!` will then implement
+//! [`Default`] to return that variant.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Enum: u32 {
+//!         A,
+//!         #[default]
+//!         B,
+//!     }
+//! }
+//!
+//! assert_eq!(Enum::default(), Enum::B);
+//! ```
+//!
+//! ## Aliases
+//! Variants marked with `#[alias]` are generated as constants as usual but are
+//! otherwise treated as another name for an existing variant. They are not
+//! included in `variants()` or the constants generated by `consts` and are
+//! never returned as the label of a value, so the primary name is always used
+//! regardless of declaration order. `from_name()` still accepts them.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Enum: u32 {
+//!         #[alias]
+//!         OLD_NAME = Self::NEW_NAME.0,
+//!         NEW_NAME = 4,
+//!     }
+//! }
+//!
+//! assert_eq!(Enum::OLD_NAME.as_str(), Some("NEW_NAME"));
+//! assert_eq!(Enum::variants().as_slice(), &[Enum::NEW_NAME]);
+//! ```
+//!
+//! ## Skipped Variants
+//! Sentinel variants such as `FOO_MAX` can be marked with `#[c_enum(skip)]`.
+//! They are still generated as constants but are otherwise ignored: they are
+//! not included in `variants()`, `LABELS`, etc., have no label, are not
+//! accepted by `from_name()` and are formatted as an unknown value.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Format: u32 {
+//!         RGB,
+//!         RGBA,
+//!         #[c_enum(skip)]
+//!         FORMAT_MAX,
+//!     }
+//! }
+//!
+//! assert_eq!(Format::FORMAT_MAX.0, 2);
+//! assert_eq!(Format::FORMAT_MAX.as_str(), None);
+//! assert_eq!(Format::variants().as_slice(), &[Format::RGB, Format::RGBA]);
+//! ```
+//!
+//! ## Deprecated Variants
+//! `#[deprecated]` attributes on variants are forwarded to the generated
+//! constants. In addition to the arguments accepted by rustc, `c_enum!` also
+//! accepts `replaced_by = NEW_NAME`. This is removed from the attribute and
+//! instead adds a note pointing to the replacement to the docs of the
+//! constant. Variants with a replacement are treated as
+//! [aliases](#aliases).
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Enum: u32 {
+//!         #[deprecated(note = "renamed", replaced_by = NEW_NAME)]
+//!         OLD_NAME = 4,
+//!         NEW_NAME = 4,
+//!     }
+//! }
+//!
+//! assert_eq!(Enum::variants().as_slice(), &[Enum::NEW_NAME]);
+//! ```
+//!
+//! With `#[c_enum(hide_deprecated)]` all deprecated variants are excluded
+//! from `variants()` and the other lists and `from_name()` no longer accepts
+//! their labels.
+//!
+//! ## Custom Labels
+//! The label of a variant can be overridden by marking it with
+//! `#[label = "..."]`. This takes priority over `rename_all` and is used by
+//! `variant_label()`, `LABELS`, `ENTRIES` and `from_name()`.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Event: u32 {
+//!         #[label = "L1 dcache miss"]
+//!         L1D_READ_MISS,
+//!         CPU_CYCLES,
+//!     }
+//! }
+//!
+//! assert_eq!(Event::L1D_READ_MISS.as_str(), Some("L1 dcache miss"));
+//! assert_eq!(Event::from_name("L1 dcache miss"), Some(Event::L1D_READ_MISS));
+//! ```
+//!
+//! Variants named after Rust keywords can be declared using raw identifiers
+//! like `r#type`. Their labels and [`Debug`] output don't include the `r#`.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Token: u8 {
+//!         r#type,
+//!         r#match,
+//!     }
+//! }
+//!
+//! assert_eq!(Token::r#type.as_str(), Some("type"));
+//! assert_eq!(Token::from_name("match"), Some(Token::r#match));
+//! ```
+//!
+//! ## Ranges
+//! For enums with integer inner types, a variant can be given a range of
+//! values using `start..=end` or `start..end` instead of a single value. The
+//! variant itself has the value `start`. Values within the range that don't
+//! correspond to any other variant will use the label of the range, and are
+//! formatted by `Debug` as the name of the range followed by the value.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Protocol: u8 {
+//!         TCP = 6,
+//!         UDP = 17,
+//!         EXPERIMENTAL = 253..=254,
+//!     }
+//! }
+//!
+//! assert_eq!(Protocol::EXPERIMENTAL.value(), 253);
+//! assert_eq!(Protocol::from(254).label(), Some("EXPERIMENTAL"));
+//! assert_eq!(format!("{:?}", Protocol::from(254)), "Protocol::EXPERIMENTAL(254)");
+//! assert!(Protocol::from(254).in_range(Protocol::EXPERIMENTAL));
+//! assert_eq!(Protocol::RANGES, &[("EXPERIMENTAL", 253..=254)]);
+//! ```
+//!
+//! Empty ranges are a compile error. The bounds of a range need to be a single
+//! literal, a path or be wrapped in parentheses. Other explicit values within
+//! the same enum can still be any expression.
+//!
+//! ## Categories
+//! Variants can be grouped by marking them with `#[category = "..."]`. The
+//! `category()` method returns the category of the current value, and
+//! `variants_in_category()` iterates over all variants within a category.
+//! These, along with `CATEGORIES`, a slice containing the category of each
+//! declared variant, are only generated if at least one variant has a category.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Event: u32 {
+//!         #[category = "hardware"]
+//!         CPU_CYCLES,
+//!         #[category = "software"]
+//!         CPU_CLOCK,
+//!         #[category = "hardware"]
+//!         INSTRUCTIONS,
+//!     }
+//! }
+//!
+//! assert_eq!(Event::CPU_CLOCK.category(), Some("software"));
+//! assert!(Event::variants_in_category("hardware").eq(&[Event::CPU_CYCLES, Event::INSTRUCTIONS]));
+//! ```
+//!
+//! ## Metadata
+//! Arbitrary string metadata can be attached to variants using
+//! `#[meta(key = "value", ...)]`. It can then be looked up using the `meta()`
+//! method or accessed directly via `METADATA`. Neither is generated unless at
+//! least one variant has metadata.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Counter: u32 {
+//!         #[meta(description = "Bytes read from disk", unit = "bytes")]
+//!         DISK_READ,
+//!         CPU_CYCLES,
+//!     }
+//! }
+//!
+//! assert_eq!(Counter::DISK_READ.meta("unit"), Some("bytes"));
+//! assert_eq!(Counter::CPU_CYCLES.meta("unit"), None);
+//! ```
+//!
+//! ## Error Codes
+//! With `#[c_enum(error)]`, variants can be given a human-readable message by
+//! marking them with `#[msg = "..."]`. Variants without a message use their
+//! label instead.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(error)]
+//!     pub enum Errno: i32 {
+//!         #[msg = "operation not permitted"]
+//!         EPERM = 1,
+//!         ENOENT = 2,
+//!     }
+//! }
+//!
+//! assert_eq!(Errno::EPERM.to_string(), "operation not permitted");
+//! assert_eq!(Errno::ENOENT.to_string(), "ENOENT");
+//! assert_eq!(Errno(77).to_string(), "unknown error 77");
+//! ```
+//!
+//! ## Predicates
+//! A method checking whether a value is a particular variant can be generated
+//! by annotating the variant with `#[predicate = method_name]`. For integer
+//! inner types these are `const fn`s and also match values within the
+//! variant's [range](#ranges), if it has one.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     pub enum Event: u32 {
+//!         #[predicate = is_cpu_cycles]
+//!         CPU_CYCLES,
+//!         INSTRUCTIONS,
+//!     }
+//! }
+//!
+//! assert!(Event::CPU_CYCLES.is_cpu_cycles());
+//! assert!(!Event::INSTRUCTIONS.is_cpu_cycles());
+//! ```
+//!
+//! With the `proc-macro` feature, the `#[c_enum]` attribute can generate one
+//! for every variant using its [`predicates`](attr/index.html#predicates)
+//! option.
+//!
 //! # Representation
 //! It is valid to add a `#[repr(C)]` or `#[repr(transparent)]` attribute to the
 //! generated type. The generated type is guaranteed to be a newtype whose only
 //! member is the inner type.
 //!
+//! # Unknown Values
+//! Like a C enum, the generated type can hold any value of its inner type and
+//! not just the values of the declared variants. `new()` and the [`From`]
+//! impls accept any value. The other generated items treat undeclared values
+//! as unknown: `variant_label()` returns `None`, `is_known()` returns `false`
+//! and the [`Debug`] impl prints the inner value (e.g. `Enum(7)`).
+//!
+//! ## Non-Exhaustive Enums
+//! A `#[non_exhaustive]` attribute on the enum is applied to the generated
+//! struct and also makes its field `pub(crate)`. Code outside the crate
+//! declaring the enum can't write `Enum(value)` or access `.0` and has to use
+//! `new()`, `value()` and the [`From`] impls instead. This leaves room to
+//! start validating values within those in the future without a breaking
+//! change.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[non_exhaustive]
+//!     pub enum Opcode: u8 {
+//!         Nop,
+//!         Halt,
+//!     }
+//! }
+//!
+//! assert_eq!(Opcode::new(1), Opcode::Halt);
+//! assert_eq!(Opcode::from(7).value(), 7);
+//! ```
+//!
 //! # Value Assignment
 //! By default, enum values are assigned like they would be for a C enum: the
 //! first variant is 0 and subsequent variants increase by 1 unless assigned a
@@ -113,6 +735,134 @@
 //! # fn main() {}
 //! ```
 //!
+//! The docs of each generated constant end with a line showing its value. For
+//! variants without an explicit value this is given relative to the previous
+//! variant (e.g. `` `A + 1` ``) since the actual value can't be computed
+//! until the enum is compiled.
+//!
+//! The value of every variant is computed at compile time, even if the
+//! variant is never used. Values that don't fit in the inner type, such as a
+//! `u8` variant following one with a value of 255, are a compile error which
+//! names the offending variant. Enums with a `Wrapping<T>` inner type wrap
+//! around instead.
+//!
+//! The amount added for each variant can be changed with `#[c_enum(step =
+//! ...)]`, which is useful for register maps and ranges of error codes.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(step = 0x100)]
+//!     pub enum Bank: u32 {
+//!         Control,         // value of 0
+//!         Status,          // value of 0x100
+//!         Data = 0x1000,   // value of 0x1000
+//!         Fifo,            // value of 0x1100
+//!     }
+//! }
+//!
+//! assert_eq!(Bank::Fifo.value(), 0x1100);
+//! ```
+//!
+//! ## Flags
+//! With `#[c_enum(bits)]`, the first variant is 1 and each following variant
+//! without an explicit value gets the smallest power of two greater than the
+//! value of the previous variant. This matches how flags are usually declared
+//! in C headers. Note that the next bit is only based on the previous variant,
+//! so variants combining several flags should be declared after them.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(bits, bitops)]
+//!     pub enum Access: u32 {
+//!         Read,                // value of 1
+//!         Write,               // value of 2
+//!         Exec,                // value of 4
+//!         Append = 0x10,       // value of 0x10
+//!         Sync,                // value of 0x20
+//!         All = 0x3f,          // value of 0x3f
+//!     }
+//! }
+//!
+//! assert_eq!(Access::Sync.value(), 0x20);
+//! assert!(Access::All.contains(Access::Exec));
+//! ```
+//!
+//! ## Conditional Variants
+//! Variants can be removed with `#[cfg(...)]` like the variants of a regular
+//! enum. Removed variants are left out of `variants()`, `LABELS`, etc. but the
+//! following variants are numbered as if they were present, so their values
+//! are the same on every target. Since the explicit value of a removed variant
+//! can't be relied upon, the variant following it needs an explicit value as
+//! well.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     pub enum Signal: u32 {
+//!         Interrupt,     // value of 0
+//!         #[cfg(unix)]
+//!         Hangup,        // value of 1 on unix
+//!         Terminate,     // value of 2 everywhere
+//!         #[cfg(unix)]
+//!         Quit = 10,     // value of 10 on unix
+//!         Kill = 11,     // needs an explicit value since `Quit` has one
+//!     }
+//! }
+//! ```
+//!
+//! Variants with `#[cfg]` attributes can't be combined with
+//! `#[c_enum(known = ...)]`, since its companion enum would still contain them.
+//!
+//! A variant can also be given a different value depending on the target by
+//! listing several values marked with `#[cfg(...)]` within braces. The first
+//! value whose predicate holds is used. A last value without `#[cfg]` is used
+//! when none of them hold; without one that is a compile error.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     pub enum OpenFlags: u32 {
+//!         O_RDONLY = 0,
+//!         O_DIRECT = {
+//!             #[cfg(target_arch = "x86_64")] 0x4000,
+//!             #[cfg(target_arch = "aarch64")] 0x10000,
+//!             0x8000,
+//!         },
+//!     }
+//! }
+//! ```
+//!
+//! ## Continuing Another Enum
+//! Several enums can be declared in the same `c_enum!` block. This is useful
+//! for C headers which split one range of values across multiple enums. With
+//! `#[c_enum(continue_from = Other)]`, the first variant without an explicit
+//! value comes after the last variant of `Other` instead of starting at 0.
+//! The inner type of both enums needs to be the same integer type.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Request: u16 {
+//!         Open,          // value of 0
+//!         Close,         // value of 1
+//!     }
+//!
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(continue_from = Request)]
+//!     pub enum Reply: u16 {
+//!         Opened,        // value of 2
+//!         Closed,        // value of 3
+//!     }
+//! }
+//!
+//! assert_eq!(Reply::Closed.value(), 3);
+//! ```
+//!
 //! ## Non-String Inner Types
 //! It is also possible to define enum types whose inner value is not an
 //! integer.
@@ -130,39 +880,357 @@
 //! # fn main() {}
 //! ```
 //!
-//! Note that at this time generics are not supported so any inner value type
-//! must be both concrete and `'static`. Furthermore, you will need to assign a
-//! value to each variant of such an enum.
+//! Any inner value type must be both concrete and `'static`, unless the enum
+//! is declared as a [generic enum](attr/index.html#generic-enums) using the
+//! `proc-macro` feature. Furthermore, you will need to assign a value to each
+//! variant of such an enum.
 //!
-//! # What's implemented by `c_enum!`
-//! The [`c_enum!`] macro implements some traits by default, but leaves the rest
-//! available for you to choose the semantics of the rest.
+//! The exception to this is `&'static str`. Variants of string enums without an
+//! explicit value use their label as their value, so `rename_all` and
+//! `strip_prefix` can be used to change how they are spelled.
 //!
-//! ## Formatting
-//! - [`Debug`], but only if the inner type implements [`PartialEq`] and
-//!   [`Debug`].
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(rename_all = "kebab-case")]
+//!     pub enum Header: &'static str {
+//!         ContentType,           // value of "content-type"
+//!         UserAgent,             // value of "user-agent"
+//!         Dnt = "DNT",           // value of "DNT"
+//!     }
+//! }
 //!
-//! ## Conversion
-//! - [`From`] to convert from the inner type and vice versa.
+//! assert_eq!(Header::ContentType.value(), "content-type");
+//! ```
+//!
+//! Enums whose inner type borrows data (e.g. a `&'a str` parsed out of a
+//! larger buffer) can be declared with a lifetime parameter using the
+//! `proc-macro` feature. See [generic enums](attr/index.html#generic-enums)
+//! for details.
+//!
+//! ## Non-Zero Inner Types
+//! Enums whose inner type is one of the non-zero integer types (e.g.
+//! `NonZeroU32` or `NonZero<u32>`) are declared using plain integers. The
+//! first variant defaults to 1 instead of 0 and declaring a variant with a
+//! value of 0 is a compile error.
+//!
+//! When combined with `#[repr(transparent)]`, `Option<Enum>` is guaranteed to
+//! have the same size as the inner type.
 //!
-//! # Generated Code
 //! ```
-//! # #[macro_use]
-//! # extern crate c_enum;
-//! #
+//! # use c_enum::c_enum;
+//! use core::num::NonZeroU32;
+//!
 //! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
 //!     #[repr(transparent)]
-//!     #[derive(Copy, Clone, PartialEq, Eq, Hash)]
-//!     enum Enum: u32 {
-//!         A,
-//!         B = 5,
+//!     pub enum Handle: NonZeroU32 {
+//!         Stdin,       // value of 1
+//!         Stdout,      // value of 2
+//!         Custom = 10, // value of 10
 //!     }
 //! }
-//! # fn main() {}
+//!
+//! assert_eq!(Handle::Stdout.value().get(), 2);
+//! assert_eq!(core::mem::size_of::<Option<Handle>>(), 4);
 //! ```
-//! is expanded into (roughly)
+//!
+//! ## Endian Inner Types
+//! Enums whose inner type is one of the integer types from [`endian`] (e.g.
+//! `U32Be`) are also declared using plain integers. They store their value in
+//! a fixed byte order and can be compared against the native integer type.
+//!
+//! ## Float Inner Types
+//! Enums whose inner type is `f32` or `f64` are numbered automatically like
+//! integer enums, with each unassigned variant being 1.0 more than the one
+//! before it.
+//!
+//! Looking up the declared variant for a value (e.g. in `variant_label()`,
+//! `is_known()` or the [`Debug`] impl) compares the bit patterns of the values
+//! instead of using [`PartialEq`]. This means that a variant declared as
+//! `f32::NAN` is found for that exact NaN value, and that `0.0` and `-0.0` are
+//! treated as different values. The [`PartialEq`] impls between the enum and
+//! its inner type still use regular floating point comparison.
+//!
 //! ```
-//! # macro_rules! ignore { {$( $tt:tt )*} => {} }
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq)]
+//!     pub enum SampleRate: f64 {
+//!         Cd = 44100.0,
+//!         Dvd = 48000.0,
+//!         Unspecified = f64::NAN,
+//!     }
+//! }
+//!
+//! assert_eq!(SampleRate(f64::NAN).as_str(), Some("Unspecified"));
+//! assert_ne!(SampleRate::Unspecified, SampleRate::Unspecified);
+//! ```
+//!
+//! ## Byte String Inner Types
+//! Enums can also wrap `&'static [u8]` or `&'static CStr`, which is useful for
+//! C APIs that identify things by string. Variants are declared using byte
+//! string (`b"..."`) or C string (`c"..."`) literals. Undeclared values are
+//! formatted by the [`Debug`] impl as an escaped literal rather than as a list
+//! of integers.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! use core::ffi::CStr;
+//!
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Magic: &'static [u8] {
+//!         Png = b"\x89PNG",
+//!         Gif = b"GIF8",
+//!     }
+//! }
+//!
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Governor: &'static CStr {
+//!         Performance = c"performance",
+//!         Powersave = c"powersave",
+//!     }
+//! }
+//!
+//! assert_eq!(format!("{:?}", Magic::Png), "Magic::Png");
+//! assert_eq!(format!("{:?}", Magic(b"\x7fELF")), r#"Magic(b"\x7fELF")"#);
+//! assert_eq!(Governor::Powersave.value().to_bytes(), b"powersave");
+//! ```
+//!
+//! ## Multiple Inner Types
+//! With the `proc-macro` feature enabled, an enum can have a type parameter as
+//! its inner type, e.g. `pub enum Register<T: Copy>: T { ... }`. This is
+//! useful for sharing the same set of variants between several inner types.
+//! See [Generic Enums](attr/index.html#generic-enums) for details.
+//!
+//! Without the feature, wrap `c_enum!` in a macro of your own and invoke it
+//! once for every inner type instead. Pass the inner type as an `ident` rather
+//! than a `ty` so that `c_enum!` can still tell that it is an integer.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! macro_rules! register {
+//!     ($name:ident: $inner:ident) => {
+//!         c_enum! {
+//!             #[derive(Copy, Clone, PartialEq, Eq)]
+//!             pub enum $name: $inner {
+//!                 CTRL,
+//!                 STATUS = 0x04,
+//!                 DATA,
+//!             }
+//!         }
+//!     };
+//! }
+//!
+//! register!(Register32: u32);
+//! register!(Register64: u64);
+//!
+//! assert_eq!(Register32::DATA.value(), 5u32);
+//! assert_eq!(Register64::DATA.value(), 5u64);
+//! ```
+//!
+//! ## Including Variants
+//! The variants of another enum with the same inner type can be declared
+//! again within an enum by starting its body with `include Other;`. Several
+//! enums can be included at once with `include A, B;`. Included variants keep
+//! their values and attributes and come before the other variants, so the
+//! numbering continues from the last included variant. [`From`] impls are
+//! generated in both directions between the two enums.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum Status: u16 {
+//!         Ok,
+//!         Busy = 5,
+//!     }
+//! }
+//!
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     pub enum UsbStatus: u16 {
+//!         include Status;
+//!         Stall, // value of 6
+//!     }
+//! }
+//!
+//! assert_eq!(UsbStatus::Busy.value(), 5);
+//! assert_eq!(UsbStatus::Stall.value(), 6);
+//! assert_eq!(UsbStatus::from(Status::Busy), UsbStatus::Busy);
+//! ```
+//!
+//! This works by having `c_enum!` declare a macro with the same name as each
+//! enum, so the included enum has to be declared before the enum including it,
+//! either in the same module or in one of its parent modules. The inner type of
+//! the including enum has to be a plain path such as `u32`.
+//!
+//! # Extensions
+//! Variants can also be added to an enum from a different module of the same
+//! crate using [`c_enum_extend!`]. This is useful for keeping vendor-specific
+//! values apart from the core definition of an enum while still sharing one
+//! type. The extension needs to be listed in `#[c_enum(extensions = (...))]`
+//! for its variants to be found by `variant_label`, `from_name`, etc.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(extensions = (acme::AcmeOpcode))]
+//!     pub enum Opcode: u8 {
+//!         Nop,
+//!         Load,
+//!         Store,
+//!     }
+//! }
+//!
+//! mod acme {
+//!     use super::Opcode;
+//!
+//!     c_enum::c_enum_extend! {
+//!         pub enum AcmeOpcode extends Opcode {
+//!             Prefetch = 0x80, // value of 0x80
+//!             Flush,           // value of 0x81
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! assert_eq!(Opcode::from_name("Flush"), Some(Opcode::Flush));
+//! assert_eq!(format!("{:?}", Opcode::Prefetch), "Opcode::Prefetch");
+//! assert!(!Opcode::variants().any(|variant| *variant == Opcode::Flush));
+//! # }
+//! ```
+//!
+//! The variants of an extension aren't part of `variants()`, `LABELS`, etc. and
+//! are listed in the `ENTRIES` constant of the extension instead. They always
+//! use the name of their constant as their label unless it is overridden with
+//! `#[label = "..."]`. The first variant of an extension needs an explicit
+//! value. The following ones are numbered like the variants of the extended
+//! enum, except for enums with a non-integer inner type where every variant
+//! needs an explicit value.
+//!
+//! # Attribute Form
+//! With the `proc-macro` feature enabled, enums can also be declared by
+//! applying the `#[c_enum]` attribute from `c_enum::attr` to a regular enum.
+//! This accepts the same attributes and options as `c_enum!` but reports
+//! errors with spans pointing at the problematic part of the enum.
+//!
+//! # What's implemented by `c_enum!`
+//! The [`c_enum!`] macro implements some traits by default, but leaves the rest
+//! available for you to choose the semantics of the rest.
+//!
+//! ## Formatting
+//! - [`Debug`], but only if the inner type implements [`PartialEq`] and
+//!   [`Debug`].
+//!
+//! - [`LowerHex`], [`UpperHex`], [`Octal`] and [`Binary`], but only if the inner
+//!   type implements them. These format the inner value directly.
+//!
+//! ## Comparison
+//! - [`PartialEq`] between the enum and its inner type (in both directions),
+//!   but only if the inner type implements [`PartialEq`].
+//!
+//! ## Conversion
+//! - [`From`] to convert from the inner type and vice versa.
+//! - `try_from_value()`, which only accepts values corresponding to a declared
+//!   variant and returns an [`UnknownValueError`] otherwise. A [`TryFrom`] impl
+//!   can't be provided since it would conflict with the blanket impl provided
+//!   by [`From`].
+//!
+//! ## Associated Items
+//! - `new()` and `value()`, `const fn`s that convert from and to the inner
+//!   type.
+//! - `variants()`, which returns an iterator over all declared variants in
+//!   declaration order.
+//! - `is_known()`, which returns whether the value corresponds to a declared
+//!   variant. This is a `const fn` for integer inner types and otherwise
+//!   requires that the inner type implements [`PartialEq`].
+//! - `variant_index()` and `from_index()`, which map declared variants to their
+//!   index within `variants()` and back. This is useful for storing per-variant
+//!   data in an array. These are `const fn`s for integer inner types.
+//! - `from_name()`, which looks up a declared variant by its name.
+//! - `as_str()`, which returns the name of the current variant, if it has one.
+//!
+//! The constants listing the declared variants are only generated with
+//! `#[c_enum(consts)]`, since they would conflict with variants of the same
+//! name:
+//! - `VARIANTS`, a slice containing all declared variants in declaration
+//!   order.
+//! - `VARIANT_COUNT`, the number of declared variants.
+//! - `LABELS`, a slice containing the names of all declared variants in
+//!   declaration order.
+//! - `VALUES`, a slice containing the inner values of all declared variants
+//!   in declaration order.
+//! - `ENTRIES`, a slice containing `(name, value)` pairs for all declared
+//!   variants in declaration order.
+//!
+//! ```
+//! # use c_enum::c_enum;
+//! c_enum! {
+//!     #[derive(Copy, Clone, PartialEq, Eq)]
+//!     #[c_enum(consts)]
+//!     pub enum Enum: u32 {
+//!         A,
+//!         B = 5,
+//!     }
+//! }
+//!
+//! assert_eq!(Enum::VARIANTS, [Enum::A, Enum::B]);
+//! assert_eq!(Enum::VARIANT_COUNT, 2);
+//! assert_eq!(Enum::LABELS, ["A", "B"]);
+//! assert_eq!(Enum::VALUES, [0, 5]);
+//! assert_eq!(Enum::ENTRIES, [("A", 0), ("B", 5)]);
+//! ```
+//!
+//! ## Integer Inner Types
+//! Some items are only generated when the inner type is an integer. Since
+//! `c_enum!` only sees the tokens making up the type this only works when the
+//! inner type is spelled as a path ending in one of the primitive integer
+//! types (e.g. `u32`) or one of the C integer aliases in [`core::ffi`] (e.g.
+//! `c_int` or `libc::c_int`). Type aliases defined elsewhere are not
+//! recognized.
+//!
+//! - `label()`, a `const fn` version of `as_str()`.
+//! - `checked_add()`, `wrapping_add()` and `offset()`, which perform
+//!   arithmetic on the inner value.
+//! - `to_le_bytes()`, `to_be_bytes()` and `to_ne_bytes()` along with the
+//!   corresponding `from_*_bytes()` functions, which convert to and from the
+//!   byte representation of the inner value.
+//! - `iter_sorted()`, which returns an iterator over all declared variants
+//!   ordered by their value. With `#[c_enum(consts)]` these are also available
+//!   as the `VARIANTS_BY_VALUE` slice.
+//! - `RANGES` and `in_range()`, see [Ranges](#ranges).
+//! - `MIN_VARIANT` and `MAX_VARIANT`, the declared variants with the smallest
+//!   and largest values. These are only generated with `#[c_enum(consts)]` and
+//!   if the enum has at least one variant.
+//!
+//! Inner types spelled as a path ending in `Wrapping<T>` (e.g.
+//! `core::num::Wrapping<u32>`) don't get these items. However, variants without
+//! an explicit value are still numbered automatically, wrapping around on
+//! overflow.
+//!
+//! # Generated Code
+//! ```
+//! # #[macro_use]
+//! # extern crate c_enum;
+//! #
+//! c_enum! {
+//!     #[repr(transparent)]
+//!     #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+//!     enum Enum: u32 {
+//!         A,
+//!         B = 5,
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//! is expanded into (roughly)
+//! ```
+//! # macro_rules! ignore { {$( $tt:tt )*} => {} }
 //! # #[macro_use]
 //! # extern crate c_enum;
 //! #
@@ -212,6 +1280,10 @@
 //! This crate is a generator for the third option.
 //!
 //! [`Debug`]: core::fmt::Debug
+//! [`LowerHex`]: core::fmt::LowerHex
+//! [`UpperHex`]: core::fmt::UpperHex
+//! [`Octal`]: core::fmt::Octal
+//! [`Binary`]: core::fmt::Binary
 //! [`PartialEq`]: core::cmp::PartialEq
 
 #![no_std]
@@ -219,11 +1291,228 @@
 
 extern crate self as c_enum;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(any(
+    feature = "schemars",
+    feature = "sea-orm",
+    feature = "pyo3",
+    feature = "async-graphql",
+    feature = "utoipa"
+))]
+extern crate alloc;
+
 #[cfg(doc)]
 #[doc = include_str!("../README.md")]
 mod readme {}
 
+mod arbitrary_impl;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod as_name;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod as_value;
+mod async_graphql_impl;
+#[cfg(feature = "proc-macro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proc-macro")))]
+pub mod attr;
+mod bincode_impl;
+mod binrw_impl;
+mod byte_str;
+mod bytemuck_impl;
+mod case;
+mod clap_impl;
 mod decl_variants;
+mod defmt_impl;
+mod deku_impl;
+pub mod endian;
+mod error;
+mod extend;
+mod minicbor_impl;
+mod napi_impl;
+mod num_enum_impl;
+mod num_traits_impl;
+mod proptest_impl;
+mod prost_impl;
+mod pyo3_impl;
+mod rand_impl;
+mod rkyv_impl;
+mod scale_impl;
+mod schemars_impl;
+mod sea_orm_impl;
+mod serde_impl;
+mod slog_impl;
+mod strum_impl;
+mod ufmt_impl;
+mod utoipa_impl;
+mod valuable_impl;
+mod wasm_bindgen_impl;
+mod zerocopy_impl;
+mod zvariant_impl;
+
+pub use crate::error::UnknownValueError;
+
+/// Implements `CEnum`, [`Debug`] and the [`From`] conversions for an existing
+/// newtype struct, such as one generated by bindgen.
+///
+/// The variants are listed with `#[c_enum(variants(...))]` and need to be
+/// associated constants of the struct. Their names are used as their labels.
+/// `skip_debug` and `skip_from` can also be passed to `#[c_enum(...)]` to
+/// skip generating the [`Debug`] impl or the [`From`] impls. If this crate has
+/// been renamed in `Cargo.toml` or is only available through a re-export,
+/// pass its path using `crate = <path>`.
+///
+/// ```
+/// use c_enum::CEnum;
+///
+/// #[derive(Copy, Clone, PartialEq, Eq, CEnum)]
+/// #[c_enum(variants(READ, WRITE))]
+/// #[repr(transparent)]
+/// pub struct Mode(pub u32);
+///
+/// impl Mode {
+///     pub const READ: Mode = Mode(1);
+///     pub const WRITE: Mode = Mode(2);
+/// }
+///
+/// assert_eq!(Mode::WRITE.variant_label(), Some("WRITE"));
+/// assert_eq!(Mode::from_name("READ"), Some(Mode::READ));
+/// assert_eq!(format!("{:?}", Mode(7)), "Mode(7)");
+/// assert_eq!(u32::from(Mode::WRITE), 2);
+/// ```
+#[cfg(feature = "proc-macro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proc-macro")))]
+pub use c_enum_macros::CEnum;
+
+#[cfg(feature = "proc-macro")]
+#[doc(hidden)]
+pub use c_enum_macros::c_enum_generic as __c_enum_generic;
+
+#[cfg(not(feature = "proc-macro"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_generic {
+    ( $( $tt:tt )* ) => {
+        ::core::compile_error!(
+            "generic enums can only be declared with the `proc-macro` feature of c-enum enabled"
+        );
+    };
+}
+
+// Items used by the code generated by `c_enum!`. These are not part of the
+// public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::byte_str::ByteStr;
+    pub use crate::case::{ascii_str, rename, truncate, unraw, Case};
+    pub use crate::extend::Primitive;
+
+    #[cfg(feature = "std")]
+    pub use std::error;
+
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(any(feature = "schemars", feature = "async-graphql", feature = "utoipa"))]
+    pub use alloc::borrow::Cow;
+    #[cfg(feature = "schemars")]
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "schemars")]
+    pub use schemars_crate as schemars;
+
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+
+    #[cfg(feature = "bincode")]
+    pub use bincode;
+
+    #[cfg(feature = "minicbor")]
+    pub use minicbor;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
+    #[cfg(feature = "ufmt")]
+    pub use ufmt;
+
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+
+    #[cfg(feature = "zerocopy")]
+    pub use zerocopy;
+
+    #[cfg(feature = "clap")]
+    pub use clap;
+
+    #[cfg(feature = "strum")]
+    pub use strum;
+
+    #[cfg(feature = "num_enum")]
+    pub use num_enum;
+
+    #[cfg(feature = "num-traits")]
+    pub use num_traits;
+
+    #[cfg(any(feature = "sea-orm", feature = "async-graphql"))]
+    pub use alloc::string::String;
+    #[cfg(feature = "sea-orm")]
+    pub use sea_orm;
+
+    #[cfg(feature = "pyo3")]
+    pub use alloc::format;
+    #[cfg(feature = "pyo3")]
+    pub use pyo3;
+
+    #[cfg(feature = "wasm-bindgen")]
+    pub use crate::wasm_bindgen_impl::wasm_bindgen_object;
+    #[cfg(feature = "wasm-bindgen")]
+    pub use wasm_bindgen;
+
+    #[cfg(feature = "napi")]
+    pub use napi;
+
+    #[cfg(feature = "rand")]
+    pub use rand;
+
+    #[cfg(feature = "prost")]
+    pub use prost;
+
+    #[cfg(feature = "scale")]
+    pub use codec;
+
+    #[cfg(feature = "valuable")]
+    pub use valuable;
+
+    #[cfg(feature = "slog")]
+    pub use slog;
+
+    #[cfg(feature = "async-graphql")]
+    pub use crate::async_graphql_impl::{async_graphql_enum_type, async_graphql_parse_enum};
+    #[cfg(feature = "async-graphql")]
+    pub use async_graphql;
+
+    #[cfg(feature = "utoipa")]
+    pub use crate::utoipa_impl::utoipa_schema;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+
+    #[cfg(feature = "zvariant")]
+    pub use zvariant;
+
+    #[cfg(feature = "deku")]
+    pub use deku;
+
+    #[cfg(feature = "binrw")]
+    pub use binrw;
+}
 
 #[doc(hidden)]
 /// A trait that is automatically implemented for all C enums.
@@ -235,6 +1524,9 @@ pub trait CEnum: From<Self::Inner> + Into<Self::Inner> {
     fn variant_label(&self) -> Option<&'static str>
     where
         Self::Inner: PartialEq;
+
+    /// Get the declared variant with the given name, if there is one.
+    fn from_name(name: &str) -> Option<Self>;
 }
 
 /// The macro used to generate the C enum structure.
@@ -242,178 +1534,717 @@ pub trait CEnum: From<Self::Inner> + Into<Self::Inner> {
 /// See the [crate level docs](crate) for complete documentation.
 #[macro_export]
 macro_rules! c_enum {
+    // Variants of other enums can be included using `include A, B;` at the
+    // start of the body. This is handled by the macros that `c_enum!` defines
+    // alongside each enum.
     {
-        $( #[$attr:meta] )*
-        $vis:vis enum $name:ident : $inner:ty {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ {
+            include $( $other:ident ),+ ;
+            $( $body:tt )*
+        }
+
+        $( $rest:tt )*
+    } => {
+        $crate::__c_enum_impl! {
+            impl(include) [ $( $other )+ ] [] {}
+            [ $( #[ $( $attr )* ] )* ] [ $vis ] $name [ $( $inner )::+ ]
+            { $( $body )* }
+            $( $rest )*
+        }
+    };
+    // Values that are a single token tree may be a list of values selected by
+    // #[cfg(...)] or the start of a range, neither of which is a valid
+    // expression. They are rewritten before being matched by the rules below.
+    // The visibility is passed back as a `vis` fragment, which these rules
+    // don't match again.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $( pub $( ( $( $vis:tt )* ) )? )? enum $name:ident : $( $inner:ident )::+ $( < $arg:ty > )? {
             $(
-                $( #[ $field_attr:meta ] )*
-                $field:ident $( = $value:expr )?
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $(
+                    = $value:tt $( :: $path:ident )*
+                    $( ..= $iend:tt $( :: $ipath:ident )* )?
+                    $( .. $hend:tt $( :: $hpath:ident )* )?
+                )?
             ),* $(,)?
         }
 
         $(
             $( #[$iattr:meta] )*
-            impl {}
+            impl { $( $item:tt )* }
         )?
     } => {
-        $crate::__c_enum_no_debug! {
-            $( #[$attr] )*
-            $vis enum $name : $inner {
+        $crate::__c_enum_impl! {
+            impl(reparse) [ $( #[ $( $attr )* ] )* ] $( pub $( ( $( $vis )* ) )? )? enum $name
+            [ $( $inner )::+ $( < $arg > )? ]
+            {
                 $(
-                    $( #[ $field_attr ] )*
-                    $field $( = $value )?
-                ),*
+                    $( #[ $( $field_attr )* ] )*
+                    $(
+                        #[c_enum(
+                            value_doc = $value $( :: $path )*
+                            $( ..= $iend $( :: $ipath )* )?
+                            $( .. $hend $( :: $hpath )* )?
+                        )]
+                        $( #[c_enum(range ..= $iend $( :: $ipath )*)] )?
+                        $( #[c_enum(range .. $hend $( :: $hpath )*)] )?
+                    )?
+                    $field $(
+                        = $crate::__c_enum_impl!(impl(cfg_value) $value $( :: $path )*)
+                    )?,
+                )*
             }
-
+            [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $( pub $( ( $( $vis:tt )* ) )? )? enum $name:ident : & $lt:lifetime $str:tt {
             $(
-                $( #[$iattr] )*
-                impl {}
-            )?
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $(
+                    = $value:tt $( :: $path:ident )*
+                    $( ..= $iend:tt $( :: $ipath:ident )* )?
+                    $( .. $hend:tt $( :: $hpath:ident )* )?
+                )?
+            ),* $(,)?
         }
 
-        impl ::core::fmt::Debug for $name
-        where
-            $inner: ::core::fmt::Debug,
-            $inner: ::core::cmp::PartialEq
-        {
-            fn fmt(
-                &self,
-                f: &mut ::core::fmt::Formatter<'_>
-            ) -> ::core::fmt::Result {
-                use $crate::CEnum;
-
-                match self.variant_label() {
-                    Some(variant) => {
-                        f.write_fmt(::core::format_args!(
-                            "{}::{}", ::core::stringify!($name), variant
-                        ))
-                    },
-                    None => f
-                        .debug_tuple(::core::stringify!($name))
-                        .field(&self.0)
-                        .finish()
-                }
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(reparse) [ $( #[ $( $attr )* ] )* ] $( pub $( ( $( $vis )* ) )? )? enum $name
+            [ & $lt $str ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $(
+                        #[c_enum(
+                            value_doc = $value $( :: $path )*
+                            $( ..= $iend $( :: $ipath )* )?
+                            $( .. $hend $( :: $hpath )* )?
+                        )]
+                        $( #[c_enum(range ..= $iend $( :: $ipath )*)] )?
+                        $( #[c_enum(range .. $hend $( :: $hpath )*)] )?
+                    )?
+                    $field $(
+                        = $crate::__c_enum_impl!(impl(cfg_value) $value $( :: $path )*)
+                    )?,
+                )*
             }
+            [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
         }
     };
-    // Catch cases where there are multiple enums declared in the same block.
-    //
-    // This was valid up until version 0.2.0 so providing a good error message
-    // is useful.
     {
-        $( #[$attr:meta] )*
-        $vis:vis enum $name:ident : $inner:ty {
+        $( #[ $( $attr:tt )* ] )*
+        $( pub $( ( $( $vis:tt )* ) )? )? enum $name:ident : $inner:ty {
             $(
-                $( #[ $field_attr:meta ] )*
-                $field:ident $( = $value:expr )?
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $(
+                    = $value:tt $( :: $path:ident )*
+                    $( ..= $iend:tt $( :: $ipath:ident )* )?
+                    $( .. $hend:tt $( :: $hpath:ident )* )?
+                )?
             ),* $(,)?
         }
 
-        $( $error:tt )+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
     } => {
-        $crate::c_enum! {
-            $( #[$attr] )*
-            $vis enum $name : $inner {
+        $crate::__c_enum_impl! {
+            impl(reparse) [ $( #[ $( $attr )* ] )* ] $( pub $( ( $( $vis )* ) )? )? enum $name
+            [ $inner ]
+            {
                 $(
-                    $( #[$field_attr] )*
-                    $field $( = $value )?,
+                    $( #[ $( $field_attr )* ] )*
+                    $(
+                        #[c_enum(
+                            value_doc = $value $( :: $path )*
+                            $( ..= $iend $( :: $ipath )* )?
+                            $( .. $hend $( :: $hpath )* )?
+                        )]
+                        $( #[c_enum(range ..= $iend $( :: $ipath )*)] )?
+                        $( #[c_enum(range .. $hend $( :: $hpath )*)] )?
+                    )?
+                    $field $(
+                        = $crate::__c_enum_impl!(impl(cfg_value) $value $( :: $path )*)
+                    )?,
                 )*
             }
+            [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
         }
+    };
+    // Enums where only some of the values are a single token tree have the
+    // other values parsed as expressions by `__c_enum_values!`, after which
+    // they are matched by the rules above.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $( pub $( ( $( $vis:tt )* ) )? )? enum $name:ident : $( $inner:ident )::+ $( < $arg:ty > )? { $( $body:tt )* }
 
-        $crate::__c_enum_expects_impl_or_nothing! { $( $error )+ }
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_values! {
+            impl(
+                [ [ $( #[ $( $attr )* ] )* ] ( $( pub $( ( $( $vis )* ) )? )? ) $name [ $( $inner )::+ $( < $arg > )? ] ]
+                [] { $( $body )* } [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+            )
+            $( $body )* ,
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $( pub $( ( $( $vis:tt )* ) )? )? enum $name:ident : & $lt:lifetime $str:tt { $( $body:tt )* }
 
-        compile_error!(
-            "Declaring multiple enums using a single c_enum! macro block is no longer supported",
-        );
-    }
-}
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_values! {
+            impl(
+                [ [ $( #[ $( $attr )* ] )* ] ( $( pub $( ( $( $vis )* ) )? )? ) $name [ & $lt $str ] ]
+                [] { $( $body )* } [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+            )
+            $( $body )* ,
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $( pub $( ( $( $vis:tt )* ) )? )? enum $name:ident : $inner:ty { $( $body:tt )* }
 
-// TODO: not sure if this is worth adding to the public API.
-/// The macro used to generate the C enum structure.
-///
-/// This version does not generate a [`Debug`] impl.
-///
-/// See the [crate level docs](crate) for complete documentation.
-///
-/// [`Debug`]: core::fmt::Debug
-#[macro_export]
-#[doc(hidden)]
-macro_rules! __c_enum_no_debug {
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_values! {
+            impl(
+                [ [ $( #[ $( $attr )* ] )* ] ( $( pub $( ( $( $vis )* ) )? )? ) $name [ $inner ] ]
+                [] { $( $body )* } [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+            )
+            $( $body )* ,
+        }
+    };
+    // Inner types that are plain paths (e.g. `u32` or `libc::c_int`) are
+    // matched separately so that we can tell whether they are integers.
     {
-        $( #[$attr:meta] )*
-        $vis:vis enum $name:ident : $inner:ty {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ {
             $(
-                $( #[ $field_attr:meta ] )*
+                $( #[ $( $field_attr:tt )* ] )*
                 $field:ident $( = $value:expr )?
             ),* $(,)?
         }
 
         $(
             $( #[$iattr:meta] )*
-            impl {}
+            impl { $( $item:tt )* }
         )?
     } => {
-        $( #[$attr] )*
-        $vis struct $name(pub $inner);
-
-        #[allow(non_upper_case_globals)]
-        $( $( #[$iattr] )* )?
-        impl $name {
-            $crate::__c_enum_decl_variants!(
-                impl($name, $inner, 0)
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (classify) [ $( $inner )* ] debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ $( $inner )::+ ]
+            {
                 $(
-                    $( #[$field_attr] )*
+                    $( #[ $( $field_attr )* ] )*
                     $field $( = $value )?,
                 )*
-            );
-        }
-
-        #[automatically_derived]
-        impl From<$inner> for $name {
-            fn from(value: $inner) -> Self {
-                Self(value)
             }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
         }
-
-        #[automatically_derived]
-        impl From<$name> for $inner {
-            fn from(value: $name) -> Self {
-                value.0
+    };
+    // Generic paths are matched separately so that `Wrapping<T>` can be
+    // recognized.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ < $arg:ty > {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (classify_generic) [ $( $inner )* ] debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ $( $inner )::+ < $arg > ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
             }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    // Variants of string enums default to their label.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : & $lt:lifetime str {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
         }
 
-        #[automatically_derived]
-        impl $crate::CEnum for $name {
-            type Inner = $inner;
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (decl) str debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ & $lt str ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    // Byte strings are matched separately so that undeclared values can be
+    // formatted as a byte string literal instead of a list of integers.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : & $lt:lifetime [ u8 ] {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
 
-            fn variant_label(&self) -> Option<&'static str>
-            where
-                Self::Inner: PartialEq
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (decl) other bytes
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ & $lt [ u8 ] ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (decl) other debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ $inner ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    // The rules in this macro all need a concrete inner type, so generic enums
+    // are declared by a procedural macro instead.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident < $( $rest:tt )*
+    } => {
+        $crate::__c_enum_generic! {
+            $crate;
+            $( #[ $( $attr )* ] )*
+            $vis enum $name < $( $rest )*
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident { $( $body:tt )* }
+
+        $( $rest:tt )*
+    } => {
+        ::core::compile_error!(::core::concat!(
+            "c_enum! requires an inner type for the enum, e.g. `enum ",
+            ::core::stringify!($name),
+            ": u32 { ... }`"
+        ));
+    };
+    // Variants with a visibility (e.g. `pub(crate) FOO`) are rewritten to use
+    // #[c_enum(vis = ...)] before being passed back to c_enum!.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ $( < $arg:ty > )? {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $first:ident $( ( $( $args:tt )* ) )? $( $second:ident )?
+                $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(field_vis) [ $( #[ $( $attr )* ] )* $vis enum $name : $( $inner )::+ $( < $arg > )? ] []
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $first $( ( $( $args )* ) )? $( $second )? $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : & $lt:lifetime $str:tt {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $first:ident $( ( $( $args:tt )* ) )? $( $second:ident )?
+                $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(field_vis) [ $( #[ $( $attr )* ] )* $vis enum $name : & $lt $str ] []
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $first $( ( $( $args )* ) )? $( $second )? $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $first:ident $( ( $( $args:tt )* ) )? $( $second:ident )?
+                $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(field_vis) [ $( #[ $( $attr )* ] )* $vis enum $name : $inner ] []
             {
-                Some(match &self.0 {
-                    $( value if Self::$field.0 == *value => ::core::stringify!($field), )*
-                    _ => return None,
-                })
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $first $( ( $( $args )* ) )? $( $second )? $( = $value )?,
+                )*
             }
+            [ $( $( #[$iattr] )* impl { $( $item )* } )? ]
+        }
+    };
+    // Anything else is an error within the body of the enum. Common mistakes
+    // get their own error message, otherwise the error points at the first
+    // token that isn't part of a variant.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            );+ $(;)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        ::core::compile_error!("the variants of a c_enum! must be separated by `,` instead of `;`");
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident
+                $( ( $( $tuple:tt )* ) )?
+                $( { $( $struct:tt )* } )?
+                $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        ::core::compile_error!(
+            "the variants of a c_enum! can't have fields, use `Variant = value` to give them a \
+             value instead"
+        );
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty { $( $body:tt )* }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_expects_variants! { $( $body )* }
+    };
+    // Multiple enums can be declared in the same block. Each enum (along with
+    // its impl block, if it has one) is split off and passed back to c_enum!
+    // on its own.
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ { $( $body:tt )* }
+
+        $( $rest:tt )+
+    } => {
+        $crate::__c_enum_impl! {
+            impl(split) [
+                $( #[ $( $attr )* ] )*
+                $vis enum $name : $( $inner )::+ { $( $body )* }
+            ]
+            $( $rest )+
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ < $arg:ty > { $( $body:tt )* }
+
+        $( $rest:tt )+
+    } => {
+        $crate::__c_enum_impl! {
+            impl(split) [
+                $( #[ $( $attr )* ] )*
+                $vis enum $name : $( $inner )::+ < $arg > { $( $body )* }
+            ]
+            $( $rest )+
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : & $lt:lifetime str { $( $body:tt )* }
+
+        $( $rest:tt )+
+    } => {
+        $crate::__c_enum_impl! {
+            impl(split) [
+                $( #[ $( $attr )* ] )*
+                $vis enum $name : & $lt str { $( $body )* }
+            ]
+            $( $rest )+
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : & $lt:lifetime [ u8 ] { $( $body:tt )* }
+
+        $( $rest:tt )+
+    } => {
+        $crate::__c_enum_impl! {
+            impl(split) [
+                $( #[ $( $attr )* ] )*
+                $vis enum $name : & $lt [ u8 ] { $( $body )* }
+            ]
+            $( $rest )+
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty { $( $body:tt )* }
+
+        $( $rest:tt )+
+    } => {
+        $crate::__c_enum_impl! {
+            impl(split) [
+                $( #[ $( $attr )* ] )*
+                $vis enum $name : $inner { $( $body )* }
+            ]
+            $( $rest )+
         }
     };
 }
 
 /// Helper macro to emit a "no rules expected the token `...`" error message.
 ///
-/// The input spec here matches the one in the `c_enum!` macro after the end of
-/// the enum declaration. That way we give the appropriate error message in case
-/// it's due to a typo and not multiple declarations.
+/// The input spec here matches the variants accepted by the `c_enum!` macro so
+/// that the error points at the first token which isn't part of a variant.
 #[doc(hidden)]
 #[macro_export]
-macro_rules! __c_enum_expects_impl_or_nothing {
+macro_rules! __c_enum_expects_variants {
+    {
+        $(
+            $( #[ $( $attr:tt )* ] )*
+            $field:ident $( = $value:expr )?
+        ),* $(,)?
+    } => {
+        ::core::compile_error!("invalid c_enum! declaration");
+    };
+}
+
+// This predates #[c_enum(skip_debug)], which should be used instead.
+/// The macro used to generate the C enum structure.
+///
+/// This version does not generate a [`Debug`] impl.
+///
+/// See the [crate level docs](crate) for complete documentation.
+///
+/// [`Debug`]: core::fmt::Debug
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __c_enum_no_debug {
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (classify) [ $( $inner )* ] no_debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ $( $inner )::+ ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $( $inner:ident )::+ < $arg:ty > {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (classify_generic) [ $( $inner )* ] no_debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ $( $inner )::+ < $arg > ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    // Variants of string enums default to their label.
     {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : & $lt:lifetime str {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+
+        $(
+            $( #[$iattr:meta] )*
+            impl { $( $item:tt )* }
+        )?
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (decl) str no_debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ & $lt str ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
+    {
+        $( #[ $( $attr:tt )* ] )*
+        $vis:vis enum $name:ident : $inner:ty {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?
+            ),* $(,)?
+        }
+
         $(
             $( #[$iattr:meta] )*
-            impl {}
+            impl { $( $item:tt )* }
         )?
-    } => {};
+    } => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) (decl) other no_debug
+            [ $( #[ $( $attr )* ] )* ] [] [] $vis $name [ $inner ]
+            {
+                $(
+                    $( #[ $( $field_attr )* ] )*
+                    $field $( = $value )?,
+                )*
+            }
+            [ $( $( #[$iattr] )* )? ]
+            { $( $( $item )* )? }
+        }
+    };
 }
 
 /// Helper macro for defining stuff in c_enum.
@@ -427,6 +2258,3465 @@ macro_rules! __c_enum_impl {
     (impl(first_expr) $first:expr $(, $rest:expr)*) => {
         $first
     };
+
+    (
+        impl(reparse) [ $( $attr:tt )* ] $vis:vis enum $name:ident [ $( $head:tt )* ]
+        { $( $body:tt )* } [ $( $impl:tt )* ]
+    ) => {
+        $crate::c_enum! { $( $attr )* $vis enum $name : $( $head )* { $( $body )* } $( $impl )* }
+    };
+
+    // Called by `__c_enum_values!` once the values of all variants are a
+    // single token tree, or with the original variants if they couldn't be
+    // parsed.
+    (
+        impl(values_done)
+        [ [ $( $attr:tt )* ] ( $( $vis:tt )* ) $name:ident [ $( $head:tt )* ] ]
+        [ $( $variant:tt )* ] [ $( $impl:tt )* ]
+    ) => {
+        $crate::c_enum! {
+            $( $attr )* $( $vis )* enum $name : $( $head )* { $( $variant )* } $( $impl )*
+        }
+    };
+    (
+        impl(values_bail)
+        [ [ $( $attr:tt )* ] ( $( $vis:tt )* ) $name:ident $head:tt ]
+        $body:tt $impl:tt
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(reparse) [ $( $attr )* ] $( $vis )* enum $name $head $body $impl
+        }
+    };
+
+    // Rewrite variants declared with a visibility to use #[c_enum(vis = ...)]
+    // instead. Variants without one are moved over in batches so that large
+    // enums don't run into the recursion limit.
+    (
+        impl(field_vis) $head:tt [ $( $done:tt )* ]
+        {
+            $( #[ $( $a0:tt )* ] )* $f0:ident $( = $v0:expr )?,
+            $( #[ $( $a1:tt )* ] )* $f1:ident $( = $v1:expr )?,
+            $( #[ $( $a2:tt )* ] )* $f2:ident $( = $v2:expr )?,
+            $( #[ $( $a3:tt )* ] )* $f3:ident $( = $v3:expr )?,
+            $( $rest:tt )*
+        }
+        $impl:tt
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(field_vis) $head
+            [
+                $( $done )*
+                $( #[ $( $a0 )* ] )* $f0 $( = $v0 )?,
+                $( #[ $( $a1 )* ] )* $f1 $( = $v1 )?,
+                $( #[ $( $a2 )* ] )* $f2 $( = $v2 )?,
+                $( #[ $( $a3 )* ] )* $f3 $( = $v3 )?,
+            ]
+            { $( $rest )* }
+            $impl
+        }
+    };
+    (
+        impl(field_vis) $head:tt [ $( $done:tt )* ]
+        {
+            $( #[ $( $attr:tt )* ] )*
+            pub $( ( $( $path:tt )* ) )? $field:ident $( = $value:expr )?,
+            $( $rest:tt )*
+        }
+        $impl:tt
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(field_vis) $head
+            [
+                $( $done )*
+                $( #[ $( $attr )* ] )*
+                #[c_enum(vis = pub $( ( $( $path )* ) )?)]
+                $field $( = $value )?,
+            ]
+            { $( $rest )* }
+            $impl
+        }
+    };
+    (
+        impl(field_vis) $head:tt [ $( $done:tt )* ]
+        {
+            $( #[ $( $attr:tt )* ] )* $field:ident $( = $value:expr )?,
+            $( $rest:tt )*
+        }
+        $impl:tt
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(field_vis) $head
+            [ $( $done )* $( #[ $( $attr )* ] )* $field $( = $value )?, ]
+            { $( $rest )* }
+            $impl
+        }
+    };
+    (
+        impl(field_vis) [ $( $head:tt )* ] [ $( $done:tt )* ] {} [ $( $impl:tt )* ]
+    ) => {
+        $crate::c_enum! { $( $head )* { $( $done )* } $( $impl )* }
+    };
+    (impl(field_vis) $head:tt $done:tt { $( $body:tt )* } $impl:tt) => {
+        ::core::compile_error!(
+            "the variants of a c_enum! can't have fields, use `Variant = value` to give them a \
+             value instead"
+        );
+    };
+
+    // Split the first enum declared in a c_enum! block off from the rest.
+    (
+        impl(split) [ $( $first:tt )* ]
+        $( #[$iattr:meta] )*
+        impl { $( $item:tt )* }
+    ) => {
+        $crate::c_enum! { $( $first )* $( #[$iattr] )* impl { $( $item )* } }
+    };
+    (
+        impl(split) [ $( $first:tt )* ]
+        $( #[$iattr:meta] )*
+        impl { $( $item:tt )* }
+        $( $rest:tt )+
+    ) => {
+        $crate::c_enum! { $( $first )* $( #[$iattr] )* impl { $( $item )* } }
+        $crate::c_enum! { $( $rest )+ }
+    };
+    (impl(split) [ $( $first:tt )* ] $( $rest:tt )+) => {
+        $crate::c_enum! { $( $first )* }
+        $crate::c_enum! { $( $rest )+ }
+    };
+
+    // Split the `#[c_enum(...)]` options out from the rest of the attributes
+    // on the enum. Runs of doc comments are consumed in batches so that long
+    // doc comments don't run into the recursion limit.
+    (
+        impl(parse_attrs) $stage:tt $prefix:tt $debug:ident
+        [
+            #[c_enum( $( $key:tt $( = $value:tt )? ),* $(,)? )]
+            $( $rest:tt )*
+        ]
+        [ $( $attrs:tt )* ] [ $( $opts:tt )* ]
+        $( $tail:tt )*
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) $stage $prefix $debug
+            [ $( $rest )* ]
+            [ $( $attrs )* ] [ $( $opts )* $( $key $( = $value )?, )* ]
+            $( $tail )*
+        }
+    };
+    (
+        impl(parse_attrs) $stage:tt $prefix:tt $debug:ident
+        [
+            #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*] #[doc $( $d4:tt )*]
+            #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*] #[doc $( $d8:tt )*]
+            $( $rest:tt )*
+        ]
+        [ $( $attrs:tt )* ] [ $( $opts:tt )* ]
+        $( $tail:tt )*
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) $stage $prefix $debug
+            [ $( $rest )* ]
+            [
+                $( $attrs )*
+                #[doc $( $d1 )*] #[doc $( $d2 )*] #[doc $( $d3 )*] #[doc $( $d4 )*]
+                #[doc $( $d5 )*] #[doc $( $d6 )*] #[doc $( $d7 )*] #[doc $( $d8 )*]
+            ]
+            [ $( $opts )* ]
+            $( $tail )*
+        }
+    };
+    (
+        impl(parse_attrs) $stage:tt $prefix:tt $debug:ident
+        [ #[ $( $attr:tt )* ] $( $rest:tt )* ]
+        [ $( $attrs:tt )* ] [ $( $opts:tt )* ]
+        $( $tail:tt )*
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(parse_attrs) $stage $prefix $debug
+            [ $( $rest )* ]
+            [ $( $attrs )* #[ $( $attr )* ] ] [ $( $opts )* ]
+            $( $tail )*
+        }
+    };
+    (
+        impl(parse_attrs) $stage:tt $prefix:tt $debug:ident
+        [] [ $( $attrs:tt )* ] [ $( $opts:tt )* ]
+        $( $tail:tt )*
+    ) => {
+        $crate::__c_enum_impl!(impl(once) [ $( $opts )* ]);
+        $crate::__c_enum_impl!(impl(ord_derive) [ $( $opts )* ] [ $( $attrs )* ]);
+        $crate::__c_enum_impl! {
+            impl $stage $prefix $debug [ $( $opts )* ] [ $( $attrs )* ] $( $tail )*
+        }
+    };
+
+    // Every option other than `bitfield` can only be specified once. Tokens
+    // can't be compared with each other in `macro_rules!`, so each option has
+    // an arm which looks for it within the options that follow it.
+    (impl(once) []) => {};
+    (impl(once) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(once_in $key) [ $( $rest )* ]);
+        $crate::__c_enum_impl!(impl(once) [ $( $rest )* ]);
+    };
+    (impl(once_in ord) [ ord $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error ord)); };
+    (impl(once_in bitops) [ bitops $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error bitops)); };
+    (impl(once_in consts) [ consts $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error consts)); };
+    (impl(once_in mod) [ mod $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error mod)); };
+    (impl(once_in known) [ known $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error known)); };
+    (impl(once_in error) [ error $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error error)); };
+    (impl(once_in display) [ display $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error display)); };
+    (impl(once_in strip_prefix) [ strip_prefix $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error strip_prefix)); };
+    (impl(once_in rename_all) [ rename_all $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error rename_all)); };
+    (impl(once_in serde) [ serde $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error serde)); };
+    (impl(once_in schemars) [ schemars $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error schemars)); };
+    (impl(once_in serde_unknown) [ serde_unknown $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error serde_unknown)); };
+    (impl(once_in rkyv) [ rkyv $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error rkyv)); };
+    (impl(once_in bytemuck) [ bytemuck $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error bytemuck)); };
+    (impl(once_in zerocopy) [ zerocopy $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error zerocopy)); };
+    (impl(once_in private) [ private $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error private)); };
+    (impl(once_in extensions) [ extensions $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error extensions)); };
+    (impl(once_in continue_from) [ continue_from $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error continue_from)); };
+    (impl(once_in bits) [ bits $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error bits)); };
+    (impl(once_in step) [ step $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error step)); };
+    (impl(once_in deny_duplicates) [ deny_duplicates $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error deny_duplicates)); };
+    (impl(once_in bincode) [ bincode $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error bincode)); };
+    (impl(once_in minicbor) [ minicbor $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error minicbor)); };
+    (impl(once_in defmt) [ defmt $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error defmt)); };
+    (impl(once_in ufmt) [ ufmt $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error ufmt)); };
+    (impl(once_in arbitrary) [ arbitrary $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error arbitrary)); };
+    (impl(once_in proptest) [ proptest $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error proptest)); };
+    (impl(once_in clap) [ clap $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error clap)); };
+    (impl(once_in strum) [ strum $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error strum)); };
+    (impl(once_in num_enum) [ num_enum $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error num_enum)); };
+    (impl(once_in num_traits) [ num_traits $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error num_traits)); };
+    (impl(once_in sea_orm) [ sea_orm $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error sea_orm)); };
+    (impl(once_in wasm_bindgen) [ wasm_bindgen $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error wasm_bindgen)); };
+    (impl(once_in napi) [ napi $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error napi)); };
+    (impl(once_in rand) [ rand $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error rand)); };
+    (impl(once_in prost) [ prost $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error prost)); };
+    (impl(once_in scale) [ scale $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error scale)); };
+    (impl(once_in valuable) [ valuable $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error valuable)); };
+    (impl(once_in slog) [ slog $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error slog)); };
+    (impl(once_in async_graphql) [ async_graphql $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error async_graphql)); };
+    (impl(once_in utoipa) [ utoipa $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error utoipa)); };
+    (impl(once_in zvariant) [ zvariant $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error zvariant)); };
+    (impl(once_in deku) [ deku $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error deku)); };
+    (impl(once_in binrw) [ binrw $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error binrw)); };
+    (impl(once_in hide_deprecated) [ hide_deprecated $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error hide_deprecated)); };
+    (impl(once_in parse) [ parse $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error parse)); };
+    (impl(once_in debug) [ debug $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error debug)); };
+    (impl(once_in skip_debug) [ skip_debug $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error skip_debug)); };
+    (impl(once_in skip_from) [ skip_from $( $rest:tt )* ]) => { $crate::__c_enum_impl!(impl(once_error skip_from)); };
+    (impl(once_in $key:tt) [ $next:tt $( = $value:tt )?, $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(once_in $key) [ $( $rest )* ]);
+    };
+    (impl(once_in $key:tt) []) => {};
+    (impl(once_error $key:tt)) => {
+        ::core::compile_error!(::core::concat!(
+            "`#[c_enum(", ::core::stringify!($key), ")]` can only be specified once"
+        ));
+    };
+
+    // `#[c_enum(ord)]` implements `PartialOrd` and `Ord` itself, so they
+    // can't also be derived.
+    (impl(ord_derive) [ ord, $( $opts:tt )* ] [ $( #[ $( $attr:tt )* ] )* ]) => {
+        $( $crate::__c_enum_impl!(impl(ord_derive_attr) [ $( $attr )* ]); )*
+    };
+    (impl(ord_derive) [ $key:tt $( = $value:tt )?, $( $opts:tt )* ] $attrs:tt) => {
+        $crate::__c_enum_impl!(impl(ord_derive) [ $( $opts )* ] $attrs);
+    };
+    (impl(ord_derive) [] $attrs:tt) => {};
+    (impl(ord_derive_attr) [ derive( $( $derive:tt )* ) ]) => {
+        $( $crate::__c_enum_impl!(impl(ord_derive_path) $derive); )*
+    };
+    (impl(ord_derive_attr) $attr:tt) => {};
+    (impl(ord_derive_path) PartialOrd) => { $crate::__c_enum_impl!(impl(ord_derive_error)); };
+    (impl(ord_derive_path) Ord) => { $crate::__c_enum_impl!(impl(ord_derive_error)); };
+    (impl(ord_derive_path) $token:tt) => {};
+    (impl(ord_derive_error)) => {
+        ::core::compile_error!(
+            "`#[c_enum(ord)]` already implements `PartialOrd` and `Ord`, so they can't also be derived"
+        );
+    };
+
+    // Determine whether the inner type is an integer or a float by looking at
+    // the last segment of its path. Only primitive integer types and the C
+    // integer aliases from `core::ffi` (and `libc`) are recognized as integers.
+    (impl(classify) [ $seg:ident $( $rest:ident )+ ] $( $tt:tt )*) => {
+        $crate::__c_enum_impl!(impl(classify) [ $( $rest )+ ] $( $tt )*);
+    };
+    (impl(classify) [ u8 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ u16 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ u32 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ u64 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ u128 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ usize ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ i8 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ i16 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ i32 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ i64 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ i128 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ isize ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_char ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_schar ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_uchar ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_short ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_ushort ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_int ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_uint ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_long ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_ulong ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_longlong ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ c_ulonglong ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ size_t ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ ssize_t ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) int $( $tt )*); };
+    (impl(classify) [ NonZeroU8 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroU16 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroU32 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroU64 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroU128 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroUsize ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroI8 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroI16 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroI32 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroI64 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroI128 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ NonZeroIsize ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*); };
+    (impl(classify) [ U16Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u16, $( $tt )*); };
+    (impl(classify) [ U16Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u16, $( $tt )*); };
+    (impl(classify) [ U32Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u32, $( $tt )*); };
+    (impl(classify) [ U32Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u32, $( $tt )*); };
+    (impl(classify) [ U64Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u64, $( $tt )*); };
+    (impl(classify) [ U64Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u64, $( $tt )*); };
+    (impl(classify) [ U128Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u128, $( $tt )*); };
+    (impl(classify) [ U128Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) u128, $( $tt )*); };
+    (impl(classify) [ I16Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i16, $( $tt )*); };
+    (impl(classify) [ I16Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i16, $( $tt )*); };
+    (impl(classify) [ I32Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i32, $( $tt )*); };
+    (impl(classify) [ I32Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i32, $( $tt )*); };
+    (impl(classify) [ I64Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i64, $( $tt )*); };
+    (impl(classify) [ I64Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i64, $( $tt )*); };
+    (impl(classify) [ I128Be ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i128, $( $tt )*); };
+    (impl(classify) [ I128Le ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(classify_endian) i128, $( $tt )*); };
+    (impl(classify) [ f32 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) float $( $tt )*); };
+    (impl(classify) [ f64 ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) float $( $tt )*); };
+    (impl(classify) [ $seg:ident ] $( $tt:tt )*) => { $crate::__c_enum_impl!(impl(decl) other $( $tt )*); };
+
+    // The integer types from `c_enum::endian` can also be compared with the
+    // corresponding native integer type.
+    (
+        impl(classify_endian) $native:ty,
+        $debug:ident $opts:tt $attrs:tt $vis:vis $name:ident [ $inner:ty ] $( $tt:tt )*
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(decl) endian $debug $opts $attrs $vis $name [ $inner ] $( $tt )*
+        );
+
+        #[automatically_derived]
+        impl ::core::cmp::PartialEq<$native> for $name {
+            fn eq(&self, other: &$native) -> bool {
+                self.0.get() == *other
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::cmp::PartialEq<$name> for $native {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0.get()
+            }
+        }
+    };
+
+    // `Wrapping<T>` and `NonZero<T>` are the only generic inner types that get
+    // special treatment.
+    (impl(classify_generic) [ $seg:ident $( $rest:ident )+ ] $( $tt:tt )*) => {
+        $crate::__c_enum_impl!(impl(classify_generic) [ $( $rest )+ ] $( $tt )*);
+    };
+    (impl(classify_generic) [ Wrapping ] $( $tt:tt )*) => {
+        $crate::__c_enum_impl!(impl(decl) wrapping $( $tt )*);
+    };
+    (impl(classify_generic) [ NonZero ] $( $tt:tt )*) => {
+        $crate::__c_enum_impl!(impl(decl) nonzero $( $tt )*);
+    };
+    (impl(classify_generic) [ $seg:ident ] $( $tt:tt )*) => {
+        $crate::__c_enum_impl!(impl(decl) other $( $tt )*);
+    };
+
+    // The actual enum declaration.
+    (
+        impl(decl) $kind:ident $debug:ident $opts:tt
+        $attrs:tt $vis:vis $name:ident [ $inner:ty ]
+        {
+            $(
+                $( #[ $( $field_attr:tt )* ] )*
+                $field:ident $( = $value:expr )?,
+            )*
+        }
+        [ $( #[$iattr:meta] )* ]
+        { $( $item:tt )* }
+    ) => {
+        $crate::__c_enum_impl!(impl(struct) $opts $attrs $vis $name [ $inner ] [ pub ]);
+        $crate::__c_enum_impl!(
+            impl(known_decl) $opts [ $vis ] $name
+            { $( [ $( #[ $( $field_attr )* ] )* ] $field, )* }
+        );
+        $crate::__c_enum_impl!(
+            impl(mod_decl) $opts [ $vis ] $name
+            { $( [ $( #[ $( $field_attr )* ] )* ] $field, )* }
+        );
+        $crate::__c_enum_impl!(
+            impl(include_macro $kind) ($) $name
+            { $( [ $( #[ $( $field_attr )* ] )* ] $field, )* }
+        );
+
+        // Everything else is declared within an anonymous const so that the
+        // trait below is only in scope for the generated code.
+        const _: () = {
+            // Variants that have been removed by #[cfg] are replaced by
+            // constants from this trait so that the code below can still refer
+            // to them. They have the value that the next variant would get by
+            // default in their place, which keeps the numbering of the
+            // following variants correct.
+            #[allow(dead_code, non_upper_case_globals)]
+            trait __CEnumCfgFallback {
+                $(
+                    $crate::__c_enum_impl!(
+                        impl(cfgs) [ $( #[ $( $field_attr )* ] )* ] []
+                        (cfg_fallback_decl $name $field)
+                    );
+                )*
+            }
+
+            #[allow(deprecated, non_upper_case_globals)]
+            impl __CEnumCfgFallback for $name {
+                $crate::__c_enum_decl_fallbacks!(
+                    impl(
+                        $kind, $opts, $name, $inner,
+                        $crate::__c_enum_impl!(impl(first $kind $opts)),
+                        $crate::__c_enum_impl!(impl(first_doc $kind $opts))
+                    )
+                    $(
+                        $( #[ $( $field_attr )* ] )*
+                        $field $( = $value )?,
+                    )*
+                );
+            }
+
+            // Evaluate the value of every variant so that values which
+            // overflow the inner type are reported even if the variant is
+            // never used.
+            $(
+                #[allow(deprecated)]
+                const _: $name = $name::$field;
+            )*
+            $(
+                $crate::__c_enum_impl!(
+                    impl(range_check) $kind $name $field
+                    [ $( #[ $( $field_attr )* ] )* ] [ $( #[ $( $field_attr )* ] )* ]
+                );
+            )*
+
+            #[allow(non_upper_case_globals)]
+            $( #[$iattr] )*
+            impl $name {
+                $crate::__c_enum_decl_variants!(
+                    impl(
+                        $kind, $opts, $name, $inner,
+                        $crate::__c_enum_impl!(impl(first $kind $opts)),
+                        $crate::__c_enum_impl!(impl(first_doc $kind $opts))
+                    )
+                    $(
+                        $( #[ $( $field_attr )* ] )*
+                        $field $( = $value )?,
+                    )*
+                );
+
+                /// Creates a new value of this enum from its inner value.
+                pub const fn new(value: $inner) -> Self {
+                    Self(value)
+                }
+
+                /// Returns the inner value of this enum.
+                pub const fn value(self) -> $inner {
+                    self.0
+                }
+
+                // These are only public with #[c_enum(consts)], see
+                // `impl(opt consts)`.
+                #[doc(hidden)]
+                #[allow(dead_code, deprecated)]
+                pub(crate) const __C_ENUM_VARIANTS: &'static [Self] = $crate::__c_enum_impl!(
+                    impl(filter_slice) listed $opts, $name,
+                    [ $( [ $( #[ $( $field_attr )* ] )* ] $name::$field, )* ]
+                );
+
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                pub(crate) const __C_ENUM_LABELS: &'static [&'static str] = $crate::__c_enum_impl!(
+                    impl(filter_slice) listed $opts, &'static str,
+                    [
+                        $(
+                            [ $( #[ $( $field_attr )* ] )* ]
+                            $crate::__c_enum_impl!(
+                                impl(label) $opts [ $( #[ $( $field_attr )* ] )* ] $field
+                            ),
+                        )*
+                    ]
+                );
+
+                #[doc(hidden)]
+                #[allow(dead_code, deprecated)]
+                pub(crate) const __C_ENUM_VALUES: &'static [$inner] = $crate::__c_enum_impl!(
+                    impl(filter_slice) listed $opts, $inner,
+                    [ $( [ $( #[ $( $field_attr )* ] )* ] $name::$field.0, )* ]
+                );
+
+                #[doc(hidden)]
+                #[allow(dead_code, deprecated)]
+                pub(crate) const __C_ENUM_ENTRIES: &'static [(&'static str, $inner)] = $crate::__c_enum_impl!(
+                    impl(filter_slice) listed $opts, (&'static str, $inner),
+                    [
+                        $(
+                            [ $( #[ $( $field_attr )* ] )* ]
+                            (
+                                $crate::__c_enum_impl!(
+                                    impl(label) $opts [ $( #[ $( $field_attr )* ] )* ] $field
+                                ),
+                                $name::$field.0
+                            ),
+                        )*
+                    ]
+                );
+
+                /// Returns an iterator over all declared variants of this enum, in
+                /// declaration order.
+                pub fn variants() -> ::core::slice::Iter<'static, Self> {
+                    Self::__C_ENUM_VARIANTS.iter()
+                }
+
+                /// Returns the declared variant with the given name, if there is
+                /// one.
+                #[allow(deprecated)]
+                pub fn from_name(name: &str) -> ::core::option::Option<Self> {
+                    let matches = $crate::__c_enum_impl!(impl(name_eq) $opts);
+
+                    $(
+                        if !$crate::__c_enum_impl!(impl(hidden) $opts [ $( #[ $( $field_attr )* ] )* ])
+                            && matches(
+                            name,
+                            $crate::__c_enum_impl!(
+                                impl(label) $opts [ $( #[ $( $field_attr )* ] )* ] $field
+                            )
+                        ) {
+                            return ::core::option::Option::Some(Self::$field);
+                        }
+                    )*
+
+                    Self::__c_enum_ext_from_name(name)
+                }
+
+                /// Converts a value of the inner type into this enum, but only if
+                /// it corresponds to one of the declared variants.
+                ///
+                /// Unlike the [`From`] impl, this returns an error containing the
+                /// rejected value for undeclared values.
+                pub fn try_from_value(
+                    value: $inner
+                ) -> ::core::result::Result<Self, $crate::UnknownValueError<$inner>>
+                where
+                    for<'a> $inner: ::core::cmp::PartialEq,
+                {
+                    let value = Self(value);
+
+                    if value.is_known() {
+                        ::core::result::Result::Ok(value)
+                    } else {
+                        ::core::result::Result::Err($crate::UnknownValueError(value.0))
+                    }
+                }
+
+                /// Returns the name of the declared variant corresponding to the
+                /// current value, if there is one.
+                ///
+                /// This is the same as `CEnum::variant_label` but doesn't require
+                /// importing the trait.
+                pub fn as_str(&self) -> ::core::option::Option<&'static str>
+                where
+                    for<'a> $inner: ::core::cmp::PartialEq,
+                {
+                    <Self as $crate::CEnum>::variant_label(self)
+                }
+
+                $crate::__c_enum_impl!(
+                    impl(is_known) $kind $inner [ $( [ $( #[ $( $field_attr )* ] )* ] $field )* ]
+                );
+                $crate::__c_enum_impl!(impl(index) $kind $inner);
+                $crate::__c_enum_impl!(impl(ext_support) $kind $inner, $opts);
+                $(
+                    $crate::__c_enum_impl!(
+                        impl(cfgs) [ $( #[ $( $field_attr )* ] )* ] []
+                        (cfg_item {
+                            $crate::__c_enum_impl!(
+                                impl(predicate) $kind $inner [ $( #[ $( $field_attr )* ] )* ] $field
+                            );
+                        })
+                    );
+                )*
+                $crate::__c_enum_impl!(
+                    impl(integer_items) $kind $name $inner, $opts,
+                    [ $( [ $( #[ $( $field_attr )* ] )* ] $field )* ]
+                );
+
+                $( $item )*
+            }
+
+            $crate::__c_enum_attr_flags!(
+                impl(
+                    [] []
+                    (
+                        $kind $opts $name [ $inner ] [ $( #[$iattr] )* ]
+                        { $( [ $( #[ $( $field_attr )* ] )* ] $field, )* }
+                    )
+                )
+                $( $( [ $( $field_attr )* ] )* )*
+            );
+
+            $crate::__c_enum_impl!(impl(from) $opts $name [ $inner ]);
+
+            #[automatically_derived]
+            impl ::core::cmp::PartialEq<$inner> for $name
+            where
+                for<'a> $inner: ::core::cmp::PartialEq,
+            {
+                fn eq(&self, other: &$inner) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::cmp::PartialEq<$name> for $inner
+            where
+                for<'a> $inner: ::core::cmp::PartialEq,
+            {
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.0
+                }
+            }
+
+            #[automatically_derived]
+            impl $crate::CEnum for $name {
+                type Inner = $inner;
+
+                #[allow(deprecated)]
+                fn variant_label(&self) -> Option<&'static str>
+                where
+                    Self::Inner: PartialEq
+                {
+                    Some(match &self.0 {
+                        $(
+                            value if $crate::__c_enum_impl!(
+                                    impl(listed) $opts [ $( #[ $( $field_attr )* ] )* ]
+                                )
+                                && $crate::__c_enum_impl!(impl(eq $kind) Self::$field.0, *value)
+                                => $crate::__c_enum_impl!(
+                                    impl(label) $opts [ $( #[ $( $field_attr )* ] )* ] $field
+                                ),
+                        )*
+                        $(
+                            value if $crate::__c_enum_impl!(
+                                    impl(range_guard) $kind [ $( #[ $( $field_attr )* ] )* ] $field value
+                                )
+                                => $crate::__c_enum_impl!(
+                                    impl(label) $opts [ $( #[ $( $field_attr )* ] )* ] $field
+                                ),
+                        )*
+                        _ => return self.__c_enum_ext_label(),
+                    })
+                }
+
+                fn from_name(name: &str) -> Option<Self> {
+                    $name::from_name(name)
+                }
+            }
+
+            $(
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( #[ $( $field_attr )* ] )* ] []
+                    (cfg_item {
+                        $crate::__c_enum_impl!(
+                            impl(default) [ $( #[ $( $field_attr )* ] )* ] $name $field
+                        );
+                    })
+                );
+            )*
+
+            $crate::__c_enum_impl!(
+                impl(debug) $debug $opts $opts $kind $name [ $inner ]
+                { $( [ $( #[ $( $field_attr )* ] )* ] $field, )* }
+            );
+            $crate::__c_enum_impl!(impl(fmt_forward) $name $inner, LowerHex);
+            $crate::__c_enum_impl!(impl(fmt_forward) $name $inner, UpperHex);
+            $crate::__c_enum_impl!(impl(fmt_forward) $name $inner, Octal);
+            $crate::__c_enum_impl!(impl(fmt_forward) $name $inner, Binary);
+            $crate::__c_enum_impl!(impl(bitfield_builder) $opts [] $name [ $inner ]);
+            $crate::__c_enum_impl!(
+                impl(opts) $opts $opts $kind [ $vis ] $name [ $inner ]
+                { $( [ $( #[ $( $field_attr )* ] )* ] $field, )* }
+            );
+        };
+    };
+
+    // Declare the variants of the enums listed in `include A, B;` ahead of the
+    // other variants, one enum at a time. Each enum is asked to add its
+    // variants by invoking the macro declared alongside it.
+    (impl(include) [ $next:ident $( $others:ident )* ] $done:tt $fields:tt $( $tail:tt )*) => {
+        $next! { impl(include) [ $( $others )* ] $done $fields $( $tail )* }
+    };
+    (
+        impl(include) [] [ $( $done:ident )* ] { $( $fields:tt )* }
+        [ $( $attr:tt )* ] [ $vis:vis ] $name:ident [ $( $inner:ident )::+ ]
+        { $( $body:tt )* }
+        $( $rest:tt )*
+    ) => {
+        $crate::c_enum! {
+            $( $attr )*
+            $vis enum $name : $( $inner )::+ {
+                $( $fields )*
+                $( $body )*
+            }
+
+            $( $rest )*
+        }
+
+        $(
+            #[automatically_derived]
+            impl ::core::convert::From<$done> for $name {
+                fn from(value: $done) -> Self {
+                    Self::new(value.value())
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<$name> for $done {
+                fn from(value: $name) -> Self {
+                    Self::new(value.value())
+                }
+            }
+        )*
+    };
+
+    // Define a macro with the same name as the enum that is used to include
+    // its variants within another enum. The included variants are declared
+    // using the values of the variants of this enum so they keep the same
+    // values.
+    (
+        impl(include_macro nonzero) $d:tt $name:ident
+        { $( [ $( $attr:tt )* ] $field:ident, )* }
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(include_macro_def) $d $name
+            { $( $( $attr )* #[allow(deprecated)] $field = $name::$field.value().get(), )* }
+        );
+    };
+    (
+        impl(include_macro endian) $d:tt $name:ident
+        { $( [ $( $attr:tt )* ] $field:ident, )* }
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(include_macro_def) $d $name
+            { $( $( $attr )* #[allow(deprecated)] $field = $name::$field.value().get(), )* }
+        );
+    };
+    (
+        impl(include_macro $kind:ident) $d:tt $name:ident
+        { $( [ $( $attr:tt )* ] $field:ident, )* }
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(include_macro_def) $d $name
+            { $( $( $attr )* #[allow(deprecated)] $field = $name::$field.value(), )* }
+        );
+    };
+    (impl(include_macro_def) ($d:tt) $name:ident { $( $variants:tt )* }) => {
+        #[allow(unused_macros)]
+        macro_rules! $name {
+            (
+                impl(include) $d others:tt [ $d( $d done:ident )* ] { $d( $d fields:tt )* }
+                $d( $d tail:tt )*
+            ) => {
+                $crate::__c_enum_impl! {
+                    impl(include) $d others [ $d( $d done )* $name ]
+                    {
+                        $d( $d fields )*
+                        $( $variants )*
+                    }
+                    $d( $d tail )*
+                }
+            };
+        }
+    };
+
+    // Declare the struct itself. Some options need to add derives to it or
+    // look at its attributes.
+    (
+        impl(struct) [ rkyv, $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_rkyv!([ $( $rest )* ] $attrs $vis $name [ $inner ] $field_vis);
+    };
+    (
+        impl(struct) [ bytemuck, $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_bytemuck!($attrs $name [ $inner ]);
+        $crate::__c_enum_impl!(
+            impl(struct) [ $( $rest )* ] $attrs $vis $name [ $inner ] $field_vis
+        );
+    };
+    (
+        impl(struct) [ zerocopy, $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_zerocopy!([ $( $rest )* ] $attrs $vis $name [ $inner ] $field_vis);
+    };
+    (
+        impl(struct) [ private, $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_impl!(impl(struct) [ $( $rest )* ] $attrs $vis $name [ $inner ] []);
+    };
+    (
+        impl(struct) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(struct) [ $( $rest )* ] $attrs $vis $name [ $inner ] $field_vis
+        );
+    };
+    (impl(struct) [] $attrs:tt $vis:vis $name:ident [ $inner:ty ] $field_vis:tt) => {
+        $crate::__c_enum_impl!(impl(struct_decl) $attrs $attrs $vis $name [ $inner ] $field_vis);
+    };
+
+    // Outside of the crate, enums marked #[non_exhaustive] can only be
+    // converted to and from the inner type using `new()`, `value()` and the
+    // `From` impls. This leaves room to validate values in the future.
+    (
+        impl(struct_decl) [ #[non_exhaustive] $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] [ pub ]
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(struct_decl) [] $attrs $vis $name [ $inner ] [ pub(crate) ]
+        );
+    };
+    (
+        impl(struct_decl) [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $attrs:tt $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(struct_decl) [ $( $rest )* ] $attrs $vis $name [ $inner ] $field_vis
+        );
+    };
+    (
+        impl(struct_decl) [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $attrs:tt
+        $vis:vis $name:ident [ $inner:ty ] $field_vis:tt
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(struct_decl) [ $( $rest )* ] $attrs $vis $name [ $inner ] $field_vis
+        );
+    };
+    (
+        impl(struct_decl) [] [ $( $attr:tt )* ]
+        $vis:vis $name:ident [ $inner:ty ] [ $( $field_vis:tt )* ]
+    ) => {
+        $( $attr )*
+        $vis struct $name($( $field_vis )* $inner);
+    };
+
+    // Expand each option passed via #[c_enum(...)] by invoking the
+    // corresponding `impl(opt <name>)` rule below.
+    (impl(opts) [] $( $args:tt )*) => {};
+    (impl(opts) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $( $args:tt )*) => {
+        $crate::__c_enum_impl!(impl(opt $key) [ $( $value )? ] $( $args )*);
+        $crate::__c_enum_impl!(impl(opts) [ $( $rest )* ] $( $args )*);
+    };
+
+    (impl(opt ord) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        #[automatically_derived]
+        impl ::core::cmp::PartialOrd for $name
+        where
+            for<'a> $name: ::core::cmp::PartialEq,
+            for<'a> $inner: ::core::cmp::PartialOrd,
+        {
+            fn partial_cmp(
+                &self,
+                other: &Self
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&self.0, &other.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::cmp::Ord for $name
+        where
+            for<'a> $name: ::core::cmp::Eq,
+            for<'a> $inner: ::core::cmp::Ord,
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.0, &other.0)
+            }
+        }
+    };
+
+    // The constants listing the declared variants are only public with
+    // #[c_enum(consts)] since they would conflict with variants of the same
+    // name. The rest of the generated code uses the hidden versions instead.
+    (impl(opt consts) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        impl $name {
+            /// All declared variants of this enum, in declaration order.
+            pub const VARIANTS: &'static [Self] = Self::__C_ENUM_VARIANTS;
+
+            /// The number of declared variants of this enum.
+            pub const VARIANT_COUNT: usize = Self::__C_ENUM_VARIANTS.len();
+
+            /// The names of all declared variants of this enum, in declaration
+            /// order.
+            pub const LABELS: &'static [&'static str] = Self::__C_ENUM_LABELS;
+
+            /// The values of all declared variants of this enum, in declaration
+            /// order.
+            pub const VALUES: &'static [$inner] = Self::__C_ENUM_VALUES;
+
+            /// The names and values of all declared variants of this enum, in
+            /// declaration order.
+            pub const ENTRIES: &'static [(&'static str, $inner)] = Self::__C_ENUM_ENTRIES;
+
+            $crate::__c_enum_impl!(impl(consts_int $kind) $variants);
+        }
+    };
+    (impl(consts_int int) { $( [ $( $attr:tt )* ] $field:ident, )* }) => {
+        /// All declared variants, ordered by their value.
+        ///
+        /// Variants with the same value are kept in declaration order.
+        pub const VARIANTS_BY_VALUE: &'static [Self] = Self::__C_ENUM_VARIANTS_BY_VALUE;
+
+        $crate::__c_enum_impl!(impl(min_max) [ $( [ $( $attr )* ] $field )* ]);
+    };
+    (impl(consts_int $kind:ident) $variants:tt) => {};
+    (impl(opt consts) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(consts)]`");
+    };
+
+    (impl(opt bitops) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_impl!(impl(bitop) $name $inner, BitOr::bitor, BitOrAssign::bitor_assign);
+        $crate::__c_enum_impl!(impl(bitop) $name $inner, BitAnd::bitand, BitAndAssign::bitand_assign);
+        $crate::__c_enum_impl!(impl(bitop) $name $inner, BitXor::bitxor, BitXorAssign::bitxor_assign);
+        $crate::__c_enum_impl!(impl(flag_mask) $kind $name $variants);
+
+        impl $name {
+            /// Returns whether all the bits set in `other` are also set in
+            /// `self`.
+            pub fn contains(&self, other: Self) -> bool
+            where
+                for<'a> $inner: ::core::ops::BitAnd<Output = $inner>
+                    + ::core::cmp::PartialEq
+                    + ::core::marker::Copy,
+            {
+                (self.0 & other.0) == other.0
+            }
+
+            /// Returns whether any of the bits set in `other` are also set in
+            /// `self`.
+            pub fn intersects(&self, other: Self) -> bool
+            where
+                for<'a> $inner: ::core::ops::BitAnd<Output = $inner>
+                    + ::core::cmp::PartialEq
+                    + ::core::default::Default
+                    + ::core::marker::Copy,
+            {
+                (self.0 & other.0) != <$inner as ::core::default::Default>::default()
+            }
+
+            /// Sets all the bits that are set in `other`.
+            pub fn insert(&mut self, other: Self)
+            where
+                for<'a> $inner: ::core::ops::BitOrAssign,
+            {
+                self.0 |= other.0;
+            }
+
+            /// Clears all the bits that are set in `other`.
+            pub fn remove(&mut self, other: Self)
+            where
+                for<'a> $inner: ::core::ops::BitAndAssign + ::core::ops::Not<Output = $inner>,
+            {
+                self.0 &= !other.0;
+            }
+
+            /// Flips all the bits that are set in `other`.
+            pub fn toggle(&mut self, other: Self)
+            where
+                for<'a> $inner: ::core::ops::BitXorAssign,
+            {
+                self.0 ^= other.0;
+            }
+        }
+    };
+
+    // The enum for #[c_enum(known = <name>)] is declared separately from the
+    // rest of the option since it has to be outside of the anonymous const.
+    (
+        impl(known_decl) [ known = $known:ident, $( $rest:tt )* ] [ $vis:vis ] $name:ident
+        { $( [ $( $field_attr:tt )* ] $field:ident, )* }
+    ) => {
+        #[doc = ::core::concat!(
+            "The declared variants of [`", ::core::stringify!($name), "`].\n\n",
+            "Unlike [`", ::core::stringify!($name), "`] this is a regular Rust enum ",
+            "so it can be matched exhaustively."
+        )]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types)]
+        #[non_exhaustive]
+        $vis enum $known {
+            $(
+                #[doc = ::core::concat!(
+                    "Corresponds to [`", ::core::stringify!($name), "::",
+                    ::core::stringify!($field), "`]."
+                )]
+                $field,
+            )*
+        }
+
+        // The variants of the companion enum can't be removed by #[cfg] along
+        // with the ones they correspond to.
+        $(
+            $crate::__c_enum_impl!(
+                impl(cfgs) [ $( $field_attr )* ] [] (known_cfg $known $field)
+            );
+        )*
+    };
+    (impl(known_decl) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $( $args:tt )*) => {
+        $crate::__c_enum_impl!(impl(known_decl) [ $( $rest )* ] $( $args )*);
+    };
+    (impl(known_decl) [] $( $args:tt )*) => {};
+
+    // The module for #[c_enum(mod = <name>)] also has to be declared outside
+    // of the anonymous const.
+    (
+        impl(mod_decl) [ mod = $module:ident, $( $rest:tt )* ] [ $vis:vis ] $name:ident
+        { $( [ $( $field_attr:tt )* ] $field:ident, )* }
+    ) => {
+        #[doc = ::core::concat!(
+            "The variants of [`", ::core::stringify!($name), "`] as free-standing constants."
+        )]
+        $vis mod $module {
+            $(
+                $crate::__c_enum_impl!(
+                    impl(cfgs) [ $( $field_attr )* ] []
+                    (cfg_item {
+                        $crate::__c_enum_impl!(
+                            impl(mod_const) [ $( $field_attr )* ] [ pub ] $name $field
+                        );
+                    })
+                );
+            )*
+        }
+    };
+    (impl(mod_decl) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $( $args:tt )*) => {
+        $crate::__c_enum_impl!(impl(mod_decl) [ $( $rest )* ] $( $args )*);
+    };
+    (impl(mod_decl) [] $( $args:tt )*) => {};
+
+    // Each constant within the module keeps the visibility of the variant.
+    // Visibilities relative to the current module need to be adjusted since
+    // the constants are declared one module further down.
+    (impl(mod_const) [] [ $( $vis:tt )* ] $name:ident $field:ident) => {
+        #[doc = ::core::concat!(
+            "See [`", ::core::stringify!($name), "::", ::core::stringify!($field),
+            "`](super::", ::core::stringify!($name), "::", ::core::stringify!($field), ")."
+        )]
+        #[allow(deprecated, non_upper_case_globals)]
+        $( $vis )* const $field: super::$name = super::$name::$field;
+    };
+    (
+        impl(mod_const) [ #[c_enum(vis = pub(self))] $( $rest:tt )* ]
+        $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(mod_const) [ $( $rest )* ] [ pub(super) ] $name $field);
+    };
+    (
+        impl(mod_const) [ #[c_enum(vis = pub(super))] $( $rest:tt )* ]
+        $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(mod_const) [ $( $rest )* ] [ pub(in super::super) ] $name $field
+        );
+    };
+    (
+        impl(mod_const) [ #[c_enum(vis = pub(in self $( $path:tt )*))] $( $rest:tt )* ]
+        $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(mod_const) [ $( $rest )* ] [ pub(in super $( $path )*) ] $name $field
+        );
+    };
+    (
+        impl(mod_const) [ #[c_enum(vis = pub(in super $( $path:tt )*))] $( $rest:tt )* ]
+        $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(mod_const) [ $( $rest )* ] [ pub(in super::super $( $path )*) ] $name $field
+        );
+    };
+    (
+        impl(mod_const) [ #[c_enum(vis = pub $( $new:tt )?)] $( $rest:tt )* ]
+        $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(mod_const) [ $( $rest )* ] [ pub $( $new )? ] $name $field);
+    };
+    (
+        impl(mod_const) [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(mod_const) [ $( $rest )* ] $vis $name $field);
+    };
+    (
+        impl(mod_const) [ #[ $( $attr:tt )* ] $( $rest:tt )* ]
+        $vis:tt $name:ident $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(mod_const) [ $( $rest )* ] $vis $name $field);
+    };
+
+    (impl(opt mod) [ $module:ident ] $( $args:tt )*) => {};
+    (impl(opt mod) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(mod = <name>)]`");
+    };
+
+    (
+        impl(opt known) [ $known:ident ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ]
+        { $( [ $( $field_attr:tt )* ] $field:ident, )* }
+    ) => {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl ::core::convert::From<$known> for $name {
+            fn from(value: $known) -> Self {
+                match value {
+                    $( $known::$field => Self::$field, )*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<$name> for $known
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            type Error = $crate::UnknownValueError<$inner>;
+
+            fn try_from(value: $name) -> ::core::result::Result<Self, Self::Error> {
+                $(
+                    if $crate::__c_enum_impl!(impl(listed) $opts [ $( $field_attr )* ])
+                        && $crate::__c_enum_impl!(impl(eq $kind) value.0, $name::$field.0)
+                    {
+                        return ::core::result::Result::Ok(Self::$field);
+                    }
+                )*
+
+                ::core::result::Result::Err($crate::UnknownValueError(value.0))
+            }
+        }
+
+        impl $name {
+            #[doc = ::core::concat!(
+                "Converts this value into a [`", ::core::stringify!($known), "`] if it ",
+                "corresponds to one of the declared variants, or returns the raw ",
+                "inner value otherwise."
+            )]
+            pub fn classify(self) -> ::core::result::Result<$known, $inner>
+            where
+                for<'a> $inner: ::core::cmp::PartialEq,
+            {
+                <$known as ::core::convert::TryFrom<$name>>::try_from(self)
+                    .map_err($crate::UnknownValueError::into_value)
+            }
+        }
+    };
+    (impl(opt known) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(known = <name>)]`");
+    };
+
+    (
+        impl(opt error) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ]
+        { $( [ $( $field_attr:tt )* ] $field:ident, )* }
+    ) => {
+        impl $name {
+            /// Returns the message of the declared variant corresponding to the
+            /// current value, as set by `#[msg = "..."]`. Variants without a
+            /// message use their label instead.
+            #[allow(deprecated)]
+            pub fn message(&self) -> ::core::option::Option<&'static str>
+            where
+                for<'a> $inner: ::core::cmp::PartialEq,
+            {
+                $(
+                    if $crate::__c_enum_impl!(impl(listed) $opts [ $( $field_attr )* ])
+                        && $crate::__c_enum_impl!(impl(eq $kind) self.0, $name::$field.0)
+                    {
+                        return ::core::option::Option::Some($crate::__c_enum_impl!(
+                            impl(message) $opts [ $( $field_attr )* ] [ $( $field_attr )* ] $field
+                        ));
+                    }
+                )*
+
+                <Self as $crate::CEnum>::variant_label(self)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self.message() {
+                    ::core::option::Option::Some(message) => f.write_str(message),
+                    ::core::option::Option::None => ::core::write!(f, "unknown error {}", self.0),
+                }
+            }
+        }
+
+        $crate::__c_enum_std_error!($name [ $inner ]);
+    };
+    (impl(opt error) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(error)]`");
+    };
+
+    (
+        impl(opt display) [ "doc" ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ]
+        { $( [ $( $field_attr:tt )* ] $field:ident, )* }
+    ) => {
+        impl $name {
+            /// Returns the first line of the doc comment of the declared variant
+            /// corresponding to the current value. Variants without a doc
+            /// comment use their label instead.
+            #[allow(deprecated)]
+            pub fn description(&self) -> ::core::option::Option<&'static str>
+            where
+                for<'a> $inner: ::core::cmp::PartialEq,
+            {
+                $(
+                    if $crate::__c_enum_impl!(impl(listed) $opts [ $( $field_attr )* ])
+                        && $crate::__c_enum_impl!(impl(eq $kind) self.0, $name::$field.0)
+                    {
+                        let text: &'static str = $crate::__c_enum_impl!(
+                            impl(doc_line) $opts [ $( $field_attr )* ] [ $( $field_attr )* ] $field
+                        );
+                        let text = text.trim_start();
+                        return ::core::option::Option::Some(
+                            text.lines().next().unwrap_or(text).trim_end()
+                        );
+                    }
+                )*
+
+                <Self as $crate::CEnum>::variant_label(self)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self.description() {
+                    ::core::option::Option::Some(description) => f.write_str(description),
+                    ::core::option::Option::None => ::core::fmt::Display::fmt(&self.0, f),
+                }
+            }
+        }
+    };
+    (impl(opt display) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(display = \"doc\")]`");
+    };
+
+    // These options are handled by `impl(label)`.
+    (impl(opt strip_prefix) [ $prefix:literal ] $( $args:tt )*) => {};
+    (impl(opt strip_prefix) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(strip_prefix = \"...\")]`");
+    };
+    (impl(opt rename_all) [ "lowercase" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "UPPERCASE" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "PascalCase" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "camelCase" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "snake_case" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "SCREAMING_SNAKE_CASE" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "kebab-case" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ "SCREAMING-KEBAB-CASE" ] $( $args:tt )*) => {};
+    (impl(opt rename_all) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!(
+            "expected one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \
+             \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \
+             \"SCREAMING-KEBAB-CASE\""
+        );
+    };
+
+    (
+        impl(opt bitfield) [
+            (
+                get = $get:ident,
+                $( set = $set:ident, )?
+                $( ty = $ty:ty, )?
+                shift = $shift:expr,
+                mask = $mask:expr $(,)?
+            )
+        ]
+        $opts:tt int [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(bitfield) $name [ $inner ] $get [ $( $set )? ]
+            [ $crate::__c_enum_impl!(impl(first_ty) $( $ty, )? $inner) ]
+            $shift, $mask
+        );
+    };
+    (impl(opt bitfield) [ $( $value:tt )? ] $opts:tt int $( $args:tt )*) => {
+        ::core::compile_error!(
+            "expected `#[c_enum(bitfield = (get = <name>, set = <name>, ty = <type>, \
+             shift = <shift>, mask = <mask>))]`"
+        );
+    };
+    (impl(opt bitfield) $( $args:tt )*) => {
+        ::core::compile_error!(
+            "#[c_enum(bitfield = ...)] is only supported for enums with integer inner types"
+        );
+    };
+
+    (
+        impl(bitfield) $name:ident [ $inner:ty ] $get:ident [ $( $set:ident )? ] [ $ty:ty ]
+        $shift:expr, $mask:expr
+    ) => {
+        impl $name {
+            #[doc = ::core::concat!(
+                "Returns the `", ::core::stringify!($get), "` bitfield, which is stored in ",
+                "the bits selected by `", ::core::stringify!($mask), "` after shifting the ",
+                "value right by `", ::core::stringify!($shift), "`."
+            )]
+            pub fn $get(&self) -> $ty
+            where
+                for<'a> $ty: ::core::convert::From<$inner>,
+            {
+                ::core::convert::From::from((self.0 >> ($shift)) & ($mask))
+            }
+
+            $(
+                #[doc = ::core::concat!(
+                    "Sets the `", ::core::stringify!($get), "` bitfield, leaving all other ",
+                    "bits unchanged."
+                )]
+                pub fn $set(&mut self, value: $ty)
+                where
+                    for<'a> $inner: ::core::convert::From<$ty>,
+                {
+                    let value = <$inner as ::core::convert::From<$ty>>::from(value);
+                    self.0 = (self.0 & !(($mask) << ($shift))) | ((value & ($mask)) << ($shift));
+                }
+            )?
+        }
+    };
+
+    // Generate a const constructor that combines all the bitfields declared
+    // via #[c_enum(bitfield = ...)].
+    (
+        impl(bitfield_builder)
+        [
+            bitfield = (
+                get = $get:ident,
+                $( set = $set:ident, )?
+                $( ty = $ty:ty, )?
+                shift = $shift:expr,
+                mask = $mask:expr $(,)?
+            ),
+            $( $rest:tt )*
+        ]
+        [ $( $fields:tt )* ] $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(bitfield_builder) [ $( $rest )* ]
+            [ $( $fields )* ($get, $shift, $mask) ] $name [ $inner ]
+        );
+    };
+    (
+        impl(bitfield_builder) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]
+        $fields:tt $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(impl(bitfield_builder) [ $( $rest )* ] $fields $name [ $inner ]);
+    };
+    (impl(bitfield_builder) [] [] $name:ident [ $inner:ty ]) => {};
+    (
+        impl(bitfield_builder) []
+        [ $( ( $get:ident, $shift:expr, $mask:expr ) )+ ] $name:ident [ $inner:ty ]
+    ) => {
+        impl $name {
+            /// Creates a new value by combining the values of all the declared
+            /// bitfields.
+            ///
+            /// Bits of each argument that fall outside of the mask for that
+            /// bitfield are ignored.
+            pub const fn from_bitfields($( $get: $inner ),+) -> Self {
+                Self(0 $( | (($get & ($mask)) << ($shift)) )+)
+            }
+        }
+    };
+
+    (impl(first_ty) $first:ty $( , $rest:ty )*) => { $first };
+
+    (impl(opt serde) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_serde!(value $name [ $inner ]);
+    };
+    (
+        impl(opt serde) [ "value" ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ]
+        $variants:tt
+    ) => {
+        $crate::__c_enum_serde!(value $name [ $inner ]);
+    };
+    (
+        impl(opt serde) [ "name" ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ]
+        $variants:tt
+    ) => {
+        $crate::__c_enum_impl!(impl(serde_unknown) $opts $name [ $inner ]);
+    };
+    (
+        impl(opt serde) [ "lenient" ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ]
+        $variants:tt
+    ) => {
+        $crate::__c_enum_serde!(lenient $name [ $inner ]);
+    };
+    (impl(opt serde) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!(
+            "expected `#[c_enum(serde = \"value\")]`, `#[c_enum(serde = \"name\")]` or \
+             `#[c_enum(serde = \"lenient\")]`"
+        );
+    };
+
+    (impl(opt schemars) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_impl!(impl(schema) $opts value value $name [ $inner ]);
+    };
+
+    // This option is handled by `#[c_enum(serde = "name")]`.
+    (impl(opt serde_unknown) [ "value" ] $( $args:tt )*) => {};
+    (impl(opt serde_unknown) [ "error" ] $( $args:tt )*) => {};
+    (impl(opt serde_unknown) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!(
+            "expected `#[c_enum(serde_unknown = \"value\")]` or \
+             `#[c_enum(serde_unknown = \"error\")]`"
+        );
+    };
+
+    // These options are handled by `impl(struct)`.
+    (impl(opt rkyv) [] $( $args:tt )*) => {};
+    (impl(opt bytemuck) [] $( $args:tt )*) => {};
+    (impl(opt zerocopy) [] $( $args:tt )*) => {};
+    (impl(opt private) [] $( $args:tt )*) => {};
+
+    // This option is handled by `impl(ext_lookup)`.
+    (impl(opt extensions) [ ( $( $ext:path ),* $(,)? ) ] $( $args:tt )*) => {};
+    (impl(opt extensions) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(extensions = (<extension>, ...))]`");
+    };
+
+    // These options are handled when numbering the variants.
+    (impl(opt continue_from) [ $prev:ident ] $( $args:tt )*) => {};
+    (impl(opt continue_from) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(continue_from = <enum>)]`");
+    };
+    (impl(opt bits) [] $( $args:tt )*) => {};
+    (impl(opt step) [ $step:tt ] $opts:tt $( $args:tt )*) => {
+        $crate::__c_enum_impl!(impl(step_check) $opts);
+    };
+    (impl(opt step) [] $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(step = <value>)]`");
+    };
+    (impl(step_check) [ bits, $( $rest:tt )* ]) => {
+        ::core::compile_error!("`#[c_enum(step = ...)]` can't be combined with `#[c_enum(bits)]`");
+    };
+    (impl(step_check) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(step_check) [ $( $rest )* ]);
+    };
+    (impl(step_check) []) => {};
+
+    // `panic!` can't be used in constants on all supported compilers, so a
+    // duplicate value instead fails compilation by indexing into an empty
+    // array. The index in the error message is the position of the second
+    // variant within `VALUES`.
+    (
+        impl(opt deny_duplicates) []
+        $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt
+    ) => {
+        const _: () = {
+            let values = $name::__C_ENUM_VALUES;
+            let mut i = 0;
+            while i < values.len() {
+                let mut j = i + 1;
+                while j < values.len() {
+                    ::core::assert!(
+                        !$crate::__c_enum_impl!(impl(const_eq $kind) values[i], values[j]),
+                        "c_enum: two variants have the same value"
+                    );
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+    };
+    (impl(opt deny_duplicates) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(deny_duplicates)]`");
+    };
+    (impl(const_eq int) $lhs:expr, $rhs:expr) => { $lhs == $rhs };
+    (impl(const_eq wrapping) $lhs:expr, $rhs:expr) => { $lhs.0 == $rhs.0 };
+    (impl(const_eq nonzero) $lhs:expr, $rhs:expr) => { $lhs.get() == $rhs.get() };
+    (impl(const_eq endian) $lhs:expr, $rhs:expr) => { $lhs.get() == $rhs.get() };
+    (impl(const_eq $kind:ident) $lhs:expr, $rhs:expr) => {
+        ::core::compile_error!("`#[c_enum(deny_duplicates)]` requires an integer inner type")
+    };
+
+    (impl(opt bincode) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_bincode!($name [ $inner ]);
+    };
+
+    (impl(opt minicbor) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_minicbor!($name [ $inner ]);
+    };
+
+    (impl(opt defmt) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_defmt!($name [ $inner ]);
+    };
+
+    (impl(opt ufmt) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_ufmt!($name [ $inner ]);
+    };
+
+    (impl(opt arbitrary) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_arbitrary!($name [ $inner ]);
+    };
+
+    (impl(opt proptest) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_proptest!($name [ $inner ]);
+    };
+
+    (impl(opt clap) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_clap!($name [ $inner ]);
+    };
+
+    (impl(opt strum) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_strum!($name [ $inner ]);
+    };
+
+    (impl(opt num_enum) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_num_enum!($name [ $inner ]);
+    };
+
+    (impl(opt num_traits) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_num_traits!($name [ $inner ]);
+    };
+
+    (impl(opt sea_orm) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_sea_orm!($name [ $inner ]);
+    };
+
+    (impl(opt pyo3) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_pyo3!($name [ $inner ]);
+    };
+
+    (impl(opt wasm_bindgen) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_wasm_bindgen!($name [ $inner ]);
+    };
+
+    (impl(opt napi) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_napi!($name [ $inner ]);
+    };
+
+    (impl(opt rand) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_rand!($name [ $inner ]);
+    };
+
+    (impl(opt prost) [ $proto:ident ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_prost!($name [ $inner ] $proto);
+    };
+    (impl(opt prost) $( $args:tt )*) => {
+        ::core::compile_error!("expected `#[c_enum(prost = <type>)]`");
+    };
+
+    (impl(opt scale) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_scale!($name [ $inner ]);
+    };
+
+    (impl(opt valuable) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_valuable!($name [ $inner ]);
+    };
+
+    (impl(opt slog) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_slog!($name [ $inner ]);
+    };
+
+    (impl(opt async_graphql) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_async_graphql!(enum $name [ $inner ]);
+    };
+    (impl(opt async_graphql) [ "enum" ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_async_graphql!(enum $name [ $inner ]);
+    };
+    (impl(opt async_graphql) [ "int" ] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_async_graphql!(int $name [ $inner ]);
+    };
+    (impl(opt async_graphql) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!("expected one of \"enum\" or \"int\"");
+    };
+
+    (impl(opt utoipa) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_utoipa!($name [ $inner ]);
+    };
+
+    (impl(opt zvariant) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_zvariant!($name [ $inner ]);
+    };
+
+    (impl(opt deku) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_deku!($name [ $inner ]);
+    };
+
+    (impl(opt binrw) [] $opts:tt $kind:ident [ $vis:vis ] $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_binrw!($name [ $inner ]);
+    };
+
+    // This option is handled by `impl(listed)` and `impl(hidden)`.
+    (impl(opt hide_deprecated) [] $( $args:tt )*) => {};
+
+    // These options are handled by `from_name`.
+    (impl(opt parse) [ "case_insensitive" ] $( $args:tt )*) => {};
+    (impl(opt parse) [ "exact" ] $( $args:tt )*) => {};
+    (impl(opt parse) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!(
+            "expected `#[c_enum(parse = \"case_insensitive\")]` or `#[c_enum(parse = \"exact\")]`"
+        );
+    };
+
+    // These options are handled by the Debug impl.
+    (impl(opt debug) [ "hex" ] $( $args:tt )*) => {};
+    (impl(opt debug) [ "decimal" ] $( $args:tt )*) => {};
+    (impl(opt debug) [ $( $value:tt )? ] $( $args:tt )*) => {
+        ::core::compile_error!(
+            "expected `#[c_enum(debug = \"hex\")]` or `#[c_enum(debug = \"decimal\")]`"
+        );
+    };
+    (impl(opt skip_debug) [] $( $args:tt )*) => {};
+
+    // This option is handled by `impl(from)`.
+    (impl(opt skip_from) [] $( $args:tt )*) => {};
+
+    // Emit an error for any options that we don't recognize.
+    (impl(opt $key:tt) $( $args:tt )*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown c_enum option `", ::core::stringify!($key), "`"
+        ));
+    };
+
+    // The mask of declared bits can only be computed in a const context for
+    // integer inner types.
+    (
+        impl(flag_mask) int $name:ident
+        { $( [ $( $attr:tt )* ] $field:ident, )* }
+    ) => {
+        impl $name {
+            /// The union of the values of all declared variants.
+            #[allow(deprecated)]
+            pub const ALL: Self = Self(
+                0 $(
+                    | if $crate::__c_enum_impl!(impl(cfgs) [ $( $attr )* ] [] (cfg_pred)) {
+                        Self::$field.0
+                    } else {
+                        0
+                    }
+                )*
+            );
+
+            /// Returns the complement of this value, restricted to the bits
+            /// that are set in at least one declared variant.
+            pub const fn complement(&self) -> Self {
+                Self(!self.0 & Self::ALL.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::Not for $name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.complement()
+            }
+        }
+    };
+    (impl(flag_mask) $kind:ident $name:ident $variants:tt) => {};
+
+    (
+        impl(bitop) $name:ident $inner:ty,
+        $trait:ident :: $method:ident,
+        $assign_trait:ident :: $assign_method:ident
+    ) => {
+        #[automatically_derived]
+        impl ::core::ops::$trait for $name
+        where
+            for<'a> $inner: ::core::ops::$trait<Output = $inner>,
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self(::core::ops::$trait::$method(self.0, rhs.0))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::$assign_trait for $name
+        where
+            for<'a> $inner: ::core::ops::$assign_trait,
+        {
+            fn $assign_method(&mut self, rhs: Self) {
+                ::core::ops::$assign_trait::$assign_method(&mut self.0, rhs.0)
+            }
+        }
+    };
+
+    (impl(is_known) int $inner:ty [ $( [ $( $attr:tt )* ] $field:ident )* ]) => {
+        /// Returns whether the current value corresponds to one of the declared
+        /// variants of this enum.
+        #[allow(deprecated)]
+        pub const fn is_known(&self) -> bool {
+            $(
+                ($crate::__c_enum_impl!(impl(cfgs) [ $( $attr )* ] [] (cfg_pred))
+                    && self.0 == Self::$field.0) ||
+            )* self.__c_enum_ext_label().is_some()
+        }
+    };
+    // The `for<'a>` here prevents the bound from being checked unless the
+    // method is actually used, so the inner type doesn't need to implement
+    // `PartialEq`.
+    (impl(is_known) $kind:ident $inner:ty [ $( [ $( $attr:tt )* ] $field:ident )* ]) => {
+        /// Returns whether the current value corresponds to one of the declared
+        /// variants of this enum.
+        #[allow(deprecated)]
+        pub fn is_known(&self) -> bool
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            $(
+                ($crate::__c_enum_impl!(impl(cfgs) [ $( $attr )* ] [] (cfg_pred))
+                    && $crate::__c_enum_impl!(impl(eq $kind) self.0, Self::$field.0)) ||
+            )* self.__c_enum_ext_label().is_some()
+        }
+    };
+
+    (impl(index) int $inner:ty) => {
+        /// Returns the index of the current value within `variants()`, or `None`
+        /// if it does not correspond to a declared variant.
+        ///
+        /// If multiple variants share the same value then the index of the
+        /// first one is returned.
+        pub const fn variant_index(&self) -> ::core::option::Option<usize> {
+            let mut index = 0;
+            while index < Self::__C_ENUM_VALUES.len() {
+                if Self::__C_ENUM_VALUES[index] == self.0 {
+                    return ::core::option::Option::Some(index);
+                }
+                index += 1;
+            }
+
+            ::core::option::Option::None
+        }
+
+        /// Returns the declared variant at `index` within `variants()`, or
+        /// `None` if `index` is out of bounds.
+        pub const fn from_index(index: usize) -> ::core::option::Option<Self> {
+            if index < Self::__C_ENUM_VALUES.len() {
+                ::core::option::Option::Some(Self(Self::__C_ENUM_VALUES[index]))
+            } else {
+                ::core::option::Option::None
+            }
+        }
+    };
+    (impl(index) $kind:ident $inner:ty) => {
+        /// Returns the index of the current value within `variants()`, or `None`
+        /// if it does not correspond to a declared variant.
+        ///
+        /// If multiple variants share the same value then the index of the
+        /// first one is returned.
+        pub fn variant_index(&self) -> ::core::option::Option<usize>
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            Self::__C_ENUM_VALUES
+                .iter()
+                .position(|value| $crate::__c_enum_impl!(impl(eq $kind) *value, self.0))
+        }
+
+        /// Returns the declared variant at `index` within `variants()`, or
+        /// `None` if `index` is out of bounds.
+        pub fn from_index(index: usize) -> ::core::option::Option<Self>
+        where
+            for<'a> $inner: ::core::clone::Clone,
+        {
+            Self::__C_ENUM_VALUES.get(index).cloned().map(Self)
+        }
+    };
+
+    // The items for #[category = ...] and #[meta(...)] are only generated if
+    // at least one variant uses the attribute. This is checked by
+    // __c_enum_attr_flags!.
+    (impl(attr_items [ $( $cat:tt )? ] [ $( $meta:tt )? ]) $args:tt) => {
+        $( $crate::__c_enum_impl!(impl(category_items $cat) $args); )?
+        $( $crate::__c_enum_impl!(impl(meta_items $meta) $args); )?
+    };
+    (
+        impl(category_items yes)
+        (
+            $kind:ident $opts:tt $name:ident [ $inner:ty ] [ $( #[$iattr:meta] )* ]
+            { $( [ $( #[ $( $field_attr:tt )* ] )* ] $field:ident, )* }
+        )
+    ) => {
+        $( #[$iattr] )*
+        impl $name {
+            /// The categories of all declared variants of this enum, in
+            /// declaration order.
+            pub const CATEGORIES: &'static [::core::option::Option<&'static str>] =
+                $crate::__c_enum_impl!(
+                    impl(filter_slice) listed $opts, ::core::option::Option<&'static str>,
+                    [
+                        $(
+                            [ $( #[ $( $field_attr )* ] )* ]
+                            $crate::__c_enum_impl!(
+                                impl(category_of) [ $( #[ $( $field_attr )* ] )* ]
+                            ),
+                        )*
+                    ]
+                );
+
+            /// Returns an iterator over all declared variants of this enum
+            /// that are in the given category, in declaration order.
+            pub fn variants_in_category(
+                category: &str
+            ) -> impl ::core::iter::Iterator<Item = &'static Self> + '_ {
+                Self::__C_ENUM_VARIANTS
+                    .iter()
+                    .zip(Self::CATEGORIES)
+                    .filter(move |(_, c)| **c == ::core::option::Option::Some(category))
+                    .map(|(variant, _)| variant)
+            }
+
+            $crate::__c_enum_impl!(impl(category) $kind $inner);
+        }
+    };
+    (
+        impl(meta_items yes)
+        (
+            $kind:ident $opts:tt $name:ident [ $inner:ty ] [ $( #[$iattr:meta] )* ]
+            { $( [ $( #[ $( $field_attr:tt )* ] )* ] $field:ident, )* }
+        )
+    ) => {
+        $( #[$iattr] )*
+        impl $name {
+            /// The metadata attached to all declared variants of this enum
+            /// using `#[meta(...)]`, in declaration order.
+            pub const METADATA: &'static [&'static [(&'static str, &'static str)]] =
+                $crate::__c_enum_impl!(
+                    impl(filter_slice) listed $opts, &'static [(&'static str, &'static str)],
+                    [
+                        $(
+                            [ $( #[ $( $field_attr )* ] )* ]
+                            $crate::__c_enum_impl!(
+                                impl(meta_of) [ $( #[ $( $field_attr )* ] )* ]
+                            ),
+                        )*
+                    ]
+                );
+
+            /// Returns the metadata value with the given key for the declared
+            /// variant corresponding to the current value, if there is one.
+            pub fn meta(&self, key: &str) -> ::core::option::Option<&'static str>
+            where
+                for<'a> $inner: ::core::cmp::PartialEq,
+            {
+                let index = self.variant_index()?;
+
+                Self::METADATA[index]
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, value)| *value)
+            }
+        }
+    };
+
+    (impl(category) int $inner:ty) => {
+        /// Returns the category of the declared variant corresponding to the
+        /// current value, if it has one.
+        pub const fn category(&self) -> ::core::option::Option<&'static str> {
+            match self.variant_index() {
+                ::core::option::Option::Some(index) => Self::CATEGORIES[index],
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        }
+    };
+    (impl(category) $kind:ident $inner:ty) => {
+        /// Returns the category of the declared variant corresponding to the
+        /// current value, if it has one.
+        pub fn category(&self) -> ::core::option::Option<&'static str>
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            self.variant_index().and_then(|index| Self::CATEGORIES[index])
+        }
+    };
+
+    // Hidden items used by `c_enum_extend!`. Extension variants are declared
+    // using the same kind of values as the variants of the enum itself and are
+    // numbered the same way.
+    (impl(ext_support) $kind:ident $inner:ty, $opts:tt) => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub(crate) const fn __c_enum_decl(
+            value: $crate::__c_enum_impl!(impl(decl_ty $kind) $inner)
+        ) -> $inner {
+            $crate::__c_enum_impl!(impl(to_inner $kind [ $inner ]) value)
+        }
+
+        $crate::__c_enum_impl!(impl(ext_next $kind $inner, $opts));
+        $crate::__c_enum_impl!(impl(ext_lookup $kind $inner, $opts) $opts);
+    };
+
+    // The type used to declare the value of a variant.
+    (impl(decl_ty nonzero) $inner:ty) => { <$inner as $crate::__private::Primitive>::Primitive };
+    (impl(decl_ty endian) $inner:ty) => { <$inner as $crate::__private::Primitive>::Primitive };
+    (impl(decl_ty $kind:ident) $inner:ty) => { $inner };
+
+    // Extension variants can only be numbered automatically if the next value
+    // can be computed in a const fn.
+    (impl(ext_next int $inner:ty, $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(ext_next_fn int $inner, $opts));
+    };
+    (impl(ext_next wrapping $inner:ty, $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(ext_next_fn wrapping $inner, $opts));
+    };
+    (impl(ext_next nonzero $inner:ty, $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(ext_next_fn nonzero $inner, $opts));
+    };
+    (impl(ext_next endian $inner:ty, $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(ext_next_fn endian $inner, $opts));
+    };
+    (impl(ext_next $kind:ident $inner:ty, $opts:tt)) => {};
+    (impl(ext_next_fn $kind:ident $inner:ty, $opts:tt)) => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub(crate) const fn __c_enum_next(self) -> $crate::__c_enum_impl!(impl(decl_ty $kind) $inner) {
+            $crate::__c_enum_impl!(impl(next $kind $opts (self)))
+        }
+    };
+
+    // The value the next variant would get by default. This is what enums
+    // using #[c_enum(continue_from = ...)] start from.
+    (impl(next_const str $inner:ty) $next:expr) => {};
+    (impl(next_const other $inner:ty) $next:expr) => {};
+    (impl(next_const ext $inner:ty) $next:expr) => {};
+    (impl(next_const $kind:ident $inner:ty) $next:expr) => {
+        #[doc(hidden)]
+        #[allow(dead_code, deprecated)]
+        pub(crate) const __C_ENUM_NEXT: $crate::__c_enum_impl!(impl(decl_ty $kind) $inner) = $next;
+    };
+
+    // Look up values and names within the extensions listed using
+    // #[c_enum(extensions = (...))].
+    (
+        impl(ext_lookup $kind:ident $inner:ty, $opts:tt)
+        [ extensions = ( $( $ext:path ),* $(,)? ), $( $rest:tt )* ]
+    ) => {
+        $crate::__c_enum_impl!(impl(ext_lookup_fns $kind $inner, $opts) [ $( $ext ),* ]);
+    };
+    (
+        impl(ext_lookup $kind:ident $inner:ty, $opts:tt)
+        [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]
+    ) => {
+        $crate::__c_enum_impl!(impl(ext_lookup $kind $inner, $opts) [ $( $rest )* ]);
+    };
+    (impl(ext_lookup $kind:ident $inner:ty, $opts:tt) []) => {
+        $crate::__c_enum_impl!(impl(ext_lookup_fns $kind $inner, $opts) []);
+    };
+    (impl(ext_lookup_fns int $inner:ty, $opts:tt) [ $( $ext:path ),* ]) => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub(crate) const fn __c_enum_ext_label(&self) -> ::core::option::Option<&'static str> {
+            $(
+                let entries = <$ext>::ENTRIES;
+                let mut index = 0;
+                while index < entries.len() {
+                    if (entries[index].1).0 == self.0 {
+                        return ::core::option::Option::Some(entries[index].0);
+                    }
+                    index += 1;
+                }
+            )*
+
+            ::core::option::Option::None
+        }
+
+        $crate::__c_enum_impl!(impl(ext_from_name $opts) [ $( $ext ),* ]);
+    };
+    (impl(ext_lookup_fns $kind:ident $inner:ty, $opts:tt) [ $( $ext:path ),* ]) => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub(crate) fn __c_enum_ext_label(&self) -> ::core::option::Option<&'static str>
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            $(
+                for (label, value) in <$ext>::ENTRIES {
+                    if $crate::__c_enum_impl!(impl(eq $kind) value.0, self.0) {
+                        return ::core::option::Option::Some(*label);
+                    }
+                }
+            )*
+
+            ::core::option::Option::None
+        }
+
+        $crate::__c_enum_impl!(impl(ext_from_name $opts) [ $( $ext ),* ]);
+    };
+    (impl(ext_from_name $opts:tt) [ $( $ext:path ),* ]) => {
+        #[doc(hidden)]
+        #[allow(dead_code, unused_variables)]
+        pub(crate) fn __c_enum_ext_from_name(name: &str) -> ::core::option::Option<Self> {
+            $(
+                if let ::core::option::Option::Some(value) =
+                    <$ext>::__c_enum_from_name(name, $crate::__c_enum_impl!(impl(name_eq) $opts))
+                {
+                    return ::core::option::Option::Some(value);
+                }
+            )*
+
+            ::core::option::Option::None
+        }
+    };
+
+    // Expands to whether `$value` is within the range declared for a variant
+    // using `start..=end` or `start..end`, which the pre-pass in `c_enum!`
+    // turns into #[c_enum(range ..= end)] or #[c_enum(range .. end)]. This is
+    // always false for variants without a range, and for enums without an
+    // integer inner type, where `impl(range_check)` reports an error instead.
+    (impl(range_guard) $kind:ident [] $field:ident $value:ident) => { false };
+    (
+        impl(range_guard) int [ #[c_enum(range ..= $( $end:tt )+)] $( $rest:tt )* ]
+        $field:ident $value:ident
+    ) => {
+        ($crate::__c_enum_impl!(impl(cfgs) [ $( $rest )* ] [] (cfg_pred))
+            && Self::$field.0 <= *$value
+            && *$value <= ($( $end )+))
+    };
+    (
+        impl(range_guard) int [ #[c_enum(range .. $( $end:tt )+)] $( $rest:tt )* ]
+        $field:ident $value:ident
+    ) => {
+        ($crate::__c_enum_impl!(impl(cfgs) [ $( $rest )* ] [] (cfg_pred))
+            && Self::$field.0 <= *$value
+            && *$value < ($( $end )+))
+    };
+    (
+        impl(range_guard) $kind:ident [ #[cfg $pred:tt] $( $rest:tt )* ]
+        $field:ident $value:ident
+    ) => {
+        (::core::cfg! $pred
+            && $crate::__c_enum_impl!(impl(range_guard) $kind [ $( $rest )* ] $field $value))
+    };
+    (
+        impl(range_guard) $kind:ident [ #[c_enum(range $( $args:tt )*)] $( $rest:tt )* ]
+        $field:ident $value:ident
+    ) => {
+        false
+    };
+    (
+        impl(range_guard) $kind:ident [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $field:ident $value:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(range_guard) $kind [ $( $rest )* ] $field $value)
+    };
+    (
+        impl(range_guard) $kind:ident [ #[ $( $attr:tt )* ] $( $rest:tt )* ]
+        $field:ident $value:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(range_guard) $kind [ $( $rest )* ] $field $value)
+    };
+
+    // Expands to whether a variant was declared with a range.
+    (impl(ranged) $opts:tt []) => { false };
+    (impl(ranged) $opts:tt [ #[c_enum(range $( $args:tt )*)] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(cfgs) [ $( $rest )* ] [] (cfg_pred))
+    };
+    (impl(ranged) $opts:tt [ #[cfg $pred:tt] $( $rest:tt )* ]) => {
+        ::core::cfg! $pred && $crate::__c_enum_impl!(impl(ranged) $opts [ $( $rest )* ])
+    };
+    (impl(ranged) $opts:tt [ #[ $( $attr:tt )* ] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(ranged) $opts [ $( $rest )* ])
+    };
+
+    // Checks that the range declared for a variant isn't empty, so that the end
+    // of a `start..end` range can't underflow when it is made inclusive.
+    (impl(range_check) $kind:ident $name:ident $field:ident $attrs:tt []) => {};
+    (
+        impl(range_check) int $name:ident $field:ident $attrs:tt
+        [ #[c_enum(range ..= $( $end:tt )+)] $( $rest:tt )* ]
+    ) => {
+        #[allow(deprecated)]
+        const _: () = ::core::assert!(
+            !$crate::__c_enum_impl!(impl(cfgs) $attrs [] (cfg_pred))
+                || $name::$field.0 <= ($( $end )+),
+            "c_enum: the range of a variant must not be empty"
+        );
+    };
+    (
+        impl(range_check) int $name:ident $field:ident $attrs:tt
+        [ #[c_enum(range .. $( $end:tt )+)] $( $rest:tt )* ]
+    ) => {
+        #[allow(deprecated)]
+        const _: () = ::core::assert!(
+            !$crate::__c_enum_impl!(impl(cfgs) $attrs [] (cfg_pred))
+                || $name::$field.0 < ($( $end )+),
+            "c_enum: the range of a variant must not be empty"
+        );
+    };
+    (
+        impl(range_check) $kind:ident $name:ident $field:ident $attrs:tt
+        [ #[c_enum(range $( $args:tt )*)] $( $rest:tt )* ]
+    ) => {
+        ::core::compile_error!("ranges are only supported for enums with integer inner types");
+    };
+    (
+        impl(range_check) $kind:ident $name:ident $field:ident $attrs:tt [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ]
+    ) => {
+        $crate::__c_enum_impl!(impl(range_check) $kind $name $field $attrs [ $( $rest )* ]);
+    };
+    (
+        impl(range_check) $kind:ident $name:ident $field:ident $attrs:tt
+        [ #[ $( $attr:tt )* ] $( $rest:tt )* ]
+    ) => {
+        $crate::__c_enum_impl!(impl(range_check) $kind $name $field $attrs [ $( $rest )* ]);
+    };
+
+    // Expands to the range declared for a variant as a `RangeInclusive`.
+    // Variants without a range get a range containing only their value.
+    (impl(range_of) [] $start:expr) => {
+        ::core::ops::RangeInclusive::new($start.0, $start.0)
+    };
+    (impl(range_of) [ #[c_enum(range ..= $( $end:tt )+)] $( $rest:tt )* ] $start:expr) => {
+        ::core::ops::RangeInclusive::new($start.0, $( $end )+)
+    };
+    (impl(range_of) [ #[c_enum(range .. $( $end:tt )+)] $( $rest:tt )* ] $start:expr) => {
+        // Empty ranges are reported by `impl(range_check)`, this just avoids
+        // an additional error from the subtraction underflowing.
+        ::core::ops::RangeInclusive::new(
+            $start.0,
+            if $start.0 < ($( $end )+) { ($( $end )+) - 1 } else { $start.0 },
+        )
+    };
+    (impl(range_of) [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $start:expr) => {
+        $crate::__c_enum_impl!(impl(range_of) [ $( $rest )* ] $start)
+    };
+
+    // Expands to the metadata declared for a variant as a slice of key-value
+    // pairs.
+    (impl(meta_of) []) => { &[] };
+    (
+        impl(meta_of)
+        [ #[meta( $( $key:ident = $value:tt ),* $(,)? )] $( $rest:tt )* ]
+    ) => {
+        &[ $( (::core::stringify!($key), $value), )* ]
+    };
+    (
+        impl(meta_of) [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ]
+    ) => {
+        $crate::__c_enum_impl!(impl(meta_of) [ $( $rest )* ])
+    };
+    (impl(meta_of) [ #[ $( $attr:tt )* ] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(meta_of) [ $( $rest )* ])
+    };
+
+    // Emits the method requested for a variant using #[predicate = is_x], if
+    // there is one.
+    (impl(predicate) $kind:ident $inner:ty [] $field:ident) => {};
+    (
+        impl(predicate) int $inner:ty [ #[predicate = $pred:ident] $( $rest:tt )* ]
+        $field:ident
+    ) => {
+        #[doc = ::core::concat!(
+            "Returns whether the current value is [`", ::core::stringify!($field),
+            "`](Self::", ::core::stringify!($field), ")."
+        )]
+        #[allow(deprecated)]
+        pub const fn $pred(&self) -> bool {
+            let value = &self.0;
+
+            *value == Self::$field.0
+                || $crate::__c_enum_impl!(
+                    impl(range_guard) int [ $( $rest )* ] $field value
+                )
+        }
+    };
+    (
+        impl(predicate) $kind:ident $inner:ty [ #[predicate = $pred:ident] $( $rest:tt )* ]
+        $field:ident
+    ) => {
+        #[doc = ::core::concat!(
+            "Returns whether the current value is [`", ::core::stringify!($field),
+            "`](Self::", ::core::stringify!($field), ")."
+        )]
+        #[allow(deprecated)]
+        pub fn $pred(&self) -> bool
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            $crate::__c_enum_impl!(impl(eq $kind) self.0, Self::$field.0)
+        }
+    };
+    (
+        impl(predicate) $kind:ident $inner:ty [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(predicate) $kind $inner [ $( $rest )* ] $field);
+    };
+    (
+        impl(predicate) $kind:ident $inner:ty [ #[ $( $attr:tt )* ] $( $rest:tt )* ]
+        $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(predicate) $kind $inner [ $( $rest )* ] $field);
+    };
+
+    // Expands to the category of a variant with the given attributes.
+    (impl(category_of) []) => { ::core::option::Option::None };
+    (impl(category_of) [ #[category = $category:tt] $( $rest:tt )* ]) => {
+        ::core::option::Option::Some($category)
+    };
+    (
+        impl(category_of) [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ]
+    ) => {
+        $crate::__c_enum_impl!(impl(category_of) [ $( $rest )* ])
+    };
+    (impl(category_of) [ #[ $( $attr:tt )* ] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(category_of) [ $( $rest )* ])
+    };
+
+    // Expands to the message of a variant set by #[msg = "..."], falling back
+    // to its label.
+    (impl(message) $opts:tt $attrs:tt [] $field:ident) => {
+        $crate::__c_enum_impl!(impl(label) $opts $attrs $field)
+    };
+    (impl(message) $opts:tt $attrs:tt [ #[msg = $msg:tt] $( $rest:tt )* ] $field:ident) => {
+        $msg
+    };
+    (
+        impl(message) $opts:tt $attrs:tt [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(message) $opts $attrs [ $( $rest )* ] $field)
+    };
+    (impl(message) $opts:tt $attrs:tt [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $field:ident) => {
+        $crate::__c_enum_impl!(impl(message) $opts $attrs [ $( $rest )* ] $field)
+    };
+
+    // Expands to the first doc comment of a variant, falling back to its label.
+    (impl(doc_line) $opts:tt $attrs:tt [] $field:ident) => {
+        $crate::__c_enum_impl!(impl(label) $opts $attrs $field)
+    };
+    (impl(doc_line) $opts:tt $attrs:tt [ #[doc = $doc:literal] $( $rest:tt )* ] $field:ident) => {
+        $doc
+    };
+    (impl(doc_line) $opts:tt $attrs:tt [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $field:ident) => {
+        $crate::__c_enum_impl!(impl(doc_line) $opts $attrs [ $( $rest )* ] $field)
+    };
+
+    // Emit the constant for a single variant, stripping out any attributes
+    // that are handled by c_enum itself.
+    (
+        impl(variant_const)
+        [ #[default] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[meta $( $meta:tt )*] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[c_enum(range $( $range:tt )*)] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[predicate = $pred:ident] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[category = $category:tt] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[label = $label:tt] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[msg = $msg:tt] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[c_enum(skip)] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[c_enum(vis = $new:vis)] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] [ $new ] $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[c_enum(value_doc = $( $doc:tt )*)] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[alias] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[deprecated( $( $args:tt )* )] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(deprecated) [ $( $args )* ] [] []
+            [ $( $rest )* ] [ $( $attrs )* ] $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const)
+        [ #[ $( $attr:tt )* ] $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* #[ $( $attr )* ] ]
+            $vis $field = $value
+        );
+    };
+    (
+        impl(variant_const) [] [ $( $attrs:tt )* ] [ $vis:vis ]
+        $field:ident = $value:expr
+    ) => {
+        $( $attrs )*
+        #[allow(non_upper_case_globals)]
+        $vis const $field: Self = Self::new($value);
+    };
+
+    // Rebuild a #[deprecated(...)] attribute without `replaced_by`, which rustc
+    // doesn't understand, and mention the replacement in the docs instead.
+    (
+        impl(deprecated) [ replaced_by = $new:ident $( , $( $args:tt )* )? ]
+        [ $( $kept:tt )* ] [ $( $docs:tt )* ] $( $tail:tt )*
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(deprecated) [ $( $( $args )* )? ] [ $( $kept )* ]
+            [
+                $( $docs )*
+                #[doc = ""]
+                #[doc = ::core::concat!(
+                    "Use [`", ::core::stringify!($new), "`](Self::",
+                    ::core::stringify!($new), ") instead."
+                )]
+            ]
+            $( $tail )*
+        );
+    };
+    (
+        impl(deprecated) [ $key:ident = $value:tt $( , $( $args:tt )* )? ]
+        [ $( $kept:tt )* ] $docs:tt $( $tail:tt )*
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(deprecated) [ $( $( $args )* )? ] [ $( $kept )* $key = $value, ]
+            $docs $( $tail )*
+        );
+    };
+    (
+        impl(deprecated) [] [] [ $( $docs:tt )* ] [ $( $rest:tt )* ] [ $( $attrs:tt )* ] $vis:tt
+        $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ] [ $( $attrs )* $( $docs )* #[deprecated] ]
+            $field = $value
+        );
+    };
+    (
+        impl(deprecated) [] [ $( $kept:tt )+ ] [ $( $docs:tt )* ] [ $( $rest:tt )* ]
+        [ $( $attrs:tt )* ] $vis:tt $field:ident = $value:expr
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(variant_const) [ $( $rest )* ]
+            [ $( $attrs )* $( $docs )* #[deprecated( $( $kept )+ )] ]
+            $vis $field = $value
+        );
+    };
+
+    // Collects the predicates of all #[cfg(...)] attributes on a variant and
+    // passes them on to `impl($rule)`. Variants without any #[cfg] attributes
+    // get an empty list.
+    (impl(cfgs) [] [ $( $preds:tt )* ] ($rule:ident $( $args:tt )*)) => {
+        $crate::__c_enum_impl! { impl($rule $( $args )*) [ $( $preds )* ] }
+    };
+    (
+        impl(cfgs) [ #[cfg( $( $pred:tt )* )] $( $rest:tt )* ] [ $( $preds:tt )* ]
+        $call:tt
+    ) => {
+        $crate::__c_enum_impl! {
+            impl(cfgs) [ $( $rest )* ] [ $( $preds )* ( $( $pred )* ) ] $call
+        }
+    };
+    (
+        impl(cfgs) [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $preds:tt $call:tt
+    ) => {
+        $crate::__c_enum_impl! { impl(cfgs) [ $( $rest )* ] $preds $call }
+    };
+    (impl(cfgs) [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $preds:tt $call:tt) => {
+        $crate::__c_enum_impl! { impl(cfgs) [ $( $rest )* ] $preds $call }
+    };
+
+    // Whether a variant is present given its #[cfg] predicates.
+    (impl(cfg_pred) []) => { true };
+    (impl(cfg_pred) [ $( ( $( $pred:tt )* ) )+ ]) => {
+        ::core::cfg!(all( $( $( $pred )* ),+ ))
+    };
+
+    // The default value of the first variant. Enums using
+    // #[c_enum(continue_from = ...)] start where the other enum left off.
+    // Otherwise, non-zero integers and enums using #[c_enum(bits)] start at 1
+    // instead of 0.
+    (impl(first $kind:ident $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(first_from $kind $opts $opts))
+    };
+    (impl(first_from $kind:ident [ continue_from = $prev:ident, $( $rest:tt )* ] $opts:tt)) => {
+        <$prev>::__C_ENUM_NEXT
+    };
+    (
+        impl(first_from $kind:ident [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $opts:tt)
+    ) => {
+        $crate::__c_enum_impl!(impl(first_from $kind [ $( $rest )* ] $opts))
+    };
+    (impl(first_from $kind:ident [] $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(first_mode $kind $opts))
+    };
+    (impl(first_mode $kind:ident [ bits, $( $rest:tt )* ])) => {
+        $crate::__c_enum_impl!(impl(first_bit $kind))
+    };
+    (impl(first_mode $kind:ident [ $key:tt $( = $value:tt )?, $( $rest:tt )* ])) => {
+        $crate::__c_enum_impl!(impl(first_mode $kind [ $( $rest )* ]))
+    };
+    (impl(first_mode wrapping [])) => { ::core::num::Wrapping(0) };
+    (impl(first_mode nonzero [])) => { 1 };
+    (impl(first_mode float [])) => { 0.0 };
+    (impl(first_mode str [])) => { "" };
+    (impl(first_mode $kind:ident [])) => { 0 };
+    (impl(first_bit wrapping)) => { ::core::num::Wrapping(1) };
+    (impl(first_bit float)) => { $crate::__c_enum_impl!(impl(bits_error)) };
+    (impl(first_bit str)) => { $crate::__c_enum_impl!(impl(bits_error)) };
+    (impl(first_bit $kind:ident)) => { 1 };
+    (impl(bits_error)) => {
+        ::core::compile_error!("`#[c_enum(bits)]` requires an integer inner type")
+    };
+    (impl(ext_first)) => {
+        ::core::compile_error!("the first variant of a `c_enum_extend!` needs an explicit value")
+    };
+
+    // The documentation for the default values of variants.
+    (impl(first_doc $kind:ident $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(first_doc_from $kind $opts $opts))
+    };
+    (
+        impl(first_doc_from $kind:ident
+            [ continue_from = $prev:ident, $( $rest:tt )* ] $opts:tt)
+    ) => {
+        ::core::concat!("after ", ::core::stringify!($prev))
+    };
+    (
+        impl(first_doc_from $kind:ident
+            [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $opts:tt)
+    ) => {
+        $crate::__c_enum_impl!(impl(first_doc_from $kind [ $( $rest )* ] $opts))
+    };
+    (impl(first_doc_from $kind:ident [] $opts:tt)) => {
+        $crate::__c_enum_impl!(impl(first_doc_mode $kind $opts))
+    };
+    (impl(first_doc_mode $kind:ident [ bits, $( $rest:tt )* ])) => { "1" };
+    (impl(first_doc_mode $kind:ident [ $key:tt $( = $value:tt )?, $( $rest:tt )* ])) => {
+        $crate::__c_enum_impl!(impl(first_doc_mode $kind [ $( $rest )* ]))
+    };
+    (impl(first_doc_mode nonzero [])) => { "1" };
+    (impl(first_doc_mode $kind:ident [])) => { "0" };
+    (impl(next_doc [ bits, $( $rest:tt )* ] $field:ident)) => {
+        ::core::concat!("(", ::core::stringify!($field), " + 1).next_power_of_two()")
+    };
+    (impl(next_doc [ step = $step:tt, $( $rest:tt )* ] $field:ident)) => {
+        ::core::concat!(::core::stringify!($field), " + ", ::core::stringify!($step))
+    };
+    (impl(next_doc [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $field:ident)) => {
+        $crate::__c_enum_impl!(impl(next_doc [ $( $rest )* ] $field))
+    };
+    (impl(next_doc [] $field:ident)) => {
+        ::core::concat!(::core::stringify!($field), " + 1")
+    };
+
+    // The default value of the variant following `$prev`. Variants removed by
+    // #[cfg] are numbered as if they were present so that the values of the
+    // following variants are the same on every target. The explicit value of
+    // a removed variant may not compile on the targets that remove it though,
+    // so the variant following it needs an explicit value as well.
+    (impl(cfg_next $kind:ident $opts:tt $prev:ident [ $value:tt ]) [ $( $preds:tt )+ ]) => {
+        ::core::panic!(::core::concat!(
+            "the variant following `", ::core::stringify!($prev),
+            "` needs an explicit value since `", ::core::stringify!($prev),
+            "` has an explicit value and is removed by #[cfg] on some targets"
+        ))
+    };
+    (impl(cfg_next $kind:ident $opts:tt $prev:ident $value:tt) $preds:tt) => {
+        $crate::__c_enum_impl!(impl(next $kind $opts (Self::$prev)))
+    };
+
+    // The default value of the variant following `$prev`, which is a
+    // parenthesized expression such as `(Self::FIELD)`.
+    //
+    // With #[c_enum(bits)] the default value is the next bit above the value
+    // of `$prev` instead, and with #[c_enum(step = ...)] the step is added
+    // instead of 1.
+    //
+    // Extension variants defer to the enum being extended since the options
+    // and inner type aren't known by `c_enum_extend!`.
+    (impl(next ext $opts:tt $prev:tt)) => {
+        $prev.__c_enum_next()
+    };
+    (impl(next $kind:ident [ bits, $( $rest:tt )* ] $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_bit $kind $prev))
+    };
+    (impl(next $kind:ident [ step = $step:tt, $( $rest:tt )* ] $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_step $kind $prev $step))
+    };
+    (impl(next $kind:ident [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next $kind [ $( $rest )* ] $prev))
+    };
+    (impl(next float [] $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_step float $prev 1.0))
+    };
+    (impl(next $kind:ident [] $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_step $kind $prev 1))
+    };
+    (impl(next_step wrapping $prev:tt $step:tt)) => {
+        ::core::num::Wrapping(($prev.0).0.wrapping_add($step))
+    };
+    (impl(next_step nonzero $prev:tt $step:tt)) => { $prev.0.get() + $step };
+    (impl(next_step endian $prev:tt $step:tt)) => { $prev.0.get() + $step };
+    (impl(next_step str $prev:tt $step:tt)) => { "" };
+    (impl(next_step $kind:ident $prev:tt $step:tt)) => { $prev.0 + $step };
+    (impl(next_bit wrapping $prev:tt)) => {
+        ::core::num::Wrapping($crate::__c_enum_impl!(impl(next_pow2 ($prev.0).0)))
+    };
+    (impl(next_bit nonzero $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_pow2 $prev.0.get()))
+    };
+    (impl(next_bit endian $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_pow2 $prev.0.get()))
+    };
+    (impl(next_bit float $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(bits_error))
+    };
+    (impl(next_bit str $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(bits_error))
+    };
+    (impl(next_bit $kind:ident $prev:tt)) => {
+        $crate::__c_enum_impl!(impl(next_pow2 $prev.0))
+    };
+    // The smallest power of two above `$prev`. The number of bits in the type
+    // is computed using `count_ones()` and `count_zeros()` since the type
+    // itself isn't known here.
+    (impl(next_pow2 $prev:expr)) => {
+        1 << ($prev.count_ones() + $prev.count_zeros() - $prev.leading_zeros())
+    };
+
+    // Converts the value of a variant into the inner type. Variants of
+    // non-zero and endian enums are declared using plain integers. Non-zero
+    // values are also checked here.
+    (impl(to_inner nonzero [ $inner:ty ]) $value:expr) => {
+        match <$inner>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            // Indexing out of bounds is a compile error in a const context.
+            // Unlike `panic!` this also works on older compilers.
+            ::core::option::Option::None => {
+                let variant_value_must_be_non_zero: [$inner; 0] = [];
+                variant_value_must_be_non_zero[0]
+            }
+        }
+    };
+    (impl(to_inner endian [ $inner:ty ]) $value:expr) => { <$inner>::new($value) };
+    (impl(to_inner ext [ $inner:ty ]) $value:expr) => { <$inner>::__c_enum_decl($value) };
+    (impl(to_inner $kind:ident [ $inner:ty ]) $value:expr) => { $value };
+
+    // The value of a variant without an explicit value. Variants of string
+    // enums use their label while everything else is numbered automatically.
+    (impl(default_value str $opts:tt $attrs:tt $field:ident) $default:expr) => {
+        $crate::__c_enum_impl!(impl(label) $opts $attrs $field)
+    };
+    (impl(default_value $kind:ident $opts:tt $attrs:tt $field:ident) $default:expr) => {
+        $default
+    };
+
+    // The documentation line listing the value of a variant. Values that were
+    // rewritten by `impl(cfg_value)` are listed as they were written.
+    (
+        impl(value_doc $kind:ident) [
+            #[c_enum(
+                value_doc = $value:tt $( :: $path:ident )*
+                $( ..= $iend:tt $( :: $ipath:ident )* )?
+                $( .. $hend:tt $( :: $hpath:ident )* )?
+            )]
+            $( $rest:tt )*
+        ] $written:tt $default:expr
+    ) => {
+        ::core::concat!(
+            "Value: `", ::core::stringify!($value),
+            $( "::", ::core::stringify!($path), )*
+            $( "..=", ::core::stringify!($iend), $( "::", ::core::stringify!($ipath), )* )?
+            $( "..", ::core::stringify!($hend), $( "::", ::core::stringify!($hpath), )* )?
+            "`"
+        )
+    };
+    (
+        impl(value_doc $kind:ident) [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $written:tt $default:expr
+    ) => {
+        $crate::__c_enum_impl!(impl(value_doc $kind) [ $( $rest )* ] $written $default)
+    };
+    (
+        impl(value_doc $kind:ident) [ #[ $( $attr:tt )* ] $( $rest:tt )* ]
+        $written:tt $default:expr
+    ) => {
+        $crate::__c_enum_impl!(impl(value_doc $kind) [ $( $rest )* ] $written $default)
+    };
+    (impl(value_doc $kind:ident) [] [ $value:expr ] $default:expr) => {
+        ::core::concat!("Value: `", $value, "`")
+    };
+    (impl(value_doc str) [] [] $default:expr) => { "Value: the label of this variant" };
+    (impl(value_doc ext) [] [] $default:expr) => {
+        "Value: the value following the previous variant"
+    };
+    (impl(value_doc $kind:ident) [] [] $default:expr) => {
+        ::core::concat!("Value: `", $default, "`")
+    };
+
+    // The value of a variant as passed to `c_enum!`. A list of values which
+    // are each marked with #[cfg(...)] selects the first one whose predicate
+    // holds, optionally followed by a value without #[cfg] to use otherwise.
+    (impl(cfg_value) { #[cfg $( $pred:tt )*] $( $rest:tt )* }) => {
+        $crate::__c_enum_impl!(
+            impl(cfg_values) value [] [] { #[cfg $( $pred )*] $( $rest )* }
+        )
+    };
+    (impl(cfg_value) $( $value:tt )*) => { $( $value )* };
+
+    (
+        impl(cfg_values) $var:ident [ $( ( $( $prev:tt )* ) )* ] [ $( $lets:tt )* ]
+        { #[cfg( $( $pred:tt )* )] $value:expr $( , $( $rest:tt )* )? }
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(cfg_values) $var [ $( ( $( $prev )* ) )* ( $( $pred )* ) ]
+            [
+                $( $lets )*
+                #[cfg(all( $( $pred )*, not(any( $( $( $prev )* ),* )) ))]
+                let $var = $value;
+            ]
+            { $( $( $rest )* )? }
+        )
+    };
+    (
+        impl(cfg_values) $var:ident [ $( ( $( $prev:tt )* ) )* ] [ $( $lets:tt )* ]
+        { $value:expr $(,)? }
+    ) => {{
+        $( $lets )*
+        #[cfg(not(any( $( $( $prev )* ),* )))]
+        let $var = $value;
+        $var
+    }};
+    (impl(cfg_values) $var:ident [ $( ( $( $prev:tt )* ) )* ] [ $( $lets:tt )* ] {}) => {{
+        $( $lets )*
+        #[cfg(not(any( $( $( $prev )* ),* )))]
+        let $var = ::core::compile_error!(
+            "none of the #[cfg] predicates for this value apply to the current target"
+        );
+        $var
+    }};
+    (impl(cfg_values) $var:ident $prev:tt $lets:tt { $( $rest:tt )* }) => {
+        ::core::compile_error!(
+            "expected a list of values each marked with #[cfg(...)], optionally followed \
+             by a single value without #[cfg]"
+        )
+    };
+
+    // Compares two inner values when looking up the declared variant for a
+    // value. Floats are compared by their bit pattern so that NaN variants can
+    // still be found.
+    (impl(eq float) $lhs:expr, $rhs:expr) => { ($lhs).to_bits() == ($rhs).to_bits() };
+    (impl(eq $kind:ident) $lhs:expr, $rhs:expr) => { $lhs == $rhs };
+
+    // Emits an item only if the variant is present.
+    (impl(cfg_item { $( $item:tt )* }) []) => { $( $item )* };
+    (impl(cfg_item { $( $item:tt )* }) [ $( ( $( $pred:tt )* ) )+ ]) => {
+        #[cfg(all( $( $( $pred )* ),+ ))]
+        $( $item )*
+    };
+
+    // Rejects variants with #[cfg] attributes for #[c_enum(known = ...)].
+    (impl(known_cfg $known:ident $field:ident) []) => {};
+    (impl(known_cfg $known:ident $field:ident) [ $( $preds:tt )+ ]) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($field), "` can't have a #[cfg] attribute since ",
+            "`#[c_enum(known = ", ::core::stringify!($known), ")]` doesn't support ",
+            "variants removed by #[cfg]"
+        ));
+    };
+
+    // Declares the fallback constant for a variant if it has been removed.
+    (impl(cfg_fallback_decl $name:ident $field:ident) []) => {};
+    (impl(cfg_fallback_decl $name:ident $field:ident) [ $( ( $( $pred:tt )* ) )+ ]) => {
+        #[cfg(not(all( $( $( $pred )* ),+ )))]
+        const $field: $name;
+    };
+
+    // Defines the fallback constant for a variant if it has been removed.
+    // Variants of enums with automatic numbering use their default value in
+    // its place, which the following variant is numbered from. The explicit
+    // value of a removed variant may not compile on this target, so it's never
+    // used here.
+    (
+        impl(cfg_fallback $kind:ident $name:ident $inner:tt $field:ident $default:tt $value:tt)
+        []
+    ) => {};
+    (
+        impl(cfg_fallback ext $name:ident [ $inner:ty ] $field:ident
+            [ $default:expr ] [ $( $value:expr )? ])
+        [ $( ( $( $pred:tt )* ) )+ ]
+    ) => {
+        #[cfg(not(all( $( $( $pred )* ),+ )))]
+        const $field: $name = <$name>::new($crate::__c_enum_impl!(
+            impl(to_inner ext [ $inner ]) $crate::__c_enum_impl!(
+                impl(first_expr) $( $value, )? $default
+            )
+        ));
+    };
+    (
+        impl(cfg_fallback other $name:ident [ $inner:ty ] $field:ident
+            [ $default:expr ] [ $( $value:expr )? ])
+        [ $( ( $( $pred:tt )* ) )+ ]
+    ) => {
+        #[cfg(not(all( $( $( $pred )* ),+ )))]
+        const $field: $name = $name($crate::__c_enum_impl!(
+            impl(first_expr) $( $value, )? $default
+        ));
+    };
+    (
+        impl(cfg_fallback $kind:ident $name:ident [ $inner:ty ] $field:ident
+            [ $default:expr ] $value:tt)
+        [ $( ( $( $pred:tt )* ) )+ ]
+    ) => {
+        #[cfg(not(all( $( $( $pred )* ),+ )))]
+        const $field: $name = $name($crate::__c_enum_impl!(
+            impl(to_inner $kind [ $inner ]) $default
+        ));
+    };
+
+    // Expands to whether a variant with the given attributes should be
+    // included in VARIANTS, LABELS, etc. Doc comments are skipped in batches to
+    // avoid hitting the recursion limit for variants with long docs.
+    (impl(listed) $opts:tt []) => { true };
+    (impl(listed) $opts:tt [ #[alias] $( $rest:tt )* ]) => { false };
+    (impl(listed) $opts:tt [ #[c_enum(skip)] $( $rest:tt )* ]) => { false };
+    (impl(listed) $opts:tt [ #[cfg $pred:tt] $( $rest:tt )* ]) => {
+        ::core::cfg! $pred && $crate::__c_enum_impl!(impl(listed) $opts [ $( $rest )* ])
+    };
+    (impl(listed) $opts:tt [ #[deprecated( $( $args:tt )* )] $( $rest:tt )* ]) => {
+        !$crate::__c_enum_impl!(impl(replaced) [ $( $args )* ])
+            && !$crate::__c_enum_impl!(impl(hide_deprecated) $opts)
+            && $crate::__c_enum_impl!(impl(listed) $opts [ $( $rest )* ])
+    };
+    (impl(listed) $opts:tt [ #[deprecated $( $args:tt )*] $( $rest:tt )* ]) => {
+        !$crate::__c_enum_impl!(impl(hide_deprecated) $opts)
+            && $crate::__c_enum_impl!(impl(listed) $opts [ $( $rest )* ])
+    };
+    (
+        impl(listed) $opts:tt [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ]
+    ) => {
+        $crate::__c_enum_impl!(impl(listed) $opts [ $( $rest )* ])
+    };
+    (impl(listed) $opts:tt [ #[ $( $attr:tt )* ] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(listed) $opts [ $( $rest )* ])
+    };
+
+    // Expands to whether a variant is deprecated and should be hidden from
+    // `from_name` because of #[c_enum(hide_deprecated)].
+    (impl(hidden) $opts:tt []) => { false };
+    (impl(hidden) $opts:tt [ #[c_enum(skip)] $( $rest:tt )* ]) => { true };
+    (impl(hidden) $opts:tt [ #[cfg $pred:tt] $( $rest:tt )* ]) => {
+        !::core::cfg! $pred || $crate::__c_enum_impl!(impl(hidden) $opts [ $( $rest )* ])
+    };
+    (impl(hidden) $opts:tt [ #[deprecated $( $args:tt )*] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(hide_deprecated) $opts)
+            || $crate::__c_enum_impl!(impl(hidden) $opts [ $( $rest )* ])
+    };
+    (
+        impl(hidden) $opts:tt [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ]
+    ) => {
+        $crate::__c_enum_impl!(impl(hidden) $opts [ $( $rest )* ])
+    };
+    (impl(hidden) $opts:tt [ #[ $( $attr:tt )* ] $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(hidden) $opts [ $( $rest )* ])
+    };
+
+    (impl(hide_deprecated) [ hide_deprecated, $( $rest:tt )* ]) => { true };
+    (impl(hide_deprecated) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(hide_deprecated) [ $( $rest )* ])
+    };
+    (impl(hide_deprecated) []) => { false };
+
+    // Pick the schema that matches the representation used by the serde
+    // options.
+    (
+        impl(schema) [ serde = "name", $( $rest:tt )* ] $serde:ident $unknown:ident
+        $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(impl(schema) [ $( $rest )* ] name $unknown $name [ $inner ]);
+    };
+    (
+        impl(schema) [ serde = "lenient", $( $rest:tt )* ] $serde:ident $unknown:ident
+        $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(impl(schema) [ $( $rest )* ] lenient $unknown $name [ $inner ]);
+    };
+    (
+        impl(schema) [ serde_unknown = "error", $( $rest:tt )* ] $serde:ident $unknown:ident
+        $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(impl(schema) [ $( $rest )* ] $serde error $name [ $inner ]);
+    };
+    (
+        impl(schema) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $serde:ident $unknown:ident
+        $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(impl(schema) [ $( $rest )* ] $serde $unknown $name [ $inner ]);
+    };
+    (impl(schema) [] value $unknown:ident $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(value $name [ $inner ]);
+    };
+    (impl(schema) [] lenient $unknown:ident $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(name_or_value $name [ $inner ]);
+    };
+    (impl(schema) [] name value $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(name_or_value $name [ $inner ]);
+    };
+    (impl(schema) [] name error $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(name $name [ $inner ]);
+    };
+
+    // Pick the policy for undeclared values used by #[c_enum(serde = "name")].
+    (
+        impl(serde_unknown) [ serde_unknown = "error", $( $rest:tt )* ]
+        $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_serde!(name error $name [ $inner ]);
+    };
+    (
+        impl(serde_unknown) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]
+        $name:ident [ $inner:ty ]
+    ) => {
+        $crate::__c_enum_impl!(impl(serde_unknown) [ $( $rest )* ] $name [ $inner ]);
+    };
+    (impl(serde_unknown) [] $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_serde!(name value $name [ $inner ]);
+    };
+
+    // Whether the arguments to #[deprecated(...)] contain `replaced_by`.
+    (impl(replaced) []) => { false };
+    (impl(replaced) [ replaced_by $( $rest:tt )* ]) => { true };
+    (impl(replaced) [ $first:tt $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(replaced) [ $( $rest )* ])
+    };
+
+    // Build a slice containing the values for all variants for which
+    // `impl($pred)` is true, in declaration order. This is unrolled instead of
+    // being done recursively so that it works for enums with lots of variants.
+    (impl(filter_slice) $pred:ident $opts:tt, $ty:ty, []) => { &[] };
+    (
+        impl(filter_slice) $pred:ident $opts:tt, $ty:ty,
+        [
+            [ $( $first_attr:tt )* ] $first:expr,
+            $( [ $( $attr:tt )* ] $value:expr, )*
+        ]
+    ) => {
+        &{
+            const LEN: usize =
+                $crate::__c_enum_impl!(impl($pred) $opts [ $( $first_attr )* ]) as usize
+                $( + $crate::__c_enum_impl!(impl($pred) $opts [ $( $attr )* ]) as usize )*;
+            const UNINIT: $ty = $first;
+
+            let mut values = [UNINIT; LEN];
+            let mut index = 0;
+
+            if $crate::__c_enum_impl!(impl($pred) $opts [ $( $first_attr )* ]) {
+                values[index] = $first;
+                index += 1;
+            }
+
+            $(
+                if $crate::__c_enum_impl!(impl($pred) $opts [ $( $attr )* ]) {
+                    values[index] = $value;
+                    index += 1;
+                }
+            )*
+
+            values
+        }
+    };
+
+    // Generate a `Default` impl if the variant is marked with `#[default]`.
+    (impl(default) [ #[default] $( $rest:tt )* ] $name:ident $field:ident) => {
+        #[automatically_derived]
+        impl ::core::default::Default for $name {
+            #[allow(deprecated)]
+            fn default() -> Self {
+                Self::$field
+            }
+        }
+    };
+    (impl(default) [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $name:ident $field:ident) => {
+        $crate::__c_enum_impl!(impl(default) [ $( $rest )* ] $name $field);
+    };
+    (impl(default) [] $name:ident $field:ident) => {};
+
+    // Inherent items that are only generated for integer inner types.
+    (
+        impl(integer_items) int $name:ident $inner:ty, $opts:tt,
+        [ $( [ $( $field_attr:tt )* ] $field:ident )* ]
+    ) => {
+        /// Returns the name of the declared variant corresponding to the
+        /// current value, if there is one.
+        ///
+        /// Unlike `as_str()`, this can be used in const contexts.
+        #[allow(deprecated)]
+        pub const fn label(&self) -> ::core::option::Option<&'static str> {
+            $(
+                if $crate::__c_enum_impl!(impl(listed) $opts [ $( $field_attr )* ])
+                    && self.0 == Self::$field.0
+                {
+                    return ::core::option::Option::Some(
+                        $crate::__c_enum_impl!(impl(label) $opts [ $( $field_attr )* ] $field)
+                    );
+                }
+            )*
+
+            let value = &self.0;
+            $(
+                if $crate::__c_enum_impl!(impl(range_guard) int [ $( $field_attr )* ] $field value) {
+                    return ::core::option::Option::Some(
+                        $crate::__c_enum_impl!(impl(label) $opts [ $( $field_attr )* ] $field)
+                    );
+                }
+            )*
+
+            self.__c_enum_ext_label()
+        }
+
+        /// The names and ranges of all variants declared with a range of
+        /// values, in declaration order.
+        #[allow(deprecated)]
+        pub const RANGES: &'static [(&'static str, ::core::ops::RangeInclusive<$inner>)] =
+            $crate::__c_enum_impl!(
+                impl(filter_slice) ranged $opts, (&'static str, ::core::ops::RangeInclusive<$inner>),
+                [
+                    $(
+                        [ $( $field_attr )* ]
+                        (
+                            $crate::__c_enum_impl!(impl(label) $opts [ $( $field_attr )* ] $field),
+                            $crate::__c_enum_impl!(impl(range_of) [ $( $field_attr )* ] $name::$field)
+                        ),
+                    )*
+                ]
+            );
+
+        /// Returns whether the current value is within the range declared for
+        /// `range`.
+        ///
+        /// If `range` was not declared with a range then this is the same as
+        /// checking whether the values are equal.
+        pub const fn in_range(&self, range: Self) -> bool {
+            let mut index = 0;
+            while index < Self::RANGES.len() {
+                let bounds = &Self::RANGES[index].1;
+                if *bounds.start() == range.0 {
+                    return *bounds.start() <= self.0 && self.0 <= *bounds.end();
+                }
+                index += 1;
+            }
+
+            self.0 == range.0
+        }
+
+        /// Adds `rhs` to the inner value, returning `None` if the addition
+        /// overflows.
+        pub const fn checked_add(self, rhs: $inner) -> ::core::option::Option<Self> {
+            match self.0.checked_add(rhs) {
+                ::core::option::Option::Some(value) => ::core::option::Option::Some(Self(value)),
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        }
+
+        /// Adds `rhs` to the inner value, wrapping around on overflow.
+        pub const fn wrapping_add(self, rhs: $inner) -> Self {
+            Self(self.0.wrapping_add(rhs))
+        }
+
+        /// Returns the value `offset` past this one.
+        ///
+        /// This is useful for C enums where some variants are used as the base
+        /// of a range of values (e.g. `ERRNO_BASE + n`).
+        ///
+        /// # Panics
+        /// Panics on overflow if overflow checks are enabled.
+        pub const fn offset(self, offset: $inner) -> Self {
+            Self(self.0 + offset)
+        }
+
+        /// Returns the inner value as a byte array in little-endian byte order.
+        pub const fn to_le_bytes(self) -> [u8; ::core::mem::size_of::<$inner>()] {
+            <$inner>::to_le_bytes(self.0)
+        }
+
+        /// Returns the inner value as a byte array in big-endian byte order.
+        pub const fn to_be_bytes(self) -> [u8; ::core::mem::size_of::<$inner>()] {
+            <$inner>::to_be_bytes(self.0)
+        }
+
+        /// Returns the inner value as a byte array in native byte order.
+        pub const fn to_ne_bytes(self) -> [u8; ::core::mem::size_of::<$inner>()] {
+            <$inner>::to_ne_bytes(self.0)
+        }
+
+        /// Creates a value from its representation as a byte array in
+        /// little-endian byte order.
+        pub const fn from_le_bytes(bytes: [u8; ::core::mem::size_of::<$inner>()]) -> Self {
+            Self(<$inner>::from_le_bytes(bytes))
+        }
+
+        /// Creates a value from its representation as a byte array in
+        /// big-endian byte order.
+        pub const fn from_be_bytes(bytes: [u8; ::core::mem::size_of::<$inner>()]) -> Self {
+            Self(<$inner>::from_be_bytes(bytes))
+        }
+
+        /// Creates a value from its representation as a byte array in native
+        /// byte order.
+        pub const fn from_ne_bytes(bytes: [u8; ::core::mem::size_of::<$inner>()]) -> Self {
+            Self(<$inner>::from_ne_bytes(bytes))
+        }
+
+        #[doc(hidden)]
+        #[allow(deprecated)]
+        pub(crate) const __C_ENUM_VARIANTS_BY_VALUE: &'static [Self] = &{
+            const UNINIT: $name = $name(0);
+            const COUNT: usize = $name::__C_ENUM_VARIANTS.len();
+
+            let mut values: [$inner; COUNT] = [0; COUNT];
+            let mut i = 0;
+            while i < values.len() {
+                values[i] = Self::__C_ENUM_VALUES[i];
+                i += 1;
+            }
+
+            // A simple insertion sort. This is stable, so variants with equal
+            // values keep their relative order.
+            let mut i = 1;
+            while i < values.len() {
+                let mut j = i;
+                while j > 0 && values[j - 1] > values[j] {
+                    let tmp = values[j - 1];
+                    values[j - 1] = values[j];
+                    values[j] = tmp;
+                    j -= 1;
+                }
+                i += 1;
+            }
+
+            let mut variants = [UNINIT; COUNT];
+            let mut i = 0;
+            while i < variants.len() {
+                variants[i] = Self(values[i]);
+                i += 1;
+            }
+
+            variants
+        };
+
+        /// Returns an iterator over all declared variants ordered by their
+        /// value.
+        pub fn iter_sorted() -> ::core::slice::Iter<'static, Self> {
+            Self::__C_ENUM_VARIANTS_BY_VALUE.iter()
+        }
+    };
+    (impl(integer_items) $kind:ident $name:ident $inner:ty, $opts:tt, $fields:tt) => {};
+
+    (impl(min_max) []) => {};
+    (impl(min_max) [ [ $( $first_attr:tt )* ] $first:ident $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(
+            impl(min_max_impl) $first [ [ $( $first_attr )* ] $first $( $rest )* ]
+        );
+    };
+    // Variants removed by #[cfg] are skipped. `$first` is only used if all of
+    // them have been removed.
+    (impl(min_max_impl) $first:ident [ $( [ $( $attr:tt )* ] $field:ident )* ]) => {
+        /// The declared variant with the smallest value.
+        #[allow(deprecated)]
+        pub const MIN_VARIANT: Self = {
+            let mut min = Self::$first.0;
+            let mut found = false;
+            $(
+                if $crate::__c_enum_impl!(impl(cfgs) [ $( $attr )* ] [] (cfg_pred))
+                    && (!found || Self::$field.0 < min)
+                {
+                    min = Self::$field.0;
+                    found = true;
+                }
+            )*
+            let _ = found;
+            Self(min)
+        };
+
+        /// The declared variant with the largest value.
+        #[allow(deprecated)]
+        pub const MAX_VARIANT: Self = {
+            let mut max = Self::$first.0;
+            let mut found = false;
+            $(
+                if $crate::__c_enum_impl!(impl(cfgs) [ $( $attr )* ] [] (cfg_pred))
+                    && (!found || Self::$field.0 > max)
+                {
+                    max = Self::$field.0;
+                    found = true;
+                }
+            )*
+            let _ = found;
+            Self(max)
+        };
+    };
+
+    // Look for a #[c_enum(parse = "...")] option to determine how names are
+    // compared by `from_name`.
+    (impl(name_eq) [ parse = "case_insensitive", $( $rest:tt )* ]) => {
+        |name: &str, label: &str| name.trim().eq_ignore_ascii_case(label)
+    };
+    (impl(name_eq) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]) => {
+        $crate::__c_enum_impl!(impl(name_eq) [ $( $rest )* ])
+    };
+    (impl(name_eq) []) => {
+        |name: &str, label: &str| name == label
+    };
+
+    // The `From` impls are skipped entirely if #[c_enum(skip_from)] is present.
+    (impl(from) [ skip_from, $( $rest:tt )* ] $name:ident [ $inner:ty ]) => {};
+    (impl(from) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_impl!(impl(from) [ $( $rest )* ] $name [ $inner ]);
+    };
+    (impl(from) [] $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        #[automatically_derived]
+        impl From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+
+    // Look for a #[c_enum(debug = "...")] option to determine how undeclared
+    // values should be formatted. #[c_enum(skip_debug)] takes priority over it
+    // no matter where it appears.
+    (impl(debug) no_debug $( $args:tt )*) => {};
+    (
+        impl(debug) $style:ident [ skip_debug, $( $rest:tt )* ] $all:tt
+        $kind:ident $name:ident [ $inner:ty ] $variants:tt
+    ) => {};
+    (
+        impl(debug) $style:ident [ debug = "hex", $( $rest:tt )* ] $all:tt
+        $kind:ident $name:ident [ $inner:ty ] $variants:tt
+    ) => {
+        $crate::__c_enum_impl!(impl(debug) hex [ $( $rest )* ] $all $kind $name [ $inner ] $variants);
+    };
+    (
+        impl(debug) $style:ident [ $key:tt $( = $value:tt )?, $( $rest:tt )* ] $all:tt
+        $kind:ident $name:ident [ $inner:ty ] $variants:tt
+    ) => {
+        $crate::__c_enum_impl!(
+            impl(debug) $style [ $( $rest )* ] $all $kind $name [ $inner ] $variants
+        );
+    };
+    (impl(debug) debug [] $all:tt $kind:ident $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_impl!(impl(debug_impl) decimal Debug $all $kind $name [ $inner ] $variants);
+    };
+    (impl(debug) hex [] $all:tt $kind:ident $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_impl!(impl(debug_impl) hex LowerHex $all $kind $name [ $inner ] $variants);
+    };
+    (impl(debug) bytes [] $all:tt $kind:ident $name:ident [ $inner:ty ] $variants:tt) => {
+        $crate::__c_enum_impl!(impl(debug_impl) bytes Debug $all $kind $name [ $inner ] $variants);
+    };
+
+    // Declared variants are always formatted using the name of their constant,
+    // even if their label has been changed. Other values within the range of a
+    // variant are formatted as the name of that variant followed by the value.
+    (
+        impl(debug_impl) $style:ident $bound:ident $opts:tt $kind:ident $name:ident [ $inner:ty ]
+        { $( [ $( $field_attr:tt )* ] $field:ident, )* }
+    ) => {
+        impl ::core::fmt::Debug for $name
+        where
+            $inner: ::core::fmt::$bound,
+            $inner: ::core::cmp::PartialEq
+        {
+            #[allow(deprecated)]
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>
+            ) -> ::core::fmt::Result {
+                match &self.0 {
+                    $(
+                        value if $crate::__c_enum_impl!(impl(listed) $opts [ $( $field_attr )* ])
+                            && $crate::__c_enum_impl!(impl(eq $kind) Self::$field.0, *value) => {
+                            f.write_fmt(::core::format_args!(
+                                "{}::{}",
+                                ::core::stringify!($name),
+                                $crate::__private::unraw(::core::stringify!($field))
+                            ))
+                        },
+                    )*
+                    $(
+                        value if $crate::__c_enum_impl!(
+                            impl(range_guard) $kind [ $( $field_attr )* ] $field value
+                        ) => {
+                            f.write_fmt(::core::format_args!(
+                                "{}::{}({:?})",
+                                ::core::stringify!($name),
+                                $crate::__private::unraw(::core::stringify!($field)),
+                                $crate::__c_enum_impl!(impl(debug_value) $style value)
+                            ))
+                        },
+                    )*
+                    value => match self.__c_enum_ext_label() {
+                        ::core::option::Option::Some(label) => f.write_fmt(::core::format_args!(
+                            "{}::{}", ::core::stringify!($name), label
+                        )),
+                        ::core::option::Option::None => f
+                            .debug_tuple(::core::stringify!($name))
+                            .field($crate::__c_enum_impl!(impl(debug_value) $style value))
+                            .finish(),
+                    },
+                }
+            }
+        }
+    };
+    (impl(debug_value) decimal $value:ident) => { $value };
+    (impl(debug_value) hex $value:ident) => { &::core::format_args!("{:#x}", $value) };
+    (impl(debug_value) bytes $value:ident) => { &$crate::__private::ByteStr(*$value) };
+
+    // Expands to the label for a variant as a `&'static str`. An explicit
+    // #[label = "..."] on the variant takes priority over everything else.
+    (impl(label) $opts:tt [ #[label = $label:tt] $( $rest:tt )* ] $field:ident) => {
+        $label
+    };
+    (
+        impl(label) $opts:tt [
+            #[doc $( $d0:tt )*] #[doc $( $d1:tt )*] #[doc $( $d2:tt )*] #[doc $( $d3:tt )*]
+            #[doc $( $d4:tt )*] #[doc $( $d5:tt )*] #[doc $( $d6:tt )*] #[doc $( $d7:tt )*]
+            $( $rest:tt )*
+        ] $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(label) $opts [ $( $rest )* ] $field)
+    };
+    (impl(label) $opts:tt [ #[ $( $attr:tt )* ] $( $rest:tt )* ] $field:ident) => {
+        $crate::__c_enum_impl!(impl(label) $opts [ $( $rest )* ] $field)
+    };
+    (impl(label) $opts:tt [] $field:ident) => {
+        $crate::__c_enum_impl!(impl(label_opts) $opts [] [] $field)
+    };
+
+    // Collect the prefix to strip and the case to convert to from the enum
+    // options.
+    (
+        impl(label_opts) [ rename_all = $case:tt, $( $rest:tt )* ]
+        $prefix:tt $prev:tt $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(label_opts) [ $( $rest )* ] $prefix [ $case ] $field)
+    };
+    (
+        impl(label_opts) [ strip_prefix = $strip:tt, $( $rest:tt )* ]
+        $prev:tt $case:tt $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(label_opts) [ $( $rest )* ] [ $strip ] $case $field)
+    };
+    (
+        impl(label_opts) [ $key:tt $( = $value:tt )?, $( $rest:tt )* ]
+        $prefix:tt $case:tt $field:ident
+    ) => {
+        $crate::__c_enum_impl!(impl(label_opts) [ $( $rest )* ] $prefix $case $field)
+    };
+    (impl(label_opts) [] [] [] $field:ident) => {
+        $crate::__private::unraw(::core::stringify!($field))
+    };
+    (impl(label_opts) [] [ $( $prefix:tt )? ] [ $( $case:tt )? ] $field:ident) => {{
+        const NAME: &str = $crate::__private::unraw(::core::stringify!($field));
+        const RENAMED: ([u8; NAME.len() * 2], usize) = $crate::__private::rename(
+            NAME,
+            $crate::__c_enum_impl!(impl(first_expr) $( $prefix, )? ""),
+            $crate::__c_enum_impl!(impl(first_expr)
+                $( ::core::option::Option::Some($crate::__c_enum_impl!(impl(case) $case)), )?
+                ::core::option::Option::None
+            )
+        );
+        const BYTES: [u8; RENAMED.1] = $crate::__private::truncate(&RENAMED.0);
+        const LABEL: &str = match $crate::__private::ascii_str(&BYTES) {
+            ::core::option::Option::Some(label) => label,
+            ::core::option::Option::None => NAME,
+        };
+
+        LABEL
+    }};
+
+    (impl(case) "lowercase") => { $crate::__private::Case::Lower };
+    (impl(case) "UPPERCASE") => { $crate::__private::Case::Upper };
+    (impl(case) "PascalCase") => { $crate::__private::Case::Pascal };
+    (impl(case) "camelCase") => { $crate::__private::Case::Camel };
+    (impl(case) "snake_case") => { $crate::__private::Case::Snake };
+    (impl(case) "SCREAMING_SNAKE_CASE") => { $crate::__private::Case::ScreamingSnake };
+    (impl(case) "kebab-case") => { $crate::__private::Case::Kebab };
+    (impl(case) "SCREAMING-KEBAB-CASE") => { $crate::__private::Case::ScreamingKebab };
+
+    // Formatting traits that just forward to the inner value.
+    (impl(fmt_forward) $name:ident $inner:ty, $trait:ident) => {
+        #[automatically_derived]
+        impl ::core::fmt::$trait for $name
+        where
+            for<'a> $inner: ::core::fmt::$trait,
+        {
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>
+            ) -> ::core::fmt::Result {
+                ::core::fmt::$trait::fmt(&self.0, f)
+            }
+        }
+    };
 }
 
 // This needs to be after all the macro definitions.
@@ -0,0 +1,64 @@
+//! Helper macros for `#[c_enum(minicbor)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `minicbor` feature is enabled.
+
+/// Implements minicbor's `Encode`, `Decode` and `CborLen` for a C enum by
+/// forwarding to the inner value.
+#[cfg(feature = "minicbor")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_minicbor {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<C> $crate::__private::minicbor::Encode<C> for $name
+        where
+            $inner: $crate::__private::minicbor::Encode<C>,
+        {
+            fn encode<W>(
+                &self,
+                e: &mut $crate::__private::minicbor::Encoder<W>,
+                ctx: &mut C,
+            ) -> ::core::result::Result<(), $crate::__private::minicbor::encode::Error<W::Error>>
+            where
+                W: $crate::__private::minicbor::encode::Write,
+            {
+                $crate::__private::minicbor::Encode::encode(&self.0, e, ctx)
+            }
+        }
+
+        #[automatically_derived]
+        impl<C> $crate::__private::minicbor::CborLen<C> for $name
+        where
+            $inner: $crate::__private::minicbor::CborLen<C>,
+        {
+            fn cbor_len(&self, ctx: &mut C) -> usize {
+                $crate::__private::minicbor::CborLen::cbor_len(&self.0, ctx)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'b, C> $crate::__private::minicbor::Decode<'b, C> for $name
+        where
+            $inner: $crate::__private::minicbor::Decode<'b, C>,
+        {
+            fn decode(
+                d: &mut $crate::__private::minicbor::Decoder<'b>,
+                ctx: &mut C,
+            ) -> ::core::result::Result<Self, $crate::__private::minicbor::decode::Error> {
+                <$inner as $crate::__private::minicbor::Decode<'b, C>>::decode(d, ctx).map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "minicbor"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_minicbor {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(minicbor)]` requires the `minicbor` feature of c-enum to be enabled"
+        );
+    };
+}
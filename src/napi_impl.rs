@@ -0,0 +1,95 @@
+//! Helper macros for `#[c_enum(napi)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `napi` feature is enabled.
+
+/// Implements the napi-rs conversion traits for a C enum by forwarding to the
+/// inner type, so that it is passed to and from JS as a number.
+#[cfg(feature = "napi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_napi {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::napi::bindgen_prelude::TypeName for $name
+        where
+            for<'a> $inner: $crate::__private::napi::bindgen_prelude::TypeName,
+        {
+            fn type_name() -> &'static str {
+                ::core::stringify!($name)
+            }
+
+            fn value_type() -> $crate::__private::napi::ValueType {
+                <$inner as $crate::__private::napi::bindgen_prelude::TypeName>::value_type()
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::napi::bindgen_prelude::ValidateNapiValue for $name
+        where
+            for<'a> $inner: $crate::__private::napi::bindgen_prelude::ValidateNapiValue,
+        {
+            unsafe fn validate(
+                env: $crate::__private::napi::sys::napi_env,
+                napi_val: $crate::__private::napi::sys::napi_value,
+            ) -> $crate::__private::napi::Result<$crate::__private::napi::sys::napi_value> {
+                <$inner as $crate::__private::napi::bindgen_prelude::ValidateNapiValue>::validate(
+                    env, napi_val,
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::napi::bindgen_prelude::ToNapiValue for $name
+        where
+            for<'a> $inner: $crate::__private::napi::bindgen_prelude::ToNapiValue,
+        {
+            unsafe fn to_napi_value(
+                env: $crate::__private::napi::sys::napi_env,
+                val: Self,
+            ) -> $crate::__private::napi::Result<$crate::__private::napi::sys::napi_value> {
+                $crate::__private::napi::bindgen_prelude::ToNapiValue::to_napi_value(env, val.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> $crate::__private::napi::bindgen_prelude::ToNapiValue for &'a $name
+        where
+            &'a $inner: $crate::__private::napi::bindgen_prelude::ToNapiValue,
+        {
+            unsafe fn to_napi_value(
+                env: $crate::__private::napi::sys::napi_env,
+                val: Self,
+            ) -> $crate::__private::napi::Result<$crate::__private::napi::sys::napi_value> {
+                $crate::__private::napi::bindgen_prelude::ToNapiValue::to_napi_value(env, &val.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::napi::bindgen_prelude::FromNapiValue for $name
+        where
+            for<'a> $inner: $crate::__private::napi::bindgen_prelude::FromNapiValue,
+        {
+            unsafe fn from_napi_value(
+                env: $crate::__private::napi::sys::napi_env,
+                napi_val: $crate::__private::napi::sys::napi_value,
+            ) -> $crate::__private::napi::Result<Self> {
+                <$inner as $crate::__private::napi::bindgen_prelude::FromNapiValue>::from_napi_value(
+                    env, napi_val,
+                )
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "napi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_napi {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(napi)]` requires the `napi` feature of c-enum to be enabled"
+        );
+    };
+}
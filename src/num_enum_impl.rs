@@ -0,0 +1,57 @@
+//! Helper macros for `#[c_enum(num_enum)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `num_enum` feature is enabled.
+
+/// Implements num_enum's `FromPrimitive` and `TryFromPrimitive` for a C enum.
+///
+/// `FromPrimitive` accepts any value, since that is always valid for a C
+/// enum, while `TryFromPrimitive` only accepts declared variants like it would
+/// for a regular Rust enum.
+#[cfg(feature = "num_enum")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_num_enum {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::num_enum::FromPrimitive for $name
+        where
+            for<'a> $inner: ::core::marker::Copy + ::core::cmp::Eq,
+        {
+            type Primitive = $inner;
+
+            fn from_primitive(number: Self::Primitive) -> Self {
+                Self(number)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::num_enum::TryFromPrimitive for $name
+        where
+            for<'a> $inner: ::core::marker::Copy + ::core::cmp::Eq + ::core::fmt::Debug,
+        {
+            type Primitive = $inner;
+            type Error = $crate::__private::num_enum::TryFromPrimitiveError<Self>;
+
+            const NAME: &'static str = ::core::stringify!($name);
+
+            fn try_from_primitive(
+                number: Self::Primitive,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_from_value(number)
+                    .map_err(|e| $crate::__private::num_enum::TryFromPrimitiveError::new(e.0))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "num_enum"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_num_enum {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(num_enum)]` requires the `num_enum` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,86 @@
+//! Helper macros for `#[c_enum(num_traits)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `num-traits` feature is enabled.
+
+/// Implements num-traits' `FromPrimitive` and `ToPrimitive` for a C enum by
+/// forwarding every method to the inner type.
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_num_traits {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::num_traits::FromPrimitive for $name
+        where
+            for<'a> $inner: $crate::__private::num_traits::FromPrimitive,
+        {
+            $crate::__c_enum_num_traits!(
+                from [ $inner ]
+                from_isize(isize),
+                from_i8(i8),
+                from_i16(i16),
+                from_i32(i32),
+                from_i64(i64),
+                from_i128(i128),
+                from_usize(usize),
+                from_u8(u8),
+                from_u16(u16),
+                from_u32(u32),
+                from_u64(u64),
+                from_u128(u128),
+                from_f32(f32),
+                from_f64(f64),
+            );
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::num_traits::ToPrimitive for $name
+        where
+            for<'a> $inner: $crate::__private::num_traits::ToPrimitive,
+        {
+            $crate::__c_enum_num_traits!(
+                to
+                to_isize(isize),
+                to_i8(i8),
+                to_i16(i16),
+                to_i32(i32),
+                to_i64(i64),
+                to_i128(i128),
+                to_usize(usize),
+                to_u8(u8),
+                to_u16(u16),
+                to_u32(u32),
+                to_u64(u64),
+                to_u128(u128),
+                to_f32(f32),
+                to_f64(f64),
+            );
+        }
+    };
+    (from [ $inner:ty ] $( $method:ident($ty:ty), )*) => {
+        $(
+            fn $method(n: $ty) -> ::core::option::Option<Self> {
+                <$inner as $crate::__private::num_traits::FromPrimitive>::$method(n).map(Self)
+            }
+        )*
+    };
+    (to $( $method:ident($ty:ty), )*) => {
+        $(
+            fn $method(&self) -> ::core::option::Option<$ty> {
+                $crate::__private::num_traits::ToPrimitive::$method(&self.0)
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "num-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_num_traits {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(num_traits)]` requires the `num-traits` feature of c-enum to be enabled"
+        );
+    };
+}
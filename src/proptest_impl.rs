@@ -0,0 +1,55 @@
+//! Helper macros for `#[c_enum(proptest)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `proptest` feature is enabled.
+
+/// Generates the `any` and `any_declared` strategies for a C enum.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_proptest {
+    ($name:ident [ $inner:ty ]) => {
+        impl $name {
+            /// Returns a strategy which generates any value of the inner type,
+            /// whether or not it corresponds to a declared variant.
+            pub fn any() -> impl $crate::__private::proptest::strategy::Strategy<Value = Self>
+            where
+                for<'a> $inner: $crate::__private::proptest::arbitrary::Arbitrary,
+                for<'a> Self: ::core::fmt::Debug,
+            {
+                $crate::__private::proptest::strategy::Strategy::prop_map(
+                    $crate::__private::proptest::arbitrary::any::<$inner>(),
+                    Self,
+                )
+            }
+
+            /// Returns a strategy which generates only the declared variants
+            /// of this enum.
+            ///
+            /// # Panics
+            /// Panics if this enum has no declared variants.
+            pub fn any_declared(
+            ) -> impl $crate::__private::proptest::strategy::Strategy<Value = Self>
+            where
+                for<'a> $inner: ::core::clone::Clone + ::core::fmt::Debug,
+                for<'a> Self: ::core::fmt::Debug,
+            {
+                $crate::__private::proptest::strategy::Strategy::prop_map(
+                    $crate::__private::proptest::sample::select(Self::__C_ENUM_VALUES),
+                    Self,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_proptest {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(proptest)]` requires the `proptest` feature of c-enum to be enabled"
+        );
+    };
+}
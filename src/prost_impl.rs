@@ -0,0 +1,54 @@
+//! Helper macros for `#[c_enum(prost = ...)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `prost` feature is enabled.
+
+/// Implements conversions between a C enum and a protobuf enum generated by
+/// prost.
+///
+/// prost stores enum fields as an `i32`, so both conversions go through
+/// `i32`. Converting to the prost enum fails for values that it doesn't
+/// declare, the same as its own `TryFrom<i32>` impl does.
+#[cfg(feature = "prost")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_prost {
+    ($name:ident [ $inner:ty ] $proto:ident) => {
+        #[automatically_derived]
+        impl ::core::convert::From<$proto> for $name
+        where
+            for<'a> $inner: ::core::convert::From<i32>,
+        {
+            fn from(value: $proto) -> Self {
+                Self(<$inner as ::core::convert::From<i32>>::from(
+                    <i32 as ::core::convert::From<$proto>>::from(value),
+                ))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<$name> for $proto
+        where
+            for<'a> i32: ::core::convert::From<$inner>,
+        {
+            type Error = $crate::__private::prost::UnknownEnumValue;
+
+            fn try_from(value: $name) -> ::core::result::Result<Self, Self::Error> {
+                <$proto as ::core::convert::TryFrom<i32>>::try_from(
+                    <i32 as ::core::convert::From<$inner>>::from(value.0),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "prost"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_prost {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(prost = ...)]` requires the `prost` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,93 @@
+//! Helper macros for `#[c_enum(pyo3)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `pyo3` feature is enabled.
+
+/// Implements PyO3's `IntoPyObject` and `FromPyObject` for a C enum.
+///
+/// Values are converted to Python using the inner value. When extracting, a
+/// `str` is looked up as a variant label and anything else is extracted as the
+/// inner type.
+#[cfg(feature = "pyo3")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_pyo3 {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<'py> $crate::__private::pyo3::IntoPyObject<'py> for $name
+        where
+            $inner: $crate::__private::pyo3::IntoPyObject<'py>,
+        {
+            type Target = <$inner as $crate::__private::pyo3::IntoPyObject<'py>>::Target;
+            type Output = <$inner as $crate::__private::pyo3::IntoPyObject<'py>>::Output;
+            type Error = <$inner as $crate::__private::pyo3::IntoPyObject<'py>>::Error;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> ::core::result::Result<Self::Output, Self::Error> {
+                $crate::__private::pyo3::IntoPyObject::into_pyobject(self.0, py)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a, 'py> $crate::__private::pyo3::IntoPyObject<'py> for &'a $name
+        where
+            &'a $inner: $crate::__private::pyo3::IntoPyObject<'py>,
+        {
+            type Target = <&'a $inner as $crate::__private::pyo3::IntoPyObject<'py>>::Target;
+            type Output = <&'a $inner as $crate::__private::pyo3::IntoPyObject<'py>>::Output;
+            type Error = <&'a $inner as $crate::__private::pyo3::IntoPyObject<'py>>::Error;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> ::core::result::Result<Self::Output, Self::Error> {
+                $crate::__private::pyo3::IntoPyObject::into_pyobject(&self.0, py)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a, 'py> $crate::__private::pyo3::FromPyObject<'a, 'py> for $name
+        where
+            $inner: $crate::__private::pyo3::FromPyObject<'a, 'py>,
+        {
+            type Error = $crate::__private::pyo3::PyErr;
+
+            fn extract(
+                obj: $crate::__private::pyo3::Borrowed<'a, 'py, $crate::__private::pyo3::PyAny>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                if let ::core::result::Result::Ok(label) =
+                    obj.cast::<$crate::__private::pyo3::types::PyString>()
+                {
+                    let label = $crate::__private::pyo3::types::PyStringMethods::to_cow(&*label)?;
+
+                    return $name::from_name(&label).ok_or_else(|| {
+                        $crate::__private::pyo3::exceptions::PyValueError::new_err(
+                            $crate::__private::format!(
+                                "unknown variant `{}` of {}",
+                                label,
+                                ::core::stringify!($name)
+                            ),
+                        )
+                    });
+                }
+
+                <$inner as $crate::__private::pyo3::FromPyObject<'a, 'py>>::extract(obj)
+                    .map(Self)
+                    .map_err(::core::convert::Into::into)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "pyo3"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_pyo3 {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(pyo3)]` requires the `pyo3` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,42 @@
+//! Helper macros for `#[c_enum(rand)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `rand` feature is enabled.
+
+/// Implements `Distribution<Self>` for rand's `StandardUniform` distribution,
+/// picking uniformly among the declared variants of a C enum.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_rand {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::rand::distr::Distribution<$name>
+            for $crate::__private::rand::distr::StandardUniform
+        where
+            for<'a> $name: ::core::clone::Clone,
+        {
+            fn sample<R>(&self, rng: &mut R) -> $name
+            where
+                R: $crate::__private::rand::Rng + ?::core::marker::Sized,
+            {
+                let index = $crate::__private::rand::RngExt::random_range(
+                    rng,
+                    0..$name::__C_ENUM_VARIANTS.len(),
+                );
+                $name::__C_ENUM_VARIANTS[index].clone()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_rand {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(rand)]` requires the `rand` feature of c-enum to be enabled"
+        );
+    };
+}
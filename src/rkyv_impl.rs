@@ -0,0 +1,40 @@
+//! Helper macros for `#[c_enum(rkyv)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `rkyv` feature is enabled.
+
+/// Adds the rkyv derives to the attributes of a C enum before continuing to
+/// declare its struct.
+#[cfg(feature = "rkyv")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_rkyv {
+    ($opts:tt [ $( $attr:tt )* ] $vis:vis $name:ident [ $inner:ty ] $field_vis:tt) => {
+        $crate::__c_enum_impl!(
+            impl(struct) $opts
+            [
+                $( $attr )*
+                #[derive(
+                    $crate::__private::rkyv::Archive,
+                    $crate::__private::rkyv::Serialize,
+                    $crate::__private::rkyv::Deserialize
+                )]
+                #[rkyv(crate = $crate::__private::rkyv)]
+            ]
+            $vis $name [ $inner ] $field_vis
+        );
+    };
+}
+
+#[cfg(not(feature = "rkyv"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_rkyv {
+    ($opts:tt $attrs:tt $vis:vis $name:ident [ $inner:ty ] $field_vis:tt) => {
+        ::core::compile_error!(
+            "`#[c_enum(rkyv)]` requires the `rkyv` feature of c-enum to be enabled"
+        );
+
+        $crate::__c_enum_impl!(impl(struct) $opts $attrs $vis $name [ $inner ] $field_vis);
+    };
+}
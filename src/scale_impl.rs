@@ -0,0 +1,96 @@
+//! Helper macros for `#[c_enum(scale)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `scale` feature is enabled.
+
+/// Implements SCALE `Encode`, `Decode` and `MaxEncodedLen` for a C enum by
+/// forwarding to the inner type.
+#[cfg(feature = "scale")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_scale {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::codec::Encode for $name
+        where
+            for<'a> $inner: $crate::__private::codec::Encode,
+        {
+            fn size_hint(&self) -> usize {
+                $crate::__private::codec::Encode::size_hint(&self.0)
+            }
+
+            fn encode_to<T>(&self, dest: &mut T)
+            where
+                T: $crate::__private::codec::Output + ?::core::marker::Sized,
+            {
+                $crate::__private::codec::Encode::encode_to(&self.0, dest)
+            }
+
+            fn using_encoded<R, F>(&self, f: F) -> R
+            where
+                F: ::core::ops::FnOnce(&[u8]) -> R,
+            {
+                $crate::__private::codec::Encode::using_encoded(&self.0, f)
+            }
+
+            fn encoded_size(&self) -> usize {
+                $crate::__private::codec::Encode::encoded_size(&self.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::codec::EncodeLike for $name where
+            for<'a> $inner: $crate::__private::codec::Encode
+        {
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::codec::Decode for $name
+        where
+            for<'a> $inner: $crate::__private::codec::Decode,
+        {
+            fn decode<I>(
+                input: &mut I,
+            ) -> ::core::result::Result<Self, $crate::__private::codec::Error>
+            where
+                I: $crate::__private::codec::Input,
+            {
+                <$inner as $crate::__private::codec::Decode>::decode(input).map(Self)
+            }
+
+            fn skip<I>(input: &mut I) -> ::core::result::Result<(), $crate::__private::codec::Error>
+            where
+                I: $crate::__private::codec::Input,
+            {
+                <$inner as $crate::__private::codec::Decode>::skip(input)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::codec::DecodeWithMemTracking for $name where
+            for<'a> $inner: $crate::__private::codec::DecodeWithMemTracking
+        {
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::codec::MaxEncodedLen for $name
+        where
+            for<'a> $inner: $crate::__private::codec::MaxEncodedLen,
+        {
+            fn max_encoded_len() -> usize {
+                <$inner as $crate::__private::codec::MaxEncodedLen>::max_encoded_len()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "scale"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_scale {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(scale)]` requires the `scale` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,91 @@
+//! Helper macros for `#[c_enum(schemars)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `schemars` feature is enabled.
+
+/// Implements `JsonSchema` for a C enum.
+///
+/// The schema matches the representation used by `#[c_enum(serde = "...")]`.
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_schemars {
+    // The inner value, with the declared variants listed as constants.
+    (value $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(impl $name [ $inner ] generator {
+            let mut variants = $crate::__private::Vec::new();
+            for (label, value) in $name::__C_ENUM_ENTRIES {
+                variants.push($crate::__private::schemars::json_schema!({
+                    "const": value,
+                    "title": label,
+                }));
+            }
+            variants.push(generator.subschema_for::<$inner>());
+
+            $crate::__private::schemars::json_schema!({ "anyOf": variants })
+        });
+    };
+    // Only the names of the declared variants.
+    (name $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(impl $name [ $inner ] generator {
+            let _ = generator;
+
+            $crate::__private::schemars::json_schema!({
+                "type": "string",
+                "enum": $name::__C_ENUM_LABELS,
+            })
+        });
+    };
+    // Either the name of a declared variant or an inner value.
+    (name_or_value $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_schemars!(impl $name [ $inner ] generator {
+            $crate::__private::schemars::json_schema!({
+                "anyOf": [
+                    {
+                        "type": "string",
+                        "enum": $name::__C_ENUM_LABELS,
+                    },
+                    generator.subschema_for::<$inner>(),
+                ]
+            })
+        });
+    };
+
+    (impl $name:ident [ $inner:ty ] $generator:ident $schema:block) => {
+        #[automatically_derived]
+        impl $crate::__private::schemars::JsonSchema for $name
+        where
+            for<'a> $inner: $crate::__private::schemars::JsonSchema
+                + $crate::__private::serde::Serialize,
+        {
+            fn schema_name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($name))
+            }
+
+            fn schema_id() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!($name)
+                ))
+            }
+
+            fn json_schema(
+                $generator: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                $schema
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_schemars {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(schemars)]` requires the `schemars` feature of c-enum to be enabled"
+        );
+    };
+}
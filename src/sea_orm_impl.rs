@@ -0,0 +1,85 @@
+//! Helper macros for `#[c_enum(sea_orm)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `sea-orm` feature is enabled.
+
+/// Implements the traits SeaORM needs in order to use a C enum as a column of
+/// an entity.
+///
+/// Everything forwards to the inner type so the enum is stored in the same
+/// column type that the inner type would be.
+#[cfg(feature = "sea-orm")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_sea_orm {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl ::core::convert::From<$name> for $crate::__private::sea_orm::Value
+        where
+            for<'a> $crate::__private::sea_orm::Value: ::core::convert::From<$inner>,
+        {
+            fn from(value: $name) -> Self {
+                ::core::convert::From::from(value.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::sea_orm::sea_query::ValueType for $name
+        where
+            for<'a> $inner: $crate::__private::sea_orm::sea_query::ValueType,
+        {
+            fn try_from(
+                v: $crate::__private::sea_orm::Value,
+            ) -> ::core::result::Result<Self, $crate::__private::sea_orm::sea_query::ValueTypeErr>
+            {
+                <$inner as $crate::__private::sea_orm::sea_query::ValueType>::try_from(v).map(Self)
+            }
+
+            fn type_name() -> $crate::__private::String {
+                ::core::convert::From::from(::core::stringify!($name))
+            }
+
+            fn array_type() -> $crate::__private::sea_orm::sea_query::ArrayType {
+                <$inner as $crate::__private::sea_orm::sea_query::ValueType>::array_type()
+            }
+
+            fn column_type() -> $crate::__private::sea_orm::sea_query::ColumnType {
+                <$inner as $crate::__private::sea_orm::sea_query::ValueType>::column_type()
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::sea_orm::sea_query::Nullable for $name
+        where
+            for<'a> $inner: $crate::__private::sea_orm::sea_query::Nullable,
+        {
+            fn null() -> $crate::__private::sea_orm::Value {
+                <$inner as $crate::__private::sea_orm::sea_query::Nullable>::null()
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::sea_orm::TryGetable for $name
+        where
+            for<'a> $inner: $crate::__private::sea_orm::TryGetable,
+        {
+            fn try_get_by<I: $crate::__private::sea_orm::ColIdx>(
+                res: &$crate::__private::sea_orm::QueryResult,
+                index: I,
+            ) -> ::core::result::Result<Self, $crate::__private::sea_orm::TryGetError> {
+                <$inner as $crate::__private::sea_orm::TryGetable>::try_get_by(res, index).map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "sea-orm"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_sea_orm {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(sea_orm)]` requires the `sea-orm` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,194 @@
+//! Helper macros for `#[c_enum(serde = "...")]`.
+//!
+//! These are defined differently depending on whether the `serde` feature is
+//! enabled since the code generated by `c_enum!` can't check the features of
+//! this crate itself.
+
+/// Implements `Serialize` and `Deserialize` for a C enum.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_serde {
+    (value $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_serde!(serialize value $name [ $inner ]);
+        $crate::__c_enum_serde!(deserialize value $name [ $inner ]);
+    };
+    // Serialize declared variants using their label. Undeclared values are
+    // either serialized using the inner value or rejected depending on the
+    // `serde_unknown` option.
+    (name value $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_serde!(serialize name_or_value $name [ $inner ]);
+        $crate::__c_enum_serde!(deserialize name_or_value $name [ $inner ]);
+    };
+    (name error $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_serde!(serialize name $name [ $inner ]);
+        $crate::__c_enum_serde!(deserialize name $name [ $inner ]);
+    };
+    // Serialize the inner value but accept either a name or a value.
+    (lenient $name:ident [ $inner:ty ]) => {
+        $crate::__c_enum_serde!(serialize value $name [ $inner ]);
+        $crate::__c_enum_serde!(deserialize name_or_value $name [ $inner ]);
+    };
+
+    (serialize value $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::serde::Serialize for $name
+        where
+            for<'a> $inner: $crate::__private::serde::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+    };
+    (serialize name_or_value $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::serde::Serialize for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + $crate::__private::serde::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => serializer.serialize_str(label),
+                    ::core::option::Option::None => {
+                        $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+                    }
+                }
+            }
+        }
+    };
+    (serialize name $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::serde::Serialize for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq,
+        {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => serializer.serialize_str(label),
+                    ::core::option::Option::None => ::core::result::Result::Err(
+                        <S::Error as $crate::__private::serde::ser::Error>::custom(
+                            "value does not correspond to any declared variant",
+                        ),
+                    ),
+                }
+            }
+        }
+    };
+
+    (deserialize value $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name
+        where
+            $inner: $crate::__private::serde::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$inner as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map(Self)
+            }
+        }
+    };
+    (deserialize name_or_value $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name
+        where
+            $inner: $crate::__private::serde::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Visitor
+                where
+                    $inner: $crate::__private::serde::Deserialize<'de>,
+                {
+                    type Value = $name;
+
+                    $crate::__c_enum_serde!(visit_name $name);
+                    $crate::__c_enum_serde!(
+                        visit_value $name [ $inner ]
+                        visit_bool(bool),
+                        visit_i64(i64),
+                        visit_i128(i128),
+                        visit_u64(u64),
+                        visit_u128(u128),
+                        visit_f64(f64),
+                        visit_char(char),
+                    );
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
+        }
+    };
+    (deserialize name $name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    $crate::__c_enum_serde!(visit_name $name);
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+    };
+
+    (visit_name $name:ident) => {
+        fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            f.write_str(::core::concat!("a variant of ", ::core::stringify!($name)))
+        }
+
+        fn visit_str<E>(self, value: &str) -> ::core::result::Result<$name, E>
+        where
+            E: $crate::__private::serde::de::Error,
+        {
+            $name::from_name(value).ok_or_else(|| E::unknown_variant(value, $name::__C_ENUM_LABELS))
+        }
+    };
+    (visit_value $name:ident [ $inner:ty ] $( $method:ident($ty:ty), )*) => {
+        $(
+            fn $method<E>(self, value: $ty) -> ::core::result::Result<$name, E>
+            where
+                E: $crate::__private::serde::de::Error,
+            {
+                <$inner as $crate::__private::serde::Deserialize<'de>>::deserialize(
+                    $crate::__private::serde::de::IntoDeserializer::into_deserializer(value),
+                )
+                .map($name)
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_serde {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(serde = ...)]` requires the `serde` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,46 @@
+//! Helper macros for `#[c_enum(slog)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `slog` feature is enabled.
+
+/// Implements `slog::Value` for a C enum.
+///
+/// Declared variants are emitted as their label while other values are
+/// emitted using the inner value.
+#[cfg(feature = "slog")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_slog {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::slog::Value for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + $crate::__private::slog::Value,
+        {
+            fn serialize(
+                &self,
+                record: &$crate::__private::slog::Record<'_>,
+                key: $crate::__private::slog::Key,
+                serializer: &mut dyn $crate::__private::slog::Serializer,
+            ) -> $crate::__private::slog::Result {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => serializer.emit_str(key, label),
+                    ::core::option::Option::None => {
+                        $crate::__private::slog::Value::serialize(&self.0, record, key, serializer)
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "slog"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_slog {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(slog)]` requires the `slog` feature of c-enum to be enabled"
+        );
+    };
+}
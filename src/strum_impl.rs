@@ -0,0 +1,51 @@
+//! Helper macros for `#[c_enum(strum)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `strum` feature is enabled.
+
+/// Implements strum's `EnumCount`, `VariantNames`, `VariantArray` and
+/// `IntoEnumIterator` for a C enum using its declared variants.
+#[cfg(feature = "strum")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_strum {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::strum::EnumCount for $name {
+            const COUNT: usize = Self::__C_ENUM_VARIANTS.len();
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::strum::VariantNames for $name {
+            const VARIANTS: &'static [&'static str] = Self::__C_ENUM_LABELS;
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::strum::VariantArray for $name {
+            const VARIANTS: &'static [Self] = Self::__C_ENUM_VARIANTS;
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::strum::IntoEnumIterator for $name
+        where
+            for<'a> $name: ::core::clone::Clone,
+        {
+            type Iterator = ::core::iter::Cloned<::core::slice::Iter<'static, Self>>;
+
+            fn iter() -> Self::Iterator {
+                Self::__C_ENUM_VARIANTS.iter().cloned()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "strum"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_strum {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(strum)]` requires the `strum` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,71 @@
+//! Helper macros for `#[c_enum(ufmt)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `ufmt` feature is enabled.
+
+/// Implements `ufmt::uDisplay` and `ufmt::uDebug` for a C enum.
+///
+/// Declared variants are written using their label. Undeclared values are
+/// written using the inner value, wrapped in the name of the enum for
+/// `uDebug`.
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_ufmt {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::ufmt::uDisplay for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + $crate::__private::ufmt::uDisplay,
+        {
+            fn fmt<W>(
+                &self,
+                f: &mut $crate::__private::ufmt::Formatter<'_, W>,
+            ) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::__private::ufmt::uWrite + ?Sized,
+            {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => f.write_str(label),
+                    ::core::option::Option::None => {
+                        $crate::__private::ufmt::uDisplay::fmt(&self.0, f)
+                    }
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::ufmt::uDebug for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + $crate::__private::ufmt::uDebug,
+        {
+            fn fmt<W>(
+                &self,
+                f: &mut $crate::__private::ufmt::Formatter<'_, W>,
+            ) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::__private::ufmt::uWrite + ?Sized,
+            {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => f.write_str(label),
+                    ::core::option::Option::None => {
+                        f.write_str(::core::concat!(::core::stringify!($name), "("))?;
+                        $crate::__private::ufmt::uDebug::fmt(&self.0, f)?;
+                        f.write_str(")")
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ufmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_ufmt {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(ufmt)]` requires the `ufmt` feature of c-enum to be enabled"
+        );
+    };
+}
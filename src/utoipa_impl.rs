@@ -0,0 +1,83 @@
+//! Helpers for `#[c_enum(utoipa)]`.
+//!
+//! Like the serde helpers, the macros here are defined differently depending
+//! on whether the `utoipa` feature is enabled.
+
+#[cfg(feature = "utoipa")]
+use alloc::string::String;
+#[cfg(feature = "utoipa")]
+use core::fmt::{Debug, Write};
+
+#[cfg(feature = "utoipa")]
+use utoipa::openapi::schema::Schema;
+#[cfg(feature = "utoipa")]
+use utoipa::openapi::RefOr;
+
+/// Adds a description listing the declared variants to the schema of the
+/// inner type.
+///
+/// Schemas that aren't objects, like references, are returned unchanged.
+#[cfg(feature = "utoipa")]
+pub fn utoipa_schema<T: Debug>(
+    schema: RefOr<Schema>,
+    labels: &[&str],
+    values: &[T],
+) -> RefOr<Schema> {
+    let mut schema = schema;
+
+    if let RefOr::T(Schema::Object(object)) = &mut schema {
+        let mut description = String::from("Known values:\n");
+        for (label, value) in labels.iter().zip(values) {
+            let _ = writeln!(description, "- `{label}`: `{value:?}`");
+        }
+
+        object.description = Some(description);
+    }
+
+    schema
+}
+
+/// Implements utoipa's `PartialSchema` and `ToSchema` for a C enum.
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_utoipa {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::utoipa::PartialSchema for $name
+        where
+            for<'a> $inner: ::core::fmt::Debug + $crate::__private::utoipa::PartialSchema,
+        {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<
+                $crate::__private::utoipa::openapi::schema::Schema,
+            > {
+                $crate::__private::utoipa_schema(
+                    <$inner as $crate::__private::utoipa::PartialSchema>::schema(),
+                    Self::__C_ENUM_LABELS,
+                    Self::__C_ENUM_VALUES,
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::utoipa::ToSchema for $name
+        where
+            for<'a> $inner: ::core::fmt::Debug + $crate::__private::utoipa::PartialSchema,
+        {
+            fn name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($name))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_utoipa {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(utoipa)]` requires the `utoipa` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,47 @@
+//! Helper macros for `#[c_enum(valuable)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `valuable` feature is enabled.
+
+/// Implements `Valuable` for a C enum.
+///
+/// Declared variants are exposed as a string containing their label while
+/// other values are exposed as the inner value.
+#[cfg(feature = "valuable")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_valuable {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::valuable::Valuable for $name
+        where
+            for<'a> $inner: ::core::cmp::PartialEq + $crate::__private::valuable::Valuable,
+        {
+            fn as_value(&self) -> $crate::__private::valuable::Value<'_> {
+                match <Self as $crate::CEnum>::variant_label(self) {
+                    ::core::option::Option::Some(label) => {
+                        $crate::__private::valuable::Value::String(label)
+                    }
+                    ::core::option::Option::None => {
+                        $crate::__private::valuable::Valuable::as_value(&self.0)
+                    }
+                }
+            }
+
+            fn visit(&self, visit: &mut dyn $crate::__private::valuable::Visit) {
+                visit.visit_value(self.as_value());
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "valuable"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_valuable {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(valuable)]` requires the `valuable` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,149 @@
+//! Helpers for `#[c_enum(wasm_bindgen)]`.
+//!
+//! Like the serde helpers, the macros here are defined differently depending
+//! on whether the `wasm-bindgen` feature is enabled.
+
+#[cfg(feature = "wasm-bindgen")]
+use wasm_bindgen::prelude::{wasm_bindgen, JsValue};
+
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = Object)]
+    fn new_object() -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = set)]
+    fn reflect_set(target: &JsValue, key: &JsValue, value: &JsValue) -> bool;
+
+    #[wasm_bindgen(js_namespace = Object, js_name = freeze)]
+    fn object_freeze(object: JsValue) -> JsValue;
+}
+
+/// Builds a frozen JS object mapping each label to the corresponding value.
+#[cfg(feature = "wasm-bindgen")]
+pub fn wasm_bindgen_object<T>(labels: &[&str], values: &[T]) -> JsValue
+where
+    T: Clone,
+    JsValue: From<T>,
+{
+    let object = new_object();
+    for (label, value) in labels.iter().zip(values) {
+        reflect_set(
+            &object,
+            &JsValue::from_str(label),
+            &JsValue::from(value.clone()),
+        );
+    }
+
+    object_freeze(object)
+}
+
+/// Implements the wasm-bindgen conversion traits for a C enum so that it is
+/// passed to and from JS as its inner value, and exports a JS function named
+/// after the enum which returns an object containing all declared variants.
+#[cfg(feature = "wasm-bindgen")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_wasm_bindgen {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::wasm_bindgen::describe::WasmDescribe for $name
+        where
+            for<'a> $inner: $crate::__private::wasm_bindgen::describe::WasmDescribe,
+        {
+            fn describe() {
+                <$inner as $crate::__private::wasm_bindgen::describe::WasmDescribe>::describe()
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::wasm_bindgen::convert::IntoWasmAbi for $name
+        where
+            for<'a> $inner: $crate::__private::wasm_bindgen::convert::IntoWasmAbi,
+        {
+            type Abi = <$inner as $crate::__private::wasm_bindgen::convert::IntoWasmAbi>::Abi;
+
+            fn into_abi(self) -> Self::Abi {
+                $crate::__private::wasm_bindgen::convert::IntoWasmAbi::into_abi(self.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::wasm_bindgen::convert::FromWasmAbi for $name
+        where
+            for<'a> $inner: $crate::__private::wasm_bindgen::convert::FromWasmAbi,
+        {
+            type Abi = <$inner as $crate::__private::wasm_bindgen::convert::FromWasmAbi>::Abi;
+
+            unsafe fn from_abi(js: Self::Abi) -> Self {
+                Self(<$inner as $crate::__private::wasm_bindgen::convert::FromWasmAbi>::from_abi(js))
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::wasm_bindgen::convert::OptionIntoWasmAbi for $name
+        where
+            for<'a> $inner: $crate::__private::wasm_bindgen::convert::OptionIntoWasmAbi,
+        {
+            fn none() -> Self::Abi {
+                <$inner as $crate::__private::wasm_bindgen::convert::OptionIntoWasmAbi>::none()
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::wasm_bindgen::convert::OptionFromWasmAbi for $name
+        where
+            for<'a> $inner: $crate::__private::wasm_bindgen::convert::OptionFromWasmAbi,
+        {
+            fn is_none(abi: &Self::Abi) -> bool {
+                <$inner as $crate::__private::wasm_bindgen::convert::OptionFromWasmAbi>::is_none(
+                    abi,
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl $crate::__private::wasm_bindgen::convert::TryFromJsValue for $name
+        where
+            for<'a> $inner: $crate::__private::wasm_bindgen::convert::TryFromJsValue,
+        {
+            fn try_from_js_value_ref(
+                value: &$crate::__private::wasm_bindgen::JsValue,
+            ) -> ::core::option::Option<Self> {
+                <$inner as $crate::__private::wasm_bindgen::convert::TryFromJsValue>::try_from_js_value_ref(value)
+                    .map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<$name> for $crate::__private::wasm_bindgen::JsValue
+        where
+            for<'a> $crate::__private::wasm_bindgen::JsValue: ::core::convert::From<$inner>,
+        {
+            fn from(value: $name) -> Self {
+                ::core::convert::From::from(value.0)
+            }
+        }
+
+        const _: () = {
+            #[$crate::__private::wasm_bindgen::prelude::wasm_bindgen(
+                wasm_bindgen = $crate::__private::wasm_bindgen,
+                js_name = $name,
+            )]
+            pub fn variants() -> $crate::__private::wasm_bindgen::JsValue {
+                $crate::__private::wasm_bindgen_object($name::__C_ENUM_LABELS, $name::__C_ENUM_VALUES)
+            }
+        };
+    };
+}
+
+#[cfg(not(feature = "wasm-bindgen"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_wasm_bindgen {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(wasm_bindgen)]` requires the `wasm-bindgen` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,44 @@
+//! Helper macros for `#[c_enum(zerocopy)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `zerocopy` feature is enabled.
+
+/// Adds the zerocopy derives to the attributes of a C enum before continuing
+/// to declare its struct.
+///
+/// The zerocopy derives only accept the crate path as a string so this can't
+/// use `$crate` and assumes that this crate is available as `c_enum`.
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_zerocopy {
+    ($opts:tt [ $( $attr:tt )* ] $vis:vis $name:ident [ $inner:ty ] $field_vis:tt) => {
+        $crate::__c_enum_impl!(
+            impl(struct) $opts
+            [
+                $( $attr )*
+                #[derive(
+                    $crate::__private::zerocopy::FromBytes,
+                    $crate::__private::zerocopy::IntoBytes,
+                    $crate::__private::zerocopy::KnownLayout,
+                    $crate::__private::zerocopy::Immutable
+                )]
+                #[zerocopy(crate = "::c_enum::__private::zerocopy")]
+            ]
+            $vis $name [ $inner ] $field_vis
+        );
+    };
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_zerocopy {
+    ($opts:tt $attrs:tt $vis:vis $name:ident [ $inner:ty ] $field_vis:tt) => {
+        ::core::compile_error!(
+            "`#[c_enum(zerocopy)]` requires the `zerocopy` feature of c-enum to be enabled"
+        );
+
+        $crate::__c_enum_impl!(impl(struct) $opts $attrs $vis $name [ $inner ] $field_vis);
+    };
+}
@@ -0,0 +1,121 @@
+//! Helper macros for `#[c_enum(zvariant)]`.
+//!
+//! Like the serde helpers, these are defined differently depending on whether
+//! the `zvariant` feature is enabled.
+
+/// Implements zvariant's `Type` for a C enum along with conversions to and
+/// from `Value` and `OwnedValue`, all of which forward to the inner type.
+#[cfg(feature = "zvariant")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_zvariant {
+    ($name:ident [ $inner:ty ]) => {
+        #[automatically_derived]
+        impl $crate::__private::zvariant::Type for $name
+        where
+            for<'a> $inner: $crate::__private::zvariant::Type,
+        {
+            const SIGNATURE: &'static $crate::__private::zvariant::Signature =
+                <$inner as $crate::__private::zvariant::Type>::SIGNATURE;
+        }
+
+        #[automatically_derived]
+        impl<'a> ::core::convert::From<$name> for $crate::__private::zvariant::Value<'a>
+        where
+            $crate::__private::zvariant::Value<'a>: ::core::convert::From<$inner>,
+        {
+            fn from(value: $name) -> Self {
+                ::core::convert::From::from(value.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> ::core::convert::TryFrom<$crate::__private::zvariant::Value<'a>> for $name
+        where
+            $inner: ::core::convert::TryFrom<
+                $crate::__private::zvariant::Value<'a>,
+                Error = $crate::__private::zvariant::Error,
+            >,
+        {
+            type Error = $crate::__private::zvariant::Error;
+
+            fn try_from(
+                value: $crate::__private::zvariant::Value<'a>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <$inner as ::core::convert::TryFrom<_>>::try_from(value).map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a, 'b> ::core::convert::TryFrom<&'b $crate::__private::zvariant::Value<'a>> for $name
+        where
+            $inner: ::core::convert::TryFrom<
+                &'b $crate::__private::zvariant::Value<'a>,
+                Error = $crate::__private::zvariant::Error,
+            >,
+        {
+            type Error = $crate::__private::zvariant::Error;
+
+            fn try_from(
+                value: &'b $crate::__private::zvariant::Value<'a>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <$inner as ::core::convert::TryFrom<_>>::try_from(value).map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<$name> for $crate::__private::zvariant::OwnedValue
+        where
+            for<'a> $crate::__private::zvariant::OwnedValue: ::core::convert::From<$inner>,
+        {
+            fn from(value: $name) -> Self {
+                ::core::convert::From::from(value.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<$crate::__private::zvariant::OwnedValue> for $name
+        where
+            for<'a> $inner: ::core::convert::TryFrom<
+                $crate::__private::zvariant::OwnedValue,
+                Error = $crate::__private::zvariant::Error,
+            >,
+        {
+            type Error = $crate::__private::zvariant::Error;
+
+            fn try_from(
+                value: $crate::__private::zvariant::OwnedValue,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <$inner as ::core::convert::TryFrom<_>>::try_from(value).map(Self)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> ::core::convert::TryFrom<&'a $crate::__private::zvariant::OwnedValue> for $name
+        where
+            $inner: ::core::convert::TryFrom<
+                &'a $crate::__private::zvariant::OwnedValue,
+                Error = $crate::__private::zvariant::Error,
+            >,
+        {
+            type Error = $crate::__private::zvariant::Error;
+
+            fn try_from(
+                value: &'a $crate::__private::zvariant::OwnedValue,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <$inner as ::core::convert::TryFrom<_>>::try_from(value).map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "zvariant"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __c_enum_zvariant {
+    ($( $args:tt )*) => {
+        ::core::compile_error!(
+            "`#[c_enum(zvariant)]` requires the `zvariant` feature of c-enum to be enabled"
+        );
+    };
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(arbitrary)]
+    enum Event: u32 {
+        CPU_CYCLES = 0x1000,
+        INSTRUCTIONS,
+        CACHE_MISSES,
+    }
+}
+
+#[test]
+fn mostly_declared() {
+    let data: Vec<u8> = (0..4096u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+        .collect();
+    let mut u = Unstructured::new(&data);
+
+    let mut declared = 0;
+    let mut undeclared = 0;
+    for _ in 0..256 {
+        if Event::arbitrary(&mut u).unwrap().is_known() {
+            declared += 1;
+        } else {
+            undeclared += 1;
+        }
+    }
+
+    assert!(declared > undeclared);
+    assert!(undeclared > 0);
+}
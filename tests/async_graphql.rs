@@ -0,0 +1,82 @@
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(async_graphql)]
+    pub enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(async_graphql = "int")]
+    pub enum RawEvent: i32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn event(&self, value: u32) -> Event {
+        Event(value)
+    }
+
+    async fn next(&self, event: Event) -> u32 {
+        event.0 + 1
+    }
+
+    async fn raw(&self, event: RawEvent) -> RawEvent {
+        event
+    }
+}
+
+fn execute(query: &str) -> async_graphql::Response {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    futures_executor::block_on(schema.execute(query))
+}
+
+#[test]
+fn enum_mode() {
+    let response = execute("{ event(value: 1) next(event: CPU_CYCLES) }");
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        serde_json::json!({ "event": "INSTRUCTIONS", "next": 1 })
+    );
+
+    let response = execute("{ event(value: 7) }");
+    assert_eq!(response.errors.len(), 1);
+
+    let response = execute("{ next(event: BRANCHES) }");
+    assert_eq!(response.errors.len(), 1);
+}
+
+#[test]
+fn int_mode() {
+    let response = execute("{ raw(event: 7) }");
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        serde_json::json!({ "raw": 7 })
+    );
+}
+
+#[test]
+fn sdl() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+
+    assert!(
+        sdl.contains("enum Event {\n\tCPU_CYCLES\n\tINSTRUCTIONS\n}"),
+        "{sdl}"
+    );
+    assert!(sdl.contains("raw(event: Int!): Int!"), "{sdl}");
+}
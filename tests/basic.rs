@@ -2,6 +2,7 @@ use c_enum::*;
 
 c_enum! {
     #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    #[c_enum(consts)]
     pub enum Software : u64 {
         /// Doc comments
         CPU_CYCLES,
@@ -15,6 +16,7 @@ c_enum! {
 
 c_enum! {
     #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    #[c_enum(consts)]
     pub enum Duplicates : u8 {
         ITEM1 = 2,
         ITEM2 = 2
@@ -62,3 +64,1032 @@ fn variant_label_overlap_assigned() {
     assert_eq!(Overlap::A1, Overlap::B1);
     assert_eq!(Overlap::A3, Overlap::B3);
 }
+
+#[test]
+fn variants_in_declaration_order() {
+    assert_eq!(
+        Software::VARIANTS,
+        &[
+            Software::CPU_CYCLES,
+            Software::INSTRUCTIONS,
+            Software::CACHE_REFERENCES,
+            Software::CACHE_MISSES,
+            Software::BRANCH_INSTRUCTIONS,
+            Software::Lowercase,
+        ]
+    );
+    assert_eq!(
+        Duplicates::variants().copied().collect::<Vec<_>>(),
+        [Duplicates::ITEM1, Duplicates::ITEM2]
+    );
+}
+
+#[test]
+fn variant_count() {
+    assert_eq!(Software::VARIANT_COUNT, 6);
+    assert_eq!(Duplicates::VARIANT_COUNT, 2);
+
+    // It can be used to size arrays.
+    let counts = [0u32; Software::VARIANT_COUNT];
+    assert_eq!(counts.len(), 6);
+}
+
+#[test]
+fn min_max_variant() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Signed : std::os::raw::c_int {
+            A = 4,
+            B = -7,
+            C = 12,
+            D = 3,
+        }
+    }
+
+    assert_eq!(Software::MIN_VARIANT, Software::CPU_CYCLES);
+    assert_eq!(Software::MAX_VARIANT, Software::Lowercase);
+    assert_eq!(Signed::MIN_VARIANT, Signed::B);
+    assert_eq!(Signed::MAX_VARIANT, Signed::C);
+}
+
+#[test]
+fn sorted_variants() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Unsorted : i16 {
+            A = 4,
+            B = -7,
+            C = 12,
+            D = 4,
+        }
+    }
+
+    assert_eq!(
+        Unsorted::VARIANTS_BY_VALUE,
+        &[Unsorted::B, Unsorted::A, Unsorted::D, Unsorted::C]
+    );
+    assert_eq!(Unsorted::iter_sorted().last(), Some(&Unsorted::C));
+}
+
+#[test]
+fn variant_index() {
+    const INDEX: Option<usize> = Software::CACHE_REFERENCES.variant_index();
+
+    assert_eq!(INDEX, Some(2));
+    assert_eq!(Software(1).variant_index(), None);
+    assert_eq!(Software::from_index(2), Some(Software::CACHE_REFERENCES));
+    assert_eq!(Software::from_index(Software::VARIANT_COUNT), None);
+
+    for (index, variant) in Software::variants().enumerate() {
+        assert_eq!(variant.variant_index(), Some(index));
+    }
+}
+
+#[test]
+fn aliases() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Aliased : u8 {
+            #[alias]
+            OLD_A = Self::A.0,
+            A = 1,
+            B,
+            /// Aliases can have other attributes too.
+            #[alias]
+            OLD_B = 2,
+        }
+    }
+
+    assert_eq!(Aliased::OLD_A, Aliased::A);
+    assert_eq!(Aliased::OLD_A.as_str(), Some("A"));
+    assert_eq!(Aliased::OLD_B.label(), Some("B"));
+    assert_eq!(Aliased::VARIANTS, &[Aliased::A, Aliased::B]);
+    assert_eq!(Aliased::LABELS, &["A", "B"]);
+    assert_eq!(Aliased::VALUES, &[1, 2]);
+    assert_eq!(Aliased::VARIANT_COUNT, 2);
+    assert_eq!(Aliased::from_name("OLD_B"), Some(Aliased::B));
+}
+
+#[test]
+fn skipped() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Format : u8 {
+            RGB,
+            RGBA,
+            /// Skipped variants can have other attributes too.
+            #[c_enum(skip)]
+            FORMAT_MAX,
+        }
+    }
+
+    assert_eq!(Format::FORMAT_MAX.0, 2);
+    assert_eq!(Format::FORMAT_MAX.label(), None);
+    assert_eq!(Format::VARIANTS, &[Format::RGB, Format::RGBA]);
+    assert_eq!(Format::LABELS, &["RGB", "RGBA"]);
+    assert_eq!(Format::VARIANT_COUNT, 2);
+    assert_eq!(Format::from_name("FORMAT_MAX"), None);
+    assert_eq!(format!("{:?}", Format::FORMAT_MAX), "Format(2)");
+}
+
+#[test]
+fn ranges() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Protocol : u8 {
+            TCP = 6,
+            UDP = 17,
+            EXPERIMENTAL = 240..=254,
+            SPECIAL = 250,
+            LOW = 20..(0x20),
+        }
+    }
+
+    assert_eq!(Protocol::from(245).as_str(), Some("EXPERIMENTAL"));
+    assert_eq!(Protocol::from(250).as_str(), Some("SPECIAL"));
+    assert_eq!(Protocol::from(31).label(), Some("LOW"));
+    assert_eq!(Protocol::from(32).label(), None);
+    assert_eq!(Protocol::from(255).label(), None);
+    assert!(Protocol::from(240).in_range(Protocol::EXPERIMENTAL));
+    assert!(!Protocol::from(239).in_range(Protocol::EXPERIMENTAL));
+    assert!(Protocol::TCP.in_range(Protocol::TCP));
+    assert_eq!(
+        format!("{:?}", Protocol::from(245)),
+        "Protocol::EXPERIMENTAL(245)"
+    );
+    assert_eq!(
+        format!("{:?}", Protocol::EXPERIMENTAL),
+        "Protocol::EXPERIMENTAL"
+    );
+    assert_eq!(format!("{:?}", Protocol::SPECIAL), "Protocol::SPECIAL");
+    assert_eq!(format!("{:?}", Protocol::from(255)), "Protocol(255)");
+    assert_eq!(
+        Protocol::RANGES,
+        &[("EXPERIMENTAL", 240..=254), ("LOW", 20..=31)]
+    );
+}
+
+#[test]
+fn ranges_with_expressions() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Protocol : u8 {
+            SUM = 1 + 2,
+            NEXT,
+            EXPERIMENTAL = 240..=254,
+            SHIFTED = 1 << 4,
+            TARGETED = { #[cfg(any())] 0x20, 0x30 },
+            MASKED = 0x4f & !0xf,
+        }
+    }
+
+    assert_eq!(Protocol::SUM.0, 3);
+    assert_eq!(Protocol::NEXT.0, 4);
+    assert_eq!(Protocol::SHIFTED.0, 0x10);
+    assert_eq!(Protocol::MASKED.0, 0x40);
+    assert_eq!(Protocol::VALUES, [3, 4, 240, 0x10, 0x30, 0x40]);
+    assert_eq!(Protocol::from(245).label(), Some("EXPERIMENTAL"));
+    assert_eq!(Protocol::RANGES, &[("EXPERIMENTAL", 240..=254)]);
+}
+
+#[test]
+fn categories() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Event : u32 {
+            #[category = "hardware"]
+            CPU_CYCLES,
+            /// Categories can be combined with other attributes.
+            #[category = "software"]
+            CPU_CLOCK,
+            #[category = "hardware"]
+            INSTRUCTIONS,
+            OTHER,
+        }
+    }
+
+    const CATEGORY: Option<&str> = Event::INSTRUCTIONS.category();
+
+    assert_eq!(CATEGORY, Some("hardware"));
+    assert_eq!(Event::CPU_CLOCK.category(), Some("software"));
+    assert_eq!(Event::OTHER.category(), None);
+    assert_eq!(Event(17).category(), None);
+    assert_eq!(
+        Event::variants_in_category("hardware").collect::<Vec<_>>(),
+        [&Event::CPU_CYCLES, &Event::INSTRUCTIONS]
+    );
+    assert_eq!(Event::variants_in_category("cache").count(), 0);
+}
+
+#[test]
+fn categories_not_generated_without_attribute() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Event : u32 {
+            CPU_CYCLES,
+            OTHER,
+        }
+
+        impl {
+            pub const CATEGORIES: &'static [&'static str] = &["hardware"];
+
+            pub fn category(&self) -> &'static str {
+                Self::CATEGORIES[0]
+            }
+        }
+    }
+
+    assert_eq!(Event::CPU_CYCLES.category(), "hardware");
+}
+
+#[test]
+fn metadata() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Counter : u32 {
+            #[meta(description = "Bytes read from disk", unit = "bytes")]
+            DISK_READ,
+            /// Metadata can be combined with other attributes.
+            #[meta(unit = "cycles",)]
+            CPU_CYCLES,
+            OTHER,
+        }
+    }
+
+    assert_eq!(
+        Counter::DISK_READ.meta("description"),
+        Some("Bytes read from disk")
+    );
+    assert_eq!(Counter::CPU_CYCLES.meta("unit"), Some("cycles"));
+    assert_eq!(Counter::CPU_CYCLES.meta("description"), None);
+    assert_eq!(Counter::OTHER.meta("unit"), None);
+    assert_eq!(Counter(7).meta("unit"), None);
+    assert_eq!(Counter::METADATA[1], &[("unit", "cycles")]);
+}
+
+#[test]
+fn metadata_not_generated_without_attribute() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Counter : u32 {
+            DISK_READ,
+            OTHER,
+        }
+
+        impl {
+            pub const METADATA: &'static str = "counters";
+
+            pub fn meta(&self) -> &'static str {
+                Self::METADATA
+            }
+        }
+    }
+
+    assert_eq!(Counter::OTHER.meta(), "counters");
+}
+
+#[test]
+fn predicates() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Event : u32 {
+            #[predicate = is_cpu_cycles]
+            CPU_CYCLES,
+            /// Predicates can be combined with other attributes.
+            #[predicate = is_instructions]
+            INSTRUCTIONS = 5..=9,
+            OTHER,
+        }
+    }
+
+    const _: () = assert!(Event::CPU_CYCLES.is_cpu_cycles());
+
+    assert!(!Event::INSTRUCTIONS.is_cpu_cycles());
+    assert!(Event::INSTRUCTIONS.is_instructions());
+    assert!(Event(7).is_instructions());
+    assert!(!Event(10).is_instructions());
+}
+
+#[test]
+fn is_known() {
+    const _: () = assert!(Software::INSTRUCTIONS.is_known());
+
+    assert!(Software::Lowercase.is_known());
+    assert!(!Software(1).is_known());
+    assert!(!Software(u64::MAX).is_known());
+}
+
+#[test]
+fn is_known_non_integer() {
+    c_enum! {
+        enum Strings : &'static str {
+            Hello = "hello",
+            World = "world",
+        }
+    }
+
+    assert!(Strings::Hello.is_known());
+    assert!(Strings::from("world").is_known());
+    assert!(!Strings::from("other").is_known());
+}
+
+#[test]
+fn from_name() {
+    assert_eq!(
+        Software::from_name("CACHE_MISSES"),
+        Some(Software::CACHE_MISSES)
+    );
+    assert_eq!(Software::from_name("Lowercase"), Some(Software::Lowercase));
+    assert_eq!(Software::from_name("lowercase"), None);
+    assert_eq!(Software::from_name(""), None);
+
+    // Duplicate values are still looked up by their own name.
+    assert_eq!(Duplicates::from_name("ITEM2"), Some(Duplicates::ITEM2));
+}
+
+#[test]
+fn as_str() {
+    assert_eq!(Software::CACHE_MISSES.as_str(), Some("CACHE_MISSES"));
+    assert_eq!(Software(77).as_str(), None);
+    assert_eq!(Duplicates::ITEM2.as_str(), Some("ITEM1"));
+}
+
+#[test]
+fn const_new_and_value() {
+    const VALUE: Software = Software::new(4);
+    const RAW: u64 = Software::CACHE_MISSES.value();
+
+    assert_eq!(VALUE, Software::CACHE_MISSES);
+    assert_eq!(RAW, 4);
+    assert_eq!(Software::new(RAW).value(), 4);
+}
+
+#[test]
+fn labels() {
+    assert_eq!(
+        Software::LABELS,
+        &[
+            "CPU_CYCLES",
+            "INSTRUCTIONS",
+            "CACHE_REFERENCES",
+            "CACHE_MISSES",
+            "BRANCH_INSTRUCTIONS",
+            "Lowercase",
+        ]
+    );
+    assert_eq!(Duplicates::LABELS, &["ITEM1", "ITEM2"]);
+}
+
+#[test]
+fn values() {
+    assert_eq!(Software::VALUES, &[0, 2, 3, 4, 5, 6]);
+    assert_eq!(Duplicates::VALUES, &[2, 2]);
+
+    for (variant, value) in Software::variants().zip(Software::VALUES) {
+        assert_eq!(variant.0, *value);
+    }
+}
+
+#[test]
+fn entries() {
+    assert_eq!(Duplicates::ENTRIES, &[("ITEM1", 2), ("ITEM2", 2)]);
+
+    for (&(label, value), variant) in Software::ENTRIES.iter().zip(Software::variants()) {
+        assert_eq!(Software::from_name(label), Some(*variant));
+        assert_eq!(variant.0, value);
+    }
+}
+
+#[test]
+fn variants_named_like_consts() {
+    c_enum! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Table : u8 {
+            LABELS,
+            VALUES,
+            ENTRIES,
+            VARIANTS,
+            VARIANT_COUNT,
+            VARIANTS_BY_VALUE,
+            MIN_VARIANT,
+            MAX_VARIANT,
+        }
+    }
+
+    assert_eq!(Table::VALUES.value(), 1);
+    assert_eq!(Table::from_name("ENTRIES"), Some(Table::ENTRIES));
+    assert_eq!(Table::new(0).as_str(), Some("LABELS"));
+    assert_eq!(Table::variants().len(), 8);
+    assert_eq!(Table::VARIANT_COUNT.as_str(), Some("VARIANT_COUNT"));
+    assert_eq!(Table::iter_sorted().last(), Some(&Table::MAX_VARIANT));
+    assert_eq!(Table::MIN_VARIANT.value(), 6);
+}
+
+#[test]
+fn const_label() {
+    const LABELS: [Option<&str>; 3] = [
+        Software::CPU_CYCLES.label(),
+        Software::new(1).label(),
+        Duplicates::ITEM2.label(),
+    ];
+
+    assert_eq!(LABELS, [Some("CPU_CYCLES"), None, Some("ITEM1")]);
+}
+
+#[test]
+fn try_from_value() {
+    assert_eq!(
+        Software::try_from_value(5),
+        Ok(Software::BRANCH_INSTRUCTIONS)
+    );
+    assert_eq!(Software::try_from_value(1), Err(UnknownValueError(1)));
+    assert_eq!(Software::try_from_value(77).unwrap_err().into_value(), 77);
+}
+
+#[test]
+fn compare_with_inner() {
+    assert!(Software::CACHE_MISSES == 4);
+    assert!(4 == Software::CACHE_MISSES);
+    assert!(Software::CACHE_MISSES != 5u64);
+    assert!(5u64 != Software::CACHE_MISSES);
+}
+
+#[test]
+fn default_variant() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum WithDefault : u8 {
+            A,
+            /// Doc comments can come before the attribute.
+            #[default]
+            B,
+            C,
+        }
+    }
+
+    assert_eq!(WithDefault::default(), WithDefault::B);
+    assert_eq!(WithDefault::B.0, 1);
+}
+
+#[test]
+fn arithmetic_helpers() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Errno : u8 {
+            BASE = 250,
+        }
+    }
+
+    assert_eq!(Errno::BASE.offset(3), 253);
+    assert_eq!(Errno::BASE.checked_add(5), Some(Errno::from(255)));
+    assert_eq!(Errno::BASE.checked_add(6), None);
+    assert_eq!(Errno::BASE.wrapping_add(6), 0);
+}
+
+#[test]
+fn byte_helpers() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Kind : std::os::raw::c_ushort {
+            DATA = 0x0102,
+        }
+    }
+
+    const BYTES: [u8; 2] = Kind::DATA.to_be_bytes();
+
+    assert_eq!(BYTES, [0x01, 0x02]);
+    assert_eq!(Kind::DATA.to_le_bytes(), [0x02, 0x01]);
+    assert_eq!(Kind::DATA.to_ne_bytes(), 0x0102u16.to_ne_bytes());
+    assert_eq!(Kind::from_be_bytes([0x01, 0x02]), Kind::DATA);
+    assert_eq!(Kind::from_le_bytes([0x02, 0x01]), Kind::DATA);
+    assert_eq!(Kind::from_ne_bytes(0x0304u16.to_ne_bytes()), Kind(0x0304));
+}
+
+#[test]
+fn radix_formatting() {
+    let value = Software::from(0x1f4);
+
+    assert_eq!(format!("{:x}", value), "1f4");
+    assert_eq!(format!("{:#X}", value), "0x1F4");
+    assert_eq!(format!("{:o}", Software::Lowercase), "6");
+    assert_eq!(format!("{:#010b}", Software::CACHE_MISSES), "0b00000100");
+}
+
+#[test]
+fn cfg_variants() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(bitops, consts)]
+        enum Feature : u32 {
+            A,
+            #[cfg(any())]
+            B,
+            /// Present everywhere.
+            #[cfg(all())]
+            C,
+            #[cfg(any())]
+            D = 10,
+            E = 11,
+            #[default]
+            #[cfg(any())]
+            F = 20..=100,
+        }
+    }
+
+    assert_eq!(Feature::A.0, 0);
+    assert_eq!(Feature::C.0, 2);
+    assert_eq!(Feature::E.0, 11);
+    assert_eq!(Feature::LABELS, ["A", "C", "E"]);
+    assert_eq!(Feature::VALUES, [0, 2, 11]);
+    assert_eq!(Feature::ALL, Feature(0b1011));
+    assert_eq!(Feature::MAX_VARIANT, Feature::E);
+    assert_eq!(Feature::from_name("B"), None);
+    assert_eq!(Feature::from_name("C"), Some(Feature::C));
+    assert!(!Feature(10).is_known());
+    assert_eq!(Feature(50).label(), None);
+    assert_eq!(Feature::E.label(), Some("E"));
+}
+
+#[test]
+fn cfg_variants_keep_numbering() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Present : u8 {
+            A,
+            #[cfg(all())]
+            B,
+            C,
+            #[cfg(all())]
+            D = 10,
+            E = 20,
+            F,
+        }
+    }
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Removed : u8 {
+            A,
+            #[cfg(any())]
+            B,
+            C,
+            #[cfg(any())]
+            D = 10,
+            E = 20,
+            F,
+        }
+    }
+
+    assert_eq!(Present::VALUES, [0, 1, 2, 10, 20, 21]);
+    assert_eq!(Removed::VALUES, [0, 2, 20, 21]);
+    assert_eq!(Present::C.0, Removed::C.0);
+    assert_eq!(Present::F.0, Removed::F.0);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(consts)]
+    pub enum WithItems : u32 {
+        READ = 1,
+        WRITE = 2,
+    }
+
+    #[allow(dead_code)]
+    impl {
+        pub const READ_WRITE: Self = Self(Self::READ.0 | Self::WRITE.0);
+
+        pub fn is_writable(self) -> bool {
+            self.0 & Self::WRITE.0 != 0
+        }
+
+        fn unused() {}
+    }
+}
+
+#[test]
+fn inherent_items() {
+    assert_eq!(WithItems::READ_WRITE.0, 3);
+    assert!(WithItems::READ_WRITE.is_writable());
+    assert!(!WithItems::READ.is_writable());
+    assert_eq!(WithItems::VARIANTS, [WithItems::READ, WithItems::WRITE]);
+}
+
+#[test]
+fn wide_integers() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(bitops, consts)]
+        enum Features : u128 {
+            AES = 1 << 100,
+            SHA,
+            LAST = u128::MAX - 1,
+            END,
+        }
+    }
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Offset : i128 {
+            MIN = i128::MIN,
+            NEXT,
+        }
+    }
+
+    assert_eq!(Features::SHA.0, (1 << 100) + 1);
+    assert_eq!(Features::END.0, u128::MAX);
+    assert_eq!(Features::MAX_VARIANT, Features::END);
+    assert_eq!(Features::ALL.0, u128::MAX);
+    assert_eq!(
+        Features::from_be_bytes(Features::AES.to_be_bytes()),
+        Features::AES
+    );
+    assert_eq!(format!("{:?}", Features::SHA), "Features::SHA");
+    assert_eq!(format!("{:?}", Features(3)), "Features(3)");
+    assert_eq!(Offset::NEXT.0, i128::MIN + 1);
+    assert_eq!(Offset::NEXT.label(), Some("NEXT"));
+}
+
+#[test]
+fn wrapping_inner() {
+    use std::num::Wrapping;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(consts)]
+        enum Counter : std::num::Wrapping<u8> {
+            A,
+            B,
+            C = Wrapping(u8::MAX),
+            D,
+        }
+    }
+
+    assert_eq!(Counter::A.0, Wrapping(0));
+    assert_eq!(Counter::B.0, Wrapping(1));
+    assert_eq!(Counter::D.0, Wrapping(0));
+    assert_eq!(
+        Counter::VALUES,
+        [Wrapping(0), Wrapping(1), Wrapping(255), Wrapping(0)]
+    );
+    assert_eq!(format!("{:?}", Counter::B), "Counter::B");
+    assert_eq!(format!("{:?}", Counter(Wrapping(7))), "Counter(7)");
+    assert_eq!(format!("{:x}", Counter::C), "ff");
+    assert!(Counter::C.is_known());
+}
+
+#[test]
+fn nonzero_inner() {
+    use std::num::NonZeroU16;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[repr(transparent)]
+        #[c_enum(consts)]
+        enum Handle : std::num::NonZeroU16 {
+            A,
+            B,
+            C = 0x100,
+            D,
+        }
+    }
+
+    let raw = |value| NonZeroU16::new(value).unwrap();
+
+    assert_eq!(Handle::VALUES, [raw(1), raw(2), raw(0x100), raw(0x101)]);
+    assert_eq!(Handle::from(raw(2)), Handle::B);
+    assert_eq!(NonZeroU16::from(Handle::D), raw(0x101));
+    assert_eq!(format!("{:?}", Handle::C), "Handle::C");
+    assert_eq!(format!("{:?}", Handle(raw(7))), "Handle(7)");
+    assert_eq!(std::mem::size_of::<Option<Handle>>(), 2);
+}
+
+#[test]
+fn endian_inner() {
+    use c_enum::endian::{U32Be, U32Le};
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Wire : c_enum::endian::U32Be {
+            A = 0x01020304,
+            B,
+            #[cfg(any())]
+            C,
+            D,
+        }
+    }
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Host : U32Le {
+            A = 1,
+        }
+    }
+
+    assert_eq!(Wire::A.value().to_bytes(), [1, 2, 3, 4]);
+    assert_eq!(Host::A.value().to_bytes(), [1, 0, 0, 0]);
+    assert_eq!(Wire::B, 0x01020305u32);
+    assert_eq!(0x01020307u32, Wire::D);
+    assert_eq!(Wire::from(U32Be::from_bytes([1, 2, 3, 5])), Wire::B);
+    assert_eq!(format!("{:?}", Wire::B), "Wire::B");
+    assert_eq!(format!("{:?}", Wire(U32Be::new(9))), "Wire(9)");
+    assert_eq!(format!("{:x}", Wire::A), "1020304");
+    assert_eq!(Wire::from_name("D"), Some(Wire::D));
+}
+
+#[test]
+fn string_default_values() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Plain : &'static str {
+            Hello,
+            World = "world",
+        }
+    }
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(rename_all = "kebab-case", strip_prefix = "Header", consts)]
+        enum Header : &'static str {
+            HeaderContentType,
+            #[cfg(any())]
+            HeaderRemoved,
+            #[label = "X-Custom"]
+            HeaderCustom,
+        }
+    }
+
+    assert_eq!(Plain::Hello, "Hello");
+    assert_eq!(Plain::World, "world");
+    assert_eq!(Header::VALUES, ["content-type", "X-Custom"]);
+    assert_eq!(
+        Header::from_name("content-type"),
+        Some(Header::HeaderContentType)
+    );
+}
+
+#[test]
+fn float_inner() {
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq)]
+        enum Rate : f32 {
+            A = 44100.0,
+            B,
+            #[cfg(any())]
+            C,
+            D,
+            #[predicate = is_nan]
+            N = f32::NAN,
+            Z = -0.0,
+        }
+
+        #[derive(Copy, Clone, PartialEq)]
+        #[c_enum(known = KnownRatio)]
+        enum Ratio : f32 {
+            N = f32::NAN,
+            Z = -0.0,
+        }
+    }
+
+    assert_eq!(Rate::B.value(), 44101.0);
+    assert_eq!(Rate::D.value(), 44103.0);
+    assert_eq!(Rate::N.as_str(), Some("N"));
+    assert!(Rate(f32::NAN).is_nan());
+    assert_eq!(Rate::N.variant_index(), Some(3));
+    assert_eq!(KnownRatio::try_from(Ratio::N), Ok(KnownRatio::N));
+    assert_eq!(KnownRatio::try_from(Ratio(0.0)).ok(), None);
+    assert!(Rate(-0.0).is_known());
+    assert!(!Rate(0.0).is_known());
+    assert_eq!(format!("{:?}", Rate::Z), "Rate::Z");
+    assert_eq!(format!("{:?}", Rate(0.0)), "Rate(0.0)");
+    assert_ne!(Rate::N, Rate::N);
+}
+
+#[test]
+fn byte_string_inner() {
+    use core::ffi::CStr;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Key : &'static [u8] {
+            A = b"foo",
+            B = b"\"bar\"\n",
+        }
+    }
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Name : &'static CStr {
+            A = c"foo",
+        }
+    }
+
+    assert_eq!(Key::B, &b"\"bar\"\n"[..]);
+    assert_eq!(Key::from_name("A"), Some(Key::A));
+    assert_eq!(format!("{:?}", Key::B), "Key::B");
+    assert_eq!(format!("{:?}", Key(b"a\"\xff")), r#"Key(b"a\"\xff")"#);
+    assert_eq!(Name::A.value().to_bytes(), b"foo");
+    assert_eq!(format!("{:?}", Name(c"a\x01")), r#"Name("a\x01")"#);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(extensions = (vendor::VendorEvent), parse = "case_insensitive", consts)]
+    pub enum Event : u16 {
+        Start,
+        Stop,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(extensions = (vendor::VendorPort))]
+    pub enum Port : core::num::NonZeroU16 {
+        Http = 80,
+    }
+}
+
+mod vendor {
+    use super::{Event, Port};
+    use c_enum::c_enum_extend;
+
+    c_enum_extend! {
+        /// Events that are only sent by some devices.
+        pub enum VendorEvent extends Event {
+            Reset = 0x8000,
+            #[cfg(any())]
+            Missing,
+            #[label = "flush"]
+            Flush,
+        }
+    }
+
+    c_enum_extend! {
+        pub enum VendorPort extends Port {
+            Admin = 8080,
+            Metrics,
+        }
+    }
+}
+
+#[test]
+fn extensions() {
+    assert_eq!(Event::Flush.value(), 0x8002);
+    assert!(Event::Reset.is_known());
+    assert!(!Event(0x8001).is_known());
+    assert_eq!(Event::Flush.label(), Some("flush"));
+    assert_eq!(Event::Reset.as_str(), Some("Reset"));
+    assert_eq!(Event::from_name("reset"), Some(Event::Reset));
+    assert_eq!(format!("{:?}", Event::Flush), "Event::flush");
+    assert_eq!(format!("{:?}", Event(0x8001)), "Event(32769)");
+    assert_eq!(
+        vendor::VendorEvent::ENTRIES,
+        [("Reset", Event::Reset), ("flush", Event::Flush)]
+    );
+    assert_eq!(Event::LABELS, ["Start", "Stop"]);
+
+    assert_eq!(Port::Metrics.value().get(), 8081);
+    assert_eq!(Port::from_name("Admin"), Some(Port::Admin));
+    assert_eq!(format!("{:?}", Port::Metrics), "Port::Metrics");
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum CommonStatus : u32 {
+        Ok,
+        #[label = "busy"]
+        Busy = 5,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum RetryStatus : u32 {
+        Retry = 50,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(consts)]
+    pub enum DeviceStatus : u32 {
+        include CommonStatus, RetryStatus;
+        Stalled,
+    }
+}
+
+#[test]
+fn included_variants() {
+    assert_eq!(DeviceStatus::Busy.value(), 5);
+    assert_eq!(DeviceStatus::Retry.value(), 50);
+    assert_eq!(DeviceStatus::Stalled.value(), 51);
+    assert_eq!(DeviceStatus::LABELS, ["Ok", "busy", "Retry", "Stalled"]);
+    assert_eq!(DeviceStatus::from(CommonStatus::Busy), DeviceStatus::Busy);
+    assert_eq!(RetryStatus::from(DeviceStatus::Retry), RetryStatus::Retry);
+    assert_eq!(CommonStatus::from(DeviceStatus::Stalled).value(), 51);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(known = KnownKeyword, consts)]
+    pub enum Keyword : u8 {
+        r#type,
+        r#async,
+        r#match = 5,
+        Plain,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(rename_all = "UPPERCASE", consts)]
+    pub enum UpperKeyword : u8 {
+        r#loop,
+    }
+}
+
+#[test]
+fn raw_identifiers() {
+    assert_eq!(Keyword::r#match.value(), 5);
+    assert_eq!(Keyword::LABELS, ["type", "async", "match", "Plain"]);
+    assert_eq!(Keyword::r#type.variant_label(), Some("type"));
+    assert_eq!(Keyword::from_name("async"), Some(Keyword::r#async));
+    assert_eq!(Keyword::from_name("r#async"), None);
+    assert_eq!(format!("{:?}", Keyword::r#match), "Keyword::match");
+    assert_eq!(Keyword::r#type.classify(), Ok(KnownKeyword::r#type));
+    assert_eq!(UpperKeyword::LABELS, ["LOOP"]);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(consts)]
+    pub enum Saturated : u8 {
+        Low = 253,
+        Mid,
+        High,
+    }
+}
+
+#[test]
+fn values_up_to_max() {
+    assert_eq!(Saturated::VALUES, [253, 254, 255]);
+}
+
+mod visibility {
+    use c_enum::c_enum;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(consts)]
+        pub enum Access : u8 {
+            Read,
+            pub(crate) Internal,
+            pub(super) Parent = 5,
+            pub(in crate::visibility) Local,
+            pub Write,
+        }
+    }
+
+    pub fn local() -> Access {
+        Access::Local
+    }
+}
+
+#[test]
+fn variant_visibility() {
+    use visibility::Access;
+
+    assert_eq!(Access::VALUES, [0, 1, 5, 6, 7]);
+    assert_eq!(Access::Internal.value(), 1);
+    assert_eq!(Access::Parent.value(), 5);
+    assert_eq!(visibility::local().variant_label(), Some("Local"));
+    assert_eq!(format!("{:?}", Access::Write), "Access::Write");
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(consts)]
+    pub enum Targeted : u32 {
+        First = 1,
+        Selected = {
+            #[cfg(any())] 0x10,
+            #[cfg(all())] 0x20,
+            #[cfg(all())] 0x30,
+        },
+        Next,
+        Fallback = { #[cfg(any())] 0x40, 0x50 },
+        Path = core::u32::MAX,
+    }
+}
+
+#[test]
+fn cfg_values() {
+    assert_eq!(Targeted::VALUES, [1, 0x20, 0x21, 0x50, u32::MAX]);
+    assert_eq!(Targeted::Selected.variant_label(), Some("Selected"));
+}
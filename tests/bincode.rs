@@ -0,0 +1,41 @@
+#![cfg(feature = "bincode")]
+
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(bincode)]
+    enum Event: u16 {
+        CPU_CYCLES,
+        INSTRUCTIONS = 0x1234,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(bincode)]
+    enum Name: &'static str {
+        A = "a",
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let config = bincode::config::standard().with_fixed_int_encoding();
+    let samples = [Event::INSTRUCTIONS, Event(77), Event::CPU_CYCLES];
+
+    let bytes = bincode::encode_to_vec(samples, config).unwrap();
+    assert_eq!(bytes, [0x34, 0x12, 77, 0, 0, 0]);
+
+    let (decoded, _): ([Event; 3], _) = bincode::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(decoded, samples);
+}
+
+#[test]
+fn borrow_decode() {
+    let config = bincode::config::standard();
+    let bytes: &'static [u8] = bincode::encode_to_vec(Name::A, config).unwrap().leak();
+
+    let (decoded, _): (Name, _) = bincode::borrow_decode_from_slice(bytes, config).unwrap();
+    assert_eq!(decoded, Name::A);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "binrw")]
+
+use std::io::Cursor;
+
+use binrw::{binrw, BinRead, BinWrite};
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(binrw)]
+    pub enum Kind: u16 {
+        HEADER,
+        PAYLOAD,
+    }
+}
+
+#[binrw]
+#[derive(Debug, PartialEq)]
+struct Record {
+    #[brw(big)]
+    big: Kind,
+    #[brw(little)]
+    little: Kind,
+}
+
+#[test]
+fn roundtrip() {
+    let record = Record {
+        big: Kind::PAYLOAD,
+        little: Kind(0x1234),
+    };
+
+    let mut cursor = Cursor::new(Vec::new());
+    record.write_le(&mut cursor).unwrap();
+    assert_eq!(cursor.get_ref(), &[0x00, 0x01, 0x34, 0x12]);
+
+    cursor.set_position(0);
+    let decoded = Record::read_le(&mut cursor).unwrap();
+    assert_eq!(decoded, record);
+}
+
+#[test]
+fn endian() {
+    let mut cursor = Cursor::new([0x00, 0x01]);
+    assert_eq!(Kind::read_be(&mut cursor).unwrap(), Kind::PAYLOAD);
+
+    let mut cursor = Cursor::new([0x01, 0x00]);
+    assert_eq!(Kind::read_le(&mut cursor).unwrap(), Kind::PAYLOAD);
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "bytemuck")]
+
+use c_enum::c_enum;
+
+c_enum! {
+    /// Attributes before the repr are fine.
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[repr(transparent)]
+    #[c_enum(bytemuck, rkyv)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn cast_slice() {
+    let raw: [u32; 3] = [1, 0, 77];
+    let events: &[Event] = bytemuck::cast_slice(&raw);
+
+    assert_eq!(events, [Event::INSTRUCTIONS, Event::CPU_CYCLES, Event(77)]);
+    assert_eq!(bytemuck::bytes_of(&Event::INSTRUCTIONS), 1u32.to_ne_bytes());
+    assert_eq!(<Event as bytemuck::Zeroable>::zeroed(), Event::CPU_CYCLES);
+}
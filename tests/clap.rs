@@ -0,0 +1,46 @@
+#![cfg(feature = "clap")]
+
+use c_enum::c_enum;
+use clap::ValueEnum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(clap, rename_all = "kebab-case")]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+        #[alias]
+        CYCLES = Self::CPU_CYCLES.0,
+    }
+}
+
+#[test]
+fn value_enum() {
+    assert_eq!(
+        Event::value_variants(),
+        [Event::CPU_CYCLES, Event::INSTRUCTIONS]
+    );
+    assert_eq!(Event::from_str("cpu-cycles", false), Ok(Event::CPU_CYCLES));
+    assert_eq!(
+        Event::from_str("Instructions", true),
+        Ok(Event::INSTRUCTIONS)
+    );
+    assert!(Event::from_str("CPU_CYCLES", false).is_err());
+}
+
+#[test]
+fn parser() {
+    let command = clap::Command::new("perf").arg(
+        clap::Arg::new("event")
+            .long("event")
+            .value_parser(clap::builder::EnumValueParser::<Event>::new()),
+    );
+
+    let matches = command
+        .try_get_matches_from(["perf", "--event", "instructions"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<Event>("event"),
+        Some(&Event::INSTRUCTIONS)
+    );
+}
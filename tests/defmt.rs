@@ -0,0 +1,28 @@
+#![cfg(feature = "defmt")]
+
+use c_enum::c_enum;
+
+c_enum! {
+    #[c_enum(defmt)]
+    enum Status: u8 {
+        OK,
+        BUSY,
+    }
+}
+
+c_enum! {
+    #[c_enum(defmt)]
+    enum Name: &'static str {
+        A = "a",
+    }
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+// Actually logging requires a global logger so we can only check that the
+// impls exist here.
+#[test]
+fn implements_format() {
+    assert_format::<Status>();
+    assert_format::<Name>();
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "deku")]
+
+use c_enum::c_enum;
+use deku::prelude::*;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(deku)]
+    pub enum Event: u16 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+struct Packet {
+    #[deku(endian = "big")]
+    big: Event,
+    #[deku(endian = "little")]
+    little: Event,
+}
+
+#[test]
+fn roundtrip() {
+    let packet = Packet {
+        big: Event::INSTRUCTIONS,
+        little: Event(0x1234),
+    };
+
+    let bytes = packet.to_bytes().unwrap();
+    assert_eq!(bytes, [0x00, 0x01, 0x34, 0x12]);
+
+    let (_, decoded) = Packet::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(decoded, packet);
+}
@@ -1028,3 +1028,616 @@ c_enum! {
         Variant1024,
     }
 }
+
+c_enum! {
+    enum LargeValued : u32 {
+        /// Variant 1.
+        Variant1 = 2,
+        /// Variant 2.
+        Variant2 = 4,
+        /// Variant 3.
+        Variant3 = 6,
+        /// Variant 4.
+        Variant4 = 8,
+        /// Variant 5.
+        Variant5 = 10,
+        /// Variant 6.
+        Variant6 = 12,
+        /// Variant 7.
+        Variant7 = 14,
+        /// Variant 8.
+        Variant8 = 16,
+        /// Variant 9.
+        Variant9 = 18,
+        /// Variant 10.
+        Variant10 = 20,
+        /// Variant 11.
+        Variant11 = 22,
+        /// Variant 12.
+        Variant12 = 24,
+        /// Variant 13.
+        Variant13 = 26,
+        /// Variant 14.
+        Variant14 = 28,
+        /// Variant 15.
+        Variant15 = 30,
+        /// Variant 16.
+        Variant16 = 32,
+        /// Variant 17.
+        Variant17 = 34,
+        /// Variant 18.
+        Variant18 = 36,
+        /// Variant 19.
+        Variant19 = 38,
+        /// Variant 20.
+        Variant20 = 40,
+        /// Variant 21.
+        Variant21 = 42,
+        /// Variant 22.
+        Variant22 = 44,
+        /// Variant 23.
+        Variant23 = 46,
+        /// Variant 24.
+        Variant24 = 48,
+        /// Variant 25.
+        Variant25 = 50,
+        /// Variant 26.
+        Variant26 = 52,
+        /// Variant 27.
+        Variant27 = 54,
+        /// Variant 28.
+        Variant28 = 56,
+        /// Variant 29.
+        Variant29 = 58,
+        /// Variant 30.
+        Variant30 = 60,
+        /// Variant 31.
+        Variant31 = 62,
+        /// Variant 32.
+        Variant32 = 64,
+        /// Variant 33.
+        Variant33 = 66,
+        /// Variant 34.
+        Variant34 = 68,
+        /// Variant 35.
+        Variant35 = 70,
+        /// Variant 36.
+        Variant36 = 72,
+        /// Variant 37.
+        Variant37 = 74,
+        /// Variant 38.
+        Variant38 = 76,
+        /// Variant 39.
+        Variant39 = 78,
+        /// Variant 40.
+        Variant40 = 80,
+        /// Variant 41.
+        Variant41 = 82,
+        /// Variant 42.
+        Variant42 = 84,
+        /// Variant 43.
+        Variant43 = 86,
+        /// Variant 44.
+        Variant44 = 88,
+        /// Variant 45.
+        Variant45 = 90,
+        /// Variant 46.
+        Variant46 = 92,
+        /// Variant 47.
+        Variant47 = 94,
+        /// Variant 48.
+        Variant48 = 96,
+        /// Variant 49.
+        Variant49 = 98,
+        /// Variant 50.
+        Variant50 = 100,
+        /// Variant 51.
+        Variant51 = 102,
+        /// Variant 52.
+        Variant52 = 104,
+        /// Variant 53.
+        Variant53 = 106,
+        /// Variant 54.
+        Variant54 = 108,
+        /// Variant 55.
+        Variant55 = 110,
+        /// Variant 56.
+        Variant56 = 112,
+        /// Variant 57.
+        Variant57 = 114,
+        /// Variant 58.
+        Variant58 = 116,
+        /// Variant 59.
+        Variant59 = 118,
+        /// Variant 60.
+        Variant60 = 120,
+        /// Variant 61.
+        Variant61 = 122,
+        /// Variant 62.
+        Variant62 = 124,
+        /// Variant 63.
+        Variant63 = 126,
+        /// Variant 64.
+        Variant64 = 128,
+        /// Variant 65.
+        Variant65 = 130,
+        /// Variant 66.
+        Variant66 = 132,
+        /// Variant 67.
+        Variant67 = 134,
+        /// Variant 68.
+        Variant68 = 136,
+        /// Variant 69.
+        Variant69 = 138,
+        /// Variant 70.
+        Variant70 = 140,
+        /// Variant 71.
+        Variant71 = 142,
+        /// Variant 72.
+        Variant72 = 144,
+        /// Variant 73.
+        Variant73 = 146,
+        /// Variant 74.
+        Variant74 = 148,
+        /// Variant 75.
+        Variant75 = 150,
+        /// Variant 76.
+        Variant76 = 152,
+        /// Variant 77.
+        Variant77 = 154,
+        /// Variant 78.
+        Variant78 = 156,
+        /// Variant 79.
+        Variant79 = 158,
+        /// Variant 80.
+        Variant80 = 160,
+        /// Variant 81.
+        Variant81 = 162,
+        /// Variant 82.
+        Variant82 = 164,
+        /// Variant 83.
+        Variant83 = 166,
+        /// Variant 84.
+        Variant84 = 168,
+        /// Variant 85.
+        Variant85 = 170,
+        /// Variant 86.
+        Variant86 = 172,
+        /// Variant 87.
+        Variant87 = 174,
+        /// Variant 88.
+        Variant88 = 176,
+        /// Variant 89.
+        Variant89 = 178,
+        /// Variant 90.
+        Variant90 = 180,
+        /// Variant 91.
+        Variant91 = 182,
+        /// Variant 92.
+        Variant92 = 184,
+        /// Variant 93.
+        Variant93 = 186,
+        /// Variant 94.
+        Variant94 = 188,
+        /// Variant 95.
+        Variant95 = 190,
+        /// Variant 96.
+        Variant96 = 192,
+        /// Variant 97.
+        Variant97 = 194,
+        /// Variant 98.
+        Variant98 = 196,
+        /// Variant 99.
+        Variant99 = 198,
+        /// Variant 100.
+        Variant100 = 200,
+        /// Variant 101.
+        Variant101 = 202,
+        /// Variant 102.
+        Variant102 = 204,
+        /// Variant 103.
+        Variant103 = 206,
+        /// Variant 104.
+        Variant104 = 208,
+        /// Variant 105.
+        Variant105 = 210,
+        /// Variant 106.
+        Variant106 = 212,
+        /// Variant 107.
+        Variant107 = 214,
+        /// Variant 108.
+        Variant108 = 216,
+        /// Variant 109.
+        Variant109 = 218,
+        /// Variant 110.
+        Variant110 = 220,
+        /// Variant 111.
+        Variant111 = 222,
+        /// Variant 112.
+        Variant112 = 224,
+        /// Variant 113.
+        Variant113 = 226,
+        /// Variant 114.
+        Variant114 = 228,
+        /// Variant 115.
+        Variant115 = 230,
+        /// Variant 116.
+        Variant116 = 232,
+        /// Variant 117.
+        Variant117 = 234,
+        /// Variant 118.
+        Variant118 = 236,
+        /// Variant 119.
+        Variant119 = 238,
+        /// Variant 120.
+        Variant120 = 240,
+        /// Variant 121.
+        Variant121 = 242,
+        /// Variant 122.
+        Variant122 = 244,
+        /// Variant 123.
+        Variant123 = 246,
+        /// Variant 124.
+        Variant124 = 248,
+        /// Variant 125.
+        Variant125 = 250,
+        /// Variant 126.
+        Variant126 = 252,
+        /// Variant 127.
+        Variant127 = 254,
+        /// Variant 128.
+        Variant128 = 256,
+        /// Variant 129.
+        Variant129 = 258,
+        /// Variant 130.
+        Variant130 = 260,
+        /// Variant 131.
+        Variant131 = 262,
+        /// Variant 132.
+        Variant132 = 264,
+        /// Variant 133.
+        Variant133 = 266,
+        /// Variant 134.
+        Variant134 = 268,
+        /// Variant 135.
+        Variant135 = 270,
+        /// Variant 136.
+        Variant136 = 272,
+        /// Variant 137.
+        Variant137 = 274,
+        /// Variant 138.
+        Variant138 = 276,
+        /// Variant 139.
+        Variant139 = 278,
+        /// Variant 140.
+        Variant140 = 280,
+        /// Variant 141.
+        Variant141 = 282,
+        /// Variant 142.
+        Variant142 = 284,
+        /// Variant 143.
+        Variant143 = 286,
+        /// Variant 144.
+        Variant144 = 288,
+        /// Variant 145.
+        Variant145 = 290,
+        /// Variant 146.
+        Variant146 = 292,
+        /// Variant 147.
+        Variant147 = 294,
+        /// Variant 148.
+        Variant148 = 296,
+        /// Variant 149.
+        Variant149 = 298,
+        /// Variant 150.
+        Variant150 = 300,
+        /// Variant 151.
+        Variant151 = 302,
+        /// Variant 152.
+        Variant152 = 304,
+        /// Variant 153.
+        Variant153 = 306,
+        /// Variant 154.
+        Variant154 = 308,
+        /// Variant 155.
+        Variant155 = 310,
+        /// Variant 156.
+        Variant156 = 312,
+        /// Variant 157.
+        Variant157 = 314,
+        /// Variant 158.
+        Variant158 = 316,
+        /// Variant 159.
+        Variant159 = 318,
+        /// Variant 160.
+        Variant160 = 320,
+        /// Variant 161.
+        Variant161 = 322,
+        /// Variant 162.
+        Variant162 = 324,
+        /// Variant 163.
+        Variant163 = 326,
+        /// Variant 164.
+        Variant164 = 328,
+        /// Variant 165.
+        Variant165 = 330,
+        /// Variant 166.
+        Variant166 = 332,
+        /// Variant 167.
+        Variant167 = 334,
+        /// Variant 168.
+        Variant168 = 336,
+        /// Variant 169.
+        Variant169 = 338,
+        /// Variant 170.
+        Variant170 = 340,
+        /// Variant 171.
+        Variant171 = 342,
+        /// Variant 172.
+        Variant172 = 344,
+        /// Variant 173.
+        Variant173 = 346,
+        /// Variant 174.
+        Variant174 = 348,
+        /// Variant 175.
+        Variant175 = 350,
+        /// Variant 176.
+        Variant176 = 352,
+        /// Variant 177.
+        Variant177 = 354,
+        /// Variant 178.
+        Variant178 = 356,
+        /// Variant 179.
+        Variant179 = 358,
+        /// Variant 180.
+        Variant180 = 360,
+        /// Variant 181.
+        Variant181 = 362,
+        /// Variant 182.
+        Variant182 = 364,
+        /// Variant 183.
+        Variant183 = 366,
+        /// Variant 184.
+        Variant184 = 368,
+        /// Variant 185.
+        Variant185 = 370,
+        /// Variant 186.
+        Variant186 = 372,
+        /// Variant 187.
+        Variant187 = 374,
+        /// Variant 188.
+        Variant188 = 376,
+        /// Variant 189.
+        Variant189 = 378,
+        /// Variant 190.
+        Variant190 = 380,
+        /// Variant 191.
+        Variant191 = 382,
+        /// Variant 192.
+        Variant192 = 384,
+        /// Variant 193.
+        Variant193 = 386,
+        /// Variant 194.
+        Variant194 = 388,
+        /// Variant 195.
+        Variant195 = 390,
+        /// Variant 196.
+        Variant196 = 392,
+        /// Variant 197.
+        Variant197 = 394,
+        /// Variant 198.
+        Variant198 = 396,
+        /// Variant 199.
+        Variant199 = 398,
+        /// Variant 200.
+        Variant200 = 400,
+        /// Variant 201.
+        Variant201 = 402,
+        /// Variant 202.
+        Variant202 = 404,
+        /// Variant 203.
+        Variant203 = 406,
+        /// Variant 204.
+        Variant204 = 408,
+        /// Variant 205.
+        Variant205 = 410,
+        /// Variant 206.
+        Variant206 = 412,
+        /// Variant 207.
+        Variant207 = 414,
+        /// Variant 208.
+        Variant208 = 416,
+        /// Variant 209.
+        Variant209 = 418,
+        /// Variant 210.
+        Variant210 = 420,
+        /// Variant 211.
+        Variant211 = 422,
+        /// Variant 212.
+        Variant212 = 424,
+        /// Variant 213.
+        Variant213 = 426,
+        /// Variant 214.
+        Variant214 = 428,
+        /// Variant 215.
+        Variant215 = 430,
+        /// Variant 216.
+        Variant216 = 432,
+        /// Variant 217.
+        Variant217 = 434,
+        /// Variant 218.
+        Variant218 = 436,
+        /// Variant 219.
+        Variant219 = 438,
+        /// Variant 220.
+        Variant220 = 440,
+        /// Variant 221.
+        Variant221 = 442,
+        /// Variant 222.
+        Variant222 = 444,
+        /// Variant 223.
+        Variant223 = 446,
+        /// Variant 224.
+        Variant224 = 448,
+        /// Variant 225.
+        Variant225 = 450,
+        /// Variant 226.
+        Variant226 = 452,
+        /// Variant 227.
+        Variant227 = 454,
+        /// Variant 228.
+        Variant228 = 456,
+        /// Variant 229.
+        Variant229 = 458,
+        /// Variant 230.
+        Variant230 = 460,
+        /// Variant 231.
+        Variant231 = 462,
+        /// Variant 232.
+        Variant232 = 464,
+        /// Variant 233.
+        Variant233 = 466,
+        /// Variant 234.
+        Variant234 = 468,
+        /// Variant 235.
+        Variant235 = 470,
+        /// Variant 236.
+        Variant236 = 472,
+        /// Variant 237.
+        Variant237 = 474,
+        /// Variant 238.
+        Variant238 = 476,
+        /// Variant 239.
+        Variant239 = 478,
+        /// Variant 240.
+        Variant240 = 480,
+        /// Variant 241.
+        Variant241 = 482,
+        /// Variant 242.
+        Variant242 = 484,
+        /// Variant 243.
+        Variant243 = 486,
+        /// Variant 244.
+        Variant244 = 488,
+        /// Variant 245.
+        Variant245 = 490,
+        /// Variant 246.
+        Variant246 = 492,
+        /// Variant 247.
+        Variant247 = 494,
+        /// Variant 248.
+        Variant248 = 496,
+        /// Variant 249.
+        Variant249 = 498,
+        /// Variant 250.
+        Variant250 = 500,
+        /// Variant 251.
+        Variant251 = 502,
+        /// Variant 252.
+        Variant252 = 504,
+        /// Variant 253.
+        Variant253 = 506,
+        /// Variant 254.
+        Variant254 = 508,
+        /// Variant 255.
+        Variant255 = 510,
+        /// Variant 256.
+        Variant256 = 512,
+        /// Variant 257.
+        Variant257 = 514,
+        /// Variant 258.
+        Variant258 = 516,
+        /// Variant 259.
+        Variant259 = 518,
+        /// Variant 260.
+        Variant260 = 520,
+        /// Variant 261.
+        Variant261 = 522,
+        /// Variant 262.
+        Variant262 = 524,
+        /// Variant 263.
+        Variant263 = 526,
+        /// Variant 264.
+        Variant264 = 528,
+        /// Variant 265.
+        Variant265 = 530,
+        /// Variant 266.
+        Variant266 = 532,
+        /// Variant 267.
+        Variant267 = 534,
+        /// Variant 268.
+        Variant268 = 536,
+        /// Variant 269.
+        Variant269 = 538,
+        /// Variant 270.
+        Variant270 = 540,
+        /// Variant 271.
+        Variant271 = 542,
+        /// Variant 272.
+        Variant272 = 544,
+        /// Variant 273.
+        Variant273 = 546,
+        /// Variant 274.
+        Variant274 = 548,
+        /// Variant 275.
+        Variant275 = 550,
+        /// Variant 276.
+        Variant276 = 552,
+        /// Variant 277.
+        Variant277 = 554,
+        /// Variant 278.
+        Variant278 = 556,
+        /// Variant 279.
+        Variant279 = 558,
+        /// Variant 280.
+        Variant280 = 560,
+        /// Variant 281.
+        Variant281 = 562,
+        /// Variant 282.
+        Variant282 = 564,
+        /// Variant 283.
+        Variant283 = 566,
+        /// Variant 284.
+        Variant284 = 568,
+        /// Variant 285.
+        Variant285 = 570,
+        /// Variant 286.
+        Variant286 = 572,
+        /// Variant 287.
+        Variant287 = 574,
+        /// Variant 288.
+        Variant288 = 576,
+        /// Variant 289.
+        Variant289 = 578,
+        /// Variant 290.
+        Variant290 = 580,
+        /// Variant 291.
+        Variant291 = 582,
+        /// Variant 292.
+        Variant292 = 584,
+        /// Variant 293.
+        Variant293 = 586,
+        /// Variant 294.
+        Variant294 = 588,
+        /// Variant 295.
+        Variant295 = 590,
+        /// Variant 296.
+        Variant296 = 592,
+        /// Variant 297.
+        Variant297 = 594,
+        /// Variant 298.
+        Variant298 = 596,
+        /// Variant 299.
+        Variant299 = 598,
+        /// Variant 300.
+        #[category = "last"]
+        Variant300 = 600,
+    }
+}
+
+#[test]
+fn large_valued() {
+    assert_eq!(LargeValued::Variant300.0, 600);
+    assert_eq!(LargeValued::Variant300.category(), Some("last"));
+    assert_eq!(LargeValued::Variant1.category(), None);
+}
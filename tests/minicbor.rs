@@ -0,0 +1,24 @@
+#![cfg(feature = "minicbor")]
+
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(minicbor)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS = 500,
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let samples = [Event::INSTRUCTIONS, Event(7), Event::CPU_CYCLES];
+
+    let bytes = minicbor::to_vec(samples).unwrap();
+    assert_eq!(bytes, [0x83, 0x19, 0x01, 0xf4, 0x07, 0x00]);
+    assert_eq!(minicbor::len(samples), bytes.len());
+
+    let decoded: [Event; 3] = minicbor::decode(&bytes).unwrap();
+    assert_eq!(decoded, samples);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "napi")]
+
+use c_enum::c_enum;
+use napi::bindgen_prelude::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
+use napi::ValueType;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(napi)]
+    pub enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+fn assert_napi<T>()
+where
+    T: ToNapiValue + FromNapiValue + ValidateNapiValue,
+    for<'a> &'a T: ToNapiValue,
+{
+}
+
+#[test]
+fn napi_traits() {
+    assert_napi::<Event>();
+
+    assert_eq!(Event::type_name(), "Event");
+    assert_eq!(Event::value_type(), ValueType::Number);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "num_enum")]
+
+use c_enum::c_enum;
+use num_enum::{FromPrimitive, TryFromPrimitive};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(num_enum)]
+    enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn from_primitive() {
+    assert_eq!(Event::from_primitive(1), Event::INSTRUCTIONS);
+    assert_eq!(Event::from_primitive(9), Event(9));
+}
+
+#[test]
+fn try_from_primitive() {
+    assert_eq!(Event::try_from_primitive(0), Ok(Event::CPU_CYCLES));
+
+    let error = Event::try_from_primitive(9).unwrap_err();
+    assert_eq!(error.number, 9);
+    assert_eq!(
+        error.to_string(),
+        "No discriminant in enum `Event` matches the value `9`"
+    );
+}
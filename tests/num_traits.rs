@@ -0,0 +1,28 @@
+#![cfg(feature = "num-traits")]
+
+use c_enum::c_enum;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(num_traits)]
+    enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn from_primitive() {
+    assert_eq!(Event::from_u64(1), Some(Event::INSTRUCTIONS));
+    assert_eq!(Event::from_i32(200), Some(Event(200)));
+    assert_eq!(Event::from_i32(-1), None);
+    assert_eq!(Event::from_u16(256), None);
+}
+
+#[test]
+fn to_primitive() {
+    assert_eq!(Event::INSTRUCTIONS.to_i64(), Some(1));
+    assert_eq!(Event(200).to_i8(), None);
+    assert_eq!(Event(200).to_f64(), Some(200.0));
+}
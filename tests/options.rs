@@ -0,0 +1,661 @@
+use std::collections::BTreeMap;
+
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[c_enum(ord, consts)]
+    pub enum Ordered : i32 {
+        A = 5,
+        B = -1,
+        C = 3,
+    }
+}
+
+c_enum! {
+    /// Options may appear anywhere in the attributes, including after a long
+    /// doc comment.
+    ///
+    /// Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod
+    /// tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim
+    /// veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea
+    /// commodo consequat. Duis aute irure dolor in reprehenderit in voluptate
+    /// velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint
+    /// occaecat cupidatat non proident, sunt in culpa qui officia deserunt
+    /// mollit anim id est laborum.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[c_enum(ord,)]
+    pub enum Strings : &'static str {
+        Zebra = "zebra",
+        Apple = "apple",
+    }
+}
+
+#[test]
+fn ord_uses_inner_value() {
+    assert!(Ordered::B < Ordered::C);
+    assert!(Ordered::C < Ordered::A);
+    assert_eq!(Ordered::A.max(Ordered::from(7)), Ordered::from(7));
+
+    let mut sorted = Ordered::VARIANTS.to_vec();
+    sorted.sort();
+    assert_eq!(sorted, [Ordered::B, Ordered::C, Ordered::A]);
+}
+
+#[test]
+fn ord_btreemap_keys() {
+    let mut map = BTreeMap::new();
+    map.insert(Strings::Zebra, 1);
+    map.insert(Strings::Apple, 2);
+    map.insert(Strings::from("mango"), 3);
+
+    let keys: Vec<_> = map.keys().map(|key| key.0).collect();
+    assert_eq!(keys, ["apple", "mango", "zebra"]);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(bitops)]
+    pub enum Flags : u32 {
+        READ = 1 << 0,
+        WRITE = 1 << 1,
+        EXEC = 1 << 2,
+    }
+}
+
+#[test]
+fn bitops() {
+    let rw = Flags::READ | Flags::WRITE;
+    assert_eq!(rw, 0b011);
+    assert_eq!(rw & Flags::WRITE, Flags::WRITE);
+    assert_eq!(rw ^ Flags::READ, Flags::WRITE);
+
+    let mut flags = Flags::READ;
+    flags |= Flags::EXEC;
+    assert_eq!(flags, 0b101);
+    flags &= Flags::EXEC;
+    assert_eq!(flags, Flags::EXEC);
+    flags ^= Flags::EXEC;
+    assert_eq!(flags, 0);
+}
+
+#[test]
+fn flag_helpers() {
+    let mut flags = Flags::READ | Flags::EXEC;
+    assert!(flags.contains(Flags::READ));
+    assert!(!flags.contains(Flags::READ | Flags::WRITE));
+    assert!(flags.intersects(Flags::READ | Flags::WRITE));
+    assert!(!flags.intersects(Flags::WRITE));
+
+    flags.insert(Flags::WRITE);
+    assert_eq!(flags, 0b111);
+    flags.remove(Flags::READ | Flags::EXEC);
+    assert_eq!(flags, Flags::WRITE);
+    flags.toggle(Flags::READ | Flags::WRITE);
+    assert_eq!(flags, Flags::READ);
+}
+
+#[test]
+fn flag_complement() {
+    assert_eq!(Flags::ALL, 0b111);
+    assert_eq!(Flags::READ.complement(), Flags::WRITE | Flags::EXEC);
+    assert_eq!(!(Flags::READ | Flags::WRITE), Flags::EXEC);
+    assert_eq!(!Flags::from(0b1000), Flags::ALL);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(bits, consts)]
+    pub enum Bits : u8 {
+        A,
+        B,
+        #[cfg(any())]
+        REMOVED,
+        C,
+        AB = 3,
+        D,
+        F = 0x20,
+        G,
+        H,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(bits)]
+    pub enum WrappingBits : std::num::Wrapping<u32> {
+        A,
+        B,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(step = 0x10, consts)]
+    pub enum Stride : u16 {
+        A,
+        B,
+        #[cfg(any())]
+        REMOVED,
+        C = 0x100,
+        D,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq)]
+    #[c_enum(step = 0.5)]
+    pub enum FloatStride : f32 {
+        A = 1.0,
+        B,
+    }
+}
+
+#[test]
+fn step() {
+    assert_eq!(Stride::VALUES, [0, 0x10, 0x100, 0x110]);
+    assert_eq!(FloatStride::B.value(), 1.5);
+}
+
+#[test]
+fn bits() {
+    assert_eq!(Bits::VALUES, [1, 2, 8, 3, 4, 0x20, 0x40, 0x80]);
+    assert_eq!(WrappingBits::B.value().0, 2);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum Requests : u16 {
+        Open,
+        Close,
+        #[cfg(any())]
+        Removed,
+    }
+
+    impl {
+        pub const COUNT: usize = 2;
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(step = 0x10, continue_from = Requests, consts)]
+    pub enum Replies : u16 {
+        Opened,
+        Closed,
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(bits, continue_from = Replies, consts)]
+    pub enum Events : u16 {
+        Readable,
+        Writable,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum FirstHandle : std::num::NonZeroU32 {
+        Stdin,
+        Stdout,
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(continue_from = FirstHandle)]
+    pub enum NextHandle : std::num::NonZeroU32 {
+        Stderr,
+    }
+}
+
+#[test]
+fn continue_from() {
+    assert_eq!(Requests::COUNT, 2);
+    assert_eq!(Replies::VALUES, [3, 0x13]);
+    assert_eq!(Events::VALUES, [0x23, 0x40]);
+    assert_eq!(NextHandle::Stderr.value().get(), 3);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(deny_duplicates, consts)]
+    pub enum Registry : u16 {
+        First,
+        Second,
+        #[alias]
+        Other = 1,
+        #[c_enum(skip)]
+        Max,
+        #[cfg(any())]
+        Removed = 0,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(deny_duplicates)]
+    pub enum NonZeroRegistry : std::num::NonZeroU16 {
+        First,
+        Second,
+    }
+}
+
+#[test]
+fn deny_duplicates() {
+    assert_eq!(Registry::VALUES, [0, 1]);
+    assert_eq!(Registry::Max.value(), 2);
+    assert_eq!(NonZeroRegistry::Second.value().get(), 2);
+}
+
+c_enum! {
+    #[c_enum(debug = "hex")]
+    pub enum Register : u16 {
+        STATUS = 0x10,
+    }
+}
+
+#[test]
+fn debug_hex() {
+    assert_eq!(format!("{:?}", Register::STATUS), "Register::STATUS");
+    assert_eq!(format!("{:?}", Register::from(0x1f4)), "Register(0x1f4)");
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(known = KnownColor)]
+    pub enum Color : u8 {
+        RED = 1,
+        GREEN,
+        BLUE,
+    }
+}
+
+#[test]
+fn known_enum() {
+    use std::convert::TryFrom;
+
+    fn name(color: KnownColor) -> &'static str {
+        match color {
+            KnownColor::RED => "red",
+            KnownColor::GREEN => "green",
+            KnownColor::BLUE => "blue",
+        }
+    }
+
+    assert_eq!(KnownColor::try_from(Color::GREEN), Ok(KnownColor::GREEN));
+    assert_eq!(name(KnownColor::try_from(Color::BLUE).unwrap()), "blue");
+    assert_eq!(
+        KnownColor::try_from(Color::from(7)),
+        Err(c_enum::UnknownValueError(7))
+    );
+    assert_eq!(Color::from(KnownColor::RED), Color::RED);
+}
+
+#[test]
+fn classify() {
+    assert_eq!(Color::RED.classify(), Ok(KnownColor::RED));
+    assert_eq!(Color::from(9).classify(), Err(9));
+}
+
+c_enum! {
+    #[c_enum(parse = "case_insensitive")]
+    pub enum Event : u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn parse_case_insensitive() {
+    assert_eq!(Event::from_name("cpu_cycles").map(|e| e.0), Some(0));
+    assert_eq!(Event::from_name(" Instructions\n").map(|e| e.0), Some(1));
+    assert_eq!(Event::from_name("cpu-cycles").map(|e| e.0), None);
+    assert_eq!(Color::from_name("red"), None);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(rename_all = "kebab-case", consts)]
+    pub enum Counter : u32 {
+        CACHE_MISSES,
+        BranchInstructions,
+        L1D_READ,
+        /// Labels can be overridden per variant.
+        #[label = "L1 dcache miss"]
+        L1D_READ_MISS,
+    }
+}
+
+#[test]
+fn rename_all() {
+    assert_eq!(Counter::CACHE_MISSES.as_str(), Some("cache-misses"));
+    assert_eq!(
+        Counter::BranchInstructions.label(),
+        Some("branch-instructions")
+    );
+    assert_eq!(
+        Counter::LABELS,
+        &[
+            "cache-misses",
+            "branch-instructions",
+            "l1d-read",
+            "L1 dcache miss"
+        ]
+    );
+    assert_eq!(Counter::ENTRIES[2], ("l1d-read", 2));
+    assert_eq!(
+        Counter::from_name("cache-misses"),
+        Some(Counter::CACHE_MISSES)
+    );
+    assert_eq!(Counter::from_name("CACHE_MISSES"), None);
+    assert_eq!(format!("{:?}", Counter::L1D_READ), "Counter::L1D_READ");
+}
+
+#[test]
+fn label_override() {
+    assert_eq!(Counter::L1D_READ_MISS.as_str(), Some("L1 dcache miss"));
+    assert_eq!(Counter::L1D_READ_MISS.label(), Some("L1 dcache miss"));
+    assert_eq!(Counter::LABELS[3], "L1 dcache miss");
+    assert_eq!(
+        Counter::from_name("L1 dcache miss"),
+        Some(Counter::L1D_READ_MISS)
+    );
+    assert_eq!(Counter::from_name("l1d-read-miss"), None);
+    assert_eq!(
+        format!("{:?}", Counter::L1D_READ_MISS),
+        "Counter::L1D_READ_MISS"
+    );
+}
+
+#[test]
+fn rename_all_cases() {
+    macro_rules! label {
+        ($case:tt, $name:ident) => {{
+            c_enum! {
+                #[c_enum(rename_all = $case, consts)]
+                enum Renamed : u8 { $name }
+            }
+
+            Renamed::LABELS[0]
+        }};
+    }
+
+    assert_eq!(label!("lowercase", CACHE_MISSES), "cache_misses");
+    assert_eq!(label!("UPPERCASE", CacheMisses), "CACHEMISSES");
+    assert_eq!(label!("PascalCase", CACHE_MISSES), "CacheMisses");
+    assert_eq!(label!("camelCase", CACHE_MISSES), "cacheMisses");
+    assert_eq!(label!("snake_case", CacheMisses), "cache_misses");
+    assert_eq!(label!("SCREAMING_SNAKE_CASE", cacheMisses), "CACHE_MISSES");
+    assert_eq!(label!("SCREAMING-KEBAB-CASE", HTTPServer), "HTTP-SERVER");
+    assert_eq!(label!("snake_case", _LEADING__DOUBLE), "leading_double");
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(strip_prefix = "PERF_COUNT_SW_", rename_all = "snake_case", consts)]
+    pub enum SwEvent : u32 {
+        PERF_COUNT_SW_CPU_CLOCK,
+        PERF_COUNT_SW_TASK_CLOCK,
+        PERF_COUNT_SW_,
+        OTHER,
+    }
+}
+
+#[test]
+fn strip_prefix() {
+    assert_eq!(
+        SwEvent::LABELS,
+        &["cpu_clock", "task_clock", "perf_count_sw", "other"]
+    );
+    assert_eq!(
+        SwEvent::from_name("task_clock"),
+        Some(SwEvent::PERF_COUNT_SW_TASK_CLOCK)
+    );
+}
+
+#[allow(deprecated)]
+mod deprecated {
+    use c_enum::c_enum;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(consts)]
+        pub enum Renamed : u32 {
+            #[deprecated(since = "0.1.0", replaced_by = NEW, note = "renamed")]
+            OLD = 1,
+            NEW = 1,
+            #[deprecated]
+            UNUSED,
+        }
+    }
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(hide_deprecated, consts)]
+        pub enum Hidden : u32 {
+            A,
+            #[deprecated = "no longer used"]
+            B,
+            C,
+        }
+    }
+
+    #[test]
+    fn replaced_by() {
+        assert_eq!(Renamed::VARIANTS, &[Renamed::NEW, Renamed::UNUSED]);
+        assert_eq!(Renamed::OLD.as_str(), Some("NEW"));
+        assert_eq!(Renamed::from_name("OLD"), Some(Renamed::NEW));
+    }
+
+    #[test]
+    fn hide_deprecated() {
+        assert_eq!(Hidden::VARIANTS, &[Hidden::A, Hidden::C]);
+        assert_eq!(Hidden::B.as_str(), None);
+        assert_eq!(Hidden::from_name("B"), None);
+        assert_eq!(Hidden::from_name("C"), Some(Hidden::C));
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum CacheResult : u64 {
+        ACCESS,
+        MISS,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(
+        bitfield = (get = id, set = set_id, shift = 0, mask = 0xff),
+        bitfield = (get = op, shift = 8, mask = 0xff),
+        bitfield = (get = result, set = set_result, ty = CacheResult, shift = 16, mask = 0xff),
+    )]
+    pub enum CacheConfig : u64 {
+        L1D_READ_MISS = 0x10000,
+    }
+}
+
+#[test]
+fn bitfields() {
+    const CONFIG: CacheConfig = CacheConfig::from_bitfields(0, 0, 1);
+
+    assert_eq!(CONFIG, CacheConfig::L1D_READ_MISS);
+    assert_eq!(CONFIG.result(), CacheResult::MISS);
+
+    let mut config = CacheConfig::from_bitfields(0x1ff, 2, 0);
+    assert_eq!(config.id(), 0xff);
+    assert_eq!(config.op(), 2);
+    assert_eq!(config.result(), CacheResult::ACCESS);
+
+    config.set_id(4);
+    config.set_result(CacheResult::MISS);
+    assert_eq!(config, CacheConfig(0x010204));
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(error)]
+    pub enum Status: i32 {
+        /// Doc comments don't affect the message.
+        #[msg = "success"]
+        OK = 0,
+        #[msg = "permission denied"]
+        DENIED = -1,
+        #[label = "timed out"]
+        TIMEOUT = -2,
+        #[alias]
+        #[msg = "also success"]
+        SUCCESS = 0,
+    }
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(Status::OK.message(), Some("success"));
+    assert_eq!(Status::DENIED.message(), Some("permission denied"));
+    assert_eq!(Status::TIMEOUT.message(), Some("timed out"));
+    assert_eq!(Status(5).message(), None);
+
+    assert_eq!(Status::SUCCESS.to_string(), "success");
+    assert_eq!(Status::DENIED.to_string(), "permission denied");
+    assert_eq!(Status(5).to_string(), "unknown error 5");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_trait() {
+    let error: Box<dyn std::error::Error> = Box::new(Status::DENIED);
+    assert_eq!(error.to_string(), "permission denied");
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(display = "doc")]
+    pub enum Described: u8 {
+        /// Total cycles.
+        ///
+        /// Only the first line is used.
+        CPU_CYCLES,
+        /** Retired instructions.
+        More text. */
+        INSTRUCTIONS,
+        #[label = "cache-misses"]
+        CACHE_MISSES,
+    }
+}
+
+#[test]
+fn display_doc() {
+    assert_eq!(Described::CPU_CYCLES.to_string(), "Total cycles.");
+    assert_eq!(Described::INSTRUCTIONS.to_string(), "Retired instructions.");
+    assert_eq!(Described::CACHE_MISSES.to_string(), "cache-misses");
+    assert_eq!(Described(9).to_string(), "9");
+    assert_eq!(Described(9).description(), None);
+}
+
+mod private {
+    use c_enum::c_enum;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(private, bitops)]
+        pub enum Validated : u8 {
+            A = 1,
+            B = 2,
+        }
+    }
+}
+
+#[test]
+fn private_field() {
+    use self::private::Validated;
+
+    assert_eq!(Validated::B.value(), 2);
+    assert_eq!(Validated::new(1), Validated::A);
+    assert_eq!(Validated::ALL, Validated::new(3));
+    assert_eq!(u8::from(Validated::A | Validated::B), 3);
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(debug = "hex", skip_debug, skip_from)]
+    pub enum Custom : u8 {
+        A = 1,
+    }
+}
+
+impl std::fmt::Debug for Custom {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "<unknown {}>", self.0),
+        }
+    }
+}
+
+impl From<u8> for Custom {
+    fn from(value: u8) -> Self {
+        Self(value.min(1))
+    }
+}
+
+impl From<Custom> for u8 {
+    fn from(value: Custom) -> Self {
+        value.0
+    }
+}
+
+#[test]
+fn skip_debug_and_from() {
+    assert_eq!(format!("{:?}", Custom::A), "A");
+    assert_eq!(format!("{:?}", Custom(7)), "<unknown 7>");
+    assert_eq!(Custom::from(7), Custom::A);
+}
+
+c_enum! {
+    /// An enum whose field is only visible within this crate.
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum Sealed : u8 {
+        A,
+        B,
+    }
+}
+
+#[test]
+fn non_exhaustive() {
+    assert_eq!(Sealed::B.0, 1);
+    assert_eq!(Sealed::new(1), Sealed::B);
+    assert_eq!(Sealed::from(7).value(), 7);
+    assert_eq!(format!("{:?}", Sealed::new(7)), "Sealed(7)");
+}
+
+mod signal {
+    use c_enum::c_enum;
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        #[c_enum(mod = consts)]
+        pub enum Signal : u8 {
+            SIGHUP = 1,
+            SIGINT,
+            #[cfg(any())]
+            SIGNONE,
+            pub(crate) SIGQUIT,
+            pub(self) SIGPRIVATE,
+        }
+    }
+
+    pub fn private() -> Signal {
+        consts::SIGPRIVATE
+    }
+}
+
+#[test]
+fn constants_module() {
+    use signal::consts::*;
+    use signal::Signal;
+
+    assert_eq!(SIGHUP, Signal::SIGHUP);
+    assert_eq!(SIGINT.value(), 2);
+    assert_eq!(SIGQUIT.value(), 4);
+    assert_eq!(signal::private(), Signal::new(5));
+}
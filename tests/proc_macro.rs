@@ -0,0 +1,228 @@
+#![cfg(feature = "proc-macro")]
+
+use c_enum::attr::c_enum;
+use c_enum::CEnum;
+
+#[c_enum(u32, rename_all = "snake_case", consts)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Event {
+    CpuCycles,
+    /// Docs are kept.
+    #[label = "insns"]
+    Instructions = 4,
+    CacheMisses,
+    #[cfg(any())]
+    Removed,
+}
+
+#[c_enum(u8, predicates)]
+enum Flag {
+    ReadOnly,
+    #[predicate = is_writable]
+    ReadWrite,
+    HTTPServer,
+    EXEC_ONLY,
+}
+
+mod reexport {
+    pub use c_enum as renamed;
+}
+
+#[c_enum(u8, crate = reexport::renamed, rename_all = "lowercase")]
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Renamed {
+    Read,
+    Write,
+}
+
+#[c_enum(T, types(u8), crate = reexport::renamed)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum RenamedGeneric<T: Copy> {
+    Read,
+    Write,
+}
+
+#[c_enum]
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(u16)]
+enum Small {
+    A,
+    B,
+}
+
+#[test]
+fn attribute() {
+    assert_eq!(Event::VALUES, [0, 4, 5]);
+    assert_eq!(Event::LABELS, ["cpu_cycles", "insns", "cache_misses"]);
+    assert_eq!(Event::from_name("cache_misses"), Some(Event::CacheMisses));
+    assert_eq!(format!("{:?}", Event::new(9)), "Event(9)");
+}
+
+#[test]
+fn crate_path() {
+    assert_eq!(Renamed::Write.value(), 1);
+    assert_eq!(Renamed::Write.as_str(), Some("write"));
+    assert_eq!(
+        <RenamedGeneric<u8> as reexport::renamed::CEnum>::variant_label(&RenamedGeneric::Read),
+        Some("Read")
+    );
+}
+
+#[test]
+fn predicates() {
+    assert!(Flag::ReadOnly.is_read_only());
+    assert!(!Flag::ReadOnly.is_writable());
+    assert!(Flag::ReadWrite.is_writable());
+    assert!(Flag::HTTPServer.is_http_server());
+    assert!(Flag::EXEC_ONLY.is_exec_only());
+    assert!(!Flag::new(9).is_exec_only());
+}
+
+c_enum::c_enum! {
+    #[c_enum(types(u32, u64), consts)]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum Register<T: Copy + 'static>: T {
+        CTRL,
+        STATUS = 0x04,
+        #[label = "data"]
+        DATA,
+    }
+}
+
+#[c_enum(T, types(u8), consts)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Command<T>
+where
+    T: Copy + PartialEq<T>,
+{
+    Reset = 0x10,
+    Start,
+}
+
+c_enum::c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(consts)]
+    pub enum Method<'a>: &'a str {
+        GET,
+        POST,
+        #[label = "delete"]
+        DELETE = "DELETE",
+    }
+}
+
+#[c_enum(Option<&'a [u8]>, consts)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Magic<'a> {
+    Empty = None,
+    Elf = Some(b"\x7fELF"),
+}
+
+fn register_label<T: RegisterValues + PartialEq>(value: T) -> Option<&'static str> {
+    Register::new(value).as_str()
+}
+
+#[test]
+fn generic() {
+    assert_eq!(Register::<u32>::VALUES, [0, 4, 5]);
+    assert_eq!(Register::<u64>::DATA.value(), 5u64);
+    assert_eq!(Register::<u32>::LABELS, ["CTRL", "STATUS", "data"]);
+    assert_eq!(Register::<u64>::from_name("data"), Some(Register::DATA));
+    assert_eq!(register_label(4u32), Some("STATUS"));
+    assert_eq!(register_label(9u64), None);
+    assert_eq!(format!("{:?}", Register::new(5u32)), "Register::DATA");
+    assert_eq!(format!("{:?}", Register::new(9u64)), "Register(9)");
+    assert_eq!(u32::from(Register::<u32>::STATUS), 4);
+    assert_eq!(Register::<u64>::new(0).variant_label(), Some("CTRL"));
+    assert_eq!(Command::<u8>::VARIANTS, [Command::Reset, Command::Start]);
+    assert_eq!(Command::new(0x11u8).variant_index(), Some(1));
+}
+
+#[test]
+fn generic_lifetime() {
+    let input = String::from("POST /index.html");
+    let method = Method::from(input.split(' ').next().unwrap());
+    assert_eq!(method, Method::POST);
+    assert_eq!(method.as_str(), Some("POST"));
+    assert_eq!(method.variant(), Some(Method::POST));
+    assert_eq!(format!("{:?}", method), "Method::POST");
+    assert_eq!(
+        format!("{:?}", Method::new(&input[5..])),
+        "Method(\"/index.html\")"
+    );
+    assert_eq!(Method::VALUES, ["GET", "POST", "DELETE"]);
+    assert_eq!(Method::LABELS, ["GET", "POST", "delete"]);
+    assert_eq!(Method::from_name("delete"), Some(Method::DELETE));
+    assert_eq!(<&str>::from(Method::GET), "GET");
+    assert_eq!(Method::new("PUT").variant_label(), None);
+
+    let header = vec![0x7f, b'E', b'L', b'F'];
+    let magic = Magic::new(Some(&header));
+    assert_eq!(magic.variant(), Some(Magic::Elf));
+    assert_eq!(Magic::VARIANTS, [Magic::Empty, Magic::Elf]);
+    assert_eq!(Magic::new(Some(&header[1..])).variant_index(), None);
+}
+
+#[test]
+fn repr_inner_type() {
+    assert_eq!(Small::B.value(), 1u16);
+    assert_eq!(core::mem::size_of::<Small>(), 2);
+    assert_eq!(Small::variants().as_slice(), [Small::A, Small::B]);
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, CEnum)]
+#[c_enum(variants(NONE, r#async, BOTH))]
+struct Flags(u8);
+
+impl Flags {
+    const NONE: Flags = Flags(0);
+    #[allow(non_upper_case_globals)]
+    const r#async: Flags = Flags(1);
+    const BOTH: Flags = Flags(3);
+    #[allow(dead_code)]
+    const ALSO_BOTH: Flags = Flags(3);
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, CEnum)]
+#[c_enum(variants(ONE), skip_debug, skip_from)]
+struct Plain(u8);
+
+impl Plain {
+    const ONE: Plain = Plain(1);
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, CEnum)]
+#[c_enum(variants(ON), crate = reexport::renamed)]
+struct Switch(u8);
+
+impl Switch {
+    const ON: Switch = Switch(1);
+}
+
+impl From<u8> for Plain {
+    fn from(value: u8) -> Self {
+        Self(value.max(1))
+    }
+}
+
+impl From<Plain> for u8 {
+    fn from(value: Plain) -> Self {
+        value.0
+    }
+}
+
+#[test]
+fn derive() {
+    assert_eq!(Flags::BOTH.variant_label(), Some("BOTH"));
+    assert_eq!(Flags::r#async.variant_label(), Some("async"));
+    assert_eq!(Flags(2).variant_label(), None);
+    assert_eq!(Flags::from_name("async"), Some(Flags::r#async));
+    assert_eq!(Flags::from_name("ALSO_BOTH"), None);
+    assert_eq!(format!("{:?}", Flags::NONE), "Flags::NONE");
+    assert_eq!(format!("{:?}", Flags(2)), "Flags(2)");
+    assert_eq!(Flags::from(3), Flags::BOTH);
+    assert_eq!(u8::from(Flags::BOTH), 3);
+    assert_eq!(Plain::from(0), Plain::ONE);
+    assert_eq!(Plain::ONE.variant_label(), Some("ONE"));
+    assert_eq!(Switch::from_name("ON"), Some(Switch::ON));
+    assert_eq!(format!("{:?}", Switch(1)), "Switch::ON");
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "proptest")]
+
+use c_enum::c_enum;
+use proptest::prelude::*;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(proptest)]
+    enum Event: u32 {
+        CPU_CYCLES = 0x1000,
+        INSTRUCTIONS,
+    }
+}
+
+proptest! {
+    #[test]
+    fn any_declared(event in Event::any_declared()) {
+        prop_assert!(event.is_known());
+    }
+
+    #[test]
+    fn any(event in Event::any()) {
+        prop_assert_eq!(Event::try_from_value(event.0).is_ok(), event.is_known());
+    }
+}
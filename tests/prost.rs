@@ -0,0 +1,56 @@
+#![cfg(feature = "prost")]
+
+use c_enum::c_enum;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+enum ProtoEvent {
+    CpuCycles = 0,
+    Instructions = 1,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct Sample {
+    #[prost(enumeration = "ProtoEvent", tag = "1")]
+    event: i32,
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(prost = ProtoEvent)]
+    enum Event: i32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+        BRANCHES,
+    }
+}
+
+#[test]
+fn from_proto() {
+    assert_eq!(Event::from(ProtoEvent::CpuCycles), Event::CPU_CYCLES);
+    assert_eq!(Event::from(ProtoEvent::Instructions), Event::INSTRUCTIONS);
+}
+
+#[test]
+fn to_proto() {
+    assert_eq!(
+        ProtoEvent::try_from(Event::INSTRUCTIONS),
+        Ok(ProtoEvent::Instructions)
+    );
+    assert_eq!(
+        ProtoEvent::try_from(Event::BRANCHES),
+        Err(prost::UnknownEnumValue(2))
+    );
+}
+
+#[test]
+fn message_field() {
+    use prost::Message;
+
+    let sample = Sample {
+        event: Event::BRANCHES.into(),
+    };
+    let decoded = Sample::decode(&*sample.encode_to_vec()).unwrap();
+
+    assert_eq!(Event::from(decoded.event), Event::BRANCHES);
+}
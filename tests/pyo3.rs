@@ -0,0 +1,45 @@
+#![cfg(feature = "pyo3")]
+
+use c_enum::c_enum;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(pyo3)]
+    enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn into_python() {
+    Python::attach(|py| {
+        let value = Event::INSTRUCTIONS.into_pyobject(py).unwrap();
+        assert!(value.is_instance_of::<PyInt>());
+        assert_eq!(value.extract::<u8>().unwrap(), 1);
+
+        let value = (&Event(200)).into_pyobject(py).unwrap();
+        assert_eq!(value.extract::<u8>().unwrap(), 200);
+    });
+}
+
+#[test]
+fn from_python() {
+    Python::attach(|py| {
+        let value = 1u8.into_pyobject(py).unwrap();
+        assert_eq!(value.extract::<Event>().unwrap(), Event::INSTRUCTIONS);
+
+        let value = "CPU_CYCLES".into_pyobject(py).unwrap();
+        assert_eq!(value.extract::<Event>().unwrap(), Event::CPU_CYCLES);
+
+        let value = "BRANCHES".into_pyobject(py).unwrap();
+        let err = value.extract::<Event>().unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+
+        let value = 300u16.into_pyobject(py).unwrap();
+        assert!(value.extract::<Event>().is_err());
+    });
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "rand")]
+
+use c_enum::c_enum;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(rand, consts)]
+    enum Event: u32 {
+        CPU_CYCLES = 10,
+        INSTRUCTIONS = 20,
+        BRANCHES = 30,
+    }
+}
+
+#[test]
+fn samples_declared_variants() {
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    let mut seen = [false; 3];
+
+    for _ in 0..1000 {
+        let event: Event = rng.random();
+        let index = Event::VARIANTS.iter().position(|&v| v == event).unwrap();
+        seen[index] = true;
+    }
+
+    assert_eq!(seen, [true; 3]);
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "rkyv")]
+
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(rkyv)]
+    pub enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize, Debug, PartialEq)]
+struct Sample {
+    events: Vec<Event>,
+}
+
+#[test]
+fn roundtrip() {
+    let sample = Sample {
+        events: vec![Event::INSTRUCTIONS, Event(77), Event::CPU_CYCLES],
+    };
+
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&sample).unwrap();
+    let archived = rkyv::access::<ArchivedSample, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived.events[0].0, 1);
+    assert_eq!(archived.events[1].0, 77);
+
+    let deserialized = rkyv::deserialize::<Sample, rkyv::rancor::Error>(archived).unwrap();
+    assert_eq!(deserialized, sample);
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "scale")]
+
+use c_enum::c_enum;
+use codec::{Decode, Encode, MaxEncodedLen};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(scale)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn encode() {
+    assert_eq!(Event::INSTRUCTIONS.encode(), 1u32.encode());
+    assert_eq!(Event(0xdead).encode(), 0xdeadu32.encode());
+    assert_eq!(Event::INSTRUCTIONS.encoded_size(), 4);
+    assert_eq!(Event::max_encoded_len(), 4);
+}
+
+#[test]
+fn decode() {
+    let bytes = 7u32.encode();
+    assert_eq!(Event::decode(&mut &bytes[..]).unwrap(), Event(7));
+    assert!(Event::decode(&mut &bytes[..2]).is_err());
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "schemars")]
+
+use c_enum::c_enum;
+use schemars_crate as schemars;
+use serde_json::json;
+
+c_enum! {
+    #[c_enum(schemars, serde)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+c_enum! {
+    #[c_enum(schemars, serde = "name", serde_unknown = "error")]
+    enum Named: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+c_enum! {
+    #[c_enum(serde = "lenient", schemars)]
+    enum Lenient: u8 {
+        A,
+        B,
+    }
+}
+
+#[test]
+fn value_schema() {
+    let schema = schemars::schema_for!(Event);
+
+    assert_eq!(schema.get("title"), Some(&json!("Event")));
+    assert_eq!(
+        schema.get("anyOf"),
+        Some(&json!([
+            { "const": 0, "title": "CPU_CYCLES" },
+            { "const": 1, "title": "INSTRUCTIONS" },
+            { "type": "integer", "format": "uint32", "minimum": 0 },
+        ]))
+    );
+}
+
+#[test]
+fn name_schema() {
+    let schema = schemars::schema_for!(Named);
+
+    assert_eq!(schema.get("type"), Some(&json!("string")));
+    assert_eq!(
+        schema.get("enum"),
+        Some(&json!(["CPU_CYCLES", "INSTRUCTIONS"]))
+    );
+}
+
+#[test]
+fn name_or_value_schema() {
+    let schema = schemars::schema_for!(Lenient);
+
+    assert_eq!(
+        schema.get("anyOf"),
+        Some(&json!([
+            { "type": "string", "enum": ["A", "B"] },
+            { "type": "integer", "format": "uint8", "minimum": 0, "maximum": 255 },
+        ]))
+    );
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "sea-orm")]
+
+use c_enum::c_enum;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{ColumnType, ValueType};
+use sea_orm::{DatabaseBackend, MockDatabase};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(sea_orm)]
+    pub enum Status: i32 {
+        ACTIVE,
+        INACTIVE,
+    }
+}
+
+mod user {
+    use super::Status;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "user")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub status: Status,
+        pub previous: Option<Status>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn value_conversion() {
+    assert_eq!(Value::from(Status::INACTIVE), Value::Int(Some(1)));
+    assert_eq!(Value::from(Status(7)), Value::Int(Some(7)));
+    assert_eq!(
+        <Status as ValueType>::try_from(Value::Int(Some(0))).ok(),
+        Some(Status::ACTIVE)
+    );
+    assert!(<Status as ValueType>::try_from(Value::String(None)).is_err());
+    assert_eq!(Status::column_type(), ColumnType::Integer);
+}
+
+#[test]
+fn entity_field() {
+    let db = MockDatabase::new(DatabaseBackend::Sqlite)
+        .append_query_results([[user::Model {
+            id: 1,
+            status: Status::INACTIVE,
+            previous: None,
+        }]])
+        .into_connection();
+
+    let model = futures_executor::block_on(user::Entity::find_by_id(1).one(&db))
+        .unwrap()
+        .unwrap();
+    assert_eq!(model.status, Status::INACTIVE);
+    assert_eq!(model.previous, None);
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "serde")]
+
+use c_enum::c_enum;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(serde)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+c_enum! {
+    #[derive(Clone, PartialEq, Eq)]
+    #[c_enum(serde = "value")]
+    enum Name: &'static str {
+        A = "a",
+        B = "b",
+    }
+}
+
+#[test]
+fn serialize_value() {
+    assert_eq!(serde_json::to_string(&Event::INSTRUCTIONS).unwrap(), "1");
+    assert_eq!(serde_json::to_string(&Event(77)).unwrap(), "77");
+    assert_eq!(serde_json::to_string(&Name::B).unwrap(), "\"b\"");
+}
+
+#[test]
+fn deserialize_value() {
+    assert_eq!(
+        serde_json::from_str::<Event>("0").unwrap(),
+        Event::CPU_CYCLES
+    );
+    assert_eq!(serde_json::from_str::<Event>("77").unwrap(), Event(77));
+    assert_eq!(serde_json::from_str::<Name>("\"a\"").unwrap(), Name::A);
+    assert!(serde_json::from_str::<Event>("\"CPU_CYCLES\"").is_err());
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(serde = "name", rename_all = "snake_case")]
+    enum Named: i32 {
+        CPU_CYCLES,
+        INSTRUCTIONS = -1,
+    }
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(serde = "name", serde_unknown = "error")]
+    enum Strict: u8 {
+        A,
+        B,
+    }
+}
+
+#[test]
+fn serialize_name() {
+    assert_eq!(
+        serde_json::to_string(&Named::CPU_CYCLES).unwrap(),
+        "\"cpu_cycles\""
+    );
+    assert_eq!(serde_json::to_string(&Named(7)).unwrap(), "7");
+    assert_eq!(serde_json::to_string(&Strict::B).unwrap(), "\"B\"");
+    assert!(serde_json::to_string(&Strict(7)).is_err());
+}
+
+#[test]
+fn deserialize_name() {
+    assert_eq!(
+        serde_json::from_str::<Named>("\"instructions\"").unwrap(),
+        Named::INSTRUCTIONS
+    );
+    assert_eq!(
+        serde_json::from_str::<Named>("-1").unwrap(),
+        Named::INSTRUCTIONS
+    );
+    assert_eq!(serde_json::from_str::<Named>("7").unwrap(), Named(7));
+    assert!(serde_json::from_str::<Named>("\"CPU_CYCLES\"").is_err());
+    assert_eq!(serde_json::from_str::<Strict>("\"A\"").unwrap(), Strict::A);
+    assert!(serde_json::from_str::<Strict>("0").is_err());
+}
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(serde = "lenient")]
+    enum Lenient: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn lenient() {
+    assert_eq!(serde_json::to_string(&Lenient::INSTRUCTIONS).unwrap(), "1");
+    assert_eq!(
+        serde_json::from_str::<Lenient>("\"CPU_CYCLES\"").unwrap(),
+        Lenient::CPU_CYCLES
+    );
+    assert_eq!(
+        serde_json::from_str::<Lenient>("0").unwrap(),
+        Lenient::CPU_CYCLES
+    );
+    assert_eq!(serde_json::from_str::<Lenient>("12").unwrap(), Lenient(12));
+    assert!(serde_json::from_str::<Lenient>("\"OTHER\"").is_err());
+    assert!(serde_json::from_str::<Lenient>("-1").is_err());
+}
+
+mod with {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    c_enum! {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Plain: u32 {
+            CPU_CYCLES,
+            INSTRUCTIONS,
+        }
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Fields {
+        #[serde(with = "c_enum::as_name")]
+        name: Plain,
+        #[serde(with = "c_enum::as_value")]
+        value: Plain,
+    }
+
+    #[test]
+    fn serialize() {
+        let fields = Fields {
+            name: Plain::INSTRUCTIONS,
+            value: Plain::INSTRUCTIONS,
+        };
+        assert_eq!(
+            serde_json::to_string(&fields).unwrap(),
+            r#"{"name":"INSTRUCTIONS","value":1}"#
+        );
+
+        let fields = Fields {
+            name: Plain(77),
+            value: Plain(77),
+        };
+        assert_eq!(
+            serde_json::to_string(&fields).unwrap(),
+            r#"{"name":77,"value":77}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let fields: Fields = serde_json::from_str(r#"{"name":"CPU_CYCLES","value":1}"#).unwrap();
+        assert_eq!(fields.name, Plain::CPU_CYCLES);
+        assert_eq!(fields.value, Plain::INSTRUCTIONS);
+
+        let fields: Fields = serde_json::from_str(r#"{"name":77,"value":77}"#).unwrap();
+        assert_eq!(fields.name, Plain(77));
+
+        assert!(serde_json::from_str::<Fields>(r#"{"name":"OTHER","value":0}"#).is_err());
+        assert!(serde_json::from_str::<Fields>(r#"{"name":0,"value":"CPU_CYCLES"}"#).is_err());
+    }
+}
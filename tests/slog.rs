@@ -0,0 +1,50 @@
+#![cfg(feature = "slog")]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use c_enum::c_enum;
+use slog::{o, Drain, Key, Logger, Never, OwnedKVList, Record, Serializer, KV};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(slog)]
+    enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<String>>>);
+
+impl Serializer for Capture {
+    fn emit_arguments(&mut self, key: Key, val: &fmt::Arguments<'_>) -> slog::Result {
+        self.0.lock().unwrap().push(format!("{key}={val}"));
+        Ok(())
+    }
+}
+
+impl Drain for Capture {
+    type Ok = ();
+    type Err = Never;
+
+    fn log(&self, record: &Record<'_>, _: &OwnedKVList) -> Result<(), Never> {
+        record.kv().serialize(record, &mut self.clone()).unwrap();
+        Ok(())
+    }
+}
+
+#[test]
+fn value() {
+    let capture = Capture::default();
+    let logger = Logger::root(capture.clone(), o!());
+
+    slog::info!(logger, "sample"; "event" => Event::INSTRUCTIONS);
+    slog::info!(logger, "sample"; "event" => Event(200));
+
+    assert_eq!(
+        *capture.0.lock().unwrap(),
+        ["event=INSTRUCTIONS", "event=200"]
+    );
+}
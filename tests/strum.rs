@@ -0,0 +1,31 @@
+#![cfg(feature = "strum")]
+
+use c_enum::c_enum;
+use strum::{EnumCount, IntoEnumIterator, VariantArray, VariantNames};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(strum)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+fn names<T: VariantNames>() -> &'static [&'static str] {
+    T::VARIANTS
+}
+
+#[test]
+fn strum_traits() {
+    assert_eq!(<Event as EnumCount>::COUNT, 2);
+    assert_eq!(names::<Event>(), ["CPU_CYCLES", "INSTRUCTIONS"]);
+    assert_eq!(
+        <Event as VariantArray>::VARIANTS,
+        [Event::CPU_CYCLES, Event::INSTRUCTIONS]
+    );
+    assert_eq!(
+        Event::iter().rev().collect::<Vec<_>>(),
+        [Event::INSTRUCTIONS, Event::CPU_CYCLES]
+    );
+}
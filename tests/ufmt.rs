@@ -0,0 +1,27 @@
+#![cfg(feature = "ufmt")]
+
+use c_enum::c_enum;
+use ufmt::uwrite;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(ufmt, rename_all = "lowercase")]
+    enum Status: u8 {
+        OK,
+        BUSY,
+    }
+}
+
+#[test]
+fn display() {
+    let mut out = String::new();
+    uwrite!(out, "{} {}", Status::BUSY, Status(9)).unwrap();
+    assert_eq!(out, "busy 9");
+}
+
+#[test]
+fn debug() {
+    let mut out = String::new();
+    uwrite!(out, "{:?} {:?}", Status::OK, Status(9)).unwrap();
+    assert_eq!(out, "ok Status(9)");
+}
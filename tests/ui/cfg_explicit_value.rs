@@ -0,0 +1,12 @@
+use c_enum::c_enum;
+
+c_enum! {
+    pub enum Signal : u32 {
+        Interrupt,
+        #[cfg(unix)]
+        Quit = 10,
+        Kill,
+    }
+}
+
+fn main() {}
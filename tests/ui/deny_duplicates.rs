@@ -0,0 +1,12 @@
+use c_enum::c_enum;
+
+c_enum! {
+    #[c_enum(deny_duplicates)]
+    pub enum Registry : u16 {
+        First = 1,
+        Second = 2,
+        Third = 1,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use c_enum::c_enum;
+
+c_enum! {
+    #[c_enum(ord, ord)]
+    pub enum Priority : u8 {
+        Low,
+        High,
+    }
+}
+
+c_enum! {
+    #[c_enum(ord)]
+    #[derive(PartialOrd)]
+    pub enum Level : u8 {
+        Debug,
+        Info,
+    }
+}
+
+fn main() {}
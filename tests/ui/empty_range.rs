@@ -0,0 +1,10 @@
+use c_enum::c_enum;
+
+c_enum! {
+    pub enum Protocol : u8 {
+        TCP = 6,
+        RESERVED = 0..0,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use c_enum::c_enum;
+
+c_enum! {
+    #[c_enum(known = KnownSignal)]
+    pub enum Signal : u32 {
+        Interrupt,
+        /// Hangs up the terminal.
+        #[cfg(unix)]
+        Hangup,
+        Terminate,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use c_enum::c_enum;
+
+c_enum! {
+    pub enum Level : u8 {
+        HIGH = 254,
+        HIGHER,
+        HIGHEST,
+        OVERFLOW,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![cfg(feature = "utoipa")]
+
+use c_enum::c_enum;
+use utoipa::{PartialSchema, ToSchema};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(utoipa)]
+    enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS = 4,
+    }
+}
+
+#[test]
+fn schema() {
+    assert_eq!(Event::name(), "Event");
+
+    let schema = serde_json::to_value(Event::schema()).unwrap();
+    let inner = serde_json::to_value(u8::schema()).unwrap();
+
+    assert_eq!(schema["type"], inner["type"]);
+    assert_eq!(schema["minimum"], inner["minimum"]);
+    assert_eq!(
+        schema["description"],
+        "Known values:\n- `CPU_CYCLES`: `0`\n- `INSTRUCTIONS`: `4`\n"
+    );
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "valuable")]
+
+use c_enum::c_enum;
+use valuable::{Valuable, Value};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(valuable)]
+    enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn as_value() {
+    assert!(matches!(
+        Event::INSTRUCTIONS.as_value(),
+        Value::String("INSTRUCTIONS")
+    ));
+    assert!(matches!(Event(200).as_value(), Value::U8(200)));
+}
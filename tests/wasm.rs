@@ -0,0 +1,33 @@
+#![cfg(feature = "wasm-bindgen")]
+
+use c_enum::c_enum;
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi};
+use wasm_bindgen::prelude::*;
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(wasm_bindgen)]
+    pub enum Event: u8 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+// The enum can be used directly in exported functions.
+#[wasm_bindgen]
+pub fn next_event(event: Event, fallback: Option<Event>) -> Event {
+    match fallback {
+        Some(fallback) => fallback,
+        None => Event(event.0.wrapping_add(1)),
+    }
+}
+
+#[test]
+fn abi_roundtrip() {
+    let abi = Event::INSTRUCTIONS.into_abi();
+    assert_eq!(unsafe { Event::from_abi(abi) }, Event::INSTRUCTIONS);
+
+    let none = <Event as OptionIntoWasmAbi>::none();
+    assert!(<Event as OptionFromWasmAbi>::is_none(&none));
+    assert!(!<Event as OptionFromWasmAbi>::is_none(&abi));
+}
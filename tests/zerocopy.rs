@@ -0,0 +1,31 @@
+#![cfg(feature = "zerocopy")]
+
+use c_enum::c_enum;
+use zerocopy::{FromBytes, IntoBytes};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[repr(transparent)]
+    #[c_enum(zerocopy)]
+    enum Protocol: u8 {
+        TCP = 6,
+        UDP = 17,
+    }
+}
+
+#[derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable)]
+#[repr(C)]
+struct Header {
+    protocol: Protocol,
+    ttl: u8,
+}
+
+#[test]
+fn parse() {
+    let header = Header::read_from_bytes(&[17, 64][..]).unwrap();
+    assert_eq!(header.protocol, Protocol::UDP);
+    assert_eq!(header.ttl, 64);
+
+    assert_eq!(Protocol::TCP.as_bytes(), [6]);
+    assert_eq!(Protocol::read_from_bytes(&[99][..]).unwrap(), Protocol(99));
+}
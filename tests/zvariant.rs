@@ -0,0 +1,42 @@
+#![cfg(all(feature = "zvariant", feature = "serde"))]
+
+use c_enum::c_enum;
+use zvariant::serialized::Context;
+use zvariant::{OwnedValue, Type, Value, LE};
+
+c_enum! {
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    #[c_enum(serde, zvariant)]
+    enum Event: u32 {
+        CPU_CYCLES,
+        INSTRUCTIONS,
+    }
+}
+
+#[test]
+fn signature() {
+    assert_eq!(Event::SIGNATURE, u32::SIGNATURE);
+}
+
+#[test]
+fn value_conversion() {
+    let value = Value::from(Event::INSTRUCTIONS);
+    assert_eq!(value, Value::U32(1));
+    assert_eq!(Event::try_from(&value).unwrap(), Event::INSTRUCTIONS);
+    assert_eq!(Event::try_from(value).unwrap(), Event::INSTRUCTIONS);
+    assert!(Event::try_from(Value::U8(1)).is_err());
+
+    let owned = OwnedValue::from(Event(7));
+    assert_eq!(Event::try_from(&owned).unwrap(), Event(7));
+    assert_eq!(Event::try_from(owned).unwrap(), Event(7));
+}
+
+#[test]
+fn encoding() {
+    let ctxt = Context::new_dbus(LE, 0);
+    let encoded = zvariant::to_bytes(ctxt, &Event(7)).unwrap();
+    assert_eq!(&*encoded, &*zvariant::to_bytes(ctxt, &7u32).unwrap());
+
+    let (decoded, _) = encoded.deserialize::<Event>().unwrap();
+    assert_eq!(decoded, Event(7));
+}